@@ -0,0 +1,308 @@
+//! Archive abstraction for name-fix import/extraction.
+//!
+//! FM name fixes are distributed as ZIP, RAR, 7z, or tar.xz (the last increasingly common
+//! because xz's large compression window makes for much smaller downloads). [`ArchiveReader`]
+//! lets `name_fix`'s detection/extraction logic work identically regardless of which container
+//! the fix shipped in, and [`open_archive`] sniffs the container by magic bytes rather than
+//! trusting the file extension — the same approach `import::sniff_archive_format` takes for
+//! mod/graphics-pack archives, just over a different set of formats.
+
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// A name-fix archive, abstracted over its container format. Implementors hand back entries in
+/// a stable order; directory entries are included exactly as the underlying container reports
+/// them (tar yields directory entries too; the RAR backend keeps file entries only), so callers filter those out the same way they
+/// already do for `.lnc`/`.edt`/`.dbc`/`editor data/` membership.
+pub trait ArchiveReader {
+    /// Every entry's path within the archive, in archive order.
+    fn entry_names(&self) -> &[String];
+
+    /// The raw bytes of the entry at `index` (as ordered by [`ArchiveReader::entry_names`]).
+    fn read_entry(&mut self, index: usize) -> Result<Vec<u8>, String>;
+
+    /// Reads every entry in `indices` (order preserved in the returned `Vec`), using whatever
+    /// parallelism this container format can offer. The default just calls [`read_entry`](
+    /// ArchiveReader::read_entry) in a loop; [`ZipArchiveReader`] overrides it to fan the reads
+    /// out across rayon workers, since ZIP's cheap random access is the one backend where that
+    /// actually pays for itself.
+    fn read_entries_parallel(&mut self, indices: &[usize]) -> Result<Vec<Vec<u8>>, String> {
+        indices.iter().map(|&i| self.read_entry(i)).collect()
+    }
+}
+
+/// ZIP backend. Kept lazy (entries are read from disk on demand via `by_index`) since `zip`
+/// supports cheap random access, unlike the other formats below.
+struct ZipArchiveReader {
+    archive: zip::ZipArchive<fs::File>,
+    path: std::path::PathBuf,
+    names: Vec<String>,
+}
+
+impl ZipArchiveReader {
+    fn open(path: &Path) -> Result<Self, String> {
+        let file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+        let mut archive =
+            zip::ZipArchive::new(file).map_err(|e| format!("Failed to read ZIP archive: {}", e))?;
+
+        let mut names = Vec::with_capacity(archive.len());
+        for i in 0..archive.len() {
+            let entry = archive
+                .by_index(i)
+                .map_err(|e| format!("Failed to read ZIP entry {}: {}", i, e))?;
+            names.push(entry.name().to_string());
+        }
+
+        Ok(Self {
+            archive,
+            path: path.to_path_buf(),
+            names,
+        })
+    }
+}
+
+impl ArchiveReader for ZipArchiveReader {
+    fn entry_names(&self) -> &[String] {
+        &self.names
+    }
+
+    fn read_entry(&mut self, index: usize) -> Result<Vec<u8>, String> {
+        let mut entry = self
+            .archive
+            .by_index(index)
+            .map_err(|e| format!("Failed to read ZIP entry {}: {}", index, e))?;
+        let mut contents = Vec::new();
+        entry
+            .read_to_end(&mut contents)
+            .map_err(|e| format!("Failed to read file from archive: {}", e))?;
+        Ok(contents)
+    }
+
+    /// `zip::ZipArchive<fs::File>` can't be shared across threads, so each rayon worker reopens
+    /// the file itself (once, not per entry) and claims disjoint indices from a shared atomic
+    /// cursor until `indices` is exhausted. Errors are collected into the first one encountered
+    /// in `indices` order, so the reported failure doesn't depend on which worker happened to
+    /// hit it first.
+    fn read_entries_parallel(&mut self, indices: &[usize]) -> Result<Vec<Vec<u8>>, String> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Mutex;
+
+        let total = indices.len();
+        if total == 0 {
+            return Ok(Vec::new());
+        }
+
+        let slots: Vec<Mutex<Option<Vec<u8>>>> = (0..total).map(|_| Mutex::new(None)).collect();
+        let next_slot = AtomicUsize::new(0);
+        // Keyed by position in `indices` so the lowest-positioned failure wins no matter which
+        // worker records it first; reopen failures aren't tied to an entry and rank last.
+        let first_error: Mutex<Option<(usize, String)>> = Mutex::new(None);
+        let record_error = |pos: usize, message: String| {
+            let mut slot = first_error.lock().unwrap();
+            if slot.as_ref().map_or(true, |(existing, _)| pos < *existing) {
+                *slot = Some((pos, message));
+            }
+        };
+        let worker_count = rayon::current_num_threads().min(total).max(1);
+
+        rayon::scope(|scope| {
+            for _ in 0..worker_count {
+                scope.spawn(|_| {
+                    let file = match fs::File::open(&self.path) {
+                        Ok(f) => f,
+                        Err(e) => {
+                            record_error(usize::MAX, format!("Failed to reopen archive: {}", e));
+                            return;
+                        }
+                    };
+                    let mut archive = match zip::ZipArchive::new(file) {
+                        Ok(a) => a,
+                        Err(e) => {
+                            record_error(usize::MAX, format!("Failed to read ZIP archive: {}", e));
+                            return;
+                        }
+                    };
+
+                    loop {
+                        let slot_idx = next_slot.fetch_add(1, Ordering::Relaxed);
+                        if slot_idx >= total {
+                            return;
+                        }
+
+                        let entry_result = archive
+                            .by_index(indices[slot_idx])
+                            .map_err(|e| format!("Failed to read ZIP entry {}: {}", indices[slot_idx], e))
+                            .and_then(|mut entry| {
+                                let mut contents = Vec::new();
+                                entry
+                                    .read_to_end(&mut contents)
+                                    .map_err(|e| format!("Failed to read file from archive: {}", e))?;
+                                Ok(contents)
+                            });
+
+                        match entry_result {
+                            Ok(contents) => *slots[slot_idx].lock().unwrap() = Some(contents),
+                            Err(e) => {
+                                record_error(slot_idx, e);
+                                return;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        if let Some((_, error)) = first_error.into_inner().unwrap() {
+            return Err(error);
+        }
+
+        Ok(slots
+            .into_iter()
+            .map(|slot| slot.into_inner().unwrap().expect("every slot was filled or an error was returned"))
+            .collect())
+    }
+}
+
+/// Backend for every non-ZIP format. RAR, 7z, and tar.xz don't offer `zip`'s cheap
+/// random-by-index access, so their entries are decompressed eagerly at open time and kept in
+/// memory — name fixes are small (at most a few hundred KB of .lnc/.edt/.dbc/editor-data files),
+/// so there's no real cost to this, and it lets every such format share one `ArchiveReader` impl.
+struct InMemoryArchiveReader {
+    names: Vec<String>,
+    contents: Vec<Vec<u8>>,
+}
+
+impl ArchiveReader for InMemoryArchiveReader {
+    fn entry_names(&self) -> &[String] {
+        &self.names
+    }
+
+    fn read_entry(&mut self, index: usize) -> Result<Vec<u8>, String> {
+        self.contents
+            .get(index)
+            .cloned()
+            .ok_or_else(|| format!("Archive entry index {} out of range", index))
+    }
+}
+
+fn open_sevenz(path: &Path) -> Result<InMemoryArchiveReader, String> {
+    let mut names = Vec::new();
+    let mut contents = Vec::new();
+
+    let mut reader = sevenz_rust::SevenZReader::open(path, sevenz_rust::Password::empty())
+        .map_err(|e| format!("Failed to open 7z archive: {}", e))?;
+
+    reader
+        .for_each_entries(|entry, entry_reader| {
+            let mut buf = Vec::new();
+            entry_reader.read_to_end(&mut buf)?;
+            names.push(entry.name().to_string());
+            contents.push(buf);
+            Ok(true)
+        })
+        .map_err(|e| format!("Failed to read 7z archive entries: {}", e))?;
+
+    Ok(InMemoryArchiveReader { names, contents })
+}
+
+fn open_rar(path: &Path) -> Result<InMemoryArchiveReader, String> {
+    let mut names = Vec::new();
+    let mut contents = Vec::new();
+
+    let mut archive = unrar::Archive::new(path)
+        .open_for_processing()
+        .map_err(|e| format!("Failed to open RAR archive: {}", e))?;
+
+    while let Some(header) = archive
+        .read_header()
+        .map_err(|e| format!("Failed to read RAR entry header: {}", e))?
+    {
+        if header.entry().is_file() {
+            let name = header.entry().filename.to_string_lossy().to_string();
+            let (data, rest) = header
+                .read()
+                .map_err(|e| format!("Failed to read RAR entry: {}", e))?;
+            names.push(name);
+            contents.push(data);
+            archive = rest;
+        } else {
+            archive = header
+                .skip()
+                .map_err(|e| format!("Failed to skip RAR entry: {}", e))?;
+        }
+    }
+
+    Ok(InMemoryArchiveReader { names, contents })
+}
+
+fn open_tar_xz(path: &Path) -> Result<InMemoryArchiveReader, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let decoder = xz2::read::XzDecoder::new(file);
+    let mut archive = tar::Archive::new(decoder);
+
+    let mut names = Vec::new();
+    let mut contents = Vec::new();
+
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Failed to read tar.xz entries: {}", e))?
+    {
+        let mut entry = entry.map_err(|e| format!("Failed to read tar.xz entry: {}", e))?;
+        let name = entry
+            .path()
+            .map_err(|e| format!("Failed to read tar.xz entry path: {}", e))?
+            .to_string_lossy()
+            .into_owned();
+        let mut buf = Vec::new();
+        entry
+            .read_to_end(&mut buf)
+            .map_err(|e| format!("Failed to read tar.xz entry contents: {}", e))?;
+        names.push(name);
+        contents.push(buf);
+    }
+
+    Ok(InMemoryArchiveReader { names, contents })
+}
+
+/// Container formats [`open_archive`] understands, detected by sniffing magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameFixArchiveFormat {
+    Zip,
+    SevenZ,
+    Rar,
+    TarXz,
+}
+
+/// Sniffs `path`'s container format from its leading magic bytes, so a renamed or
+/// extension-less name-fix archive still opens correctly.
+fn sniff_format(path: &Path) -> Result<NameFixArchiveFormat, String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut header = [0u8; 8];
+    let n = file
+        .read(&mut header)
+        .map_err(|e| format!("Failed to read archive header: {}", e))?;
+    let header = &header[..n];
+
+    if header.starts_with(b"PK\x03\x04") || header.starts_with(b"PK\x05\x06") {
+        Ok(NameFixArchiveFormat::Zip)
+    } else if header.starts_with(b"7z\xBC\xAF\x27\x1C") {
+        Ok(NameFixArchiveFormat::SevenZ)
+    } else if header.starts_with(b"Rar!\x1A\x07") {
+        Ok(NameFixArchiveFormat::Rar)
+    } else if header.starts_with(b"\xFD7zXZ\x00") {
+        Ok(NameFixArchiveFormat::TarXz)
+    } else {
+        Err("Unrecognized archive format (expected zip, rar, 7z, or tar.xz)".to_string())
+    }
+}
+
+/// Opens `path` as whichever [`ArchiveReader`] backend matches its sniffed container format.
+pub fn open_archive(path: &Path) -> Result<Box<dyn ArchiveReader>, String> {
+    match sniff_format(path)? {
+        NameFixArchiveFormat::Zip => Ok(Box::new(ZipArchiveReader::open(path)?)),
+        NameFixArchiveFormat::SevenZ => Ok(Box::new(open_sevenz(path)?)),
+        NameFixArchiveFormat::Rar => Ok(Box::new(open_rar(path)?)),
+        NameFixArchiveFormat::TarXz => Ok(Box::new(open_tar_xz(path)?)),
+    }
+}
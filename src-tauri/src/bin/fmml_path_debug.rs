@@ -1,62 +1,57 @@
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::{generate, Shell};
 use fmmloader26::{config, mod_manager, types::FileEntry};
-use std::env;
+use std::io;
 use std::path::PathBuf;
 
-fn parse_args() -> (Vec<String>, Option<String>, Option<String>, Vec<String>) {
-    let mut mod_types = Vec::new();
-    let mut target_path = None;
-    let mut user_dir = None;
-    let mut files = Vec::new();
-
-    let mut args = env::args().skip(1);
-    while let Some(arg) = args.next() {
-        match arg.as_str() {
-            "--mod-type" | "-m" => {
-                if let Some(value) = args.next() {
-                    mod_types.push(value);
-                }
-            }
-            "--file" | "-f" => {
-                if let Some(value) = args.next() {
-                    files.push(value);
-                }
-            }
-            "--target-path" | "-t" => {
-                if let Some(value) = args.next() {
-                    target_path = Some(value);
-                }
-            }
-            "--user-dir" | "-u" => {
-                if let Some(value) = args.next() {
-                    user_dir = Some(value);
-                }
-            }
-            "--help" | "-h" => {
-                println!(
-                    "Usage: fmml-path-debug [options]\n\n\
-                     Options:\n  \
-                     -m, --mod-type <type>    Mod type to preview (repeatable)\n  \
-                     -t, --target-path <path> Override game target path\n  \
-                     -u, --user-dir <path>    Override FM user directory\n  \
-                     -f, --file <subpath>     Target subpath to preview (repeatable)\n"
-                );
-                std::process::exit(0);
-            }
-            _ => {}
-        }
-    }
+/// Previews where FMMLoader would resolve each target file for a given mod type, without
+/// actually having a mod installed — a quick way to sanity-check
+/// `mod_manager::get_target_for_type`/`preview_mod_install` against the configured (or
+/// overridden) game target and user directory.
+#[derive(Parser)]
+#[command(
+    name = "fmml-path-debug",
+    version,
+    about = "Preview FMMLoader install paths without installing a mod"
+)]
+struct Cli {
+    /// Mod type to preview (repeatable). Defaults to bundle, ui, graphics, tactics, editor-data.
+    #[arg(short = 'm', long = "mod-type")]
+    mod_type: Vec<String>,
+
+    /// Override the configured game target path.
+    #[arg(short = 't', long = "target-path")]
+    target_path: Option<String>,
+
+    /// Override the configured FM user directory.
+    #[arg(short = 'u', long = "user-dir")]
+    user_dir: Option<String>,
+
+    /// Target subpath to preview (repeatable). Defaults to each mod type's sample files.
+    #[arg(short = 'f', long = "file")]
+    file: Vec<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
 
-    if mod_types.is_empty() {
-        mod_types = vec![
-            "bundle".to_string(),
-            "ui".to_string(),
-            "graphics".to_string(),
-            "tactics".to_string(),
-            "editor-data".to_string(),
-        ];
-    }
+#[derive(Subcommand)]
+enum Command {
+    /// Emit a shell-completion script for this binary on stdout. Hidden: only the handful of
+    /// users wiring up their own completions need it, not part of the everyday preview workflow.
+    #[command(hide = true)]
+    Completions {
+        shell: CompletionShell,
+    },
+}
 
-    (mod_types, target_path, user_dir, files)
+#[derive(Clone, Copy, ValueEnum)]
+enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+    Nushell,
 }
 
 fn ensure_dir(path: &PathBuf) {
@@ -80,8 +75,38 @@ fn default_files_for(mod_type: &str) -> Vec<String> {
     }
 }
 
+fn print_completions(shell: CompletionShell) {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    match shell {
+        CompletionShell::Bash => generate(Shell::Bash, &mut cmd, name, &mut io::stdout()),
+        CompletionShell::Zsh => generate(Shell::Zsh, &mut cmd, name, &mut io::stdout()),
+        CompletionShell::Fish => generate(Shell::Fish, &mut cmd, name, &mut io::stdout()),
+        CompletionShell::PowerShell => generate(Shell::PowerShell, &mut cmd, name, &mut io::stdout()),
+        CompletionShell::Nushell => {
+            generate(clap_complete_nushell::Nushell, &mut cmd, name, &mut io::stdout())
+        }
+    }
+}
+
 fn main() {
-    let (mod_types, target_override, user_override, file_subpaths) = parse_args();
+    let cli = Cli::parse();
+
+    if let Some(Command::Completions { shell }) = cli.command {
+        print_completions(shell);
+        return;
+    }
+
+    let mut mod_types = cli.mod_type;
+    if mod_types.is_empty() {
+        mod_types = vec![
+            "bundle".to_string(),
+            "ui".to_string(),
+            "graphics".to_string(),
+            "tactics".to_string(),
+            "editor-data".to_string(),
+        ];
+    }
 
     let config = match config::load_config() {
         Ok(cfg) => cfg,
@@ -91,19 +116,18 @@ fn main() {
         }
     };
 
-    let target_path = target_override
+    let target_path = cli
+        .target_path
         .or(config.target_path.clone())
         .unwrap_or_else(|| {
             eprintln!("No target path set. Provide --target-path or set it in config.");
             std::process::exit(1);
         });
 
-    let user_dir = user_override
-        .or(config.user_dir_path.clone())
-        .inspect(|path| {
-            let buf = PathBuf::from(path);
-            ensure_dir(&buf);
-        });
+    let user_dir = cli.user_dir.or(config.user_dir_path.clone()).inspect(|path| {
+        let buf = PathBuf::from(path);
+        ensure_dir(&buf);
+    });
 
     let game_target = PathBuf::from(&target_path);
     ensure_dir(&game_target);
@@ -118,10 +142,10 @@ fn main() {
     println!();
 
     for mod_type in mod_types {
-        let targets = if file_subpaths.is_empty() {
+        let targets = if cli.file.is_empty() {
             default_files_for(&mod_type)
         } else {
-            file_subpaths.clone()
+            cli.file.clone()
         };
 
         let files: Vec<FileEntry> = targets
@@ -130,11 +154,18 @@ fn main() {
                 source: subpath.clone(),
                 target_subpath: subpath.clone(),
                 platform: None,
+                install_mode: None,
+                sha256: None,
             })
             .collect();
 
-        let preview =
-            mod_manager::preview_mod_install(&mod_type, &game_target, user_dir.as_deref(), &files);
+        let preview = mod_manager::preview_mod_install(
+            &mod_type,
+            &mod_type,
+            &game_target,
+            user_dir.as_deref(),
+            &files,
+        );
 
         println!("Mod type: {}", mod_type);
         println!("  Base: {}", preview.base_target);
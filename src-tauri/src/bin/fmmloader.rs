@@ -0,0 +1,65 @@
+//! `fmmloader` CLI - currently just the `new` scaffolding subcommand.
+//!
+//! Usage: `fmmloader new <type> [dest]`
+//!
+//! `<type>` is one of the templates in [`fmmloader26::scaffold::Template`] (`ui`, `tactics`,
+//! `bundle`). `dest` defaults to the current directory.
+
+use fmmloader26::scaffold::{scaffold, Template};
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn print_usage() {
+    let templates = Template::all()
+        .iter()
+        .map(|t| t.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!(
+        "Usage: fmmloader new <type> [dest]\n\n\
+         Types: {}\n\n\
+         Creates a ready-to-fill mod skeleton at <dest> (default: current directory).",
+        templates
+    );
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("new") => {
+            let Some(type_arg) = args.next() else {
+                eprintln!("Error: missing <type> argument");
+                print_usage();
+                std::process::exit(1);
+            };
+
+            let template = match Template::from_str(&type_arg) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            let dest = args
+                .next()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| env::current_dir().expect("Failed to read current directory"));
+
+            if let Err(e) = scaffold(template, &dest) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+
+            println!("Scaffolded '{}' mod at {}", template, dest.display());
+        }
+        Some("--help") | Some("-h") | None => print_usage(),
+        Some(other) => {
+            eprintln!("Error: unknown subcommand '{}'", other);
+            print_usage();
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,14 +1,39 @@
-use crate::types::{Config, GraphicsPackMetadata, GraphicsPacksRegistry};
+use crate::types::{
+    BackupCompression, BackupMode, Config, GraphicsPackMetadata, GraphicsPacksRegistry,
+    InstallMode, LineEndingStyle, NameFixBackupCompression, NameFixBackupMode, OwnershipIndex,
+    Profile,
+};
 use std::env;
 use std::fs;
 use std::path::PathBuf;
 
-pub fn get_app_data_dir() -> PathBuf {
-    if let Ok(override_dir) = env::var("FMML_TEST_APPDATA") {
-        let path = PathBuf::from(override_dir);
-        if !path.as_os_str().is_empty() {
-            return path;
-        }
+/// Dropping this file beside the executable switches the loader to portable mode: config, name
+/// fixes, and backups are kept in a folder next to the executable instead of the user's roaming
+/// profile, so the whole install is self-contained and can live on a USB stick.
+pub(crate) const PORTABLE_MARKER_FILE: &str = "fmmloader.portable";
+
+/// If the portable marker sits next to the running executable, returns the data directory that
+/// should be used in its place. `pub(crate)` (rather than private) so
+/// [`crate::game_detection::get_fm_user_dir`] can also redirect the game's own user-data folder
+/// into the portable directory, not just the loader's own config/cache/backups.
+pub(crate) fn portable_data_dir() -> Option<PathBuf> {
+    let exe_dir = env::current_exe().ok()?.parent()?.to_path_buf();
+    if exe_dir.join(PORTABLE_MARKER_FILE).exists() {
+        Some(exe_dir.join("FMMLoader26"))
+    } else {
+        None
+    }
+}
+
+/// Resolves the loader's own config/cache/backup directory: the portable directory next to the
+/// executable if [`PORTABLE_MARKER_FILE`] is present there, otherwise the OS-standard
+/// Documents/Application Support/.local location. Encapsulates exactly the marker check and
+/// per-OS fallback, with no test-only override, so other modules (like `game_detection`) can
+/// reuse the same resolution without pulling in `get_app_data_dir`'s `FMML_TEST_APPDATA` escape
+/// hatch.
+pub fn resolve_app_data_dir() -> PathBuf {
+    if let Some(portable_dir) = portable_data_dir() {
+        return portable_dir;
     }
 
     let app_name = "FMMLoader26";
@@ -44,6 +69,17 @@ pub fn get_app_data_dir() -> PathBuf {
     }
 }
 
+pub fn get_app_data_dir() -> PathBuf {
+    if let Ok(override_dir) = env::var("FMML_TEST_APPDATA") {
+        let path = PathBuf::from(override_dir);
+        if !path.as_os_str().is_empty() {
+            return path;
+        }
+    }
+
+    resolve_app_data_dir()
+}
+
 pub fn init_storage() -> Result<(), String> {
     let base_dir = get_app_data_dir();
 
@@ -54,6 +90,7 @@ pub fn init_storage() -> Result<(), String> {
         base_dir.join("logs"),
         base_dir.join("restore_points"),
         base_dir.join("name_fixes"),
+        base_dir.join("receipts"),
     ];
 
     for dir in dirs {
@@ -67,6 +104,20 @@ pub fn get_config_path() -> PathBuf {
     get_app_data_dir().join("config.json")
 }
 
+/// Id of the profile synthesized from a flat `enabled_mods` list on first load. Stable so
+/// configs written before profiles existed always migrate onto the same id.
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+fn default_profile(enabled_mods: Vec<String>, active_name_fix: Option<String>) -> Profile {
+    Profile {
+        id: DEFAULT_PROFILE_ID.to_string(),
+        name: "Default".to_string(),
+        enabled_mods,
+        active_name_fix,
+        target_path_override: None,
+    }
+}
+
 pub fn load_config() -> Result<Config, String> {
     let config_path = get_config_path();
 
@@ -78,13 +129,39 @@ pub fn load_config() -> Result<Config, String> {
             dark_mode: false,
             language: None,
             active_name_fix: None,
+            default_install_mode: InstallMode::default(),
+            default_backup_mode: BackupMode::default(),
+            backup_compression: BackupCompression::default(),
+            backup_compression_level: 0,
+            name_fix_backup_mode: NameFixBackupMode::default(),
+            name_fix_backup_compression: NameFixBackupCompression::default(),
+            name_fix_backup_compression_level: 0,
+            name_fix_backup_retention: 10,
+            name_fix_line_ending: LineEndingStyle::default(),
+            name_fix_stack: Vec::new(),
+            active_profile: DEFAULT_PROFILE_ID.to_string(),
+            profiles: vec![default_profile(Vec::new(), None)],
+            launch_args: Vec::new(),
+            launch_via_steam: false,
+            steam_app_id: None,
         });
     }
 
     let contents =
         fs::read_to_string(&config_path).map_err(|e| format!("Failed to read config: {}", e))?;
 
-    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse config: {}", e))
+    let mut config: Config =
+        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse config: {}", e))?;
+
+    // Migrate configs written before profiles existed: wrap the flat `enabled_mods` list in a
+    // single "Default" profile so they keep working without the user losing their setup.
+    if config.profiles.is_empty() {
+        let migrated = default_profile(config.enabled_mods.clone(), config.active_name_fix.clone());
+        config.active_profile = migrated.id.clone();
+        config.profiles.push(migrated);
+    }
+
+    Ok(config)
 }
 
 pub fn save_config(config: &Config) -> Result<(), String> {
@@ -110,6 +187,17 @@ pub fn get_restore_points_dir() -> PathBuf {
     get_app_data_dir().join("restore_points")
 }
 
+pub fn get_receipts_dir() -> PathBuf {
+    get_app_data_dir().join("receipts")
+}
+
+/// Where [`crate::mod_manager::set_mod_enabled`] parks a disabled mod's installed files
+/// (under a per-mod subdirectory) while it's toggled off, so re-enabling is a move back rather
+/// than a full reinstall.
+pub fn get_staging_dir() -> PathBuf {
+    get_app_data_dir().join("staging")
+}
+
 #[allow(dead_code)]
 pub fn get_logs_dir() -> PathBuf {
     get_app_data_dir().join("logs")
@@ -157,6 +245,32 @@ pub fn add_graphics_pack(metadata: GraphicsPackMetadata) -> Result<(), String> {
     Ok(())
 }
 
+pub fn get_ownership_index_path() -> PathBuf {
+    get_app_data_dir().join("ownership_index.json")
+}
+
+pub fn load_ownership_index() -> Result<OwnershipIndex, String> {
+    let path = get_ownership_index_path();
+
+    if !path.exists() {
+        return Ok(OwnershipIndex::default());
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read ownership index: {}", e))?;
+
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse ownership index: {}", e))
+}
+
+pub fn save_ownership_index(index: &OwnershipIndex) -> Result<(), String> {
+    let path = get_ownership_index_path();
+
+    let json = serde_json::to_string_pretty(index)
+        .map_err(|e| format!("Failed to serialize ownership index: {}", e))?;
+
+    fs::write(&path, json).map_err(|e| format!("Failed to write ownership index: {}", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,11 +296,29 @@ mod tests {
         assert_eq!(get_mods_dir(), base.join("mods"));
         assert_eq!(get_backup_dir(), base.join("backups"));
         assert_eq!(get_restore_points_dir(), base.join("restore_points"));
+        assert_eq!(get_receipts_dir(), base.join("receipts"));
+        assert_eq!(get_staging_dir(), base.join("staging"));
         assert_eq!(get_logs_dir(), base.join("logs"));
         assert_eq!(get_name_fixes_dir(), base.join("name_fixes"));
         assert_eq!(get_graphics_packs_path(), base.join("graphics_packs.json"));
+        assert_eq!(
+            get_ownership_index_path(),
+            base.join("ownership_index.json")
+        );
 
         env::remove_var("FMML_TEST_APPDATA");
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn portable_marker_redirects_app_data_dir_next_to_exe() {
+        let exe_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+        let marker = exe_dir.join(PORTABLE_MARKER_FILE);
+        fs::write(&marker, "").unwrap();
+
+        let base = get_app_data_dir();
+        let _ = fs::remove_file(&marker);
+
+        assert_eq!(base, exe_dir.join("FMMLoader26"));
+    }
 }
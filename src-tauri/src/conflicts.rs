@@ -1,8 +1,12 @@
 use crate::config::get_mods_dir;
 use crate::game_detection::get_fm_user_dir;
 use crate::mod_manager::{get_target_for_type, read_manifest};
-use crate::types::ConflictInfo;
-use std::collections::HashMap;
+use crate::types::{
+    ConflictClassification, ConflictInfo, ConflictResolution, ContentConflictReport,
+    DuplicateGroup, FileHashEntry, HashConflictGroup, ModContentHash,
+};
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::PathBuf;
 
 pub fn find_conflicts(
@@ -11,7 +15,7 @@ pub fn find_conflicts(
     user_dir: Option<&str>,
 ) -> Result<Vec<ConflictInfo>, String> {
     let mods_dir = get_mods_dir();
-    let mut file_to_mods: HashMap<String, Vec<String>> = HashMap::new();
+    let mut file_to_mods: HashMap<String, Vec<(String, PathBuf)>> = HashMap::new();
 
     // Build index of which mods touch which files
     for mod_name in enabled_mods {
@@ -31,11 +35,12 @@ pub fn find_conflicts(
         for file_entry in &manifest.files {
             let target_path = target_base.join(&file_entry.target_subpath);
             let target_str = target_path.to_string_lossy().to_string();
+            let source_path = mod_dir.join(&file_entry.source);
 
             file_to_mods
                 .entry(target_str)
                 .or_insert_with(Vec::new)
-                .push(mod_name.clone());
+                .push((mod_name.clone(), source_path));
         }
     }
 
@@ -44,9 +49,30 @@ pub fn find_conflicts(
 
     for (file_path, mods) in file_to_mods {
         if mods.len() > 1 {
+            let content_hashes: Vec<ModContentHash> = mods
+                .iter()
+                .filter_map(|(mod_name, source_path)| {
+                    let bytes = fs::read(source_path).ok()?;
+                    Some(ModContentHash {
+                        mod_name: mod_name.clone(),
+                        hash: blake3::hash(&bytes).to_hex().to_string(),
+                    })
+                })
+                .collect();
+
+            let distinct_hashes: HashSet<&str> =
+                content_hashes.iter().map(|h| h.hash.as_str()).collect();
+            let classification = if distinct_hashes.len() <= 1 {
+                ConflictClassification::Identical
+            } else {
+                ConflictClassification::Divergent
+            };
+
             conflicts.push(ConflictInfo {
                 file_path,
-                conflicting_mods: mods,
+                conflicting_mods: mods.into_iter().map(|(mod_name, _)| mod_name).collect(),
+                content_hashes,
+                classification,
             });
         }
     }
@@ -54,6 +80,39 @@ pub fn find_conflicts(
     Ok(conflicts)
 }
 
+/// Resolves each `Divergent` conflict via last-enabled-wins: the conflicting mod that sits
+/// latest in `enabled_mods` order is the winner, same as a mod manager applying mods in list
+/// order. `Identical` conflicts are skipped since there's nothing to resolve.
+pub fn resolve_conflicts(
+    conflicts: &[ConflictInfo],
+    enabled_mods: &[String],
+) -> Vec<ConflictResolution> {
+    conflicts
+        .iter()
+        .filter(|c| c.classification == ConflictClassification::Divergent)
+        .filter_map(|c| {
+            let winning_mod = enabled_mods
+                .iter()
+                .rev()
+                .find(|m| c.conflicting_mods.contains(m))?
+                .clone();
+
+            let shadowed_mods = c
+                .conflicting_mods
+                .iter()
+                .filter(|m| **m != winning_mod)
+                .cloned()
+                .collect();
+
+            Some(ConflictResolution {
+                file_path: c.file_path.clone(),
+                winning_mod,
+                shadowed_mods,
+            })
+        })
+        .collect()
+}
+
 pub fn build_mod_index(mod_name: &str) -> Result<Vec<String>, String> {
     let mod_dir = get_mods_dir().join(mod_name);
 
@@ -70,3 +129,227 @@ pub fn build_mod_index(mod_name: &str) -> Result<Vec<String>, String> {
 
     Ok(files)
 }
+
+/// Hashes every file the enabled mods ship and reports hard conflicts (different mods
+/// claiming the same target path with different content) and duplicates (byte-identical
+/// files shipped by more than one mod under different target paths), so the caller can warn
+/// before install.
+///
+/// Unlike [`find_conflicts`], which lists every target path claimed by more than one mod (as
+/// identical or divergent), this leaves out shared targets whose bytes match, so two mods that
+/// happen to overlap but ship the same file don't get flagged.
+pub fn find_content_conflicts(
+    enabled_mods: &[String],
+    game_target: &PathBuf,
+    user_dir: Option<&str>,
+) -> Result<ContentConflictReport, String> {
+    let mods_dir = get_mods_dir();
+    let mut entries = Vec::new();
+
+    for mod_name in enabled_mods {
+        let mod_dir = mods_dir.join(mod_name);
+
+        if !mod_dir.exists() {
+            continue;
+        }
+
+        let manifest = match read_manifest(&mod_dir) {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+
+        let target_base = get_target_for_type(&manifest.mod_type, game_target, user_dir);
+
+        for file_entry in &manifest.files {
+            let source_path = mod_dir.join(&file_entry.source);
+
+            let bytes = match fs::read(&source_path) {
+                Ok(b) => b,
+                Err(_) => continue,
+            };
+
+            let target_path = target_base
+                .join(&file_entry.target_subpath)
+                .to_string_lossy()
+                .to_string();
+
+            entries.push(FileHashEntry {
+                mod_name: mod_name.clone(),
+                target_path,
+                hash: blake3::hash(&bytes).to_hex().to_string(),
+                size: bytes.len() as u64,
+            });
+        }
+    }
+
+    Ok(group_content_conflicts(&entries))
+}
+
+/// Groups already-hashed file entries into hard conflicts and duplicates. Split out from
+/// [`find_content_conflicts`] so the grouping logic can be exercised without touching disk.
+fn group_content_conflicts(entries: &[FileHashEntry]) -> ContentConflictReport {
+    // One pass fills both maps; duplicates are keyed by (size, hash) instead of hash alone,
+    // the size+hash key a classic duplicate-finder uses.
+    let mut by_target: HashMap<&str, Vec<&FileHashEntry>> = HashMap::new();
+    let mut by_size_hash: HashMap<(u64, &str), Vec<&FileHashEntry>> = HashMap::new();
+
+    for entry in entries {
+        by_target
+            .entry(entry.target_path.as_str())
+            .or_default()
+            .push(entry);
+        by_size_hash
+            .entry((entry.size, entry.hash.as_str()))
+            .or_default()
+            .push(entry);
+    }
+
+    let mut hard_conflicts: Vec<HashConflictGroup> = by_target
+        .into_iter()
+        .filter_map(|(target_path, group)| {
+            let distinct_hashes: HashSet<&str> = group.iter().map(|e| e.hash.as_str()).collect();
+            if group.len() > 1 && distinct_hashes.len() > 1 {
+                Some(HashConflictGroup {
+                    target_path: target_path.to_string(),
+                    entries: group.into_iter().cloned().collect(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    hard_conflicts.sort_by(|a, b| a.target_path.cmp(&b.target_path));
+
+    let mut duplicates: Vec<DuplicateGroup> = by_size_hash
+        .into_iter()
+        .filter_map(|((size, hash), group)| {
+            let distinct_mods: HashSet<&str> = group.iter().map(|e| e.mod_name.as_str()).collect();
+            let distinct_targets: HashSet<&str> =
+                group.iter().map(|e| e.target_path.as_str()).collect();
+            if group.len() > 1 && distinct_mods.len() > 1 && distinct_targets.len() > 1 {
+                Some(DuplicateGroup {
+                    hash: hash.to_string(),
+                    size,
+                    files: group.into_iter().cloned().collect(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    duplicates.sort_by(|a, b| a.hash.cmp(&b.hash));
+
+    ContentConflictReport {
+        hard_conflicts,
+        duplicates,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(mod_name: &str, target_path: &str, hash: &str, size: u64) -> FileHashEntry {
+        FileHashEntry {
+            mod_name: mod_name.to_string(),
+            target_path: target_path.to_string(),
+            hash: hash.to_string(),
+            size,
+        }
+    }
+
+    #[test]
+    fn flags_differing_hashes_at_same_target_as_hard_conflict() {
+        let entries = vec![
+            entry("mod-a", "graphics/faces/1.png", "aaa", 100),
+            entry("mod-b", "graphics/faces/1.png", "bbb", 100),
+        ];
+
+        let report = group_content_conflicts(&entries);
+        assert_eq!(report.hard_conflicts.len(), 1);
+        assert_eq!(report.hard_conflicts[0].target_path, "graphics/faces/1.png");
+        assert!(report.duplicates.is_empty());
+    }
+
+    #[test]
+    fn flags_matching_hashes_across_mods_as_duplicates() {
+        let entries = vec![
+            entry("mod-a", "graphics/faces/1.png", "aaa", 100),
+            entry("mod-b", "graphics/logos/2.png", "aaa", 100),
+        ];
+
+        let report = group_content_conflicts(&entries);
+        assert!(report.hard_conflicts.is_empty());
+        assert_eq!(report.duplicates.len(), 1);
+        assert_eq!(report.duplicates[0].files.len(), 2);
+    }
+
+    #[test]
+    fn ignores_same_mod_shipping_the_same_file_twice() {
+        let entries = vec![
+            entry("mod-a", "graphics/faces/1.png", "aaa", 100),
+            entry("mod-a", "graphics/faces/1_copy.png", "aaa", 100),
+        ];
+
+        let report = group_content_conflicts(&entries);
+        assert!(report.hard_conflicts.is_empty());
+        assert!(report.duplicates.is_empty());
+    }
+
+    #[test]
+    fn same_target_same_hash_is_neither_conflict_nor_duplicate() {
+        let entries = vec![
+            entry("mod-a", "graphics/faces/1.png", "aaa", 100),
+            entry("mod-b", "graphics/faces/1.png", "aaa", 100),
+        ];
+
+        let report = group_content_conflicts(&entries);
+        assert!(report.hard_conflicts.is_empty());
+        assert!(report.duplicates.is_empty());
+    }
+
+    fn conflict(
+        file_path: &str,
+        mods: &[&str],
+        classification: ConflictClassification,
+    ) -> ConflictInfo {
+        ConflictInfo {
+            file_path: file_path.to_string(),
+            conflicting_mods: mods.iter().map(|m| m.to_string()).collect(),
+            content_hashes: Vec::new(),
+            classification,
+        }
+    }
+
+    #[test]
+    fn resolve_conflicts_skips_identical_ones() {
+        let conflicts = vec![conflict(
+            "data/db/edt001.edt",
+            &["mod-a", "mod-b"],
+            ConflictClassification::Identical,
+        )];
+
+        let resolutions = resolve_conflicts(&conflicts, &["mod-a".to_string(), "mod-b".to_string()]);
+        assert!(resolutions.is_empty());
+    }
+
+    #[test]
+    fn resolve_conflicts_picks_last_enabled_mod_as_winner() {
+        let conflicts = vec![conflict(
+            "data/db/edt001.edt",
+            &["mod-a", "mod-b", "mod-c"],
+            ConflictClassification::Divergent,
+        )];
+        let enabled_mods = vec![
+            "mod-b".to_string(),
+            "mod-a".to_string(),
+            "mod-c".to_string(),
+        ];
+
+        let resolutions = resolve_conflicts(&conflicts, &enabled_mods);
+
+        assert_eq!(resolutions.len(), 1);
+        assert_eq!(resolutions[0].winning_mod, "mod-c");
+        assert_eq!(resolutions[0].shadowed_mods, vec!["mod-a", "mod-b"]);
+    }
+}
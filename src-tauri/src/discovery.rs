@@ -0,0 +1,188 @@
+//! Broader game-install discovery than [`crate::game_detection::get_candidates_with_validation`]'s
+//! fixed locations: parses Steam's `libraryfolders.vdf` to pick up non-default library drives,
+//! widens the Epic/`/Applications`/Proton search radius, and validates every candidate against
+//! [`crate::name_fix::get_db_dir`] before returning it, so the caller only ever sees installs
+//! that are actually usable rather than just present on disk.
+
+use crate::name_fix;
+use std::fs;
+use std::path::PathBuf;
+
+const GAME_DIR_NAME: &str = "Football Manager 26";
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+const WINDOWS_LAYOUT_STREAMING_ASSETS_SUBDIRS: &[&str] = &[
+    "fm_Data/StreamingAssets/aa/StandaloneWindows64",
+    "data/StreamingAssets/aa/StandaloneWindows64",
+];
+
+/// Parses the `"path"` values out of a Steam `libraryfolders.vdf` file. The format is Valve's
+/// simple key/value text format, so rather than pull in a full VDF parser for one field we just
+/// scan for `"path"` lines and take the quoted value.
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn parse_steam_library_paths(vdf_path: &std::path::Path) -> Vec<PathBuf> {
+    let Ok(contents) = fs::read_to_string(vdf_path) else {
+        return Vec::new();
+    };
+
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if !line.starts_with("\"path\"") {
+                return None;
+            }
+            let value = line.trim_start_matches("\"path\"").trim();
+            let value = value.trim_matches('"');
+            Some(PathBuf::from(value.replace("\\\\", "/")))
+        })
+        .collect()
+}
+
+/// Default Steam install root plus every additional library folder listed in
+/// `steamapps/libraryfolders.vdf` (covers users who installed Steam games to a second drive).
+#[cfg(target_os = "windows")]
+fn steam_library_roots() -> Vec<PathBuf> {
+    let program_files_x86 = std::env::var("PROGRAMFILES(X86)")
+        .unwrap_or_else(|_| "C:/Program Files (x86)".to_string());
+    let default_steam = PathBuf::from(&program_files_x86).join("Steam");
+
+    let mut roots = vec![default_steam.clone()];
+    let vdf_path = default_steam.join("steamapps").join("libraryfolders.vdf");
+    roots.extend(parse_steam_library_paths(&vdf_path));
+    roots
+}
+
+#[cfg(target_os = "linux")]
+fn steam_library_roots() -> Vec<PathBuf> {
+    let home = dirs::home_dir().unwrap_or_default();
+    let default_steam = home.join(".local/share/Steam");
+
+    let mut roots = vec![default_steam.clone()];
+    let vdf_path = default_steam.join("steamapps").join("libraryfolders.vdf");
+    roots.extend(parse_steam_library_paths(&vdf_path));
+    roots
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn candidates_from_steam_roots(roots: &[PathBuf]) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    for root in roots {
+        let game_base = root.join("steamapps").join("common").join(GAME_DIR_NAME);
+        for sub in WINDOWS_LAYOUT_STREAMING_ASSETS_SUBDIRS {
+            let path = game_base.join(sub);
+            if path.exists() {
+                candidates.push(path);
+            }
+        }
+    }
+    candidates
+}
+
+/// Epic installs to `%ProgramFiles%\Epic Games\<game>` for almost all users regardless of
+/// where its manifests (`ManifestsDir`) live, so we only need the install root here.
+#[cfg(target_os = "windows")]
+fn epic_candidates() -> Vec<PathBuf> {
+    let program_files =
+        std::env::var("PROGRAMFILES").unwrap_or_else(|_| "C:/Program Files".to_string());
+    let epic_base = PathBuf::from(&program_files)
+        .join("Epic Games")
+        .join(GAME_DIR_NAME);
+
+    WINDOWS_LAYOUT_STREAMING_ASSETS_SUBDIRS
+        .iter()
+        .map(|sub| epic_base.join(sub))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Scans `/Applications` and `~/Applications` for `.app` bundles instead of assuming one
+/// store's fixed path, since FM26 can land in either depending on how it was installed.
+#[cfg(target_os = "macos")]
+fn macos_candidates() -> Vec<PathBuf> {
+    let mut app_dirs = vec![PathBuf::from("/Applications")];
+    if let Some(home) = dirs::home_dir() {
+        app_dirs.push(home.join("Applications"));
+    }
+
+    let mut candidates = Vec::new();
+    for app_dir in app_dirs {
+        let Ok(entries) = fs::read_dir(&app_dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("app") {
+                continue;
+            }
+            for sub in &[
+                "Contents/Resources/Data/StreamingAssets/aa/StandaloneOSX",
+                "fm_Data/StreamingAssets/aa/StandaloneOSXUniversal",
+            ] {
+                let candidate = path.join(sub);
+                if candidate.exists() {
+                    candidates.push(candidate);
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// FM26 doesn't ship a native Linux build on every store, so under Proton it lives inside a
+/// Windows-layout prefix at `steamapps/compatdata/<appid>/pfx/drive_c/...`. We don't know the
+/// appid up front, so every compatdata entry is checked for the usual Windows layout.
+#[cfg(target_os = "linux")]
+fn proton_prefix_candidates(steam_roots: &[PathBuf]) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    for root in steam_roots {
+        let compatdata = root.join("steamapps").join("compatdata");
+        let Ok(entries) = fs::read_dir(&compatdata) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let game_base = entry
+                .path()
+                .join("pfx/drive_c/Program Files (x86)/Steam/steamapps/common")
+                .join(GAME_DIR_NAME);
+            for sub in WINDOWS_LAYOUT_STREAMING_ASSETS_SUBDIRS {
+                let path = game_base.join(sub);
+                if path.exists() {
+                    candidates.push(path);
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// Probes well-known install locations across Steam (including non-default library folders),
+/// Epic, and (on Linux) Proton prefixes, then keeps only the candidates where
+/// [`name_fix::get_db_dir`] can actually resolve a database schema folder underneath — turning
+/// first-run setup from manual browsing into one-click detection.
+pub fn discover_install_candidates() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    #[cfg(target_os = "windows")]
+    {
+        candidates.extend(candidates_from_steam_roots(&steam_library_roots()));
+        candidates.extend(epic_candidates());
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        candidates.extend(macos_candidates());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let steam_roots = steam_library_roots();
+        candidates.extend(candidates_from_steam_roots(&steam_roots));
+        candidates.extend(proton_prefix_candidates(&steam_roots));
+    }
+
+    candidates
+        .into_iter()
+        .filter(|path| name_fix::get_db_dir(path.to_str()).is_ok())
+        .collect()
+}
@@ -0,0 +1,93 @@
+//! Typed replacement for the `Result<_, String>` + [`crate::messages`] code-constant pattern.
+//! [`AppError`] still carries the same stable codes `messages.rs` defines, but as real enum
+//! variants the frontend can branch on via `code` instead of string-matching a formatted
+//! `"[CODE] detail"` message.
+
+use crate::messages;
+use serde::ser::SerializeStruct;
+use serde::Serialize;
+use thiserror::Error;
+
+/// Stable, serializable error type for commands migrated off `Result<_, String>`. Serializes as
+/// `{ code, message, details }`: `code` is the stable machine-readable tag (one of the
+/// `messages::CODE_*` constants, or `ERR_IO`/`ERR_ZIP`/`ERR_OTHER`), `message` is the full
+/// human-readable text, and `details` is the offending value (path, mod name, ...) where the
+/// variant carries one.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("Game target path not set")]
+    GameTargetNotSet,
+    #[error("Game target path does not exist")]
+    GameTargetInvalid,
+    #[error("Mod not found: {0}")]
+    ModNotFound(String),
+    #[error("Mod '{0}' already exists")]
+    ModAlreadyExists(String),
+    #[error("Source path does not exist")]
+    SourcePathMissing,
+    #[error("Path not found: {0}")]
+    PathNotFound(String),
+    /// Signals that importing a mod without a manifest needs `mod_name`/`version`/`mod_type`
+    /// supplied by the caller before it can proceed. Previously surfaced as the bare string
+    /// `CODE_METADATA_REQUIRED` with no way to attach detail.
+    #[error("Metadata required to import this mod")]
+    MetadataRequired,
+    #[error("Conflict unresolved: {0}")]
+    ConflictUnresolved(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+    /// Catch-all for the many helper functions across this codebase that still return
+    /// `Result<_, String>`. Lets commands migrate to `AppError` without rewriting every callee
+    /// in the same commit.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::GameTargetNotSet => messages::CODE_GAME_TARGET_NOT_SET,
+            AppError::GameTargetInvalid => messages::CODE_GAME_TARGET_INVALID,
+            AppError::ModNotFound(_) => messages::CODE_MOD_NOT_FOUND,
+            AppError::ModAlreadyExists(_) => messages::CODE_MOD_ALREADY_EXISTS,
+            AppError::SourcePathMissing => messages::CODE_SOURCE_PATH_MISSING,
+            AppError::PathNotFound(_) => messages::CODE_PATH_NOT_FOUND,
+            AppError::MetadataRequired => messages::CODE_METADATA_REQUIRED,
+            AppError::ConflictUnresolved(_) => messages::CODE_CONFLICT_UNRESOLVED,
+            AppError::Io(_) => "ERR_IO",
+            AppError::Zip(_) => "ERR_ZIP",
+            AppError::Other(_) => "ERR_OTHER",
+        }
+    }
+
+    fn details(&self) -> Option<String> {
+        match self {
+            AppError::ModNotFound(d)
+            | AppError::ModAlreadyExists(d)
+            | AppError::PathNotFound(d)
+            | AppError::ConflictUnresolved(d) => Some(d.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        AppError::Other(message)
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("details", &self.details())?;
+        state.end()
+    }
+}
@@ -0,0 +1,347 @@
+//! Import Module - External Launcher Pack Formats
+//!
+//! Users migrating from other modding tools arrive with pack bundles in formats this loader
+//! doesn't natively understand. This module detects two common shapes and converts each into
+//! a `ModManifest` so the rest of the pipeline (preview, install, uninstall) treats them like
+//! any native mod:
+//!
+//! - **Zipped manifest+overrides**: an `index.json` describing the pack, with actual files
+//!   under an `overrides/` directory mirroring the target layout.
+//! - **Flat instance config**: an `instance.json` that lists every file with an explicit
+//!   `path` (relative to the archive) and `target` (relative to the install root).
+//!
+//! Both importers only read the extracted archive and build a `ModManifest` in memory;
+//! nothing is written until [`import_external_pack`] is called with a confirmed preview.
+
+use crate::import::extract_zip;
+use crate::messages::{code_error, CODE_MOD_ALREADY_EXISTS, CODE_SOURCE_PATH_MISSING};
+use crate::mod_manager::preview_mod_install as compute_preview;
+use crate::types::{
+    Compatibility, FileEntry, ModInstallPreview, ModManifest, NameFixSourceType,
+};
+use crate::{config::get_mods_dir, utils};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Shape of an `index.json` alongside an `overrides/` directory.
+#[derive(Debug, Clone, Deserialize)]
+struct ExternalPackIndex {
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    author: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+/// Shape of an `instance.json` listing files with explicit install paths.
+#[derive(Debug, Clone, Deserialize)]
+struct InstanceConfig {
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    author: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    dependencies: Vec<String>,
+    files: Vec<InstanceConfigFile>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct InstanceConfigFile {
+    path: String,
+    target: String,
+}
+
+/// Which external pack shape an extracted archive matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExternalPackFormat {
+    ZippedManifestOverrides,
+    FlatInstanceConfig,
+}
+
+/// Inspects an already-extracted archive and decides which importer applies.
+fn detect_external_format(extracted_root: &Path) -> Result<ExternalPackFormat, String> {
+    if extracted_root.join("index.json").exists() {
+        Ok(ExternalPackFormat::ZippedManifestOverrides)
+    } else if extracted_root.join("instance.json").exists() {
+        Ok(ExternalPackFormat::FlatInstanceConfig)
+    } else {
+        Err("Archive does not match a known external pack format (expected index.json+overrides/ or instance.json)".to_string())
+    }
+}
+
+fn import_zipped_manifest_overrides(extracted_root: &Path) -> Result<ModManifest, String> {
+    let index_path = extracted_root.join("index.json");
+    let contents = fs::read_to_string(&index_path)
+        .map_err(|e| format!("Failed to read index.json: {}", e))?;
+    let index: ExternalPackIndex =
+        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse index.json: {}", e))?;
+
+    let overrides_dir = extracted_root.join("overrides");
+    if !overrides_dir.is_dir() {
+        return Err("index.json is present but no overrides/ directory was found".to_string());
+    }
+
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(&overrides_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        if !entry.path().is_file() {
+            continue;
+        }
+        let rel = entry
+            .path()
+            .strip_prefix(&overrides_dir)
+            .map_err(|e| format!("Failed to compute relative path: {}", e))?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        files.push(FileEntry {
+            source: format!("overrides/{}", rel),
+            target_subpath: rel,
+            platform: None,
+            install_mode: None,
+            sha256: None,
+        });
+    }
+
+    if files.is_empty() {
+        return Err("overrides/ directory contains no files".to_string());
+    }
+
+    Ok(ModManifest {
+        name: index.name,
+        schema_version: crate::mod_manager::CURRENT_MANIFEST_SCHEMA_VERSION,
+        version: index.version,
+        mod_type: "misc".to_string(),
+        author: index.author,
+        homepage: String::new(),
+        description: index.description,
+        license: String::new(),
+        compatibility: Compatibility::default(),
+        dependencies: index.dependencies,
+        conflicts: Vec::new(),
+        load_after: Vec::new(),
+        files,
+        source_type: Some(NameFixSourceType::Imported),
+    })
+}
+
+fn import_flat_instance_config(extracted_root: &Path) -> Result<ModManifest, String> {
+    let config_path = extracted_root.join("instance.json");
+    let contents = fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read instance.json: {}", e))?;
+    let config: InstanceConfig = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse instance.json: {}", e))?;
+
+    if config.files.is_empty() {
+        return Err("instance.json lists no files".to_string());
+    }
+
+    let files = config
+        .files
+        .into_iter()
+        .map(|f| FileEntry {
+            source: f.path,
+            target_subpath: f.target,
+            platform: None,
+            install_mode: None,
+            sha256: None,
+        })
+        .collect();
+
+    Ok(ModManifest {
+        name: config.name,
+        schema_version: crate::mod_manager::CURRENT_MANIFEST_SCHEMA_VERSION,
+        version: config.version,
+        mod_type: "misc".to_string(),
+        author: config.author,
+        homepage: String::new(),
+        description: config.description,
+        license: String::new(),
+        compatibility: Compatibility::default(),
+        dependencies: config.dependencies,
+        conflicts: Vec::new(),
+        load_after: Vec::new(),
+        files,
+        source_type: Some(NameFixSourceType::Imported),
+    })
+}
+
+/// Detects the pack format under `extracted_root` and builds the equivalent `ModManifest`.
+fn build_external_manifest(extracted_root: &Path) -> Result<ModManifest, String> {
+    match detect_external_format(extracted_root)? {
+        ExternalPackFormat::ZippedManifestOverrides => {
+            import_zipped_manifest_overrides(extracted_root)
+        }
+        ExternalPackFormat::FlatInstanceConfig => import_flat_instance_config(extracted_root),
+    }
+}
+
+fn extract_to_temp(source_path: &str) -> Result<PathBuf, String> {
+    let source = PathBuf::from(source_path);
+    if !source.exists() {
+        return Err(code_error(
+            CODE_SOURCE_PATH_MISSING,
+            "Source path does not exist",
+        ));
+    }
+
+    let temp_dir =
+        std::env::temp_dir().join(format!("fmmloader_external_import_{}", uuid::Uuid::new_v4()));
+    extract_zip(&source, &temp_dir)?;
+    Ok(temp_dir)
+}
+
+/// Extracts `source_path`, converts it to a `ModManifest`, and resolves where its files would
+/// land without writing anything — mirrors `preview_mod_install` for native mods.
+#[tauri::command]
+pub fn preview_external_pack_import(
+    source_path: String,
+    game_target: Option<String>,
+    user_dir: Option<String>,
+) -> Result<ModInstallPreview, String> {
+    use crate::config::load_config;
+    use crate::messages::{code_only, CODE_GAME_TARGET_NOT_SET};
+
+    let extracted_root = extract_to_temp(&source_path)?;
+    let manifest = build_external_manifest(&extracted_root)?;
+
+    let config = load_config()?;
+    let target_path = game_target
+        .or(config.target_path.clone())
+        .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
+
+    Ok(compute_preview(
+        &manifest.name,
+        &manifest.mod_type,
+        &PathBuf::from(target_path),
+        user_dir.as_deref().or(config.user_dir_path.as_deref()),
+        &manifest.files,
+    ))
+}
+
+/// Re-extracts `source_path`, rebuilds its `ModManifest`, and writes it into the mods
+/// directory — the confirmation step after [`preview_external_pack_import`].
+#[tauri::command]
+pub fn import_external_pack(
+    source_path: String,
+    mod_name: Option<String>,
+) -> Result<String, String> {
+    let extracted_root = extract_to_temp(&source_path)?;
+    let mut manifest = build_external_manifest(&extracted_root)?;
+    if let Some(name) = mod_name {
+        manifest.name = name;
+    }
+
+    let dest_dir = get_mods_dir().join(&manifest.name);
+    if dest_dir.exists() {
+        return Err(code_error(
+            CODE_MOD_ALREADY_EXISTS,
+            format!("Mod '{}' already exists", manifest.name),
+        ));
+    }
+
+    let manifest_path = extracted_root.join("manifest.json");
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    fs::write(&manifest_path, json).map_err(|e| format!("Failed to write manifest: {}", e))?;
+
+    utils::copy_dir_recursive(&extracted_root, &dest_dir).map_err(|e| e.to_string())?;
+
+    Ok(manifest.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_file(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut file = fs::File::create(path).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn detects_zipped_manifest_overrides_format() {
+        let root = std::env::temp_dir().join(format!("ext_import_test_{}", uuid::Uuid::new_v4()));
+        write_file(&root.join("index.json"), "{\"name\": \"Pack\"}");
+
+        assert_eq!(
+            detect_external_format(&root).unwrap(),
+            ExternalPackFormat::ZippedManifestOverrides
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn detects_flat_instance_config_format() {
+        let root = std::env::temp_dir().join(format!("ext_import_test_{}", uuid::Uuid::new_v4()));
+        write_file(&root.join("instance.json"), "{\"name\": \"Pack\", \"files\": []}");
+
+        assert_eq!(
+            detect_external_format(&root).unwrap(),
+            ExternalPackFormat::FlatInstanceConfig
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        let root = std::env::temp_dir().join(format!("ext_import_test_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&root).unwrap();
+
+        assert!(detect_external_format(&root).is_err());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn imports_zipped_manifest_overrides_into_manifest() {
+        let root = std::env::temp_dir().join(format!("ext_import_test_{}", uuid::Uuid::new_v4()));
+        write_file(
+            &root.join("index.json"),
+            "{\"name\": \"Pack\", \"version\": \"1.0.0\", \"dependencies\": [\"base\"]}",
+        );
+        write_file(&root.join("overrides/editor data/tactic.lnc"), "data");
+
+        let manifest = import_zipped_manifest_overrides(&root).unwrap();
+        assert_eq!(manifest.name, "Pack");
+        assert_eq!(manifest.dependencies, vec!["base".to_string()]);
+        assert_eq!(manifest.source_type, Some(NameFixSourceType::Imported));
+        assert_eq!(manifest.files.len(), 1);
+        assert_eq!(manifest.files[0].target_subpath, "editor data/tactic.lnc");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn imports_flat_instance_config_into_manifest() {
+        let root = std::env::temp_dir().join(format!("ext_import_test_{}", uuid::Uuid::new_v4()));
+        write_file(
+            &root.join("instance.json"),
+            r#"{"name": "Pack", "version": "2.0.0", "files": [{"path": "tactic.fmf", "target": "tactics/tactic.fmf"}]}"#,
+        );
+
+        let manifest = import_flat_instance_config(&root).unwrap();
+        assert_eq!(manifest.version, "2.0.0");
+        assert_eq!(manifest.files.len(), 1);
+        assert_eq!(manifest.files[0].source, "tactic.fmf");
+        assert_eq!(manifest.files[0].target_subpath, "tactics/tactic.fmf");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
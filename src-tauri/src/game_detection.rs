@@ -1,6 +1,65 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-pub fn get_default_candidates() -> Vec<PathBuf> {
+/// Outcome of validating [`collect_raw_candidates`]'s hits against [`validate_fm_install`]:
+/// `validated` entries actually contain FM's addressable-assets signature and are safe to
+/// auto-select; `unvalidated` entries merely existed on disk — an empty or
+/// partially-uninstalled directory, say — and are kept separate so a UI can still offer them as
+/// a "found but looks incomplete" override instead of silently dropping them.
+#[derive(Debug, Default)]
+pub struct CandidateDetectionResult {
+    pub validated: Vec<PathBuf>,
+    pub unvalidated: Vec<PathBuf>,
+}
+
+/// Confirms `path` actually contains Football Manager's addressable-assets signature rather
+/// than merely existing on disk: a `catalog*.json` manifest alongside at least one `*.bundle`
+/// asset file, the shape Unity's addressables build always produces under an `aa/Standalone*`
+/// folder. Analogous to OpenLoco checking for its own `g1` data file before trusting a
+/// candidate install path.
+pub fn validate_fm_install(path: &Path) -> bool {
+    let Ok(entries) = fs::read_dir(path) else {
+        return false;
+    };
+
+    let mut has_catalog = false;
+    let mut has_bundle = false;
+    for entry in entries.flatten() {
+        if !entry.path().is_file() {
+            continue;
+        }
+        let name_lower = entry.file_name().to_string_lossy().to_lowercase();
+        if name_lower.contains("catalog") && name_lower.ends_with(".json") {
+            has_catalog = true;
+        } else if name_lower.ends_with(".bundle") {
+            has_bundle = true;
+        }
+        if has_catalog && has_bundle {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Default-location candidates from [`collect_raw_candidates`], split by whether they pass
+/// [`validate_fm_install`], so a caller can auto-select the `validated` ones while still
+/// surfacing `unvalidated` hits for manual override instead of silently dropping them.
+pub fn get_candidates_with_validation() -> CandidateDetectionResult {
+    let mut result = CandidateDetectionResult::default();
+
+    for candidate in collect_raw_candidates() {
+        if validate_fm_install(&candidate) {
+            result.validated.push(candidate);
+        } else {
+            result.unvalidated.push(candidate);
+        }
+    }
+
+    result
+}
+
+fn collect_raw_candidates() -> Vec<PathBuf> {
     let mut candidates = Vec::new();
 
     #[cfg(target_os = "windows")]
@@ -76,6 +135,8 @@ pub fn get_default_candidates() -> Vec<PathBuf> {
         for path in paths {
             if path.exists() {
                 candidates.push(path);
+            } else if let Some(similar) = find_similar_path(path) {
+                candidates.push(similar);
             }
         }
     }
@@ -92,6 +153,8 @@ pub fn get_default_candidates() -> Vec<PathBuf> {
         for path in paths {
             if path.exists() {
                 candidates.push(path);
+            } else if let Some(similar) = find_similar_path(path) {
+                candidates.push(similar);
             }
         }
     }
@@ -99,6 +162,37 @@ pub fn get_default_candidates() -> Vec<PathBuf> {
     candidates
 }
 
+/// Finds the real on-disk path for `intended` when some of its components differ only in case or
+/// trailing whitespace from what's actually there — case-sensitive Linux/macOS filesystems won't
+/// match `Football Manager 26` against a launcher-created `football manager 26 `, where Windows
+/// wouldn't have noticed the difference at all. Walks up from `intended` to the nearest ancestor
+/// that actually exists, then back down one component at a time, matching each remaining segment
+/// case-insensitively (trimmed) against that directory's real entries and rebuilding the actual
+/// path. Stops and returns `None` as soon as a component has no match, rather than guessing.
+#[cfg(not(target_os = "windows"))]
+fn find_similar_path(intended: PathBuf) -> Option<PathBuf> {
+    let mut existing_root: &std::path::Path = &intended;
+    let mut missing_components = Vec::new();
+
+    while !existing_root.exists() {
+        missing_components.push(existing_root.file_name()?.to_os_string());
+        existing_root = existing_root.parent()?;
+    }
+
+    let mut current = existing_root.to_path_buf();
+    for component in missing_components.into_iter().rev() {
+        let wanted = component.to_string_lossy().trim().to_lowercase();
+        let matched = fs::read_dir(&current)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .find(|entry| entry.file_name().to_string_lossy().trim().to_lowercase() == wanted)?;
+
+        current = matched.path();
+    }
+
+    Some(current)
+}
+
 pub fn get_fm_user_dir(custom_path: Option<&str>) -> PathBuf {
     // Check if user has set a custom path
     if let Some(path) = custom_path {
@@ -108,6 +202,14 @@ pub fn get_fm_user_dir(custom_path: Option<&str>) -> PathBuf {
         }
     }
 
+    // Portable installs (see `crate::config::resolve_app_data_dir`) keep FM's own user-data
+    // folder inside the portable directory too, so the whole tool — loader config and the
+    // game's save/graphics data — can run from a USB stick or game-drive folder without
+    // touching the home directory.
+    if let Some(portable_dir) = crate::config::portable_data_dir() {
+        return portable_dir.join("FM26UserData");
+    }
+
     // Default paths
     #[cfg(target_os = "windows")]
     {
@@ -202,4 +304,70 @@ mod tests {
             assert!(result.ends_with(&expected_suffix));
         }
     }
+
+    #[test]
+    fn portable_marker_redirects_user_dir_into_portable_folder() {
+        let exe_dir = std::env::current_exe().unwrap().parent().unwrap().to_path_buf();
+        let marker = exe_dir.join(crate::config::PORTABLE_MARKER_FILE);
+        fs::write(&marker, "").unwrap();
+
+        let result = get_fm_user_dir(None);
+        let _ = fs::remove_file(&marker);
+
+        assert_eq!(
+            result,
+            exe_dir.join("FMMLoader26").join("FM26UserData")
+        );
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn find_similar_path_matches_case_and_trailing_whitespace() {
+        let temp_dir = unique_temp_dir();
+        let real = temp_dir.join("Football Manager 26 ").join("StreamingAssets");
+        fs::create_dir_all(&real).unwrap();
+
+        let intended = temp_dir.join("football manager 26").join("streamingassets");
+        let result = find_similar_path(intended);
+
+        assert_eq!(result, Some(real));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn find_similar_path_stops_at_first_unmatched_component() {
+        let temp_dir = unique_temp_dir();
+
+        let intended = temp_dir.join("does-not-exist-anywhere");
+        let result = find_similar_path(intended);
+
+        assert_eq!(result, None);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn validate_fm_install_accepts_catalog_and_bundle_pair() {
+        let temp_dir = unique_temp_dir();
+        fs::write(temp_dir.join("catalog_2026.1.0.json"), "{}").unwrap();
+        fs::write(temp_dir.join("abc123.bundle"), "").unwrap();
+
+        assert!(validate_fm_install(&temp_dir));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn validate_fm_install_rejects_empty_or_partial_directory() {
+        let empty_dir = unique_temp_dir();
+        assert!(!validate_fm_install(&empty_dir));
+        let _ = fs::remove_dir_all(&empty_dir);
+
+        let catalog_only = unique_temp_dir();
+        fs::write(catalog_only.join("catalog_2026.1.0.json"), "{}").unwrap();
+        assert!(!validate_fm_install(&catalog_only));
+        let _ = fs::remove_dir_all(&catalog_only);
+    }
 }
@@ -2,15 +2,296 @@
 
 use crate::config::{get_app_data_dir, load_config, load_graphics_packs, save_graphics_packs};
 use crate::game_detection;
-use crate::graphics_analyzer::{self, analyze_graphics_pack, split_mixed_pack};
-use crate::import::{extract_zip, extract_zip_async};
-use crate::types::{ExtractionProgress, GraphicsConflictInfo, GraphicsPackMetadata};
+use crate::graphics_analyzer::{
+    self, analyze_graphics_pack, find_duplicate_graphics_tree, split_mixed_pack,
+};
+use crate::import::{
+    detect_archive_format_label, extract_archive_async, extract_archive_with_limits,
+    ExtractionError, ExtractionLimits,
+};
+use crate::types::{
+    CopyReport, DuplicateGraphicsReport, ExtractionProgress, GraphicsConflictInfo,
+    GraphicsDedupeResult, GraphicsImportResult, GraphicsPackMetadata, ImageValidationReport,
+    SkippedCopyEntry,
+};
 use crate::utils;
+use std::collections::HashMap;
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
 use tauri::{Emitter, Manager};
 use walkdir::WalkDir;
 
+/// Current on-disk layout version for graphics packs, stamped onto every newly imported pack's
+/// [`GraphicsPackMetadata::format_version`]. Mirrors the OpenGOAL launcher's
+/// `texture_replacement` format versioning: packs with no version (or version 0) predate this
+/// and may still have a flat layout with unprefixed `config.xml` `from=` paths, which
+/// [`import_graphics_pack_with_type`] detects and upgrades automatically — see
+/// [`migrate_legacy_graphics_pack`].
+pub const GRAPHICS_PACK_FORMAT_VERSION: u32 = 2;
+
+/// Number of discrete stages [`import_graphics_pack_with_type`] reports through
+/// `ExtractionProgress::max_stage`: extracting, analyzing, writing config, copying. The "writing
+/// config" stage still fires (with zero entries) even when [`migrate_legacy_graphics_pack`] finds
+/// nothing to rewrite, so the UI's stage count never changes mid-import.
+const IMPORT_STAGE_COUNT: usize = 4;
+const IMPORT_STAGE_EXTRACTING: usize = 1;
+const IMPORT_STAGE_ANALYZING: usize = 2;
+const IMPORT_STAGE_WRITING_CONFIG: usize = 3;
+const IMPORT_STAGE_COPYING: usize = 4;
+
+/// How many rayon worker threads [`copy_graphics_content`]/[`copy_flat_pack_content`]/
+/// [`migrate_graphics_pack`] use to copy files concurrently when the caller doesn't override it.
+/// Kept modest by default: these copies are I/O-bound, and on a spinning disk more than a
+/// handful of concurrent readers/writers just adds seek thrash instead of throughput. A caller
+/// who knows the destination is an SSD (or the source pack is all tiny files, where syscall
+/// overhead rather than disk seeks dominates) can raise this via `copy_threads`.
+const DEFAULT_COPY_THREADS: usize = 4;
+
+/// Builds a dedicated rayon thread pool sized by `thread_count` (falling back to
+/// [`DEFAULT_COPY_THREADS`]), so a pack copy's concurrency doesn't contend with rayon's global
+/// pool (used elsewhere for duplicate-hashing) or silently pick up however many CPUs the host
+/// has, which is a poor default for a disk-bound workload.
+fn build_copy_pool(thread_count: Option<usize>) -> Result<rayon::ThreadPool, String> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(thread_count.unwrap_or(DEFAULT_COPY_THREADS).max(1))
+        .build()
+        .map_err(|e| format!("Failed to build copy thread pool: {}", e))
+}
+
+/// Install-time dHash dedup state shared across a copy's rayon workers (see
+/// [`build_copy_pool`]). `installed_hashes` seeds the comparison with every image hash already
+/// recorded in [`GraphicsPackMetadata::image_hashes`] across previously installed packs;
+/// `new_hashes` accumulates hashes of files this import actually copies in, so later files in the
+/// same pack also dedup against earlier ones instead of only against prior packs. A plain `Vec`
+/// scanned linearly is fine here — even a few thousand installed images is only a few thousand
+/// cheap popcounts per incoming file, nowhere near hot enough to warrant an index.
+struct ImageDedup {
+    threshold: u32,
+    installed_hashes: Vec<u64>,
+    new_hashes: std::sync::Mutex<Vec<u64>>,
+    duplicates_skipped: std::sync::atomic::AtomicUsize,
+}
+
+impl ImageDedup {
+    fn new(installed_hashes: Vec<u64>, threshold: u32) -> Self {
+        Self {
+            threshold,
+            installed_hashes,
+            new_hashes: std::sync::Mutex::new(Vec::new()),
+            duplicates_skipped: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Non-image files are never duplicates. Images that fail to decode are let through
+    /// uncounted rather than blocked, matching [`graphics_analyzer::compute_dhash`]'s own
+    /// fail-open behavior elsewhere. Otherwise hashes `path` and compares it against every
+    /// installed/already-copied-this-import hash; on a hit, records the skip and returns `true`
+    /// without remembering `path`'s hash (no point growing the haystack with a hash that's
+    /// already represented). On a miss, remembers the hash so later files in this same pack can
+    /// dedup against it too.
+    fn is_duplicate(&self, path: &Path) -> bool {
+        if !graphics_analyzer::is_image_extension(path) {
+            return false;
+        }
+        let Some(hash) = graphics_analyzer::compute_dhash(path) else {
+            return false;
+        };
+
+        let mut new_hashes = self.new_hashes.lock().unwrap();
+        let is_dup = self
+            .installed_hashes
+            .iter()
+            .chain(new_hashes.iter())
+            .any(|&existing| graphics_analyzer::hash_hamming_distance(existing, hash) <= self.threshold);
+
+        if is_dup {
+            self.duplicates_skipped.fetch_add(1, Ordering::Relaxed);
+        } else {
+            new_hashes.push(hash);
+        }
+        is_dup
+    }
+
+    fn duplicates_skipped(&self) -> usize {
+        self.duplicates_skipped.load(Ordering::Relaxed)
+    }
+
+    fn into_new_hashes(self) -> Vec<u64> {
+        self.new_hashes.into_inner().unwrap()
+    }
+}
+
+/// Non-PNG extensions [`ImageNormalizer`] will attempt to decode and re-encode to PNG. Covers
+/// the formats graphics packs are commonly shipped in (`jpg`/`bmp`/`webp`/...) plus HEIF/RAW
+/// camera formats, which need the feature-gated backends in [`decode_source_image`] since the
+/// plain `image` crate can't read them.
+fn is_normalizable_image_extension(ext: &str) -> bool {
+    matches!(
+        ext,
+        "jpg" | "jpeg" | "bmp" | "webp" | "gif" | "tiff" | "tif"
+    ) || is_heif_extension(ext)
+        || is_raw_extension(ext)
+}
+
+fn is_heif_extension(ext: &str) -> bool {
+    matches!(ext, "heif" | "heic")
+}
+
+fn is_raw_extension(ext: &str) -> bool {
+    matches!(ext, "raw" | "cr2" | "nef" | "arw" | "dng")
+}
+
+/// Decodes a non-PNG source image ahead of re-encoding by [`ImageNormalizer::normalize`]. HEIF
+/// and RAW sources are routed to dedicated, feature-gated decoders (`libheif`/`rawloader`) since
+/// they pull in system codec dependencies most builds don't need; everything else goes through
+/// the `image` crate's own decoders, the same ones [`graphics_analyzer::validate_pack_images`]
+/// uses.
+fn decode_source_image(path: &Path, ext: &str) -> Result<image::DynamicImage, String> {
+    if is_heif_extension(ext) {
+        return decode_heif_image(path);
+    }
+    if is_raw_extension(ext) {
+        return decode_raw_image(path);
+    }
+    image::open(path).map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "libheif")]
+fn decode_heif_image(path: &Path) -> Result<image::DynamicImage, String> {
+    let ctx = libheif_rs::HeifContext::read_from_file(&path.to_string_lossy())
+        .map_err(|e| format!("libheif: {}", e))?;
+    let handle = ctx
+        .primary_image_handle()
+        .map_err(|e| format!("libheif: {}", e))?;
+    let heif_image = handle
+        .decode(
+            libheif_rs::ColorSpace::Rgb(libheif_rs::RgbChroma::Rgba),
+            None,
+        )
+        .map_err(|e| format!("libheif: {}", e))?;
+
+    let width = heif_image.width();
+    let height = heif_image.height();
+    let plane = heif_image
+        .planes()
+        .interleaved
+        .ok_or_else(|| "libheif: decoded image has no interleaved RGBA plane".to_string())?;
+
+    image::RgbaImage::from_raw(width, height, plane.data.to_vec())
+        .map(image::DynamicImage::ImageRgba8)
+        .ok_or_else(|| "libheif: decoded plane didn't match the reported dimensions".to_string())
+}
+
+#[cfg(not(feature = "libheif"))]
+fn decode_heif_image(_path: &Path) -> Result<image::DynamicImage, String> {
+    Err("HEIF/HEIC decoding requires the 'libheif' build feature, which this build doesn't have".to_string())
+}
+
+#[cfg(feature = "rawloader")]
+fn decode_raw_image(path: &Path) -> Result<image::DynamicImage, String> {
+    let raw = rawloader::decode_file(path).map_err(|e| format!("rawloader: {:?}", e))?;
+    let (width, height) = (raw.width as u32, raw.height as u32);
+    let rawloader::RawImageData::Integer(data) = raw.data else {
+        return Err("rawloader: only integer RAW sensor data is supported".to_string());
+    };
+    // RAW sensor data is a single-channel Bayer mosaic, not RGB — a full debayer is out of scope
+    // here, so this takes the cheap route of treating it as grayscale, matching how this loader
+    // only needs *a* usable preview PNG rather than a publication-quality conversion.
+    let gray: Vec<u8> = data.iter().map(|&v| (v >> 8) as u8).collect();
+    image::GrayImage::from_raw(width, height, gray)
+        .map(image::DynamicImage::ImageLuma8)
+        .ok_or_else(|| "rawloader: decoded sensor data didn't match the reported dimensions".to_string())
+}
+
+#[cfg(not(feature = "rawloader"))]
+fn decode_raw_image(_path: &Path) -> Result<image::DynamicImage, String> {
+    Err("RAW decoding requires the 'rawloader' build feature, which this build doesn't have".to_string())
+}
+
+/// What [`ImageNormalizer::normalize`] decided to do with a given source file.
+enum NormalizeOutcome {
+    /// Normalization doesn't apply — not enabled, or the file is already a PNG/not an image
+    /// format this pass recognizes. The caller should copy the file as-is.
+    NotApplicable,
+    /// Decoded successfully and passed the sanity checks; ready to be re-encoded as PNG.
+    Converted(image::DynamicImage),
+    /// Failed to decode, or decoded to a degenerate/absurd size. The caller should skip the
+    /// file entirely rather than install something broken.
+    Rejected(String),
+}
+
+/// Install-time image format normalization, mirroring [`ImageDedup`]'s shape: shared (read-mostly
+/// plus atomic counters) state handed to every rayon worker a pack copy spawns. FM only reads PNG
+/// faces/logos, but packs routinely ship `.jpg`/`.bmp`/`.webp` (or HEIF/RAW source art); when
+/// `enabled`, [`normalize`](Self::normalize) decodes each non-PNG image encountered during copy
+/// and re-encodes it to PNG instead of copying the original bytes through unchanged.
+/// `dimension_bounds` (the same per-pack-type range [`graphics_analyzer::validate_pack_images`]
+/// checks against) rejects a decode that's 0-byte/corrupt or absurdly large before it's written
+/// to disk.
+struct ImageNormalizer {
+    enabled: bool,
+    dimension_bounds: (u32, u32),
+    converted: std::sync::atomic::AtomicUsize,
+    rejected: std::sync::atomic::AtomicUsize,
+}
+
+impl ImageNormalizer {
+    fn new(enabled: bool, pack_type: &graphics_analyzer::GraphicsPackType) -> Self {
+        Self {
+            enabled,
+            dimension_bounds: graphics_analyzer::expected_dimension_range(pack_type),
+            converted: std::sync::atomic::AtomicUsize::new(0),
+            rejected: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    fn normalize(&self, path: &Path) -> NormalizeOutcome {
+        if !self.enabled {
+            return NormalizeOutcome::NotApplicable;
+        }
+
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        if ext.is_empty() || ext == "png" || !is_normalizable_image_extension(&ext) {
+            return NormalizeOutcome::NotApplicable;
+        }
+
+        let image = match decode_source_image(path, &ext) {
+            Ok(image) => image,
+            Err(e) => {
+                self.rejected.fetch_add(1, Ordering::Relaxed);
+                return NormalizeOutcome::Rejected(format!("Failed to decode {} image: {}", ext, e));
+            }
+        };
+
+        let (width, height) = (image.width(), image.height());
+        let (min_dim, max_dim) = self.dimension_bounds;
+        if width == 0 || height == 0 || width < min_dim || height < min_dim || width > max_dim || height > max_dim {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            return NormalizeOutcome::Rejected(format!(
+                "Decoded dimensions {}x{} are outside the expected {}-{}px range",
+                width, height, min_dim, max_dim
+            ));
+        }
+
+        self.converted.fetch_add(1, Ordering::Relaxed);
+        NormalizeOutcome::Converted(image)
+    }
+
+    fn converted(&self) -> usize {
+        self.converted.load(Ordering::Relaxed)
+    }
+
+    fn rejected(&self) -> usize {
+        self.rejected.load(Ordering::Relaxed)
+    }
+}
+
 /// Issue found during graphics pack validation
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GraphicsPackIssue {
@@ -28,14 +309,175 @@ pub fn list_graphics_packs() -> Result<Vec<GraphicsPackMetadata>, String> {
     Ok(registry.graphics_packs)
 }
 
-/// Analyzes a graphics pack (file or directory) to determine its type
+/// The current graphics-pack layout version, so the UI can flag registry entries whose
+/// `format_version` falls behind it as candidates for re-import/migration.
+#[tauri::command]
+pub fn graphics_pack_format_version() -> u32 {
+    GRAPHICS_PACK_FORMAT_VERSION
+}
+
+/// Compression level used for [`export_graphics_pack`]'s tarball, matching the xz default
+/// [`crate::name_fix`] falls back to for its own tar.xz backups.
+const EXPORT_XZ_LEVEL: u32 = 6;
+
+/// Descriptor written as `manifest.json` at the root of an [`export_graphics_pack`] tarball,
+/// so the pack can be identified and re-imported without the original registry entry.
+#[derive(Debug, Clone, serde::Serialize)]
+struct GraphicsPackExportManifest {
+    pack_name: String,
+    pack_type: String,
+    file_count: usize,
+    source_filename: String,
+    source_format: String,
+    install_date: String,
+    format_version: u32,
+    files: Vec<String>,
+}
+
+/// Replaces characters that aren't safe in a filename with `_`, used to turn a pack's
+/// (user-supplied) display name into a safe export archive filename.
+fn sanitize_export_filename(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Exports the installed pack identified by `pack_id` back into a redistributable `.tar.xz`
+/// bundle under `dest_dir` — the inverse of [`import_graphics_pack_with_type`]. The archive holds
+/// the pack's files under `payload/` (mirroring `installed_to`'s own layout) plus two top-level
+/// descriptor files, the same way a build system assembles a tarball from payload files and a
+/// fixed set of descriptors: a generated `manifest.json` (name, type, file count, original
+/// source filename/format, install date, and every payload file's relative path) and, if one
+/// exists under `installed_to`, the pack's `config.xml` copied to the top level so it can be
+/// inspected without unpacking the whole payload. Returns the archive's path.
+#[tauri::command]
+pub async fn export_graphics_pack(pack_id: String, dest_dir: String) -> Result<String, String> {
+    let registry = load_graphics_packs()?;
+    let pack = registry
+        .graphics_packs
+        .into_iter()
+        .find(|p| p.id == pack_id)
+        .ok_or_else(|| format!("No installed graphics pack with id '{}'", pack_id))?;
+
+    let installed_to = PathBuf::from(&pack.installed_to);
+    if !installed_to.is_dir() {
+        return Err(format!(
+            "Pack's installed directory no longer exists: {:?}",
+            installed_to
+        ));
+    }
+
+    let dest_dir = PathBuf::from(dest_dir);
+
+    tauri::async_runtime::spawn_blocking(move || -> Result<String, String> {
+        fs::create_dir_all(&dest_dir)
+            .map_err(|e| format!("Failed to create export directory: {}", e))?;
+
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let archive_path = dest_dir.join(format!(
+            "{}-{}.tar.xz",
+            sanitize_export_filename(&pack.name),
+            timestamp
+        ));
+
+        let files: Vec<String> = WalkDir::new(&installed_to)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                e.path()
+                    .strip_prefix(&installed_to)
+                    .ok()
+                    .map(|p| p.to_string_lossy().replace('\\', "/"))
+            })
+            .collect();
+
+        let manifest = GraphicsPackExportManifest {
+            pack_name: pack.name.clone(),
+            pack_type: pack.pack_type.clone(),
+            file_count: files.len(),
+            source_filename: pack.source_filename.clone(),
+            source_format: pack.source_format.clone(),
+            install_date: pack.install_date.clone(),
+            format_version: pack.format_version,
+            files,
+        };
+        let manifest_json = serde_json::to_string_pretty(&manifest)
+            .map_err(|e| format!("Failed to serialize export manifest: {}", e))?;
+
+        let archive_file = fs::File::create(&archive_path)
+            .map_err(|e| format!("Failed to create export archive: {}", e))?;
+        let mut tar_builder =
+            tar::Builder::new(xz2::write::XzEncoder::new(archive_file, EXPORT_XZ_LEVEL));
+
+        let mut manifest_header = tar::Header::new_gnu();
+        manifest_header.set_size(manifest_json.len() as u64);
+        manifest_header.set_mode(0o644);
+        manifest_header.set_cksum();
+        tar_builder
+            .append_data(
+                &mut manifest_header,
+                "manifest.json",
+                manifest_json.as_bytes(),
+            )
+            .map_err(|e| format!("Failed to write manifest.json to archive: {}", e))?;
+
+        let config_xml_path = installed_to.join("config.xml");
+        if config_xml_path.is_file() {
+            tar_builder
+                .append_path_with_name(&config_xml_path, "config.xml")
+                .map_err(|e| format!("Failed to write config.xml to archive: {}", e))?;
+        }
+
+        tar_builder
+            .append_dir_all("payload", &installed_to)
+            .map_err(|e| format!("Failed to write pack payload to archive: {}", e))?;
+
+        tar_builder
+            .into_inner()
+            .map_err(|e| format!("Failed to finish export archive: {}", e))?
+            .finish()
+            .map_err(|e| format!("Failed to finish xz stream: {}", e))?;
+
+        tracing::info!("Exported graphics pack '{}' to {:?}", pack.name, archive_path);
+
+        Ok(archive_path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|e| format!("Graphics pack export task panicked: {}", e))?
+}
+
+/// Builds [`ExtractionLimits`] from a command's optional per-call overrides, falling back to
+/// [`ExtractionLimits::default`] for anything left unset. Exposed on the graphics import/analyze
+/// commands so a caller importing a genuinely huge face megapack can raise the caps instead of
+/// being stuck with limits sized for a typical pack.
+fn resolve_extraction_limits(
+    max_total_bytes: Option<u64>,
+    max_entries: Option<usize>,
+    max_entry_bytes: Option<u64>,
+) -> ExtractionLimits {
+    let defaults = ExtractionLimits::default();
+    ExtractionLimits {
+        max_total_bytes: max_total_bytes.unwrap_or(defaults.max_total_bytes),
+        max_entries: max_entries.unwrap_or(defaults.max_entries),
+        max_entry_bytes: max_entry_bytes.unwrap_or(defaults.max_entry_bytes),
+    }
+}
+
+/// Analyzes a graphics pack (file or directory) to determine its type. `max_total_bytes`/
+/// `max_entries`/`max_entry_bytes` override [`ExtractionLimits::default`] for callers analyzing
+/// a genuinely huge megapack; see [`resolve_extraction_limits`].
 #[tauri::command]
 pub async fn analyze_graphics_pack_cmd(
     source_path: String,
-) -> Result<graphics_analyzer::GraphicsPackAnalysis, String> {
+    max_total_bytes: Option<u64>,
+    max_entries: Option<usize>,
+    max_entry_bytes: Option<u64>,
+) -> Result<graphics_analyzer::GraphicsPackAnalysis, ExtractionError> {
     tracing::info!("Analyzing graphics pack: {}", source_path);
 
     let source = PathBuf::from(&source_path);
+    let limits = resolve_extraction_limits(max_total_bytes, max_entries, max_entry_bytes);
 
     // If it's an archive, extract it to a temp directory first
     let (analysis_path, temp_dir_to_cleanup) = if source.is_file() {
@@ -44,8 +486,10 @@ pub async fn analyze_graphics_pack_cmd(
 
         tracing::info!("Extracting to temp for analysis: {:?}", temp_dir);
 
-        // Extract without progress tracking (just for analysis)
-        extract_zip(&source, &temp_dir)?;
+        // Extract without progress tracking (just for analysis), enforcing the same zip-slip
+        // and zip-bomb limits as a real import so an untrusted pack can't be used to probe disk
+        // space via "analyze" alone.
+        extract_archive_with_limits(&source, &temp_dir, &limits)?;
 
         // Find the content root
         let content_root = utils::find_graphics_content_root(&temp_dir)?;
@@ -73,6 +517,70 @@ pub async fn analyze_graphics_pack_cmd(
     Ok(analysis)
 }
 
+/// Deep-validates a graphics pack's images by actually decoding them, rather than just inferring
+/// shape from file names like [`analyze_graphics_pack_cmd`] does — see
+/// [`graphics_analyzer::validate_pack_images`] for what it checks. Shares that command's
+/// archive-vs-directory handling: an archive is extracted to a temp directory (under the same
+/// zip-slip/zip-bomb limits) and cleaned up afterward regardless of outcome.
+///
+/// `sample_all` forces every image in the pack to be decoded instead of just the first couple
+/// hundred — slower, but exhaustive; `check_config_mappings` additionally cross-checks every
+/// `config.xml`'s `from=` targets against what's actually on disk.
+#[tauri::command]
+pub async fn validate_graphics_pack_images(
+    source_path: String,
+    sample_all: bool,
+    check_config_mappings: bool,
+    max_total_bytes: Option<u64>,
+    max_entries: Option<usize>,
+    max_entry_bytes: Option<u64>,
+) -> Result<ImageValidationReport, ExtractionError> {
+    tracing::info!("Validating images in graphics pack: {}", source_path);
+
+    let source = PathBuf::from(&source_path);
+    let limits = resolve_extraction_limits(max_total_bytes, max_entries, max_entry_bytes);
+
+    let (validation_path, temp_dir_to_cleanup) = if source.is_file() {
+        let temp_dir = std::env::temp_dir().join(format!(
+            "fmmloader_image_validation_{}",
+            uuid::Uuid::new_v4()
+        ));
+
+        extract_archive_with_limits(&source, &temp_dir, &limits)?;
+
+        let content_root = utils::find_graphics_content_root(&temp_dir)?;
+        (content_root, Some(temp_dir))
+    } else {
+        (source, None)
+    };
+
+    let analysis = analyze_graphics_pack(&validation_path);
+    let report = analysis.and_then(|analysis| {
+        graphics_analyzer::validate_pack_images(
+            &validation_path,
+            &analysis.pack_type,
+            sample_all,
+            check_config_mappings,
+        )
+    });
+
+    if let Some(temp_dir) = temp_dir_to_cleanup {
+        if let Err(e) = fs::remove_dir_all(&temp_dir) {
+            tracing::warn!("Failed to cleanup image validation temp directory: {}", e);
+        }
+    }
+
+    let report = report?;
+    tracing::info!(
+        "Image validation complete: {} issue(s) across {}/{} image(s)",
+        report.issues.len(),
+        report.images_checked,
+        report.images_total
+    );
+
+    Ok(report)
+}
+
 /// Validates existing graphics packs and identifies misplaced ones
 #[tauri::command]
 pub fn validate_graphics() -> Result<Vec<GraphicsPackIssue>, String> {
@@ -152,6 +660,168 @@ pub fn validate_graphics() -> Result<Vec<GraphicsPackIssue>, String> {
     Ok(issues)
 }
 
+/// Scans the whole installed `graphics` directory for byte-identical files shipped under
+/// different packs/names, returning every duplicate group plus the bytes reclaimable so the UI
+/// can show savings before the user commits to [`deduplicate_graphics`]. Runs on a blocking
+/// thread and streams [`crate::types::DuplicateScanProgress`] over the `duplicate-scan-progress`
+/// event, mirroring `migration-progress`.
+#[tauri::command]
+pub async fn find_duplicate_graphics(
+    app: tauri::AppHandle,
+) -> Result<DuplicateGraphicsReport, String> {
+    let config = load_config()?;
+    let user_dir = game_detection::get_fm_user_dir(config.user_dir_path.as_deref());
+    let graphics_dir = user_dir.join("graphics");
+
+    if !graphics_dir.exists() {
+        return Ok(DuplicateGraphicsReport {
+            groups: Vec::new(),
+            duplicate_count: 0,
+            bytes_reclaimable: 0,
+        });
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let groups = find_duplicate_graphics_tree(&graphics_dir, move |progress| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("duplicate-scan-progress", &progress);
+            }
+        })?;
+
+        let duplicate_count: usize = groups.iter().map(|g| g.paths.len() - 1).sum();
+        let bytes_reclaimable: u64 = groups
+            .iter()
+            .map(|g| g.size_bytes * (g.paths.len() as u64 - 1))
+            .sum();
+
+        Ok(DuplicateGraphicsReport {
+            groups,
+            duplicate_count,
+            bytes_reclaimable,
+        })
+    })
+    .await
+    .map_err(|e| format!("Duplicate scan task panicked: {}", e))?
+}
+
+/// Replaces duplicate files found by [`find_duplicate_graphics`] with hard links to the first
+/// (canonical) member of each group, reclaiming the disk space megapacks waste shipping the
+/// same face/logo under multiple IDs. Groups whose canonical file and a duplicate don't share a
+/// filesystem are left untouched (hard links can't cross devices) and counted in
+/// `skipped_cross_device`. A link's success is verified (by comparing inode/file IDs) before the
+/// duplicate it replaces is deleted, so a failed or silently wrong link can never lose data.
+#[tauri::command]
+pub async fn deduplicate_graphics(app: tauri::AppHandle) -> Result<GraphicsDedupeResult, String> {
+    let config = load_config()?;
+    let user_dir = game_detection::get_fm_user_dir(config.user_dir_path.as_deref());
+    let graphics_dir = user_dir.join("graphics");
+
+    if !graphics_dir.exists() {
+        return Ok(GraphicsDedupeResult {
+            files_linked: 0,
+            bytes_reclaimed: 0,
+            skipped_cross_device: 0,
+        });
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let groups = find_duplicate_graphics_tree(&graphics_dir, move |progress| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("duplicate-scan-progress", &progress);
+            }
+        })?;
+
+        let mut files_linked = 0usize;
+        let mut bytes_reclaimed = 0u64;
+        let mut skipped_cross_device = 0usize;
+
+        for group in &groups {
+            let Some((canonical, duplicates)) = group.paths.split_first() else {
+                continue;
+            };
+            let canonical_path = PathBuf::from(canonical);
+
+            for duplicate in duplicates {
+                let duplicate_path = PathBuf::from(duplicate);
+                match replace_with_hardlink(&canonical_path, &duplicate_path) {
+                    Ok(true) => {
+                        files_linked += 1;
+                        bytes_reclaimed += group.size_bytes;
+                    }
+                    Ok(false) => skipped_cross_device += 1,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to deduplicate '{}' against '{}': {}",
+                            duplicate_path.display(),
+                            canonical_path.display(),
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
+        tracing::info!(
+            "Deduplication complete: {} file(s) linked, {} byte(s) reclaimed, {} skipped (cross-device)",
+            files_linked,
+            bytes_reclaimed,
+            skipped_cross_device
+        );
+
+        Ok(GraphicsDedupeResult {
+            files_linked,
+            bytes_reclaimed,
+            skipped_cross_device,
+        })
+    })
+    .await
+    .map_err(|e| format!("Deduplication task panicked: {}", e))?
+}
+
+/// Replaces `duplicate` with a hard link to `canonical`, verifying the link actually resolves to
+/// the same file before removing the original bytes. Returns `Ok(false)` (not an error) when
+/// `canonical`/`duplicate` live on different filesystems, since hard-linking across devices is
+/// simply not possible and the caller should count that as skipped rather than failed.
+fn replace_with_hardlink(canonical: &Path, duplicate: &Path) -> Result<bool, String> {
+    let temp_link = duplicate.with_extension("fmmloader-dedupe-tmp");
+
+    if let Err(e) = fs::hard_link(canonical, &temp_link) {
+        if crate::mod_manager::is_cross_device_error(&e) {
+            return Ok(false);
+        }
+        return Err(format!("Failed to create hard link: {}", e));
+    }
+
+    let linked_to_canonical = same_file(&temp_link, canonical).unwrap_or(false);
+    if !linked_to_canonical {
+        let _ = fs::remove_file(&temp_link);
+        return Err("Hard link did not resolve to the canonical file".to_string());
+    }
+
+    fs::rename(&temp_link, duplicate)
+        .map_err(|e| format!("Failed to replace duplicate with hard link: {}", e))?;
+
+    Ok(true)
+}
+
+/// Whether `a` and `b` are the same file on disk (same device + inode on Unix), used to confirm
+/// a hard link actually landed before the file it replaces is deleted.
+#[cfg(unix)]
+fn same_file(a: &Path, b: &Path) -> io::Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+    let a_meta = fs::metadata(a)?;
+    let b_meta = fs::metadata(b)?;
+    Ok(a_meta.dev() == b_meta.dev() && a_meta.ino() == b_meta.ino())
+}
+
+#[cfg(not(unix))]
+fn same_file(a: &Path, b: &Path) -> io::Result<bool> {
+    // No stable file-identity comparison on non-Unix targets without nightly APIs; falling back
+    // to byte-for-byte equality is conservative (a false negative just means the caller treats a
+    // successful link as failed and reports it as an error rather than data loss).
+    Ok(fs::read(a)? == fs::read(b)?)
+}
+
 /// Adds a prefix to all PNG files in the provided directory (non-recursive).
 /// Useful for quickly migrating face packs from `123.png` to `face_123.png`.
 #[tauri::command]
@@ -259,32 +929,9 @@ pub fn prefix_graphics_files(
         dir_path
     );
 
-    if do_config && !config_files.is_empty() {
-        // Match from="..."; we skip if already prefixed.
-        let from_regex = regex::Regex::new("from=\"([^\"]+)\"")
-            .map_err(|e| format!("Failed to build regex: {e}"))?;
-
+    if do_config {
         for config_path in config_files {
-            let contents = fs::read_to_string(&config_path)
-                .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
-
-            let replaced = from_regex.replace_all(&contents, |caps: &regex::Captures| {
-                let current = &caps[1];
-                if current.starts_with(&prefix) {
-                    format!("from=\"{}\"", current)
-                } else {
-                    format!("from=\"{}{}\"", prefix, current)
-                }
-            });
-
-            if replaced != contents {
-                fs::write(&config_path, replaced.as_ref()).map_err(|e| {
-                    format!(
-                        "Failed to write updated config.xml at {}: {}",
-                        config_path.display(),
-                        e
-                    )
-                })?;
+            if add_config_xml_prefix(&config_path, &prefix)? {
                 tracing::info!("Updated config.xml prefixes at {}", config_path.display());
             }
         }
@@ -293,12 +940,134 @@ pub fn prefix_graphics_files(
     Ok(files_to_rename.len())
 }
 
-/// Migrates a graphics pack to the correct subdirectory
+/// Rewrites every `from="..."` attribute in the `config.xml` at `config_path` to be prefixed
+/// with `prefix`, skipping attributes that already start with it. Shared by
+/// [`prefix_graphics_files`] and [`migrate_legacy_graphics_pack`]. Returns whether the file
+/// actually changed.
+fn add_config_xml_prefix(config_path: &Path, prefix: &str) -> Result<bool, String> {
+    let from_regex = regex::Regex::new("from=\"([^\"]+)\"")
+        .map_err(|e| format!("Failed to build regex: {e}"))?;
+
+    let contents = fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
+
+    let replaced = from_regex.replace_all(&contents, |caps: &regex::Captures| {
+        let current = &caps[1];
+        if current.starts_with(prefix) {
+            format!("from=\"{}\"", current)
+        } else {
+            format!("from=\"{}{}\"", prefix, current)
+        }
+    });
+
+    if replaced == contents {
+        return Ok(false);
+    }
+
+    fs::write(config_path, replaced.as_ref()).map_err(|e| {
+        format!(
+            "Failed to write updated config.xml at {}: {}",
+            config_path.display(),
+            e
+        )
+    })?;
+
+    Ok(true)
+}
+
+/// Whether `kind` (from [`graphics_analyzer::detect_known_pack`]) is consistent with
+/// `pack_type` (from [`analyze_graphics_pack`]'s independent content-based scan). `Unknown` on
+/// either side always matches — there's nothing to contradict — and a `Mixed` pack type matches
+/// any `kind` its constituent types include.
+fn detected_kind_matches_pack_type(
+    kind: &crate::types::DetectedPackKind,
+    pack_type: &graphics_analyzer::GraphicsPackType,
+) -> bool {
+    use crate::types::DetectedPackKind;
+    use graphics_analyzer::GraphicsPackType;
+
+    if matches!(kind, DetectedPackKind::Unknown) || matches!(pack_type, GraphicsPackType::Unknown) {
+        return true;
+    }
+
+    match pack_type {
+        GraphicsPackType::Faces => matches!(kind, DetectedPackKind::Faces),
+        GraphicsPackType::Logos => matches!(kind, DetectedPackKind::Logos),
+        GraphicsPackType::Kits => matches!(kind, DetectedPackKind::Kits),
+        GraphicsPackType::Mixed(types) => types.iter().any(|t| match t {
+            GraphicsPackType::Faces => matches!(kind, DetectedPackKind::Faces),
+            GraphicsPackType::Logos => matches!(kind, DetectedPackKind::Logos),
+            GraphicsPackType::Kits => matches!(kind, DetectedPackKind::Kits),
+            _ => false,
+        }),
+        GraphicsPackType::Unknown => true,
+    }
+}
+
+/// Detects whether a freshly extracted pack at `content_root` uses the legacy/unversioned
+/// layout — a flat pack whose `config.xml` `from=` paths were written assuming they sit at the
+/// graphics root instead of under their type subdirectory — and rewrites those paths in place if
+/// so. Returns whether a migration was performed, so [`import_graphics_pack_with_type`] can
+/// report it to the caller.
+fn migrate_legacy_graphics_pack(
+    content_root: &Path,
+    pack_type: &graphics_analyzer::GraphicsPackType,
+    is_flat_pack: bool,
+    has_config_xml: bool,
+) -> Result<bool, String> {
+    if !is_flat_pack || !has_config_xml {
+        return Ok(false);
+    }
+
+    let expected_subdir = match pack_type {
+        graphics_analyzer::GraphicsPackType::Faces => "faces",
+        graphics_analyzer::GraphicsPackType::Logos => "logos",
+        graphics_analyzer::GraphicsPackType::Kits => "kits",
+        graphics_analyzer::GraphicsPackType::Mixed(_) | graphics_analyzer::GraphicsPackType::Unknown => {
+            return Ok(false)
+        }
+    };
+    let prefix = format!("{}/", expected_subdir);
+
+    let mut migrated = false;
+    for entry in WalkDir::new(content_root).into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let is_config_xml = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|name| name.eq_ignore_ascii_case("config.xml"))
+            .unwrap_or(false);
+        if !is_config_xml {
+            continue;
+        }
+
+        if add_config_xml_prefix(path, &prefix)? {
+            migrated = true;
+        }
+    }
+
+    if migrated {
+        tracing::info!(
+            "Migrated legacy graphics pack layout at {:?} to format version {}",
+            content_root,
+            GRAPHICS_PACK_FORMAT_VERSION
+        );
+    }
+
+    Ok(migrated)
+}
+
+/// Migrates a graphics pack to the correct subdirectory. `copy_threads` overrides the default
+/// concurrency of the flat-pack copy loop; see [`build_copy_pool`].
 #[tauri::command]
 pub async fn migrate_graphics_pack(
     app: tauri::AppHandle,
     pack_name: String,
     target_subdir: String,
+    copy_threads: Option<usize>,
 ) -> Result<String, String> {
     tracing::info!("Migrating pack '{}' to '{}'", pack_name, target_subdir);
 
@@ -329,6 +1098,8 @@ pub async fn migrate_graphics_pack(
             has_config_xml: false,
             subdirectory_breakdown: std::collections::HashMap::new(),
             is_flat_pack: false,
+            invalid_files: Vec::new(),
+            duplicate_bytes: 0,
         }
     });
 
@@ -352,7 +1123,11 @@ pub async fn migrate_graphics_pack(
         .map_err(|e| format!("Failed to create backup directory: {}", e))?;
 
     // Copy to backup first
-    utils::copy_dir_recursive(&current_path, &backup_path)?;
+    let backup_report =
+        utils::copy_dir_recursive(&current_path, &backup_path).map_err(|e| e.to_string())?;
+    for entry in &backup_report.skipped {
+        tracing::warn!("Skipped {:?} while backing up pack: {}", entry.path, entry.reason);
+    }
 
     tracing::info!("Backup created, now moving to new location");
 
@@ -377,51 +1152,104 @@ pub async fn migrate_graphics_pack(
 
         // Count total files for progress tracking
         let total_files = utils::count_files_in_dir(&current_path)?;
-        let mut current_file_count = 0;
 
         // Emit initial progress
         if let Some(window) = app.get_webview_window("main") {
             let progress = ExtractionProgress {
-                current: 0,
-                total: total_files,
+                current_stage: 1,
+                max_stage: 1,
+                stage_name: "migrating".to_string(),
+                entries_checked: 0,
+                entries_total: total_files,
                 current_file: "Starting migration...".to_string(),
                 bytes_processed: 0,
-                phase: "migrating".to_string(),
+                audit_findings: Vec::new(),
+                duplicates_skipped: 0,
+                images_normalized: 0,
+                images_rejected: 0,
+                mixed_pack_routed: HashMap::new(),
             };
             let _ = window.emit("migration-progress", &progress);
         }
 
-        for entry in fs::read_dir(&current_path).map_err(|e| e.to_string())? {
-            let entry = entry.map_err(|e| e.to_string())?;
-            let src_path = entry.path();
-            let file_name = entry.file_name();
-            let dst_path = target_dir.join(&file_name);
+        // Copy each top-level entry concurrently over a dedicated rayon pool — a megapack's
+        // faces/logos/kits subdirectories copy independently of each other, so there's no reason
+        // to serialize them. `current_file_count` is an atomic running total so progress stays
+        // monotonic across threads; `seen_targets` guards against two entries ever resolving to
+        // the same destination path (e.g. a case-insensitive filesystem collapsing two
+        // differently-cased names) racing to write the same file.
+        let entries: Vec<fs::DirEntry> = fs::read_dir(&current_path)
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, io::Error>>()
+            .map_err(|e| e.to_string())?;
+
+        let current_file_count = std::sync::atomic::AtomicUsize::new(0);
+        let migration_skipped: std::sync::Mutex<Vec<SkippedCopyEntry>> =
+            std::sync::Mutex::new(Vec::new());
+        let seen_targets: std::sync::Mutex<std::collections::HashSet<PathBuf>> =
+            std::sync::Mutex::new(std::collections::HashSet::new());
+
+        let pool = build_copy_pool(copy_threads)?;
+        let app_for_pool = app.clone();
+        let target_dir_for_pool = target_dir.clone();
+
+        pool.install(|| -> Result<(), String> {
+            use rayon::prelude::*;
+            entries.into_par_iter().try_for_each(|entry| -> Result<(), String> {
+                let src_path = entry.path();
+                let file_name = entry.file_name();
+                let dst_path = target_dir_for_pool.join(&file_name);
+
+                let target_key = PathBuf::from(dst_path.to_string_lossy().to_lowercase());
+                if !seen_targets.lock().unwrap().insert(target_key) {
+                    return Err(format!(
+                        "Duplicate migration target detected: {}",
+                        dst_path.display()
+                    ));
+                }
 
-            if src_path.is_dir() {
-                // Copy directory and update progress
-                let dir_file_count = utils::count_files_in_dir(&src_path)?;
-                utils::copy_dir_recursive(&src_path, &dst_path)?;
-                current_file_count += dir_file_count;
-            } else {
-                fs::copy(&src_path, &dst_path)
-                    .map_err(|e| format!("Failed to copy file: {}", e))?;
-                current_file_count += 1;
-            }
+                let copied = if src_path.is_dir() {
+                    let dir_file_count = utils::count_files_in_dir(&src_path)?;
+                    let dir_report = utils::copy_dir_recursive(&src_path, &dst_path)
+                        .map_err(|e| e.to_string())?;
+                    migration_skipped.lock().unwrap().extend(dir_report.skipped);
+                    dir_file_count
+                } else {
+                    fs::copy(&src_path, &dst_path)
+                        .map_err(|e| format!("Failed to copy file: {}", e))?;
+                    1
+                };
 
-            // Emit progress every 100 files or on last file
-            if current_file_count % 100 == 0 || current_file_count == total_files {
-                if let Some(window) = app.get_webview_window("main") {
-                    let progress = ExtractionProgress {
-                        current: current_file_count,
-                        total: total_files,
-                        current_file: format!("Migrating {}", file_name.to_string_lossy()),
-                        bytes_processed: 0,
-                        phase: "migrating".to_string(),
-                    };
-                    let _ = window.emit("migration-progress", &progress);
+                let new_count =
+                    current_file_count.fetch_add(copied, Ordering::Relaxed) + copied;
+
+                // Emit roughly every 100 files (or on the last one); `< copied` catches the case
+                // where a whole subdirectory's worth of files jumped straight past a boundary.
+                if copied > 0 && (new_count % 100 < copied || new_count == total_files) {
+                    if let Some(window) = app_for_pool.get_webview_window("main") {
+                        let progress = ExtractionProgress {
+                            current_stage: 1,
+                            max_stage: 1,
+                            stage_name: "migrating".to_string(),
+                            entries_checked: new_count,
+                            entries_total: total_files,
+                            current_file: format!("Migrating {}", file_name.to_string_lossy()),
+                            bytes_processed: 0,
+                            audit_findings: Vec::new(),
+                            duplicates_skipped: 0,
+                            images_normalized: 0,
+                            images_rejected: 0,
+                            mixed_pack_routed: HashMap::new(),
+                        };
+                        let _ = window.emit("migration-progress", &progress);
+                    }
                 }
-            }
-        }
+
+                Ok(())
+            })
+        })?;
+
+        let migration_skipped = migration_skipped.into_inner().unwrap();
 
         // Remove the original pack directory
         fs::remove_dir_all(&current_path)
@@ -430,11 +1258,18 @@ pub async fn migrate_graphics_pack(
         // Emit completion
         if let Some(window) = app.get_webview_window("main") {
             let progress = ExtractionProgress {
-                current: total_files,
-                total: total_files,
+                current_stage: 1,
+                max_stage: 1,
+                stage_name: "complete".to_string(),
+                entries_checked: total_files,
+                entries_total: total_files,
                 current_file: "Migration complete".to_string(),
                 bytes_processed: 0,
-                phase: "complete".to_string(),
+                audit_findings: Vec::new(),
+                duplicates_skipped: 0,
+                images_normalized: 0,
+                images_rejected: 0,
+                mixed_pack_routed: HashMap::new(),
             };
             let _ = window.emit("migration-progress", &progress);
         }
@@ -448,11 +1283,25 @@ pub async fn migrate_graphics_pack(
             tracing::info!("Backup cleaned up successfully");
         }
 
-        Ok(format!(
-            "Pack '{}' contents moved to {}",
-            pack_name,
-            target_dir.display()
-        ))
+        for entry in &migration_skipped {
+            tracing::warn!("Skipped {:?} while migrating pack: {}", entry.path, entry.reason);
+        }
+
+        Ok(if migration_skipped.is_empty() {
+            format!(
+                "Pack '{}' contents moved to {}",
+                pack_name,
+                target_dir.display()
+            )
+        } else {
+            format!(
+                "Pack '{}' contents moved to {} ({} entr{} skipped — see logs)",
+                pack_name,
+                target_dir.display(),
+                migration_skipped.len(),
+                if migration_skipped.len() == 1 { "y" } else { "ies" }
+            )
+        })
     } else {
         // For structured packs, move the whole directory
         let target_path = target_dir.join(&pack_name);
@@ -468,11 +1317,18 @@ pub async fn migrate_graphics_pack(
         // Emit progress for structured pack (quick rename operation)
         if let Some(window) = app.get_webview_window("main") {
             let progress = ExtractionProgress {
-                current: 0,
-                total: 1,
+                current_stage: 1,
+                max_stage: 1,
+                stage_name: "migrating".to_string(),
+                entries_checked: 0,
+                entries_total: 1,
                 current_file: format!("Moving {}", pack_name),
                 bytes_processed: 0,
-                phase: "migrating".to_string(),
+                audit_findings: Vec::new(),
+                duplicates_skipped: 0,
+                images_normalized: 0,
+                images_rejected: 0,
+                mixed_pack_routed: HashMap::new(),
             };
             let _ = window.emit("migration-progress", &progress);
         }
@@ -483,11 +1339,18 @@ pub async fn migrate_graphics_pack(
         // Emit completion
         if let Some(window) = app.get_webview_window("main") {
             let progress = ExtractionProgress {
-                current: 1,
-                total: 1,
+                current_stage: 1,
+                max_stage: 1,
+                stage_name: "complete".to_string(),
+                entries_checked: 1,
+                entries_total: 1,
                 current_file: "Migration complete".to_string(),
                 bytes_processed: 0,
-                phase: "complete".to_string(),
+                audit_findings: Vec::new(),
+                duplicates_skipped: 0,
+                images_normalized: 0,
+                images_rejected: 0,
+                mixed_pack_routed: HashMap::new(),
             };
             let _ = window.emit("migration-progress", &progress);
         }
@@ -549,7 +1412,22 @@ pub fn check_graphics_conflicts(
     Ok(None)
 }
 
-/// Async command to import graphics packs with type detection and smart routing
+/// Async command to import graphics packs with type detection and smart routing. `source_path`
+/// isn't required to be a zip — the container is sniffed by magic bytes (see
+/// [`detect_archive_format_label`]), so a `.tar.gz`/`.tar.xz`/`.tar.zst` megapack extracts the
+/// same way a zip does; the detected format is stamped onto the installed pack's
+/// [`GraphicsPackMetadata::source_format`]. `max_total_bytes`/
+/// `max_entries`/`max_entry_bytes` override [`ExtractionLimits::default`]; see
+/// [`resolve_extraction_limits`]. `copy_threads` overrides the default concurrency of the install
+/// copy; see [`build_copy_pool`]. Progress is reported in [`IMPORT_STAGE_COUNT`] stages —
+/// extracting, analyzing, writing config, copying — over the `graphics-extraction-progress` event,
+/// with an extra `"dedup"`-phase event fired before copying starts. Incoming images within
+/// `dedup_threshold` Hamming-distance dHash bits (default
+/// [`graphics_analyzer::DEFAULT_DHASH_HAMMING_THRESHOLD`]) of an image already installed by a
+/// previous pack are skipped rather than copied; see [`ImageDedup`]. When `normalize_images` is
+/// set, every non-PNG image the copy encounters (`.jpg`/`.bmp`/`.webp`/HEIF/RAW) is decoded and
+/// re-encoded to PNG instead of copied as-is, and rejected outright if it fails to decode or
+/// decodes to a degenerate/absurd size; see [`ImageNormalizer`].
 #[tauri::command]
 pub async fn import_graphics_pack_with_type(
     app: tauri::AppHandle,
@@ -557,32 +1435,43 @@ pub async fn import_graphics_pack_with_type(
     target_path: String,
     should_split: bool,
     _force: bool,
-) -> Result<String, String> {
+    max_total_bytes: Option<u64>,
+    max_entries: Option<usize>,
+    max_entry_bytes: Option<u64>,
+    copy_threads: Option<usize>,
+    dedup_threshold: Option<u32>,
+    normalize_images: Option<bool>,
+) -> Result<GraphicsImportResult, ExtractionError> {
     tracing::info!(
         "Starting graphics pack import with type detection from: {}",
         source_path
     );
 
     let source = PathBuf::from(&source_path);
+    let limits = resolve_extraction_limits(max_total_bytes, max_entries, max_entry_bytes);
 
     // Validate source exists
     if !source.exists() {
-        return Err("Source path does not exist".to_string());
+        return Err(ExtractionError::Other("Source path does not exist".to_string()));
     }
 
-    // Check if it's an archive file
-    let is_archive = source.is_file()
-        && source
-            .extension()
-            .and_then(|s| s.to_str())
-            .map(|ext| ext.eq_ignore_ascii_case("zip"))
-            .unwrap_or(false);
-
-    if !is_archive {
-        return Err("Only ZIP archives are currently supported for graphics packs".to_string());
+    // Sniff the container format from magic bytes rather than trusting the extension — large FM
+    // graphics megapacks are routinely shipped as `.tar.xz`/`.tar.zst` rather than `.zip` to get
+    // a better ratio on thousands of similar PNGs, and `extract_archive_async` below already
+    // handles every format `detect_archive_format_label` recognizes.
+    if !source.is_file() {
+        return Err(ExtractionError::Other(
+            "Source path is not an archive file".to_string(),
+        ));
     }
-
-    // Check source file size and estimate extraction size
+    let source_format = detect_archive_format_label(&source).map_err(|e| {
+        ExtractionError::Other(format!("Unsupported graphics pack archive: {}", e))
+    })?;
+
+    // Check source file size for the disk-space heads-up below. The real protection against an
+    // oversized or malicious pack is `limits`, enforced by `extract_archive_async` while
+    // streaming — a guessed multiple of the compressed size can't be trusted since it comes
+    // from the archive's own (attacker-controlled) metadata.
     let source_size = fs::metadata(&source)
         .map_err(|e| format!("Failed to read source file: {}", e))?
         .len();
@@ -611,13 +1500,20 @@ pub async fn import_graphics_pack_with_type(
     let source_clone = source.clone();
     let temp_dir_clone = temp_dir.clone();
 
-    let extracted_dir = extract_zip_async(source_clone, temp_dir_clone, move |progress| {
-        if let Some(window) = app_clone.get_webview_window("main") {
-            if let Err(e) = window.emit("graphics-extraction-progress", &progress) {
-                tracing::error!("Failed to emit progress event: {}", e);
+    let extracted_dir = extract_archive_async(
+        source_clone,
+        temp_dir_clone,
+        limits,
+        IMPORT_STAGE_EXTRACTING,
+        IMPORT_STAGE_COUNT,
+        move |progress| {
+            if let Some(window) = app_clone.get_webview_window("main") {
+                if let Err(e) = window.emit("graphics-extraction-progress", &progress) {
+                    tracing::error!("Failed to emit progress event: {}", e);
+                }
             }
-        }
-    })
+        },
+    )
     .await?;
 
     tracing::info!("Extraction complete to: {:?}", extracted_dir);
@@ -631,6 +1527,25 @@ pub async fn import_graphics_pack_with_type(
     fs::create_dir_all(&graphics_dir)
         .map_err(|e| format!("Failed to create graphics directory: {}", e))?;
 
+    // Emit analyzing stage progress
+    if let Some(window) = app.get_webview_window("main") {
+        let progress = ExtractionProgress {
+            current_stage: IMPORT_STAGE_ANALYZING,
+            max_stage: IMPORT_STAGE_COUNT,
+            stage_name: "analyzing".to_string(),
+            entries_checked: 0,
+            entries_total: 0,
+            current_file: "Analyzing pack contents...".to_string(),
+            bytes_processed: 0,
+            audit_findings: Vec::new(),
+            duplicates_skipped: 0,
+            images_normalized: 0,
+            images_rejected: 0,
+            mixed_pack_routed: HashMap::new(),
+        };
+        let _ = window.emit("graphics-extraction-progress", &progress);
+    }
+
     // Find the actual graphics content root
     let content_root = utils::find_graphics_content_root(&extracted_dir)?;
     tracing::info!("Found graphics content root: {:?}", content_root);
@@ -639,6 +1554,60 @@ pub async fn import_graphics_pack_with_type(
     let analysis = analyze_graphics_pack(&content_root)?;
     tracing::info!("Pack analysis: {:?}", analysis);
 
+    // Identify which known pack release this is (see graphics_analyzer::detect_known_pack) and
+    // warn if its actual contents don't look like what that release is supposed to contain --
+    // usually a sign of a corrupted or mislabeled download rather than a genuinely new pack.
+    let detected_pack = graphics_analyzer::detect_known_pack(&content_root);
+    let known_kind_matches =
+        detected_kind_matches_pack_type(&detected_pack.kind, &analysis.pack_type);
+    if detected_pack.confidence > 0.0 && !known_kind_matches {
+        tracing::warn!(
+            "Pack '{}' was identified as {:?} (v{}) but its contents look like {:?} -- \
+             the download may be corrupted or mislabeled",
+            detected_pack.id,
+            detected_pack.kind,
+            detected_pack.version.as_deref().unwrap_or("unknown"),
+            analysis.pack_type
+        );
+    }
+
+    // Emit writing-config stage progress. Fires unconditionally, even when the pack is already
+    // on the current layout and nothing gets rewritten, so `max_stage` stays consistent across
+    // every import rather than silently skipping a numbered stage.
+    if let Some(window) = app.get_webview_window("main") {
+        let progress = ExtractionProgress {
+            current_stage: IMPORT_STAGE_WRITING_CONFIG,
+            max_stage: IMPORT_STAGE_COUNT,
+            stage_name: "writing config".to_string(),
+            entries_checked: 0,
+            entries_total: 0,
+            current_file: "Checking pack layout...".to_string(),
+            bytes_processed: 0,
+            audit_findings: Vec::new(),
+            duplicates_skipped: 0,
+            images_normalized: 0,
+            images_rejected: 0,
+            mixed_pack_routed: HashMap::new(),
+        };
+        let _ = window.emit("graphics-extraction-progress", &progress);
+    }
+
+    // Detect and upgrade legacy/unversioned layouts before installing, so the pack lands on
+    // disk already matching GRAPHICS_PACK_FORMAT_VERSION.
+    let migrated = migrate_legacy_graphics_pack(
+        &content_root,
+        &analysis.pack_type,
+        analysis.is_flat_pack,
+        analysis.has_config_xml,
+    )?;
+    if migrated {
+        tracing::warn!(
+            "Pack at {:?} used a legacy layout and was upgraded to format version {}",
+            content_root,
+            GRAPHICS_PACK_FORMAT_VERSION
+        );
+    }
+
     let pack_name = source
         .file_stem()
         .and_then(|n| n.to_str())
@@ -648,8 +1617,51 @@ pub async fn import_graphics_pack_with_type(
     // Determine installation targets
     let final_target = PathBuf::from(&target_path);
 
+    // Load the registry once, both to seed the dedup pass below with every image hash already
+    // installed by a previous pack and to register this pack's own metadata once it's done.
+    let mut registry = load_graphics_packs().unwrap_or_default();
+    let installed_image_hashes: Vec<u64> = registry
+        .graphics_packs
+        .iter()
+        .flat_map(|pack| pack.image_hashes.iter().copied())
+        .collect();
+    let dedup_threshold_value =
+        dedup_threshold.unwrap_or(graphics_analyzer::DEFAULT_DHASH_HAMMING_THRESHOLD);
+    let dedup = std::sync::Arc::new(ImageDedup::new(installed_image_hashes, dedup_threshold_value));
+    let normalizer = std::sync::Arc::new(ImageNormalizer::new(
+        normalize_images.unwrap_or(false),
+        &analysis.pack_type,
+    ));
+
+    // Emit the dedup-phase event up front, still under the "copying" stage (see
+    // `IMPORT_STAGE_COUNT`'s doc comment on why stage numbering never changes mid-import) since
+    // the actual skip checks run file-by-file as part of the copy below.
+    if let Some(window) = app.get_webview_window("main") {
+        let progress = ExtractionProgress {
+            current_stage: IMPORT_STAGE_COPYING,
+            max_stage: IMPORT_STAGE_COUNT,
+            stage_name: "dedup".to_string(),
+            entries_checked: 0,
+            entries_total: dedup.installed_hashes.len(),
+            current_file: format!(
+                "Checking against {} previously installed image(s)...",
+                dedup.installed_hashes.len()
+            ),
+            bytes_processed: 0,
+            audit_findings: Vec::new(),
+            duplicates_skipped: 0,
+            images_normalized: 0,
+            images_rejected: 0,
+            mixed_pack_routed: HashMap::new(),
+        };
+        let _ = window.emit("graphics-extraction-progress", &progress);
+    }
+
     // Track total installed files for metadata
     let mut total_installed_files = 0;
+    // How many files `split_mixed_pack` routed to each category (faces/logos/kits), so the UI
+    // can see the routing decision instead of just a single opaque file count.
+    let mut mixed_pack_routed: HashMap<String, usize> = HashMap::new();
 
     // Handle mixed packs if splitting is requested
     if should_split
@@ -662,21 +1674,94 @@ pub async fn import_graphics_pack_with_type(
 
         let split_map = split_mixed_pack(&content_root, &analysis)?;
 
-        for (pack_type, source_dir) in split_map {
-            let target_dir = graphics_dir
-                .join(&pack_type)
-                .join(format!("{}-{}", pack_name, pack_type));
+        // Copying every split portion is filesystem-heavy, so it runs on a blocking thread
+        // rather than the async runtime's worker thread.
+        let graphics_dir_for_split = graphics_dir.clone();
+        let pack_name_for_split = pack_name.clone();
+        let app_for_split = app.clone();
+        let dedup_for_split = dedup.clone();
+        let normalizer_for_split = normalizer.clone();
+        let (installed_files, routed) = tauri::async_runtime::spawn_blocking(
+            move || -> Result<(usize, HashMap<String, usize>), String> {
+            let mut installed_files = 0;
+            let mut routed: HashMap<String, usize> = HashMap::new();
+
+            for (pack_type, source_dir) in split_map {
+                let target_dir = graphics_dir_for_split
+                    .join(&pack_type)
+                    .join(format!("{}-{}", pack_name_for_split, pack_type));
+
+                fs::create_dir_all(&target_dir)
+                    .map_err(|e| format!("Failed to create target directory: {}", e))?;
+
+                // Copy this portion
+                let file_count = utils::count_files_in_dir(&source_dir)?;
+                let app_for_progress = app_for_split.clone();
+                let report = copy_graphics_content(
+                    &source_dir,
+                    &target_dir,
+                    file_count,
+                    copy_threads,
+                    Some(dedup_for_split.as_ref()),
+                    Some(normalizer_for_split.as_ref()),
+                    move |current, bytes_processed, current_file| {
+                        if let Some(window) = app_for_progress.get_webview_window("main") {
+                            let progress = ExtractionProgress {
+                                current_stage: IMPORT_STAGE_COPYING,
+                                max_stage: IMPORT_STAGE_COUNT,
+                                stage_name: "copying".to_string(),
+                                entries_checked: current,
+                                entries_total: file_count,
+                                current_file,
+                                bytes_processed,
+                                audit_findings: Vec::new(),
+                                duplicates_skipped: 0,
+                                images_normalized: 0,
+                                images_rejected: 0,
+                                mixed_pack_routed: HashMap::new(),
+                            };
+                            if let Err(e) = window.emit("graphics-extraction-progress", &progress) {
+                                tracing::error!("Failed to emit copy progress event: {}", e);
+                            }
+                        }
+                    },
+                )?;
+                for entry in &report.skipped {
+                    tracing::warn!(
+                        "Skipped {:?} while installing {} pack: {}",
+                        entry.path,
+                        pack_type,
+                        entry.reason
+                    );
+                }
+                if report.duplicates_skipped > 0 {
+                    tracing::info!(
+                        "Skipped {} duplicate image(s) while installing {} pack",
+                        report.duplicates_skipped,
+                        pack_type
+                    );
+                }
+                if report.images_normalized > 0 || report.images_rejected > 0 {
+                    tracing::info!(
+                        "Normalized {} and rejected {} image(s) while installing {} pack",
+                        report.images_normalized,
+                        report.images_rejected,
+                        pack_type
+                    );
+                }
 
-            fs::create_dir_all(&target_dir)
-                .map_err(|e| format!("Failed to create target directory: {}", e))?;
+                installed_files += file_count;
+                *routed.entry(pack_type.clone()).or_insert(0) += file_count;
+                tracing::info!("Installed {} pack to: {:?}", pack_type, target_dir);
+            }
 
-            // Copy this portion
-            let file_count = utils::count_files_in_dir(&source_dir)?;
-            copy_graphics_content(&source_dir, &target_dir, file_count, |_, _| {})?;
+            Ok((installed_files, routed))
+        })
+        .await
+        .map_err(|e| format!("Graphics pack split-install task panicked: {}", e))??;
 
-            total_installed_files += file_count;
-            tracing::info!("Installed {} pack to: {:?}", pack_type, target_dir);
-        }
+        total_installed_files += installed_files;
+        mixed_pack_routed = routed;
     } else {
         // Single install location
         // Determine if this is a flat pack - if so, install contents directly to target directory
@@ -710,14 +1795,22 @@ pub async fn import_graphics_pack_with_type(
         fs::create_dir_all(&install_path)
             .map_err(|e| format!("Failed to create install directory: {}", e))?;
 
-        // Emit indexing phase progress
+        // Emit indexing progress, still under the copying stage since counting files is the
+        // prelude to the actual copy below.
         if let Some(window) = app.get_webview_window("main") {
             let progress = ExtractionProgress {
-                current: 0,
-                total: 100,
+                current_stage: IMPORT_STAGE_COPYING,
+                max_stage: IMPORT_STAGE_COUNT,
+                stage_name: "indexing".to_string(),
+                entries_checked: 0,
+                entries_total: 0,
                 current_file: "Indexing files...".to_string(),
                 bytes_processed: 0,
-                phase: "indexing".to_string(),
+                audit_findings: Vec::new(),
+                duplicates_skipped: 0,
+                images_normalized: 0,
+                images_rejected: 0,
+                mixed_pack_routed: HashMap::new(),
             };
             let _ = window.emit("graphics-extraction-progress", &progress);
         }
@@ -726,33 +1819,86 @@ pub async fn import_graphics_pack_with_type(
         let file_count = utils::count_files_in_dir(&content_root)?;
         total_installed_files = file_count;
 
-        // Copy with progress tracking based on pack type
+        // Copy with progress tracking based on pack type. Runs on a blocking thread since
+        // copying a large graphics pack is filesystem-heavy and would otherwise stall the
+        // async runtime's worker thread.
         let app_clone_copy = app.clone();
-
-        // Copy files based on pack type
-        copy_flat_pack_content(
-            &content_root,
-            &install_path,
-            file_count,
-            move |current, current_file| {
-                if let Some(window) = app_clone_copy.get_webview_window("main") {
-                    let progress = ExtractionProgress {
-                        current,
-                        total: file_count,
-                        current_file,
-                        bytes_processed: 0,
-                        phase: "copying".to_string(),
-                    };
-                    if let Err(e) = window.emit("graphics-extraction-progress", &progress) {
-                        tracing::error!("Failed to emit copy progress event: {}", e);
+        let content_root_for_copy = content_root.clone();
+        let install_path_for_copy = install_path.clone();
+        let dedup_for_copy = dedup.clone();
+        let normalizer_for_copy = normalizer.clone();
+
+        let copy_report = tauri::async_runtime::spawn_blocking(move || {
+            copy_flat_pack_content(
+                &content_root_for_copy,
+                &install_path_for_copy,
+                file_count,
+                copy_threads,
+                Some(dedup_for_copy.as_ref()),
+                Some(normalizer_for_copy.as_ref()),
+                move |current, bytes_processed, current_file| {
+                    if let Some(window) = app_clone_copy.get_webview_window("main") {
+                        let progress = ExtractionProgress {
+                            current_stage: IMPORT_STAGE_COPYING,
+                            max_stage: IMPORT_STAGE_COUNT,
+                            stage_name: "copying".to_string(),
+                            entries_checked: current,
+                            entries_total: file_count,
+                            current_file,
+                            bytes_processed,
+                            audit_findings: Vec::new(),
+                            duplicates_skipped: 0,
+                            images_normalized: 0,
+                            images_rejected: 0,
+                            mixed_pack_routed: HashMap::new(),
+                        };
+                        if let Err(e) = window.emit("graphics-extraction-progress", &progress) {
+                            tracing::error!("Failed to emit copy progress event: {}", e);
+                        }
                     }
-                }
-            },
-        )?;
+                },
+            )
+        })
+        .await
+        .map_err(|e| format!("Graphics pack copy task panicked: {}", e))??;
+
+        for entry in &copy_report.skipped {
+            tracing::warn!(
+                "Skipped {:?} while installing graphics pack: {}",
+                entry.path,
+                entry.reason
+            );
+        }
+        if copy_report.duplicates_skipped > 0 {
+            tracing::info!(
+                "Skipped {} duplicate image(s) while installing graphics pack",
+                copy_report.duplicates_skipped
+            );
+        }
+        if copy_report.images_normalized > 0 || copy_report.images_rejected > 0 {
+            tracing::info!(
+                "Normalized {} and rejected {} image(s) while installing graphics pack",
+                copy_report.images_normalized,
+                copy_report.images_rejected
+            );
+        }
 
         tracing::info!("Installed pack to: {:?}", install_path);
     }
 
+    // Pull the dedup pass's results back out now that every copy using it has finished: how
+    // many duplicates it skipped overall, and the hashes of the images it actually let through,
+    // so this pack's own images become part of the haystack for the *next* import.
+    let dedup = std::sync::Arc::try_unwrap(dedup)
+        .unwrap_or_else(|_| panic!("dedup state still shared after copy completed"));
+    let duplicates_skipped = dedup.duplicates_skipped();
+    let image_hashes = dedup.into_new_hashes();
+
+    let normalizer = std::sync::Arc::try_unwrap(normalizer)
+        .unwrap_or_else(|_| panic!("normalizer state still shared after copy completed"));
+    let images_normalized = normalizer.converted();
+    let images_rejected = normalizer.rejected();
+
     // Register the pack in the metadata registry
     let pack_type_str = match &analysis.pack_type {
         graphics_analyzer::GraphicsPackType::Faces => "Faces",
@@ -772,12 +1918,21 @@ pub async fn import_graphics_pack_with_type(
             .and_then(|n| n.to_str())
             .unwrap_or("unknown.zip")
             .to_string(),
+        source_format,
         pack_type: pack_type_str.to_string(),
         installed_to: final_target.to_str().unwrap_or("").to_string(),
+        // Graphics packs are always placed by copying their bytes into the graphics
+        // directory (see `copy_flat_pack_content`/`copy_graphics_content` above), regardless
+        // of `Config::default_install_mode` — linking is only wired up for `FileEntry`-based
+        // mod installs so far.
+        install_mode: crate::types::InstallMode::Copy,
+        is_junction: false,
+        format_version: GRAPHICS_PACK_FORMAT_VERSION,
+        image_hashes,
     };
 
-    // Load registry, add pack, and save
-    let mut registry = load_graphics_packs().unwrap_or_default();
+    // Add this pack to the registry loaded at the top (alongside the installed hashes the
+    // dedup pass above was seeded with) and save.
     registry.graphics_packs.push(pack_metadata);
     save_graphics_packs(&registry)?;
 
@@ -786,11 +1941,18 @@ pub async fn import_graphics_pack_with_type(
     // Emit completion event
     if let Some(window) = app.get_webview_window("main") {
         let completion = ExtractionProgress {
-            current: 100,
-            total: 100,
+            current_stage: IMPORT_STAGE_COUNT,
+            max_stage: IMPORT_STAGE_COUNT,
+            stage_name: "complete".to_string(),
+            entries_checked: total_installed_files,
+            entries_total: total_installed_files,
             current_file: "Installation complete".to_string(),
             bytes_processed: 0,
-            phase: "complete".to_string(),
+            audit_findings: Vec::new(),
+            duplicates_skipped,
+            images_normalized,
+            images_rejected,
+            mixed_pack_routed: mixed_pack_routed.clone(),
         };
         let _ = window.emit("graphics-extraction-progress", &completion);
     }
@@ -801,7 +1963,18 @@ pub async fn import_graphics_pack_with_type(
     }
 
     tracing::info!("Graphics pack imported successfully");
-    Ok("Graphics pack installed successfully".to_string())
+    Ok(GraphicsImportResult {
+        message: if migrated {
+            "Graphics pack installed successfully (legacy layout migrated — re-run validation recommended)".to_string()
+        } else {
+            "Graphics pack installed successfully".to_string()
+        },
+        migrated,
+        duplicates_skipped,
+        images_normalized,
+        images_rejected,
+        mixed_pack_routed,
+    })
 }
 
 /// Legacy import function (kept for backwards compatibility)
@@ -810,161 +1983,515 @@ pub async fn import_graphics_pack(
     app: tauri::AppHandle,
     source_path: String,
 ) -> Result<String, String> {
-    // Delegate to new function with default behavior (no splitting, auto-detect path, no force)
-    import_graphics_pack_with_type(app, source_path, "graphics".to_string(), false, false).await
+    // Delegate to new function with default behavior (no splitting, auto-detect path, no force,
+    // default extraction limits)
+    let result = import_graphics_pack_with_type(
+        app,
+        source_path,
+        "graphics".to_string(),
+        false,
+        false,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    Ok(result.message)
+}
+
+/// Resolves the symlink at `link_path`, encountered while walking a pack for
+/// [`copy_graphics_content`]/[`copy_flat_pack_content`], and copies its target in at
+/// `target_path`. Delegates to [`utils::copy_dir_recursive`] when the target is a directory,
+/// reusing its cycle/max-jump guard instead of duplicating it here.
+fn copy_symlink_for_install(
+    link_path: &Path,
+    target_path: &Path,
+) -> Result<CopyReport, utils::CopyError> {
+    let resolved = fs::canonicalize(link_path)
+        .map_err(|_| utils::CopyError::BrokenSymlink(link_path.to_path_buf()))?;
+    let metadata = fs::metadata(&resolved)
+        .map_err(|_| utils::CopyError::BrokenSymlink(link_path.to_path_buf()))?;
+
+    if metadata.is_dir() {
+        fs::create_dir_all(target_path)
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+        utils::copy_dir_recursive(&resolved, target_path)
+    } else {
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create parent directory: {}", e))?;
+        }
+        fs::copy(&resolved, target_path).map_err(|e| format!("Failed to copy file: {}", e))?;
+        Ok(CopyReport {
+            files_copied: 1,
+            ..Default::default()
+        })
+    }
+}
+
+/// Outcome of [`copy_install_file`]: whether it wrote bytes, how many, and whether it was
+/// skipped instead (dedup/normalize rejection), shared by [`copy_graphics_subdir_tree`] and
+/// [`copy_flat_pack_content`] so both copy loops dedup/normalize/copy a single file the same way.
+struct InstallFileOutcome {
+    bytes_written: u64,
+    copied: bool,
+    normalized: bool,
+    skipped: Option<SkippedCopyEntry>,
+}
+
+/// Copies a single regular file from `src_path` to `target_path` during a pack install, applying
+/// `dedup`'s near-duplicate check first (see [`ImageDedup`]) and then `normalizer`'s format
+/// conversion (see [`ImageNormalizer`]) ahead of the actual copy. A normalized file is written to
+/// `target_path` with its extension rewritten to `.png` rather than to `target_path` itself —
+/// callers that report `current_file`/paths back to the caller should prefer the path this
+/// function actually wrote to over `target_path`.
+fn copy_install_file(
+    src_path: &Path,
+    target_path: &Path,
+    dedup: Option<&ImageDedup>,
+    normalizer: Option<&ImageNormalizer>,
+) -> Result<(InstallFileOutcome, PathBuf), String> {
+    if dedup.is_some_and(|d| d.is_duplicate(src_path)) {
+        return Ok((
+            InstallFileOutcome {
+                bytes_written: 0,
+                copied: false,
+                normalized: false,
+                skipped: Some(SkippedCopyEntry {
+                    path: src_path.to_path_buf(),
+                    reason: "Duplicate image (dHash within threshold of an installed image)"
+                        .to_string(),
+                }),
+            },
+            target_path.to_path_buf(),
+        ));
+    }
+
+    if let Some(normalizer) = normalizer {
+        match normalizer.normalize(src_path) {
+            NormalizeOutcome::Rejected(reason) => {
+                return Ok((
+                    InstallFileOutcome {
+                        bytes_written: 0,
+                        copied: false,
+                        normalized: false,
+                        skipped: Some(SkippedCopyEntry {
+                            path: src_path.to_path_buf(),
+                            reason,
+                        }),
+                    },
+                    target_path.to_path_buf(),
+                ));
+            }
+            NormalizeOutcome::Converted(image) => {
+                let png_target = target_path.with_extension("png");
+                if let Some(parent) = png_target.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create directory: {}", e))?;
+                }
+                // Written to a temp sibling and renamed over `png_target` rather than saved
+                // directly, since `png_target` may be hard-linked to other packs via
+                // `deduplicate_graphics` and writing in place would corrupt them too.
+                let png_tmp = png_target.with_extension("fmmloader-normalize-tmp");
+                image.save_with_format(&png_tmp, image::ImageFormat::Png).map_err(|e| {
+                    format!(
+                        "Failed to write normalized PNG for {}: {}",
+                        src_path.display(),
+                        e
+                    )
+                })?;
+                fs::rename(&png_tmp, &png_target).map_err(|e| {
+                    let _ = fs::remove_file(&png_tmp);
+                    format!(
+                        "Failed to finalize normalized PNG for {}: {}",
+                        src_path.display(),
+                        e
+                    )
+                })?;
+                let bytes = fs::metadata(&png_target).map(|m| m.len()).unwrap_or(0);
+                return Ok((
+                    InstallFileOutcome {
+                        bytes_written: bytes,
+                        copied: true,
+                        normalized: true,
+                        skipped: None,
+                    },
+                    png_target,
+                ));
+            }
+            NormalizeOutcome::NotApplicable => {}
+        }
+    }
+
+    if src_path.file_name().and_then(|n| n.to_str()) == Some("config.xml") {
+        tracing::info!("Copying config.xml: {:?} -> {:?}", src_path, target_path);
+    }
+    let bytes = utils::copy_file_replacing(src_path, target_path)?;
+    Ok((
+        InstallFileOutcome {
+            bytes_written: bytes,
+            copied: true,
+            normalized: false,
+            skipped: None,
+        },
+        target_path.to_path_buf(),
+    ))
+}
+
+/// Copies one graphics subdirectory's full tree into `dest_subdir`, used by
+/// [`copy_graphics_content`] as the unit of work each rayon task copies independently, and by
+/// [`copy_flat_pack_content`] for directories it finds alongside flat pack content. Walks the
+/// source tree once up front to dedup destination directories into a `HashSet` (so
+/// `create_dir_all` runs once per directory instead of once per file in it) and to collect every
+/// file/symlink/special entry to copy, then dispatches those copies across rayon's work-stealing
+/// pool instead of one at a time. `cancelled` is checked before starting each entry and set on the
+/// first copy error, so the rest of the tree stops dispatching new work instead of racing to
+/// finish a copy nobody wants anymore.
+fn copy_graphics_subdir_tree(
+    source_subdir: &Path,
+    dest_subdir: &Path,
+    files_copied: &std::sync::atomic::AtomicUsize,
+    bytes_processed: &std::sync::atomic::AtomicU64,
+    skipped: &std::sync::Mutex<Vec<SkippedCopyEntry>>,
+    total_files: usize,
+    dedup: Option<&ImageDedup>,
+    normalizer: Option<&ImageNormalizer>,
+    cancelled: &std::sync::atomic::AtomicBool,
+    progress_callback: &(dyn Fn(usize, u64, String) + Send + Sync),
+) -> Result<(), String> {
+    fs::create_dir_all(dest_subdir)
+        .map_err(|e| format!("Failed to create destination subdirectory: {}", e))?;
+
+    let mut dirs_to_create: std::collections::HashSet<PathBuf> =
+        std::collections::HashSet::new();
+    let mut entries: Vec<walkdir::DirEntry> = Vec::new();
+
+    for walk_entry in WalkDir::new(source_subdir) {
+        let walk_entry = walk_entry.map_err(|e| format!("Failed to walk directory: {}", e))?;
+        let rel_path = match walk_entry.path().strip_prefix(source_subdir) {
+            Ok(rel_path) => rel_path.to_path_buf(),
+            Err(_) => continue,
+        };
+
+        if walk_entry.file_type().is_dir() {
+            dirs_to_create.insert(dest_subdir.join(&rel_path));
+        } else {
+            if let Some(parent) = rel_path.parent() {
+                dirs_to_create.insert(dest_subdir.join(parent));
+            }
+            entries.push(walk_entry);
+        }
+    }
+
+    for dir in &dirs_to_create {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+
+    use rayon::prelude::*;
+    entries
+        .into_par_iter()
+        .try_for_each(|walk_entry| -> Result<(), String> {
+            if cancelled.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+
+            let entry_path = walk_entry.path();
+            let file_type = walk_entry.file_type();
+            let rel_path = entry_path
+                .strip_prefix(source_subdir)
+                .expect("entry was walked from source_subdir");
+            let target_path = dest_subdir.join(rel_path);
+
+            let copy_result = (|| -> Result<(usize, u64), String> {
+                if file_type.is_symlink() {
+                    let linked = copy_symlink_for_install(entry_path, &target_path)
+                        .map_err(|e| e.to_string())?;
+                    skipped.lock().unwrap().extend(linked.skipped);
+                    Ok((linked.files_copied, 0))
+                } else if file_type.is_file() {
+                    let (outcome, _written_path) =
+                        copy_install_file(entry_path, &target_path, dedup, normalizer)?;
+                    if let Some(entry) = outcome.skipped {
+                        skipped.lock().unwrap().push(entry);
+                    }
+                    if outcome.copied {
+                        Ok((1, outcome.bytes_written))
+                    } else {
+                        Ok((0, 0))
+                    }
+                } else {
+                    skipped.lock().unwrap().push(SkippedCopyEntry {
+                        path: entry_path.to_path_buf(),
+                        reason: utils::special_entry_reason(&file_type),
+                    });
+                    Ok((0, 0))
+                }
+            })();
+
+            match copy_result {
+                Ok((copied, bytes)) => {
+                    if copied > 0 {
+                        let new_count = files_copied.fetch_add(copied, Ordering::Relaxed) + copied;
+                        let new_bytes = bytes_processed.fetch_add(bytes, Ordering::Relaxed) + bytes;
+                        if new_count % 50 < copied || new_count == total_files {
+                            let current_file_name = entry_path
+                                .file_name()
+                                .and_then(|n| n.to_str())
+                                .unwrap_or("")
+                                .to_string();
+                            progress_callback(new_count, new_bytes, current_file_name);
+                        }
+                    }
+                    Ok(())
+                }
+                Err(e) => {
+                    cancelled.store(true, Ordering::Relaxed);
+                    Err(e)
+                }
+            }
+        })
 }
 
-// Helper function to copy graphics content, preserving subdirectories
+// Helper function to copy graphics content, preserving subdirectories. Symlinks are followed
+// (via `copy_symlink_for_install`, sharing `utils::copy_dir_recursive`'s cycle protection) and
+// anything that's neither a regular file, directory, nor symlink is skipped and recorded in the
+// returned `CopyReport` instead of aborting the whole pack install. The graphics subdirectories
+// found at `content_root` (faces/logos/kits/...) copy independently of each other, and each
+// subdirectory's own files copy independently in turn (see `copy_graphics_subdir_tree`), all over
+// the same `copy_threads`-sized rayon pool; see [`build_copy_pool`].
 fn copy_graphics_content<F>(
     content_root: &Path,
     graphics_dir: &Path,
     total_files: usize,
-    mut progress_callback: F,
-) -> Result<(), String>
+    copy_threads: Option<usize>,
+    dedup: Option<&ImageDedup>,
+    normalizer: Option<&ImageNormalizer>,
+    progress_callback: F,
+) -> Result<CopyReport, String>
 where
-    F: FnMut(usize, String),
+    F: Fn(usize, u64, String) + Send + Sync,
 {
+    let duplicates_before = dedup.map_or(0, |d| d.duplicates_skipped());
+    let normalized_before = normalizer.map_or(0, |n| n.converted());
+    let rejected_before = normalizer.map_or(0, |n| n.rejected());
     // Graphics subdirectory names to look for
     let graphics_subdirs = ["faces", "logos", "kits", "badges", "graphics"];
-    let mut files_copied = 0;
+    let mut subdir_pairs = Vec::new();
 
-    // Find and copy each graphics subdirectory
     if let Ok(entries) = fs::read_dir(content_root) {
         for entry in entries.flatten() {
             let path = entry.path();
             if path.is_dir() {
                 if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
                     let name_lower = name.to_lowercase();
-
-                    // Check if this is a graphics subdirectory
                     if graphics_subdirs.iter().any(|&gd| name_lower.contains(gd)) {
-                        // This is a graphics subdirectory, copy it preserving structure
                         let dest_subdir = graphics_dir.join(name);
                         tracing::info!(
                             "Copying graphics subdirectory: {} -> {:?}",
                             name,
                             dest_subdir
                         );
-
-                        // Create destination subdirectory if it doesn't exist
-                        fs::create_dir_all(&dest_subdir).map_err(|e| {
-                            format!("Failed to create destination subdirectory: {}", e)
-                        })?;
-
-                        // Copy all contents recursively
-                        for walk_entry in WalkDir::new(&path) {
-                            let walk_entry = walk_entry
-                                .map_err(|e| format!("Failed to walk directory: {}", e))?;
-                            let entry_path = walk_entry.path();
-
-                            if let Ok(rel_path) = entry_path.strip_prefix(&path) {
-                                let target_path = dest_subdir.join(rel_path);
-
-                                if entry_path.is_dir() {
-                                    fs::create_dir_all(&target_path).map_err(|e| {
-                                        format!("Failed to create directory: {}", e)
-                                    })?;
-                                    tracing::debug!("Created directory: {:?}", target_path);
-                                } else {
-                                    if let Some(parent) = target_path.parent() {
-                                        fs::create_dir_all(parent).map_err(|e| {
-                                            format!("Failed to create parent directory: {}", e)
-                                        })?;
-                                    }
-
-                                    if entry_path.file_name().and_then(|n| n.to_str())
-                                        == Some("config.xml")
-                                    {
-                                        tracing::info!(
-                                            "Copying config.xml: {:?} -> {:?}",
-                                            entry_path,
-                                            target_path
-                                        );
-                                    }
-
-                                    fs::copy(entry_path, &target_path)
-                                        .map_err(|e| format!("Failed to copy file: {}", e))?;
-
-                                    files_copied += 1;
-
-                                    if files_copied % 50 == 0 || files_copied == total_files {
-                                        let current_file_name = entry_path
-                                            .file_name()
-                                            .and_then(|n| n.to_str())
-                                            .unwrap_or("")
-                                            .to_string();
-                                        progress_callback(files_copied, current_file_name);
-                                    }
-                                }
-                            }
-                        }
+                        subdir_pairs.push((path, dest_subdir));
                     }
                 }
             }
         }
     }
 
+    let files_copied = std::sync::atomic::AtomicUsize::new(0);
+    let bytes_processed = std::sync::atomic::AtomicU64::new(0);
+    let skipped: std::sync::Mutex<Vec<SkippedCopyEntry>> = std::sync::Mutex::new(Vec::new());
+    let cancelled = std::sync::atomic::AtomicBool::new(false);
+
+    let pool = build_copy_pool(copy_threads)?;
+    pool.install(|| -> Result<(), String> {
+        use rayon::prelude::*;
+        subdir_pairs
+            .into_par_iter()
+            .try_for_each(|(source_subdir, dest_subdir)| {
+                copy_graphics_subdir_tree(
+                    &source_subdir,
+                    &dest_subdir,
+                    &files_copied,
+                    &bytes_processed,
+                    &skipped,
+                    total_files,
+                    dedup,
+                    normalizer,
+                    &cancelled,
+                    &progress_callback,
+                )
+            })
+    })?;
+
+    let files_copied = files_copied.load(Ordering::Relaxed);
     if files_copied > 0 {
-        progress_callback(files_copied, "Complete".to_string());
+        progress_callback(
+            files_copied,
+            bytes_processed.load(Ordering::Relaxed),
+            "Complete".to_string(),
+        );
     }
 
-    Ok(())
+    Ok(CopyReport {
+        files_copied,
+        skipped: skipped.into_inner().unwrap(),
+        duplicates_skipped: dedup.map_or(0, |d| d.duplicates_skipped()) - duplicates_before,
+        images_normalized: normalizer.map_or(0, |n| n.converted()) - normalized_before,
+        images_rejected: normalizer.map_or(0, |n| n.rejected()) - rejected_before,
+        unchanged_skipped: 0,
+    })
 }
 
-/// Copy flat pack contents directly to destination (for packs with PNGs/config.xml at root)
+/// Copy flat pack contents directly to destination (for packs with PNGs/config.xml at root).
+/// See [`copy_graphics_content`] for the symlink/bad-entry handling shared with this function.
+/// Top-level entries under `content_root` copy independently over a `copy_threads`-sized rayon
+/// pool; see [`build_copy_pool`]. Top-level directories delegate to
+/// [`copy_graphics_subdir_tree`], which dispatches the files inside them across the same pool
+/// rather than copying them one at a time. `seen_targets` guards against two entries (e.g.
+/// differently cased names colliding on a case-insensitive filesystem) racing to write the same
+/// destination.
 fn copy_flat_pack_content<F>(
     content_root: &Path,
     install_dir: &Path,
     total_files: usize,
-    mut progress_callback: F,
-) -> Result<(), String>
+    copy_threads: Option<usize>,
+    dedup: Option<&ImageDedup>,
+    normalizer: Option<&ImageNormalizer>,
+    progress_callback: F,
+) -> Result<CopyReport, String>
 where
-    F: FnMut(usize, String),
+    F: Fn(usize, u64, String) + Send + Sync,
 {
-    let mut files_copied = 0;
-
-    // Copy all files from content_root directly to install_dir
-    for entry in WalkDir::new(content_root) {
-        let entry = entry.map_err(|e| format!("Failed to walk directory: {}", e))?;
-        let entry_path = entry.path();
+    let duplicates_before = dedup.map_or(0, |d| d.duplicates_skipped());
+    let normalized_before = normalizer.map_or(0, |n| n.converted());
+    let rejected_before = normalizer.map_or(0, |n| n.rejected());
+    let entries: Vec<fs::DirEntry> = fs::read_dir(content_root)
+        .map_err(|e| format!("Failed to read directory: {}", e))?
+        .collect::<Result<Vec<_>, io::Error>>()
+        .map_err(|e| format!("Failed to read directory entry: {}", e))?;
+
+    let files_copied = std::sync::atomic::AtomicUsize::new(0);
+    let bytes_processed = std::sync::atomic::AtomicU64::new(0);
+    let skipped: std::sync::Mutex<Vec<SkippedCopyEntry>> = std::sync::Mutex::new(Vec::new());
+    let seen_targets: std::sync::Mutex<std::collections::HashSet<PathBuf>> =
+        std::sync::Mutex::new(std::collections::HashSet::new());
+    let cancelled = std::sync::atomic::AtomicBool::new(false);
+
+    let pool = build_copy_pool(copy_threads)?;
+    pool.install(|| -> Result<(), String> {
+        use rayon::prelude::*;
+        entries.into_par_iter().try_for_each(|entry| -> Result<(), String> {
+            if cancelled.load(Ordering::Relaxed) {
+                return Ok(());
+            }
 
-        if let Ok(rel_path) = entry_path.strip_prefix(content_root) {
-            let target_path = install_dir.join(rel_path);
+            let src_path = entry.path();
+            let file_name = entry.file_name();
+            let target_path = install_dir.join(&file_name);
+
+            let target_key = PathBuf::from(target_path.to_string_lossy().to_lowercase());
+            if !seen_targets.lock().unwrap().insert(target_key) {
+                cancelled.store(true, Ordering::Relaxed);
+                return Err(format!(
+                    "Duplicate copy target detected: {}",
+                    target_path.display()
+                ));
+            }
 
-            if entry_path.is_dir() {
-                fs::create_dir_all(&target_path)
-                    .map_err(|e| format!("Failed to create directory: {}", e))?;
-                tracing::debug!("Created directory: {:?}", target_path);
+            let file_type = entry.file_type().map_err(|e| e.to_string())?;
+
+            let result = if file_type.is_symlink() {
+                copy_symlink_for_install(&src_path, &target_path).map_err(|e| e.to_string())
+            } else if file_type.is_dir() {
+                copy_graphics_subdir_tree(
+                    &src_path,
+                    &target_path,
+                    &files_copied,
+                    &bytes_processed,
+                    &skipped,
+                    total_files,
+                    dedup,
+                    normalizer,
+                    &cancelled,
+                    &progress_callback,
+                )
+                .map(|_| CopyReport::default())
+            } else if file_type.is_file() {
+                copy_install_file(&src_path, &target_path, dedup, normalizer).map(
+                    |(outcome, _written_path)| {
+                        if let Some(entry) = outcome.skipped {
+                            skipped.lock().unwrap().push(entry);
+                        }
+                        if outcome.copied {
+                            let new_count = files_copied.fetch_add(1, Ordering::Relaxed) + 1;
+                            let new_bytes = bytes_processed
+                                .fetch_add(outcome.bytes_written, Ordering::Relaxed)
+                                + outcome.bytes_written;
+                            if new_count % 50 == 0 || new_count == total_files {
+                                progress_callback(
+                                    new_count,
+                                    new_bytes,
+                                    file_name.to_string_lossy().to_string(),
+                                );
+                            }
+                        }
+                        CopyReport::default()
+                    },
+                )
             } else {
-                if let Some(parent) = target_path.parent() {
-                    fs::create_dir_all(parent)
-                        .map_err(|e| format!("Failed to create parent directory: {}", e))?;
-                }
+                skipped.lock().unwrap().push(SkippedCopyEntry {
+                    path: src_path.clone(),
+                    reason: utils::special_entry_reason(&file_type),
+                });
+                Ok(CopyReport::default())
+            };
 
-                if entry_path.file_name().and_then(|n| n.to_str()) == Some("config.xml") {
-                    tracing::info!("Copying config.xml: {:?} -> {:?}", entry_path, target_path);
+            match result {
+                Ok(linked) => {
+                    // `copy_symlink_for_install` is the only branch above that returns a
+                    // non-default report; the directory/file branches already recorded their
+                    // own counts/progress directly against the shared atomics.
+                    skipped.lock().unwrap().extend(linked.skipped);
+                    if linked.files_copied > 0 {
+                        let new_count =
+                            files_copied.fetch_add(linked.files_copied, Ordering::Relaxed)
+                                + linked.files_copied;
+                        progress_callback(
+                            new_count,
+                            bytes_processed.load(Ordering::Relaxed),
+                            file_name.to_string_lossy().to_string(),
+                        );
+                    }
+                    Ok(())
                 }
-
-                fs::copy(entry_path, &target_path)
-                    .map_err(|e| format!("Failed to copy file: {}", e))?;
-
-                files_copied += 1;
-
-                if files_copied % 50 == 0 || files_copied == total_files {
-                    let current_file_name = entry_path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("")
-                        .to_string();
-                    progress_callback(files_copied, current_file_name);
+                Err(e) => {
+                    cancelled.store(true, Ordering::Relaxed);
+                    Err(e)
                 }
             }
-        }
-    }
+        })
+    })?;
 
+    let files_copied = files_copied.load(Ordering::Relaxed);
     if files_copied > 0 {
-        progress_callback(files_copied, "Complete".to_string());
+        progress_callback(
+            files_copied,
+            bytes_processed.load(Ordering::Relaxed),
+            "Complete".to_string(),
+        );
     }
 
-    Ok(())
+    Ok(CopyReport {
+        files_copied,
+        skipped: skipped.into_inner().unwrap(),
+        duplicates_skipped: dedup.map_or(0, |d| d.duplicates_skipped()) - duplicates_before,
+        images_normalized: normalizer.map_or(0, |n| n.converted()) - normalized_before,
+        images_rejected: normalizer.map_or(0, |n| n.rejected()) - rejected_before,
+        unchanged_skipped: 0,
+    })
 }
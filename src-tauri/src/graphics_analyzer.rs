@@ -35,10 +35,19 @@
 //! The analyzer limits directory traversal depth to 3 levels to prevent excessive
 //! processing on malformed pack structures.
 
+use crate::types::{
+    DetectedPack, DuplicateGraphicsGroup, DuplicateScanProgress, ImageValidationIssue,
+    ImageValidationReport, ManifestDiff, PackManifest, PackManifestEntry, PackManifestMismatch,
+    PackManifestVerification,
+};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use walkdir::WalkDir;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -60,6 +69,16 @@ pub struct GraphicsPackAnalysis {
     pub has_config_xml: bool,
     pub subdirectory_breakdown: HashMap<String, usize>, // type -> file count
     pub is_flat_pack: bool, // true if PNGs are at root with no subdirectories
+    /// Files whose content doesn't match what their extension declares — truncated
+    /// downloads, zero-length files, or a renamed file of the wrong format — surfaced so
+    /// callers can warn users before install instead of silently mis-counting them.
+    #[serde(default)]
+    pub invalid_files: Vec<PathBuf>,
+    /// Total bytes wasted on byte-identical image files shipped under more than one name —
+    /// megapacks frequently ship the same face/logo under multiple IDs. One copy per group
+    /// of [`find_duplicate_images`] counts as the original; the rest count as waste.
+    #[serde(default)]
+    pub duplicate_bytes: u64,
 }
 
 #[derive(Debug)]
@@ -67,21 +86,106 @@ struct PackContents {
     has_faces_dir: bool,
     has_logos_dir: bool,
     has_kits_dir: bool,
-    png_files: Vec<PathBuf>,
+    image_files: Vec<PathBuf>,
     xml_files: Vec<PathBuf>,
     #[allow(dead_code)]
     subdirs: Vec<PathBuf>,
     total_size: u64,
+    invalid_files: Vec<PathBuf>,
+}
+
+/// Magic-byte signature sniffed from a candidate image file's content, independent of what
+/// its extension declares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageSignature {
+    Png,
+    Jpeg,
+    Bmp,
+    /// TGA has no magic bytes, so this is only returned after a basic header sanity check.
+    Tga,
+}
+
+const PNG_MAGIC: [u8; 8] = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+const JPEG_MAGIC: [u8; 3] = [0xFF, 0xD8, 0xFF];
+const BMP_MAGIC: [u8; 2] = [0x42, 0x4D];
+
+/// Reads the first bytes of `path` and matches them against known image signatures: PNG,
+/// JPEG, and BMP all have magic bytes; TGA doesn't, so it's accepted only when `path` already
+/// has a `.tga` extension and its 18-byte header looks sane (valid color-map type and image
+/// type fields).
+fn sniff_image_signature(path: &Path) -> Option<ImageSignature> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut header = [0u8; 18];
+    let read = file.read(&mut header).ok()?;
+
+    if read >= 8 && header[..8] == PNG_MAGIC {
+        return Some(ImageSignature::Png);
+    }
+    if read >= 3 && header[..3] == JPEG_MAGIC {
+        return Some(ImageSignature::Jpeg);
+    }
+    if read >= 2 && header[..2] == BMP_MAGIC {
+        return Some(ImageSignature::Bmp);
+    }
+
+    let is_tga_extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().eq_ignore_ascii_case("tga"))
+        .unwrap_or(false);
+    if is_tga_extension && read == 18 {
+        let color_map_type = header[1];
+        let image_type = header[2];
+        let valid_image_types = [0, 1, 2, 3, 9, 10, 11, 32, 33];
+        if color_map_type <= 1 && valid_image_types.contains(&image_type) {
+            return Some(ImageSignature::Tga);
+        }
+    }
+
+    None
+}
+
+fn expected_signature_for_extension(ext_lower: &str) -> Option<ImageSignature> {
+    match ext_lower {
+        "png" => Some(ImageSignature::Png),
+        "jpg" | "jpeg" => Some(ImageSignature::Jpeg),
+        "bmp" => Some(ImageSignature::Bmp),
+        "tga" => Some(ImageSignature::Tga),
+        _ => None,
+    }
+}
+
+/// Include/ignore glob filters narrowing an [`analyze_graphics_pack_filtered`] scan to a
+/// subtree of the pack, so a huge megapack doesn't need a full walk when a caller only cares
+/// about (say) `logos/**`, and junk like `__MACOSX/**` or `*.txt` can be skipped outright.
+/// Patterns use `*` (any run of characters except `/`), `**` (any run including `/`), and
+/// `?` (a single character), matched against each entry's path relative to the pack root.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct ScanFilters {
+    pub include: Vec<String>,
+    pub ignore: Vec<String>,
 }
 
 /// Analyzes a graphics pack directory to determine its type and routing
 pub fn analyze_graphics_pack(pack_path: &Path) -> Result<GraphicsPackAnalysis, String> {
+    analyze_graphics_pack_filtered(pack_path, &ScanFilters::default())
+}
+
+/// Same as [`analyze_graphics_pack`], but only scans the subtree matched by `filters.include`
+/// (pruning anything matching `filters.ignore` along the way) — useful for huge megapacks
+/// where a caller only cares about one type-specific subdirectory. With no filters, behaves
+/// identically to [`analyze_graphics_pack`].
+#[allow(dead_code)]
+pub fn analyze_graphics_pack_filtered(
+    pack_path: &Path,
+    filters: &ScanFilters,
+) -> Result<GraphicsPackAnalysis, String> {
     if !pack_path.exists() {
         return Err(format!("Pack path does not exist: {}", pack_path.display()));
     }
 
     // Gather pack contents
-    let contents = scan_pack_contents(pack_path)?;
+    let contents = scan_pack_contents_filtered(pack_path, filters)?;
 
     // Check for config.xml and parse it
     let config_analysis = analyze_config_xml(pack_path);
@@ -98,18 +202,968 @@ pub fn analyze_graphics_pack(pack_path: &Path) -> Result<GraphicsPackAnalysis, S
     // Detect if this is a flat pack
     let is_flat_pack = detect_flat_pack(&contents);
 
+    // Flag byte-identical images shipped under multiple names so the caller can warn before
+    // install instead of silently wasting disk.
+    let duplicate_images = find_duplicate_images(pack_path)?;
+    let duplicate_bytes = total_duplicate_bytes(&duplicate_images);
+
     Ok(GraphicsPackAnalysis {
         pack_type,
         confidence,
         suggested_paths,
-        file_count: contents.png_files.len(),
+        file_count: contents.image_files.len(),
         total_size_bytes: contents.total_size,
         has_config_xml: !contents.xml_files.is_empty(),
         subdirectory_breakdown,
         is_flat_pack,
+        invalid_files: contents.invalid_files,
+        duplicate_bytes,
+    })
+}
+
+/// Finds byte-identical image files within a pack, keyed by content digest.
+///
+/// Works in two passes to stay fast on big megapacks: first walks the pack and buckets
+/// files by exact byte size (cheap metadata-only comparison); then, only for buckets with
+/// more than one file, reads each candidate in fixed 4 KiB blocks and incrementally hashes
+/// it, dropping a file out of consideration the moment its block content diverges from
+/// every other file still being tracked. This means a file that's merely the same size as
+/// another — but not actually a duplicate — never needs its entire content read.
+pub fn find_duplicate_images(pack_path: &Path) -> Result<HashMap<String, Vec<PathBuf>>, String> {
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+
+    for entry in WalkDir::new(pack_path).max_depth(3) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let Some(ext) = path.extension() else {
+            continue;
+        };
+        let ext_lower = ext.to_string_lossy().to_lowercase();
+        if !matches!(ext_lower.as_str(), "png" | "jpg" | "jpeg" | "bmp" | "tga") {
+            continue;
+        }
+
+        let Ok(metadata) = fs::metadata(path) else {
+            continue;
+        };
+        if metadata.len() == 0 {
+            continue;
+        }
+
+        by_size.entry(metadata.len()).or_default().push(path.to_path_buf());
+    }
+
+    let mut duplicates: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    for candidates in by_size.into_values().filter(|paths| paths.len() > 1) {
+        for (hash, members) in hash_duplicate_candidates(&candidates)? {
+            duplicates.insert(hash, members);
+        }
+    }
+
+    Ok(duplicates)
+}
+
+const DUPLICATE_HASH_BLOCK_SIZE: usize = 4096;
+
+/// Reads same-sized candidate files in fixed-size blocks, maintaining one running
+/// [`blake3::Hasher`] per file still eligible to match another. Each round, files are
+/// re-partitioned by the block they just read; a partition that drops to a single file is
+/// dropped from further reading — it's already proven distinct from the rest of the bucket.
+/// Returns only the partitions that survived to end-of-file with more than one member,
+/// keyed by their shared content digest.
+fn hash_duplicate_candidates(paths: &[PathBuf]) -> Result<HashMap<String, Vec<PathBuf>>, String> {
+    let mut readers = Vec::with_capacity(paths.len());
+    for path in paths {
+        let file = fs::File::open(path)
+            .map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+        readers.push((path.clone(), file, blake3::Hasher::new()));
+    }
+
+    let mut groups: Vec<Vec<usize>> = vec![(0..readers.len()).collect()];
+    let mut buf = vec![0u8; DUPLICATE_HASH_BLOCK_SIZE];
+
+    loop {
+        let mut any_read = false;
+        let mut next_groups: Vec<Vec<usize>> = Vec::new();
+
+        for group in &groups {
+            if group.len() < 2 {
+                continue;
+            }
+
+            let mut by_block: HashMap<Vec<u8>, Vec<usize>> = HashMap::new();
+            for &index in group {
+                let (path, file, hasher) = &mut readers[index];
+                let read = file
+                    .read(&mut buf)
+                    .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+                if read > 0 {
+                    hasher.update(&buf[..read]);
+                    any_read = true;
+                }
+                by_block.entry(buf[..read].to_vec()).or_default().push(index);
+            }
+
+            next_groups.extend(by_block.into_values());
+        }
+
+        groups = next_groups;
+
+        if !any_read {
+            break;
+        }
+    }
+
+    let mut duplicates: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    for group in groups.into_iter().filter(|g| g.len() > 1) {
+        let digest = readers[group[0]].2.finalize().to_hex().to_string();
+        duplicates.insert(digest, group.into_iter().map(|i| readers[i].0.clone()).collect());
+    }
+
+    Ok(duplicates)
+}
+
+/// Sums the wasted bytes across duplicate groups — every member past the first counts as
+/// waste, since one copy per group is the original.
+fn total_duplicate_bytes(duplicates: &HashMap<String, Vec<PathBuf>>) -> u64 {
+    duplicates
+        .values()
+        .map(|members| {
+            let size = members
+                .first()
+                .and_then(|p| fs::metadata(p).ok())
+                .map(|m| m.len())
+                .unwrap_or(0);
+            size * (members.len() as u64 - 1)
+        })
+        .sum()
+}
+
+/// How many images [`validate_pack_images`] decodes when `sample_all` is false — enough to catch
+/// endemic corruption (a bad export batch, a half-downloaded archive) without paying to fully
+/// decode every file in a multi-thousand-image megapack.
+const DEFAULT_IMAGE_SAMPLE: usize = 200;
+
+/// Width/height bounds [`validate_pack_images`] expects for a pack of this type. FM renders
+/// faces, logos, and kits as small-to-medium icons; a file wildly outside this range is usually
+/// a reused unrelated image rather than a legitimate variant. `Mixed`/`Unknown` packs get a wider
+/// range since they can legitimately contain any of the above.
+pub(crate) fn expected_dimension_range(pack_type: &GraphicsPackType) -> (u32, u32) {
+    match pack_type {
+        GraphicsPackType::Faces => (32, 1024),
+        GraphicsPackType::Logos => (16, 1024),
+        GraphicsPackType::Kits => (32, 1024),
+        GraphicsPackType::Mixed(_) | GraphicsPackType::Unknown => (16, 2048),
+    }
+}
+
+/// Deep-validates a pack's images by actually decoding them with the `image` crate, instead of
+/// just inferring shape from file names/extensions like [`analyze_graphics_pack`] does. Catches
+/// what shape-only analysis can't: truncated or corrupt files, dimensions off the conventions
+/// for `pack_type` (see [`expected_dimension_range`]), non-RGBA images where FM expects a
+/// transparency channel (faces/logos), and files whose extension claims PNG but whose content
+/// says otherwise.
+///
+/// Decoding every image in a large megapack is slow, so `sample_all=false` checks only the first
+/// [`DEFAULT_IMAGE_SAMPLE`] images found; `sample_all=true` checks all of them. When
+/// `check_config_mappings` is also set, every `config.xml` under the pack is parsed for its
+/// `from="..."` targets, flagging references that don't resolve to a real file on disk as well
+/// as images that aren't referenced by any mapping (orphans) — this pass always covers every
+/// image regardless of `sample_all`, since it's a cheap string/filesystem check, not a decode.
+pub fn validate_pack_images(
+    pack_path: &Path,
+    pack_type: &GraphicsPackType,
+    sample_all: bool,
+    check_config_mappings: bool,
+) -> Result<ImageValidationReport, String> {
+    let mut image_paths = Vec::new();
+    for entry in WalkDir::new(pack_path).max_depth(3) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Some(ext) = path.extension() else {
+            continue;
+        };
+        let ext_lower = ext.to_string_lossy().to_lowercase();
+        if matches!(ext_lower.as_str(), "png" | "jpg" | "jpeg" | "bmp" | "tga") {
+            image_paths.push(path.to_path_buf());
+        }
+    }
+
+    let images_total = image_paths.len();
+    if !sample_all && image_paths.len() > DEFAULT_IMAGE_SAMPLE {
+        image_paths.truncate(DEFAULT_IMAGE_SAMPLE);
+    }
+
+    let (min_dim, max_dim) = expected_dimension_range(pack_type);
+    let expects_transparency =
+        matches!(pack_type, GraphicsPackType::Faces | GraphicsPackType::Logos);
+
+    let mut issues = Vec::new();
+    for path in &image_paths {
+        let rel_path = path
+            .strip_prefix(pack_path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+
+        let ext_lower = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        if ext_lower == "png" {
+            match sniff_image_signature(path) {
+                Some(ImageSignature::Png) => {}
+                Some(_) => {
+                    issues.push(ImageValidationIssue {
+                        path: rel_path,
+                        reason: "File has a .png extension but its content is a different image format".to_string(),
+                    });
+                    continue;
+                }
+                None => {
+                    issues.push(ImageValidationIssue {
+                        path: rel_path,
+                        reason: "File has a .png extension but its content doesn't look like any recognized image format".to_string(),
+                    });
+                    continue;
+                }
+            }
+        }
+
+        match image::image_dimensions(path) {
+            Ok((width, height)) => {
+                if width < min_dim || width > max_dim || height < min_dim || height > max_dim {
+                    issues.push(ImageValidationIssue {
+                        path: rel_path.clone(),
+                        reason: format!(
+                            "Dimensions {}x{} are outside the expected range ({}-{}px) for a {:?} pack",
+                            width, height, min_dim, max_dim, pack_type
+                        ),
+                    });
+                }
+            }
+            Err(e) => {
+                issues.push(ImageValidationIssue {
+                    path: rel_path,
+                    reason: format!("Failed to decode image header: {}", e),
+                });
+                continue;
+            }
+        }
+
+        if expects_transparency {
+            match image::open(path) {
+                Ok(img) => {
+                    if !matches!(
+                        img.color(),
+                        image::ColorType::Rgba8 | image::ColorType::Rgba16 | image::ColorType::Rgba32F
+                    ) {
+                        issues.push(ImageValidationIssue {
+                            path: rel_path,
+                            reason: format!(
+                                "Image is {:?}, not RGBA — {:?} packs are expected to carry an alpha channel",
+                                img.color(),
+                                pack_type
+                            ),
+                        });
+                    }
+                }
+                Err(e) => {
+                    issues.push(ImageValidationIssue {
+                        path: rel_path,
+                        reason: format!("Failed to decode image: {}", e),
+                    });
+                }
+            }
+        }
+    }
+
+    if check_config_mappings {
+        issues.extend(cross_check_config_xml_mappings(pack_path)?);
+    }
+
+    Ok(ImageValidationReport {
+        issues,
+        images_checked: image_paths.len(),
+        images_total,
+    })
+}
+
+/// Parses every `config.xml` under `pack_path` for its `from="..."` targets (the real on-disk
+/// path each mapping points to — see [`crate::graphics::add_config_xml_prefix`], which edits the
+/// same attribute) and cross-checks them against what's actually on disk: a target that doesn't
+/// exist is flagged, and so is an image file under the pack that no mapping references at all
+/// (an orphan, shipped but never wired up to anything in-game).
+fn cross_check_config_xml_mappings(pack_path: &Path) -> Result<Vec<ImageValidationIssue>, String> {
+    let from_regex = regex::Regex::new("from=\"([^\"]+)\"")
+        .map_err(|e| format!("Failed to build regex: {e}"))?;
+
+    let mut issues = Vec::new();
+    let mut referenced: std::collections::HashSet<PathBuf> = std::collections::HashSet::new();
+    let mut all_images: std::collections::HashSet<PathBuf> = std::collections::HashSet::new();
+
+    for entry in WalkDir::new(pack_path).max_depth(3) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let is_config_xml = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|name| name.eq_ignore_ascii_case("config.xml"))
+            .unwrap_or(false);
+
+        if is_config_xml {
+            let Some(content) = read_config_xml_text(path) else {
+                continue;
+            };
+            let config_dir = path.parent().unwrap_or(pack_path);
+
+            for caps in from_regex.captures_iter(&content) {
+                let rel = &caps[1];
+                let target = config_dir.join(rel);
+                referenced.insert(target.clone());
+                if !target.exists() {
+                    issues.push(ImageValidationIssue {
+                        path: path
+                            .strip_prefix(pack_path)
+                            .unwrap_or(path)
+                            .to_string_lossy()
+                            .to_string(),
+                        reason: format!(
+                            "config.xml references '{}' which does not exist on disk",
+                            rel
+                        ),
+                    });
+                }
+            }
+            continue;
+        }
+
+        let Some(ext) = path.extension() else {
+            continue;
+        };
+        let ext_lower = ext.to_string_lossy().to_lowercase();
+        if matches!(ext_lower.as_str(), "png" | "jpg" | "jpeg" | "bmp" | "tga") {
+            all_images.insert(path.to_path_buf());
+        }
+    }
+
+    for image_path in &all_images {
+        if !referenced.contains(image_path) {
+            issues.push(ImageValidationIssue {
+                path: image_path
+                    .strip_prefix(pack_path)
+                    .unwrap_or(image_path)
+                    .to_string_lossy()
+                    .to_string(),
+                reason: "Image is not referenced by any config.xml from= mapping (orphan file)"
+                    .to_string(),
+            });
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Size of the sample taken from the start and end of each file during the partial-hash pass —
+/// enough to split apart same-sized files that differ early or late without reading the whole
+/// file until a later stage actually needs to.
+const PARTIAL_HASH_SAMPLE_BYTES: u64 = 16 * 1024;
+
+/// Finds byte-identical files anywhere under `graphics_dir`, for
+/// [`crate::graphics::find_duplicate_graphics`]. Unlike [`find_duplicate_images`] (per-pack,
+/// image-extension-only, depth-limited to 3), this walks the whole installed `graphics` tree and
+/// every regular file, since megapacks duplicate kit/config files as well as images.
+///
+/// Runs in three stages, each only operating on candidates the previous stage couldn't already
+/// rule out, emitting one `on_progress` call per stage (plus periodic in-stage updates for the
+/// expensive ones) so the UI can show which pass is running:
+/// 1. Group by exact byte size via `fs::metadata` — no file reads at all.
+/// 2. Within each surviving size group, hash a sample of the first/last
+///    [`PARTIAL_HASH_SAMPLE_BYTES`] to split apart files that merely share a size.
+/// 3. Only the candidates that still collide get a full-content blake3 hash, computed in
+///    parallel with rayon since that's the expensive pass.
+pub fn find_duplicate_graphics_tree(
+    graphics_dir: &Path,
+    mut on_progress: impl FnMut(DuplicateScanProgress) + Send,
+) -> Result<Vec<DuplicateGraphicsGroup>, String> {
+    // Stage 1: group by size.
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    let mut entries_total = 0usize;
+    for entry in WalkDir::new(graphics_dir) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Ok(metadata) = fs::metadata(path) else {
+            continue;
+        };
+        if metadata.len() == 0 {
+            continue;
+        }
+
+        entries_total += 1;
+        by_size
+            .entry(metadata.len())
+            .or_default()
+            .push(path.to_path_buf());
+    }
+    on_progress(DuplicateScanProgress {
+        current_stage: 1,
+        max_stage: 3,
+        stage_name: "Grouping by size".to_string(),
+        entries_checked: entries_total,
+        entries_total,
+    });
+
+    let size_candidates: Vec<(u64, PathBuf)> = by_size
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .flat_map(|(size, paths)| paths.into_iter().map(move |p| (size, p)))
+        .collect();
+
+    // Stage 2: partial hash of the first/last sample bytes.
+    let mut by_partial: HashMap<(u64, String), Vec<PathBuf>> = HashMap::new();
+    let partial_total = size_candidates.len();
+    for (checked, (size, path)) in size_candidates.into_iter().enumerate() {
+        let digest = partial_sample_hash(&path, size)?;
+        by_partial.entry((size, digest)).or_default().push(path);
+
+        if (checked + 1) % 200 == 0 || checked + 1 == partial_total {
+            on_progress(DuplicateScanProgress {
+                current_stage: 2,
+                max_stage: 3,
+                stage_name: "Hashing samples".to_string(),
+                entries_checked: checked + 1,
+                entries_total: partial_total,
+            });
+        }
+    }
+
+    let full_candidates: Vec<PathBuf> = by_partial
+        .into_values()
+        .filter(|paths| paths.len() > 1)
+        .flatten()
+        .collect();
+
+    // Stage 3: full content hash, computed in parallel since this is the expensive pass.
+    let full_total = full_candidates.len();
+    let checked = AtomicUsize::new(0);
+    let progress = Mutex::new(&mut on_progress);
+
+    let hashed: Vec<(String, u64, PathBuf)> = full_candidates
+        .par_iter()
+        .filter_map(|path| {
+            let result = full_content_hash(path).ok().map(|hash| {
+                let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                (hash, size, path.clone())
+            });
+
+            let done = checked.fetch_add(1, Ordering::Relaxed) + 1;
+            if done % 50 == 0 || done == full_total {
+                if let Ok(mut on_progress) = progress.lock() {
+                    on_progress(DuplicateScanProgress {
+                        current_stage: 3,
+                        max_stage: 3,
+                        stage_name: "Hashing full content".to_string(),
+                        entries_checked: done,
+                        entries_total: full_total,
+                    });
+                }
+            }
+
+            result
+        })
+        .collect();
+
+    let mut by_hash: HashMap<String, Vec<(u64, PathBuf)>> = HashMap::new();
+    for (hash, size, path) in hashed {
+        by_hash.entry(hash).or_default().push((size, path));
+    }
+
+    let groups = by_hash
+        .into_iter()
+        .filter(|(_, members)| members.len() > 1)
+        .map(|(hash, members)| DuplicateGraphicsGroup {
+            hash,
+            size_bytes: members[0].0,
+            paths: members
+                .into_iter()
+                .map(|(_, p)| p.to_string_lossy().into_owned())
+                .collect(),
+        })
+        .collect();
+
+    Ok(groups)
+}
+
+/// Hashes a sample of `path`'s first/last [`PARTIAL_HASH_SAMPLE_BYTES`] (the whole file, for
+/// anything smaller than that) with blake3. Cheap enough to run on every same-sized candidate
+/// before committing to a full read.
+fn partial_sample_hash(path: &Path, size: u64) -> Result<String, String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+    let mut hasher = blake3::Hasher::new();
+
+    let head_len = PARTIAL_HASH_SAMPLE_BYTES.min(size) as usize;
+    let mut head = vec![0u8; head_len];
+    file.read_exact(&mut head)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    hasher.update(&head);
+
+    if size > PARTIAL_HASH_SAMPLE_BYTES {
+        let tail_start = size - PARTIAL_HASH_SAMPLE_BYTES;
+        file.seek(SeekFrom::Start(tail_start))
+            .map_err(|e| format!("Failed to seek '{}': {}", path.display(), e))?;
+        let mut tail = vec![0u8; (size - tail_start) as usize];
+        file.read_exact(&mut tail)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        hasher.update(&tail);
+    }
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Streams `path` through a blake3 hasher and returns the hex digest, without holding the
+/// whole file in memory.
+fn full_content_hash(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+    let mut hasher = blake3::Hasher::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let read = file
+            .read(&mut buf)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Walks `pack_path` once, recording each image's relative path, size, and a CRC32 of its
+/// bytes, alongside the detected `pack_type` and per-type counts from `analysis`. The result
+/// is a durable, serializable record a later re-install or update can diff against instead
+/// of rescanning and recopying the whole pack. CRC32 rather than blake3/sha256 is deliberate:
+/// this is for integrity/change detection, not security, so a cheap checksum is the right
+/// tool for the job.
+#[allow(dead_code)]
+pub fn generate_pack_manifest(
+    pack_path: &Path,
+    analysis: &GraphicsPackAnalysis,
+) -> Result<PackManifest, String> {
+    let mut entries = Vec::new();
+
+    for entry in WalkDir::new(pack_path).max_depth(3) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let Some(ext) = path.extension() else {
+            continue;
+        };
+        let ext_lower = ext.to_string_lossy().to_lowercase();
+        if !matches!(ext_lower.as_str(), "png" | "jpg" | "jpeg" | "bmp" | "tga") {
+            continue;
+        }
+
+        let relative_path = path.strip_prefix(pack_path).unwrap_or(path).to_path_buf();
+        let bytes = fs::read(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+
+        entries.push(PackManifestEntry {
+            relative_path,
+            size_bytes: bytes.len() as u64,
+            crc32: crc32fast::hash(&bytes),
+        });
+    }
+
+    entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    Ok(PackManifest {
+        pack_type: analysis.pack_type.clone(),
+        type_counts: analysis.subdirectory_breakdown.clone(),
+        entries,
     })
 }
 
+/// Re-reads every file `manifest` recorded under `pack_path` and recomputes its CRC32,
+/// flagging anything missing or whose content no longer matches what was recorded at
+/// generation time.
+#[allow(dead_code)]
+pub fn verify_pack_manifest(
+    pack_path: &Path,
+    manifest: &PackManifest,
+) -> Result<PackManifestVerification, String> {
+    let mut mismatches = Vec::new();
+
+    for entry in &manifest.entries {
+        let path = pack_path.join(&entry.relative_path);
+
+        let bytes = match fs::read(&path) {
+            Ok(b) => b,
+            Err(_) => {
+                mismatches.push(PackManifestMismatch {
+                    relative_path: entry.relative_path.clone(),
+                    reason: "File is missing".to_string(),
+                });
+                continue;
+            }
+        };
+
+        if bytes.len() as u64 != entry.size_bytes || crc32fast::hash(&bytes) != entry.crc32 {
+            mismatches.push(PackManifestMismatch {
+                relative_path: entry.relative_path.clone(),
+                reason: "File content no longer matches the recorded checksum".to_string(),
+            });
+        }
+    }
+
+    Ok(PackManifestVerification { mismatches })
+}
+
+/// Compares two generations of the same pack's manifest, returning entries added, removed,
+/// or changed (same relative path, different size or CRC) — so an installer can copy only
+/// the delta instead of the whole pack when a pack is updated.
+#[allow(dead_code)]
+pub fn diff_manifests(old: &PackManifest, new: &PackManifest) -> ManifestDiff {
+    let old_by_path: HashMap<&PathBuf, &PackManifestEntry> =
+        old.entries.iter().map(|e| (&e.relative_path, e)).collect();
+    let new_by_path: HashMap<&PathBuf, &PackManifestEntry> =
+        new.entries.iter().map(|e| (&e.relative_path, e)).collect();
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    for entry in &new.entries {
+        match old_by_path.get(&entry.relative_path) {
+            None => added.push(entry.clone()),
+            Some(old_entry) => {
+                if old_entry.size_bytes != entry.size_bytes || old_entry.crc32 != entry.crc32 {
+                    changed.push(entry.clone());
+                }
+            }
+        }
+    }
+
+    let removed: Vec<PackManifestEntry> = old
+        .entries
+        .iter()
+        .filter(|e| !new_by_path.contains_key(&e.relative_path))
+        .cloned()
+        .collect();
+
+    ManifestDiff {
+        added,
+        removed,
+        changed,
+    }
+}
+
+/// How many leading bytes of a fingerprint candidate are hashed — large enough to tell known
+/// releases apart, small enough that detection doesn't mean reading whole face/logo archives.
+const FINGERPRINT_SAMPLE_BYTES: u64 = 8 * 1024;
+
+/// Files checked against [`KNOWN_PACKS`] when identifying a pack, mirroring ScummVM's use of a
+/// game's own data files (rather than anything loader-specific) as detection signatures.
+/// `config.xml` is the only one every pack format in this loader is expected to ship; more
+/// sentinel files (a known logo or kit texture, say) can be added here as real releases get
+/// fingerprinted.
+const FINGERPRINT_CANDIDATE_FILES: &[&str] = &["config.xml"];
+
+/// One known graphics pack release's fingerprint: the truncated hash and size of a single
+/// candidate file, which together are distinctive enough to name the exact pack and version
+/// that shipped it.
+struct KnownPackFingerprint {
+    hash_prefix: &'static str,
+    size: u64,
+    id: &'static str,
+    kind: crate::types::DetectedPackKind,
+    version: &'static str,
+}
+
+/// Known graphics pack releases, fingerprinted by a single marker file rather than by trusting
+/// a pack's folder name or a claimed version number. Empty for now — there's no reference
+/// library of real packs to fingerprint in this tree — and grows over time the same way
+/// ScummVM's and OpenLoco's signature tables do: one verified entry per release, added as
+/// packs are fingerprinted in the field.
+const KNOWN_PACKS: &[KnownPackFingerprint] = &[];
+
+/// Hashes the first [`FINGERPRINT_SAMPLE_BYTES`] of `path` (the whole file, if it's smaller)
+/// and returns a truncated hex digest alongside the file's full size — the `(hash, size)` pair
+/// [`detect_known_pack`] looks up in [`KNOWN_PACKS`]. Truncating to 16 hex chars keeps the
+/// table's entries short while still being collision-proof for the handful of known releases
+/// this is meant to distinguish.
+fn fingerprint_sample_hash(path: &Path) -> Result<(String, u64), String> {
+    let metadata = fs::metadata(path)
+        .map_err(|e| format!("Failed to stat '{}': {}", path.display(), e))?;
+    let size = metadata.len();
+
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+    let mut sample = vec![0u8; FINGERPRINT_SAMPLE_BYTES.min(size) as usize];
+    file.read_exact(&mut sample)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&sample);
+    let hash = hasher.finalize().to_hex().to_string();
+    Ok((hash[..16].to_string(), size))
+}
+
+/// Identifies which known graphics pack release `content_root` (as found by
+/// [`crate::utils::find_graphics_content_root`]) actually is, by fingerprinting
+/// [`FINGERPRINT_CANDIDATE_FILES`] and matching them against [`KNOWN_PACKS`] — the same
+/// signature-table approach ScummVM uses to identify a game from its data files instead of
+/// its folder name. Falls back to an "unknown" [`DetectedPack`] carrying the content root's
+/// folder name as a presumed id, the same fallback ScummVM reports for an undetected game,
+/// so the loader always has something to show rather than failing outright.
+pub fn detect_known_pack(content_root: &Path) -> DetectedPack {
+    for candidate in FINGERPRINT_CANDIDATE_FILES {
+        let path = content_root.join(candidate);
+        let Ok((hash_prefix, size)) = fingerprint_sample_hash(&path) else {
+            continue;
+        };
+
+        if let Some(known) = KNOWN_PACKS
+            .iter()
+            .find(|k| k.hash_prefix == hash_prefix && k.size == size)
+        {
+            return DetectedPack {
+                id: known.id.to_string(),
+                kind: known.kind,
+                version: Some(known.version.to_string()),
+                confidence: 1.0,
+            };
+        }
+    }
+
+    unknown_pack(content_root)
+}
+
+/// The "unknown" fallback for [`detect_known_pack`]: no `confidence`, no `version`, and the
+/// content root's own folder name standing in for an id since nothing better is available.
+fn unknown_pack(content_root: &Path) -> DetectedPack {
+    let id = content_root
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    DetectedPack {
+        id,
+        kind: crate::types::DetectedPackKind::Unknown,
+        version: None,
+        confidence: 0.0,
+    }
+}
+
+/// Side length of the grayscale image perceptual hashing is computed over, before taking the
+/// low-frequency DCT block.
+const PHASH_SIZE: u32 = 32;
+/// Side length of the low-frequency DCT block the hash bits are derived from.
+const PHASH_LOW_FREQ: usize = 8;
+/// Default Hamming-distance threshold for [`find_similar_images`]: pHashes within this many
+/// bits of each other are treated as visually the same image.
+pub const DEFAULT_PHASH_HAMMING_THRESHOLD: u32 = 5;
+
+/// Computes a 64-bit perceptual hash of the image at `path`: the image is reduced to
+/// `PHASH_SIZE`×`PHASH_SIZE` grayscale, a 2D DCT is run over it, and the top-left
+/// `PHASH_LOW_FREQ`×`PHASH_LOW_FREQ` low-frequency block (excluding the DC term) is reduced
+/// to 63 bits — one per remaining coefficient, set according to whether it's above the block's
+/// median; the top bit of the returned `u64` is always clear.
+/// Unlike an exact byte hash, two visually similar images (re-encoded, lightly cropped or
+/// recolored) typically differ by only a handful of bits rather than completely.
+fn compute_phash(path: &Path) -> Option<u64> {
+    let gray = image::open(path)
+        .ok()?
+        .grayscale()
+        .resize_exact(PHASH_SIZE, PHASH_SIZE, image::imageops::FilterType::Triangle)
+        .into_luma8();
+
+    let size = PHASH_SIZE as usize;
+    let mut pixels = vec![vec![0f64; size]; size];
+    for y in 0..size {
+        for x in 0..size {
+            pixels[y][x] = gray.get_pixel(x as u32, y as u32).0[0] as f64;
+        }
+    }
+
+    let mut coeffs = [[0f64; PHASH_LOW_FREQ]; PHASH_LOW_FREQ];
+    for (u, row) in coeffs.iter_mut().enumerate() {
+        for (v, cell) in row.iter_mut().enumerate() {
+            *cell = dct_coefficient(&pixels, u, v);
+        }
+    }
+
+    // Exclude the DC term (u == 0 && v == 0): it just reflects overall brightness and would
+    // otherwise dominate the median, making the hash mostly measure exposure rather than shape.
+    let mut values = Vec::with_capacity(PHASH_LOW_FREQ * PHASH_LOW_FREQ - 1);
+    for (u, row) in coeffs.iter().enumerate() {
+        for (v, &coeff) in row.iter().enumerate() {
+            if u != 0 || v != 0 {
+                values.push(coeff);
+            }
+        }
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let median = values[values.len() / 2];
+
+    let mut hash: u64 = 0;
+    let mut bit = 0;
+    for (u, row) in coeffs.iter().enumerate() {
+        for (v, &coeff) in row.iter().enumerate() {
+            if u == 0 && v == 0 {
+                continue;
+            }
+            if coeff > median {
+                hash |= 1 << bit;
+            }
+            bit += 1;
+        }
+    }
+
+    Some(hash)
+}
+
+/// Computes the `(u, v)` 2D DCT-II coefficient of `pixels` (a `PHASH_SIZE`×`PHASH_SIZE` grid).
+fn dct_coefficient(pixels: &[Vec<f64>], u: usize, v: usize) -> f64 {
+    let size = pixels.len();
+    let mut sum = 0.0;
+    for (y, row) in pixels.iter().enumerate() {
+        for (x, &value) in row.iter().enumerate() {
+            sum += value
+                * ((std::f64::consts::PI / size as f64) * (x as f64 + 0.5) * u as f64).cos()
+                * ((std::f64::consts::PI / size as f64) * (y as f64 + 0.5) * v as f64).cos();
+        }
+    }
+    let cu = if u == 0 { 1.0 / std::f64::consts::SQRT_2 } else { 1.0 };
+    let cv = if v == 0 { 1.0 / std::f64::consts::SQRT_2 } else { 1.0 };
+    sum * cu * cv * (2.0 / size as f64)
+}
+
+/// Hamming distance between two 64-bit perceptual hashes — shared by [`find_similar_images`]'s
+/// pHash clustering and [`crate::graphics`]'s install-time dHash dedup, since the bit-distance
+/// math is identical regardless of which hash family produced the bits.
+pub(crate) fn hash_hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Finds groups of visually-similar images within a pack via perceptual hashing, catching
+/// re-encoded or lightly edited variants that exact-byte dedup ([`find_duplicate_images`])
+/// misses. Images are decoded and hashed in parallel — this is CPU-bound — then greedily
+/// clustered: each image joins the first existing cluster whose representative pHash is
+/// within `max_hamming_distance` bits, or starts a new cluster otherwise. Returns only
+/// clusters with more than one member; images that fail to decode are skipped.
+#[allow(dead_code)]
+pub fn find_similar_images(
+    pack_path: &Path,
+    max_hamming_distance: u32,
+) -> Result<Vec<Vec<PathBuf>>, String> {
+    let mut candidates = Vec::new();
+    for entry in WalkDir::new(pack_path).max_depth(3) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let is_decodable = path
+            .extension()
+            .map(|e| matches!(e.to_string_lossy().to_lowercase().as_str(), "png" | "jpg" | "jpeg"))
+            .unwrap_or(false);
+        if is_decodable {
+            candidates.push(path.to_path_buf());
+        }
+    }
+
+    let hashes: Vec<(PathBuf, u64)> = candidates
+        .into_par_iter()
+        .filter_map(|path| compute_phash(&path).map(|hash| (path, hash)))
+        .collect();
+
+    let mut clusters: Vec<(u64, Vec<PathBuf>)> = Vec::new();
+    for (path, hash) in hashes {
+        match clusters
+            .iter_mut()
+            .find(|(representative, _)| hash_hamming_distance(*representative, hash) <= max_hamming_distance)
+        {
+            Some((_, members)) => members.push(path),
+            None => clusters.push((hash, vec![path])),
+        }
+    }
+
+    Ok(clusters
+        .into_iter()
+        .filter(|(_, members)| members.len() > 1)
+        .map(|(_, members)| members)
+        .collect())
+}
+
+/// Width/height of the grayscale grid [`compute_dhash`] downscales images to before taking
+/// neighbor differences — one column wider than [`DHASH_HEIGHT`] so each row yields
+/// `DHASH_HEIGHT` difference bits.
+const DHASH_WIDTH: u32 = 9;
+const DHASH_HEIGHT: u32 = 8;
+/// Default Hamming-distance threshold for install-time dHash dedup: two images whose dHashes
+/// differ by this many bits or fewer are treated as the same portrait/logo. Same value as
+/// [`DEFAULT_PHASH_HAMMING_THRESHOLD`], although dHash only encodes gradient direction, not
+/// frequency content.
+pub const DEFAULT_DHASH_HAMMING_THRESHOLD: u32 = 5;
+
+/// Computes a 64-bit difference hash (dHash) of the image at `path`: downscaled to
+/// `DHASH_WIDTH`×`DHASH_HEIGHT` grayscale, each pixel is compared to its right neighbor, and the
+/// 8×8 grid of "brighter than the next pixel" bits becomes the hash. Cheaper than
+/// [`compute_phash`]'s DCT (no trigonometric sums, no median sort) and well suited to running
+/// once per incoming file during a copy rather than once per analysis pass.
+pub(crate) fn compute_dhash(path: &Path) -> Option<u64> {
+    let gray = image::open(path)
+        .ok()?
+        .grayscale()
+        .resize_exact(DHASH_WIDTH, DHASH_HEIGHT, image::imageops::FilterType::Triangle)
+        .into_luma8();
+
+    let mut hash: u64 = 0;
+    let mut bit = 0;
+    for y in 0..DHASH_HEIGHT {
+        for x in 0..(DHASH_WIDTH - 1) {
+            let left = gray.get_pixel(x, y).0[0];
+            let right = gray.get_pixel(x + 1, y).0[0];
+            if left > right {
+                hash |= 1 << bit;
+            }
+            bit += 1;
+        }
+    }
+
+    Some(hash)
+}
+
+/// Whether `path`'s extension is one [`compute_dhash`]/[`compute_phash`] can decode — the same
+/// png/jpg/jpeg/bmp/tga set used elsewhere in this module, so a file this function rejects would
+/// have failed `image::open` anyway.
+pub(crate) fn is_image_extension(path: &Path) -> bool {
+    path.extension()
+        .map(|e| matches!(e.to_string_lossy().to_lowercase().as_str(), "png" | "jpg" | "jpeg" | "bmp" | "tga"))
+        .unwrap_or(false)
+}
+
 /// Detects if a pack is "flat" - PNGs and config.xml at root with no type-specific subdirectories
 fn detect_flat_pack(contents: &PackContents) -> bool {
     // Flat pack criteria:
@@ -117,58 +1171,164 @@ fn detect_flat_pack(contents: &PackContents) -> bool {
     // 2. Does NOT have type-specific subdirectories (faces/, logos/, kits/)
     // 3. Usually has config.xml at root
 
-    let has_images_at_root = !contents.png_files.is_empty();
+    let has_images_at_root = !contents.image_files.is_empty();
     let has_type_subdirs =
         contents.has_faces_dir || contents.has_logos_dir || contents.has_kits_dir;
 
     has_images_at_root && !has_type_subdirs
 }
 
+#[allow(dead_code)]
 fn scan_pack_contents(pack_path: &Path) -> Result<PackContents, String> {
-    let mut png_files = Vec::new();
+    scan_pack_contents_filtered(pack_path, &ScanFilters::default())
+}
+
+/// Splits a glob `pattern` into its literal, wildcard-free leading path components — which
+/// become a base directory the walk can start from instead of the pack root — and the
+/// remaining pattern, matched against each entry's path relative to that base.
+fn split_glob_base(pattern: &str) -> (PathBuf, String) {
+    let components: Vec<&str> = pattern.split('/').collect();
+    let split_at = components
+        .iter()
+        .position(|part| part.contains('*') || part.contains('?'))
+        .unwrap_or(components.len());
+
+    let base: PathBuf = components[..split_at].iter().collect();
+    let remainder = components[split_at..].join("/");
+    (base, remainder)
+}
+
+/// Matches `text` against a glob `pattern`: `*` matches any run of characters except `/`,
+/// `**` matches any run of characters including `/`, `?` matches exactly one character, and
+/// everything else must match literally. Hand-rolled rather than pulling in a glob crate —
+/// the supported syntax is small enough that this is simpler than a new dependency.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    glob_match_chars(&pattern.chars().collect::<Vec<_>>(), &text.chars().collect::<Vec<_>>())
+}
+
+fn glob_match_chars(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') if pattern.get(1) == Some(&'*') => {
+            let rest = &pattern[2..];
+            (0..=text.len()).any(|i| glob_match_chars(rest, &text[i..]))
+        }
+        Some('*') => {
+            let rest = &pattern[1..];
+            let mut i = 0;
+            loop {
+                if glob_match_chars(rest, &text[i..]) {
+                    return true;
+                }
+                if i >= text.len() || text[i] == '/' {
+                    return false;
+                }
+                i += 1;
+            }
+        }
+        Some('?') => !text.is_empty() && glob_match_chars(&pattern[1..], &text[1..]),
+        Some(&c) => text.first() == Some(&c) && glob_match_chars(&pattern[1..], &text[1..]),
+    }
+}
+
+fn scan_pack_contents_filtered(
+    pack_path: &Path,
+    filters: &ScanFilters,
+) -> Result<PackContents, String> {
+    let mut image_files = Vec::new();
     let mut xml_files = Vec::new();
     let mut subdirs = Vec::new();
     let mut total_size = 0u64;
+    let mut invalid_files = Vec::new();
 
     let mut has_faces_dir = false;
     let mut has_logos_dir = false;
     let mut has_kits_dir = false;
 
-    for entry in WalkDir::new(pack_path).max_depth(3) {
-        let entry = entry.map_err(|e| e.to_string())?;
-        let path = entry.path();
+    // Each include pattern contributes its own walk root (the literal prefix before the
+    // first wildcard component) paired with the remaining pattern, matched against paths
+    // relative to *that* root rather than the pack root — so "logos/*.png" only walks
+    // "logos/" and matches "1.png" there, not "logos/1.png" against the whole pack.
+    let walk_plan: Vec<(PathBuf, Option<String>)> = if filters.include.is_empty() {
+        vec![(pack_path.to_path_buf(), None)]
+    } else {
+        filters
+            .include
+            .iter()
+            .map(|pattern| {
+                let (base, remainder) = split_glob_base(pattern);
+                (pack_path.join(base), Some(remainder))
+            })
+            .collect()
+    };
 
-        if path.is_dir() {
-            if let Some(name) = path.file_name() {
-                let name_lower = name.to_string_lossy().to_lowercase();
-                if name_lower == "faces" || name_lower.contains("face") {
-                    has_faces_dir = true;
-                }
-                if name_lower == "logos"
-                    || name_lower.contains("logo")
-                    || name_lower.contains("badge")
-                {
-                    has_logos_dir = true;
-                }
-                if name_lower == "kits" || name_lower.contains("kit") {
-                    has_kits_dir = true;
+    for (root, include_pattern) in &walk_plan {
+        if !root.exists() {
+            continue;
+        }
+
+        let ignore = &filters.ignore;
+        let walker = WalkDir::new(root).max_depth(3).into_iter().filter_entry(|entry| {
+            let relative = entry.path().strip_prefix(pack_path).unwrap_or(entry.path());
+            let relative_str = relative.to_string_lossy().replace('\\', "/");
+            !ignore.iter().any(|pattern| glob_match(pattern, &relative_str))
+        });
+
+        for entry in walker {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let path = entry.path();
+
+            if let Some(pattern) = include_pattern {
+                if path.is_file() && !pattern.is_empty() {
+                    let relative_to_base = path.strip_prefix(root).unwrap_or(path);
+                    let relative_str = relative_to_base.to_string_lossy().replace('\\', "/");
+                    if !glob_match(pattern, &relative_str) {
+                        continue;
+                    }
                 }
-                subdirs.push(path.to_path_buf());
             }
-        } else if path.is_file() {
-            if let Some(ext) = path.extension() {
-                let ext_lower = ext.to_string_lossy().to_lowercase();
-                match ext_lower.as_str() {
-                    "png" | "jpg" | "jpeg" => {
-                        png_files.push(path.to_path_buf());
-                        if let Ok(metadata) = fs::metadata(path) {
-                            total_size += metadata.len();
-                        }
+
+            if path.is_dir() {
+                if let Some(name) = path.file_name() {
+                    let name_lower = name.to_string_lossy().to_lowercase();
+                    if name_lower == "faces" || name_lower.contains("face") {
+                        has_faces_dir = true;
                     }
-                    "xml" => {
-                        xml_files.push(path.to_path_buf());
+                    if name_lower == "logos"
+                        || name_lower.contains("logo")
+                        || name_lower.contains("badge")
+                    {
+                        has_logos_dir = true;
+                    }
+                    if name_lower == "kits" || name_lower.contains("kit") {
+                        has_kits_dir = true;
+                    }
+                    subdirs.push(path.to_path_buf());
+                }
+            } else if path.is_file() {
+                if let Some(ext) = path.extension() {
+                    let ext_lower = ext.to_string_lossy().to_lowercase();
+                    match ext_lower.as_str() {
+                        "png" | "jpg" | "jpeg" | "bmp" | "tga" => {
+                            image_files.push(path.to_path_buf());
+
+                            match fs::metadata(path) {
+                                Ok(metadata) if metadata.len() > 0 => {
+                                    total_size += metadata.len();
+
+                                    let expected = expected_signature_for_extension(&ext_lower);
+                                    if sniff_image_signature(path) != expected {
+                                        invalid_files.push(path.to_path_buf());
+                                    }
+                                }
+                                _ => invalid_files.push(path.to_path_buf()),
+                            }
+                        }
+                        "xml" => {
+                            xml_files.push(path.to_path_buf());
+                        }
+                        _ => {}
                     }
-                    _ => {}
                 }
             }
         }
@@ -178,10 +1338,11 @@ fn scan_pack_contents(pack_path: &Path) -> Result<PackContents, String> {
         has_faces_dir,
         has_logos_dir,
         has_kits_dir,
-        png_files,
+        image_files,
         xml_files,
         subdirs,
         total_size,
+        invalid_files,
     })
 }
 
@@ -193,6 +1354,33 @@ struct ConfigAnalysis {
     mapping_count: usize,
 }
 
+/// Reads `config.xml`'s text, skipping a leading UTF-8 or UTF-16 (LE/BE) byte-order mark if
+/// present. Some FM graphics tools write the file with a BOM, which would otherwise land in
+/// the first line and silently break the very first pattern match against it.
+fn read_config_xml_text(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return String::from_utf8(rest.to_vec()).ok();
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        let units: Vec<u16> = rest
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        return String::from_utf16(&units).ok();
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = rest
+            .chunks_exact(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .collect();
+        return String::from_utf16(&units).ok();
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
 fn analyze_config_xml(pack_path: &Path) -> ConfigAnalysis {
     let config_path = pack_path.join("config.xml");
     if !config_path.exists() {
@@ -201,7 +1389,7 @@ fn analyze_config_xml(pack_path: &Path) -> ConfigAnalysis {
 
     let mut analysis = ConfigAnalysis::default();
 
-    if let Ok(content) = fs::read_to_string(&config_path) {
+    if let Some(content) = read_config_xml_text(&config_path) {
         // Count mapping types by looking for patterns
         let lines: Vec<&str> = content.lines().collect();
 
@@ -224,6 +1412,126 @@ fn analyze_config_xml(pack_path: &Path) -> ConfigAnalysis {
     analysis
 }
 
+/// Per-category whitelist of file extensions accepted when routing a flat mixed pack. Anything
+/// outside its category's list is rejected rather than silently installed.
+const MIXED_PACK_CATEGORY_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("faces", &["png"]),
+    ("logos", &["png"]),
+    ("kits", &["png", "xml"]),
+];
+
+fn mixed_pack_category_allows_extension(category: &str, ext: &str) -> bool {
+    MIXED_PACK_CATEGORY_EXTENSIONS
+        .iter()
+        .find(|(key, _)| *key == category)
+        .map(|(_, exts)| exts.contains(&ext))
+        .unwrap_or(false)
+}
+
+/// Parses `config.xml`'s `from="..."` targets the same way [`cross_check_config_xml_mappings`]
+/// does, and tags each referenced file with the category implied by its mapping, using the same
+/// line-substring signals as [`analyze_config_xml`]. Returns a lower-cased file name -> category
+/// lookup so a flat pack's individual files can be routed without a type-named subdirectory.
+fn config_xml_category_hints(pack_path: &Path) -> HashMap<String, &'static str> {
+    let mut hints = HashMap::new();
+    let config_path = pack_path.join("config.xml");
+    let Some(content) = read_config_xml_text(&config_path) else {
+        return hints;
+    };
+    let Ok(from_regex) = regex::Regex::new("from=\"([^\"]+)\"") else {
+        return hints;
+    };
+
+    for line in content.lines() {
+        let Some(caps) = from_regex.captures(line) else {
+            continue;
+        };
+
+        let category = if line.contains("graphics/pictures/person") && line.contains("portrait") {
+            "faces"
+        } else if line.contains("graphics/pictures/team") && line.contains("logo") {
+            "logos"
+        } else if line.contains("graphics/pictures/team") && line.contains("kit") {
+            "kits"
+        } else {
+            continue;
+        };
+
+        if let Some(file_name) = Path::new(&caps[1]).file_name().and_then(|n| n.to_str()) {
+            hints.insert(file_name.to_lowercase(), category);
+        }
+    }
+
+    hints
+}
+
+/// Classifies every top-level file in a flat mixed pack (one with no type-named subdirectory)
+/// into one of `types`, preferring a `config.xml` mapping and falling back to extension when a
+/// file isn't mentioned there. Files with no recognized category, or whose only matching
+/// category doesn't accept their extension, come back as rejects instead of being guessed into
+/// `faces` the way the old whole-pack fallback did.
+fn classify_flat_mixed_pack_files(
+    pack_path: &Path,
+    types: &[GraphicsPackType],
+) -> Result<(HashMap<PathBuf, &'static str>, Vec<(PathBuf, String)>), String> {
+    let available: Vec<&'static str> = types
+        .iter()
+        .filter_map(|t| match t {
+            GraphicsPackType::Faces => Some("faces"),
+            GraphicsPackType::Logos => Some("logos"),
+            GraphicsPackType::Kits => Some("kits"),
+            _ => None,
+        })
+        .collect();
+
+    let hints = config_xml_category_hints(pack_path);
+    let mut routed = HashMap::new();
+    let mut rejected = Vec::new();
+
+    for entry in fs::read_dir(pack_path).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+            continue;
+        };
+        if file_name.eq_ignore_ascii_case("config.xml") {
+            continue;
+        }
+
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        let category = hints
+            .get(&file_name.to_lowercase())
+            .copied()
+            .filter(|category| available.contains(category))
+            .or_else(|| (ext == "png" && available.contains(&"faces")).then_some("faces"));
+
+        match category {
+            Some(category) if mixed_pack_category_allows_extension(category, &ext) => {
+                routed.insert(path, category);
+            }
+            Some(category) => rejected.push((
+                path,
+                format!("'.{}' isn't a valid extension for the {} category", ext, category),
+            )),
+            None => rejected.push((
+                path,
+                format!("Couldn't determine which graphics category '{}' belongs to", file_name),
+            )),
+        }
+    }
+
+    Ok((routed, rejected))
+}
+
 fn determine_pack_type(
     contents: &PackContents,
     config: &ConfigAnalysis,
@@ -426,11 +1734,46 @@ pub fn split_mixed_pack(
         }
     }
 
-    // If we couldn't identify separate subdirectories, the pack might be flat
-    // In this case, we need to analyze the config.xml more carefully
+    // If we couldn't identify separate subdirectories, the pack is flat: classify each file
+    // individually instead of handing the whole pack to every type, which used to dump logos
+    // and kits into `faces` (and vice versa) since every type's target directory got a full
+    // copy of everything.
     if split_map.is_empty() {
-        // For flat packs, we can't really split them - they should stay together
-        // Return the whole pack as each type (the config.xml will handle routing)
+        let (routed, rejected) = classify_flat_mixed_pack_files(pack_path, types)?;
+
+        if !rejected.is_empty() {
+            tracing::warn!(
+                "Rejected {} file(s) while routing flat mixed pack {:?}: {}",
+                rejected.len(),
+                pack_path,
+                rejected
+                    .iter()
+                    .map(|(path, reason)| format!(
+                        "{}: {}",
+                        path.file_name().and_then(|n| n.to_str()).unwrap_or("?"),
+                        reason
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        // Staged under the pack itself (rather than a fresh system temp dir) so it gets cleaned
+        // up for free by whatever already deletes the extracted pack once installation finishes.
+        let staging_root = pack_path.join(".fmmloader-mixed-split");
+
+        for (path, category) in &routed {
+            let category_dir = staging_root.join(category);
+            fs::create_dir_all(&category_dir).map_err(|e| e.to_string())?;
+            let file_name = path
+                .file_name()
+                .ok_or_else(|| format!("File has no name: {:?}", path))?;
+            let dest = category_dir.join(file_name);
+            if fs::hard_link(path, &dest).is_err() {
+                fs::copy(path, &dest).map_err(|e| format!("Failed to stage {:?}: {}", path, e))?;
+            }
+        }
+
         for pack_type in types {
             let type_key = match pack_type {
                 GraphicsPackType::Faces => "faces",
@@ -438,7 +1781,10 @@ pub fn split_mixed_pack(
                 GraphicsPackType::Kits => "kits",
                 _ => continue,
             };
-            split_map.insert(type_key.to_string(), pack_path.to_path_buf());
+            let category_dir = staging_root.join(type_key);
+            if category_dir.is_dir() {
+                split_map.insert(type_key.to_string(), category_dir);
+            }
         }
     }
 
@@ -518,6 +1864,138 @@ pub fn get_installation_targets(
     targets
 }
 
+/// Per-install limits enforced by [`install_pack_contents`], configurable so a legitimately
+/// huge megapack isn't penalized by limits sized for a typical face/logo pack.
+#[derive(Debug, Clone, Copy)]
+pub struct InstallLimits {
+    pub max_total_bytes: u64,
+    pub max_file_count: usize,
+    pub max_single_file_bytes: u64,
+}
+
+impl Default for InstallLimits {
+    fn default() -> Self {
+        Self {
+            max_total_bytes: 20 * 1024 * 1024 * 1024, // 20GB
+            max_file_count: 200_000,
+            max_single_file_bytes: 2 * 1024 * 1024 * 1024, // 2GB
+        }
+    }
+}
+
+/// Copies every file under `pack_path` into `dest_root`, hardened against malicious or
+/// malformed packs.
+///
+/// Every destination path is rebuilt one component at a time via [`safe_join`], which
+/// rejects `..`, a rooted/absolute component, or anything else that isn't a plain path
+/// segment — so a pack's inner paths can never escape `dest_root`. Symlinks encountered
+/// while walking the pack are refused outright rather than followed, since a legitimate
+/// graphics pack has no reason to contain one. A running checked total enforces `limits`
+/// incrementally — aborting on the entry that first exceeds a limit — rather than summing
+/// everything up front, so a zip-bomb-style pack fails fast instead of filling the disk
+/// before being rejected.
+#[allow(dead_code)]
+pub fn install_pack_contents(
+    pack_path: &Path,
+    dest_root: &Path,
+    limits: &InstallLimits,
+) -> Result<u64, String> {
+    fs::create_dir_all(dest_root)
+        .map_err(|e| format!("Failed to create install directory: {}", e))?;
+
+    let mut file_count = 0usize;
+    let mut total_bytes = 0u64;
+
+    for entry in WalkDir::new(pack_path) {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+
+        if path == pack_path {
+            continue;
+        }
+
+        let relative = path
+            .strip_prefix(pack_path)
+            .map_err(|e| format!("Failed to resolve relative path for '{}'", path.display()))?;
+        let dest_path = safe_join(dest_root, relative)?;
+
+        if entry.file_type().is_symlink() {
+            return Err(format!(
+                "Refusing to install symlink '{}': packs may not contain symlinks",
+                relative.display()
+            ));
+        }
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&dest_path).map_err(|e| {
+                format!("Failed to create directory '{}': {}", dest_path.display(), e)
+            })?;
+            continue;
+        }
+
+        let size = entry
+            .metadata()
+            .map_err(|e| format!("Failed to read metadata for '{}': {}", relative.display(), e))?
+            .len();
+
+        if size > limits.max_single_file_bytes {
+            return Err(format!(
+                "File '{}' ({} bytes) exceeds the maximum single-file size of {} bytes",
+                relative.display(),
+                size,
+                limits.max_single_file_bytes
+            ));
+        }
+
+        file_count += 1;
+        if file_count > limits.max_file_count {
+            return Err(format!(
+                "Pack contains too many files (> {}). This may be a malformed or malicious pack.",
+                limits.max_file_count
+            ));
+        }
+
+        total_bytes = total_bytes
+            .checked_add(size)
+            .ok_or_else(|| "Pack size overflowed while tallying total bytes".to_string())?;
+        if total_bytes > limits.max_total_bytes {
+            return Err(format!(
+                "Pack exceeds the maximum unpacked size of {} bytes",
+                limits.max_total_bytes
+            ));
+        }
+
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory '{}': {}", parent.display(), e))?;
+        }
+        fs::copy(path, &dest_path)
+            .map_err(|e| format!("Failed to copy '{}': {}", relative.display(), e))?;
+    }
+
+    Ok(total_bytes)
+}
+
+/// Rebuilds `relative` onto `dest_root` one component at a time, rejecting anything other
+/// than a plain `Normal` segment — no `ParentDir`, no `RootDir`, no absolute prefix — so a
+/// pack's inner path can never walk outside `dest_root`.
+fn safe_join(dest_root: &Path, relative: &Path) -> Result<PathBuf, String> {
+    let mut result = dest_root.to_path_buf();
+    for component in relative.components() {
+        match component {
+            std::path::Component::Normal(part) => result.push(part),
+            other => {
+                return Err(format!(
+                    "Refusing to install path '{}' containing unsafe component {:?}",
+                    relative.display(),
+                    other
+                ))
+            }
+        }
+    }
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -531,4 +2009,479 @@ mod tests {
 
         assert_eq!(mixed, deserialized);
     }
+
+    fn test_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("fmml_graphics_analyzer_test_{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_sniff_image_signature_detects_real_formats() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let png_path = dir.join("a.png");
+        fs::write(&png_path, [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat()).unwrap();
+        assert_eq!(sniff_image_signature(&png_path), Some(ImageSignature::Png));
+
+        let jpeg_path = dir.join("b.jpg");
+        fs::write(&jpeg_path, [JPEG_MAGIC.to_vec(), vec![0u8; 4]].concat()).unwrap();
+        assert_eq!(sniff_image_signature(&jpeg_path), Some(ImageSignature::Jpeg));
+
+        let bmp_path = dir.join("c.bmp");
+        fs::write(&bmp_path, [BMP_MAGIC.to_vec(), vec![0u8; 4]].concat()).unwrap();
+        assert_eq!(sniff_image_signature(&bmp_path), Some(ImageSignature::Bmp));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_sniff_image_signature_rejects_renamed_file() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        // A plain text file renamed to .png should not sniff as PNG.
+        let fake_png = dir.join("fake.png");
+        fs::write(&fake_png, b"just some text, not an image").unwrap();
+        assert_eq!(sniff_image_signature(&fake_png), None);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_scan_pack_contents_flags_zero_length_and_mismatched_files() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        fs::write(dir.join("real.png"), [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat()).unwrap();
+        fs::write(dir.join("empty.png"), b"").unwrap();
+        fs::write(dir.join("mislabeled.png"), JPEG_MAGIC).unwrap();
+
+        let contents = scan_pack_contents(&dir).expect("scan_pack_contents should succeed");
+
+        assert_eq!(contents.image_files.len(), 3);
+        assert_eq!(contents.invalid_files.len(), 2);
+        assert!(contents
+            .invalid_files
+            .iter()
+            .any(|p| p.ends_with("empty.png")));
+        assert!(contents
+            .invalid_files
+            .iter()
+            .any(|p| p.ends_with("mislabeled.png")));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_config_xml_text_strips_utf8_bom() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let config_path = dir.join("config.xml");
+        let mut bytes = vec![0xEF, 0xBB, 0xBF];
+        bytes.extend_from_slice(b"<config>hello</config>");
+        fs::write(&config_path, bytes).unwrap();
+
+        let text = read_config_xml_text(&config_path).expect("should decode");
+        assert_eq!(text, "<config>hello</config>");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_find_duplicate_images_groups_identical_files_by_content() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let payload = [PNG_MAGIC.to_vec(), vec![0xAB; 10_000]].concat();
+        fs::write(dir.join("a.png"), &payload).unwrap();
+        fs::write(dir.join("b.png"), &payload).unwrap();
+        // Same size as the pair above, but different content after the shared header —
+        // must not be grouped with them even though it lands in the same size bucket.
+        let mut diverging = payload.clone();
+        *diverging.last_mut().unwrap() ^= 0xFF;
+        fs::write(dir.join("c.png"), &diverging).unwrap();
+        // Unique size entirely; should never be hashed into any group.
+        fs::write(dir.join("d.png"), [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat()).unwrap();
+
+        let groups = find_duplicate_images(&dir).expect("find_duplicate_images should succeed");
+
+        assert_eq!(groups.len(), 1);
+        let group = groups.values().next().unwrap();
+        assert_eq!(group.len(), 2);
+        assert!(group.iter().any(|p| p.ends_with("a.png")));
+        assert!(group.iter().any(|p| p.ends_with("b.png")));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_total_duplicate_bytes_counts_all_but_one_copy_per_group() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let payload = [PNG_MAGIC.to_vec(), vec![0xCD; 1_000]].concat();
+        fs::write(dir.join("a.png"), &payload).unwrap();
+        fs::write(dir.join("b.png"), &payload).unwrap();
+        fs::write(dir.join("c.png"), &payload).unwrap();
+
+        let groups = find_duplicate_images(&dir).expect("find_duplicate_images should succeed");
+        let wasted = total_duplicate_bytes(&groups);
+
+        assert_eq!(wasted, payload.len() as u64 * 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_safe_join_rejects_parent_dir_escape() {
+        let dest_root = PathBuf::from("/tmp/fmml_dest");
+        let err = safe_join(&dest_root, Path::new("../../etc/passwd")).unwrap_err();
+        assert!(err.contains("unsafe component"));
+    }
+
+    #[test]
+    fn test_safe_join_rejects_absolute_component() {
+        let dest_root = PathBuf::from("/tmp/fmml_dest");
+        let err = safe_join(&dest_root, Path::new("/etc/passwd")).unwrap_err();
+        assert!(err.contains("unsafe component"));
+    }
+
+    #[test]
+    fn test_safe_join_allows_nested_normal_path() {
+        let dest_root = PathBuf::from("/tmp/fmml_dest");
+        let joined = safe_join(&dest_root, Path::new("faces/club/1.png")).unwrap();
+        assert_eq!(joined, dest_root.join("faces").join("club").join("1.png"));
+    }
+
+    #[test]
+    fn test_install_pack_contents_copies_files_preserving_structure() {
+        let src = test_dir();
+        let dest = test_dir();
+        fs::create_dir_all(src.join("faces")).expect("create src subdir");
+        fs::write(src.join("faces").join("1.png"), b"face data").unwrap();
+        fs::write(src.join("root.png"), b"root data").unwrap();
+
+        let total = install_pack_contents(&src, &dest, &InstallLimits::default())
+            .expect("install_pack_contents should succeed");
+
+        assert_eq!(total, 9 + 9);
+        assert_eq!(fs::read(dest.join("faces").join("1.png")).unwrap(), b"face data");
+        assert_eq!(fs::read(dest.join("root.png")).unwrap(), b"root data");
+
+        let _ = fs::remove_dir_all(&src);
+        let _ = fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_install_pack_contents_rejects_oversized_single_file() {
+        let src = test_dir();
+        let dest = test_dir();
+        fs::create_dir_all(&src).expect("create src dir");
+        fs::write(src.join("big.png"), vec![0u8; 1024]).unwrap();
+
+        let limits = InstallLimits {
+            max_total_bytes: u64::MAX,
+            max_file_count: usize::MAX,
+            max_single_file_bytes: 10,
+        };
+
+        let err = install_pack_contents(&src, &dest, &limits).unwrap_err();
+        assert!(err.contains("exceeds the maximum single-file size"));
+
+        let _ = fs::remove_dir_all(&src);
+        let _ = fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_install_pack_contents_rejects_too_many_files() {
+        let src = test_dir();
+        let dest = test_dir();
+        fs::create_dir_all(&src).expect("create src dir");
+        for i in 0..5 {
+            fs::write(src.join(format!("{}.png", i)), b"x").unwrap();
+        }
+
+        let limits = InstallLimits {
+            max_total_bytes: u64::MAX,
+            max_file_count: 3,
+            max_single_file_bytes: u64::MAX,
+        };
+
+        let err = install_pack_contents(&src, &dest, &limits).unwrap_err();
+        assert!(err.contains("too many files"));
+
+        let _ = fs::remove_dir_all(&src);
+        let _ = fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_install_pack_contents_rejects_total_size_over_budget() {
+        let src = test_dir();
+        let dest = test_dir();
+        fs::create_dir_all(&src).expect("create src dir");
+        fs::write(src.join("a.png"), vec![0u8; 100]).unwrap();
+        fs::write(src.join("b.png"), vec![0u8; 100]).unwrap();
+
+        let limits = InstallLimits {
+            max_total_bytes: 150,
+            max_file_count: usize::MAX,
+            max_single_file_bytes: u64::MAX,
+        };
+
+        let err = install_pack_contents(&src, &dest, &limits).unwrap_err();
+        assert!(err.contains("exceeds the maximum unpacked size"));
+
+        let _ = fs::remove_dir_all(&src);
+        let _ = fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_glob_match_supports_star_question_and_double_star() {
+        assert!(glob_match("logos/*.png", "logos/1.png"));
+        assert!(!glob_match("logos/*.png", "logos/clubs/1.png"));
+        assert!(glob_match("logos/**/*.png", "logos/clubs/1.png"));
+        assert!(glob_match("*.t?t", "readme.txt"));
+        assert!(!glob_match("*.png", "readme.txt"));
+    }
+
+    #[test]
+    fn test_split_glob_base_separates_literal_prefix_from_pattern() {
+        assert_eq!(
+            split_glob_base("logos/clubs/*.png"),
+            (PathBuf::from("logos/clubs"), "*.png".to_string())
+        );
+        assert_eq!(
+            split_glob_base("**/*.png"),
+            (PathBuf::new(), "**/*.png".to_string())
+        );
+    }
+
+    #[test]
+    fn test_scan_pack_contents_filtered_include_limits_to_subtree() {
+        let dir = test_dir();
+        fs::create_dir_all(dir.join("logos")).expect("create logos dir");
+        fs::create_dir_all(dir.join("faces")).expect("create faces dir");
+        fs::write(dir.join("logos").join("1.png"), [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat())
+            .unwrap();
+        fs::write(dir.join("faces").join("2.png"), [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat())
+            .unwrap();
+
+        let filters = ScanFilters {
+            include: vec!["logos/*.png".to_string()],
+            ignore: vec![],
+        };
+        let contents =
+            scan_pack_contents_filtered(&dir, &filters).expect("filtered scan should succeed");
+
+        assert_eq!(contents.image_files.len(), 1);
+        assert!(contents.image_files[0].ends_with("1.png"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_scan_pack_contents_filtered_ignore_prunes_matched_directory() {
+        let dir = test_dir();
+        fs::create_dir_all(dir.join("__MACOSX")).expect("create junk dir");
+        fs::create_dir_all(dir.join("logos")).expect("create logos dir");
+        fs::write(
+            dir.join("__MACOSX").join("junk.png"),
+            [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat(),
+        )
+        .unwrap();
+        fs::write(dir.join("logos").join("1.png"), [PNG_MAGIC.to_vec(), vec![0u8; 4]].concat())
+            .unwrap();
+
+        let filters = ScanFilters {
+            include: vec![],
+            ignore: vec!["__MACOSX/**".to_string()],
+        };
+        let contents =
+            scan_pack_contents_filtered(&dir, &filters).expect("filtered scan should succeed");
+
+        assert_eq!(contents.image_files.len(), 1);
+        assert!(contents.image_files[0].ends_with("1.png"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_generate_and_verify_pack_manifest_round_trips() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+        fs::write(dir.join("1.png"), [PNG_MAGIC.to_vec(), vec![0xAB; 16]].concat()).unwrap();
+
+        let analysis = analyze_graphics_pack(&dir).expect("analyze should succeed");
+        let manifest =
+            generate_pack_manifest(&dir, &analysis).expect("generate_pack_manifest should succeed");
+        assert_eq!(manifest.entries.len(), 1);
+
+        let verification =
+            verify_pack_manifest(&dir, &manifest).expect("verify_pack_manifest should succeed");
+        assert!(verification.mismatches.is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_verify_pack_manifest_flags_missing_and_corrupted_files() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+        fs::write(dir.join("1.png"), [PNG_MAGIC.to_vec(), vec![0xAB; 16]].concat()).unwrap();
+        fs::write(dir.join("2.png"), [PNG_MAGIC.to_vec(), vec![0xCD; 16]].concat()).unwrap();
+
+        let analysis = analyze_graphics_pack(&dir).expect("analyze should succeed");
+        let manifest =
+            generate_pack_manifest(&dir, &analysis).expect("generate_pack_manifest should succeed");
+
+        fs::remove_file(dir.join("1.png")).unwrap();
+        fs::write(dir.join("2.png"), b"corrupted").unwrap();
+
+        let verification =
+            verify_pack_manifest(&dir, &manifest).expect("verify_pack_manifest should succeed");
+        assert_eq!(verification.mismatches.len(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_diff_manifests_reports_added_removed_and_changed() {
+        let unchanged = PackManifestEntry {
+            relative_path: PathBuf::from("unchanged.png"),
+            size_bytes: 10,
+            crc32: 111,
+        };
+        let removed = PackManifestEntry {
+            relative_path: PathBuf::from("removed.png"),
+            size_bytes: 10,
+            crc32: 222,
+        };
+        let changed_old = PackManifestEntry {
+            relative_path: PathBuf::from("changed.png"),
+            size_bytes: 10,
+            crc32: 333,
+        };
+        let changed_new = PackManifestEntry {
+            relative_path: PathBuf::from("changed.png"),
+            size_bytes: 20,
+            crc32: 444,
+        };
+        let added = PackManifestEntry {
+            relative_path: PathBuf::from("added.png"),
+            size_bytes: 10,
+            crc32: 555,
+        };
+
+        let old = PackManifest {
+            pack_type: GraphicsPackType::Unknown,
+            type_counts: HashMap::new(),
+            entries: vec![unchanged.clone(), removed.clone(), changed_old],
+        };
+        let new = PackManifest {
+            pack_type: GraphicsPackType::Unknown,
+            type_counts: HashMap::new(),
+            entries: vec![unchanged, changed_new.clone(), added.clone()],
+        };
+
+        let diff = diff_manifests(&old, &new);
+        assert_eq!(diff.added, vec![added]);
+        assert_eq!(diff.removed, vec![removed]);
+        assert_eq!(diff.changed, vec![changed_new]);
+    }
+
+    fn make_phash_test_image(path: &Path, seed: u32) {
+        let img = image::ImageBuffer::from_fn(64, 64, |x, y| {
+            let v = ((x.wrapping_mul(7) + y.wrapping_mul(13) + seed) % 256) as u8;
+            image::Rgb([v, v, v])
+        });
+        img.save(path).expect("save test image");
+    }
+
+    #[test]
+    fn test_compute_phash_identical_images_have_zero_distance() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let a = dir.join("a.png");
+        let b = dir.join("b.png");
+        make_phash_test_image(&a, 0);
+        make_phash_test_image(&b, 0);
+
+        let hash_a = compute_phash(&a).expect("should compute phash");
+        let hash_b = compute_phash(&b).expect("should compute phash");
+        assert_eq!(hash_hamming_distance(hash_a, hash_b), 0);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compute_dhash_identical_images_have_zero_distance() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let a = dir.join("a.png");
+        let b = dir.join("b.png");
+        make_phash_test_image(&a, 0);
+        make_phash_test_image(&b, 0);
+
+        let hash_a = compute_dhash(&a).expect("should compute dhash");
+        let hash_b = compute_dhash(&b).expect("should compute dhash");
+        assert_eq!(hash_hamming_distance(hash_a, hash_b), 0);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compute_dhash_distinguishes_different_images() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        let a = dir.join("a.png");
+        let b = dir.join("b.png");
+        make_phash_test_image(&a, 0);
+        make_phash_test_image(&b, 128);
+
+        let hash_a = compute_dhash(&a).expect("should compute dhash");
+        let hash_b = compute_dhash(&b).expect("should compute dhash");
+        assert!(hash_hamming_distance(hash_a, hash_b) > DEFAULT_DHASH_HAMMING_THRESHOLD);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_find_similar_images_clusters_visually_similar_files() {
+        let dir = test_dir();
+        fs::create_dir_all(&dir).expect("create test dir");
+
+        make_phash_test_image(&dir.join("a.png"), 0);
+        make_phash_test_image(&dir.join("b.png"), 0);
+        make_phash_test_image(&dir.join("c.png"), 128);
+
+        let groups = find_similar_images(&dir, DEFAULT_PHASH_HAMMING_THRESHOLD)
+            .expect("find_similar_images should succeed");
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].len(), 2);
+        assert!(groups[0].iter().any(|p| p.ends_with("a.png")));
+        assert!(groups[0].iter().any(|p| p.ends_with("b.png")));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_install_pack_contents_rejects_symlinks() {
+        let src = test_dir();
+        let dest = test_dir();
+        fs::create_dir_all(&src).expect("create src dir");
+        fs::write(src.join("real.png"), b"data").unwrap();
+        std::os::unix::fs::symlink(src.join("real.png"), src.join("link.png")).unwrap();
+
+        let err = install_pack_contents(&src, &dest, &InstallLimits::default()).unwrap_err();
+        assert!(err.contains("Refusing to install symlink"));
+
+        let _ = fs::remove_dir_all(&src);
+        let _ = fs::remove_dir_all(&dest);
+    }
 }
@@ -8,17 +8,36 @@
 //! **Synchronous**: `extract_zip()` - Used for small mod imports where blocking is acceptable
 //! **Asynchronous**: `extract_zip_async()` - Used for large graphics packs (5GB+) with progress events
 //!
+//! Large graphics packs often arrive as gzip/xz/zstd-compressed tarballs rather than zips (a
+//! wider compression window makes 5GB+ face packs far smaller to distribute), so
+//! `extract_archive()`/`extract_archive_async()` sniff the container by magic bytes and dispatch
+//! to the right decoder, while still routing plain zips through the existing zip path.
+//!
 //! # Zip Bomb Protection
 //!
-//! The async extractor implements security limits:
+//! The async extractors implement security limits:
 //! - Maximum 50GB total extraction size
 //! - Maximum 500,000 files per archive
 //! - Early termination when limits exceeded
 //!
+//! # Permission Audit
+//!
+//! `extract_zip_async()` blindly applies each entry's archive-supplied `unix_mode()`, so a
+//! malicious or sloppy pack could otherwise drop world-writable or setuid/setgid files into
+//! the game directory. After extraction it walks the destination, strips setuid/setgid and
+//! other-write from every file regardless of what the archive asked for, and emits one final
+//! `ExtractionProgress` with `stage_name: "auditing"` carrying the offending paths in
+//! `audit_findings` so callers can surface a security summary.
+//!
 //! # Progress Tracking
 //!
-//! Progress callbacks emit every 50 files (not per file) to balance responsiveness with performance.
-//! Progress includes current file number, total files, current filename, and bytes processed.
+//! Progress callbacks emit every 50 entries (not per entry) to balance responsiveness with
+//! performance. `ExtractionProgress` reports a numbered stage (`current_stage`/`max_stage`/
+//! `stage_name`) alongside `entries_checked`/`entries_total`, `current_file`, and
+//! `bytes_processed` — see [`crate::graphics::import_graphics_pack_with_type`] for how a multi-step
+//! pipeline numbers its stages. Tar formats are streamed entry-by-entry without a cheap upfront
+//! count, so their progress reports `entries_total: 0` and should be read by `bytes_processed`
+//! rather than `entries_checked`/`entries_total`.
 //!
 //! # Mod Type Detection
 //!
@@ -27,13 +46,542 @@
 //! - Tactics: Contains .fmf files
 //! - Editor Data: Contains .dbc, .edt, .lnc files or editor data/ directory
 //! - UI/Bundle: Default for other content
+//!
+//! Extension-less or renamed files fall back to magic-byte sniffing (PNG/JPEG/zip headers,
+//! the FMF signature) for a bounded sample of files per directory, short-circuiting once a
+//! strong signal (several confirmed images) is found.
 
 use crate::types::ExtractionProgress;
+use serde::ser::SerializeStruct;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::fs;
 use std::io;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use zip::ZipArchive;
 
+// Zip bomb protection limits, shared by every archive format.
+const MAX_TOTAL_BYTES: u64 = 50 * 1024 * 1024 * 1024; // 50GB max extraction size
+const MAX_ENTRIES: usize = 500_000; // 500k files max
+// Per-entry cap is deliberately far below MAX_TOTAL_BYTES: it exists to stop a single lying
+// entry from being a bomb on its own, which setting it equal to the aggregate budget would
+// entirely defeat.
+const MAX_ENTRY_BYTES: u64 = 4 * 1024 * 1024 * 1024; // 4GB max for any single entry
+
+/// Configurable caps enforced by the async extractors (`extract_zip_async`/
+/// `extract_archive_async`), so a caller importing a genuinely huge megapack can raise them
+/// instead of being stuck with limits sized for a typical mod. [`Default`] matches the
+/// previous hardcoded [`MAX_TOTAL_BYTES`]/[`MAX_ENTRIES`] constants, and also bounds how many
+/// bytes any single entry may write to disk — a zip's central-directory size is attacker
+/// controlled and can understate how much a malicious entry will actually inflate to, so the
+/// per-entry cap is checked while streaming rather than only after the fact.
+#[derive(Debug, Clone, Copy)]
+pub struct ExtractionLimits {
+    pub max_total_bytes: u64,
+    pub max_entries: usize,
+    pub max_entry_bytes: u64,
+}
+
+impl Default for ExtractionLimits {
+    fn default() -> Self {
+        Self {
+            max_total_bytes: MAX_TOTAL_BYTES,
+            max_entries: MAX_ENTRIES,
+            max_entry_bytes: MAX_ENTRY_BYTES,
+        }
+    }
+}
+
+/// Typed failure reason from the async extractors, so a caller (the graphics-pack import
+/// commands, specifically) can explain *why* an archive was refused instead of just showing a
+/// formatted string. Every other helper in this module still returns `Result<_, String>` —
+/// `Other` is the catch-all those convert into via `From<String>`, the same pattern
+/// [`crate::error::AppError`] uses to let callers migrate one function at a time.
+#[derive(Debug, thiserror::Error)]
+pub enum ExtractionError {
+    #[error("Refusing to extract: {0}")]
+    ZipSlip(String),
+    #[error("Archive contains too many files ({found} > {limit}). This may be a corrupted or malicious file.")]
+    TooManyEntries { found: usize, limit: usize },
+    #[error("Archive extraction exceeded the size limit of {limit_bytes} bytes. This may be a corrupted or malicious file.")]
+    TooLarge { limit_bytes: u64 },
+    #[error("Entry '{name}' exceeded the per-file size limit of {limit_bytes} bytes while extracting. This may be a corrupted or malicious file.")]
+    EntryTooLarge { name: String, limit_bytes: u64 },
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<String> for ExtractionError {
+    fn from(message: String) -> Self {
+        ExtractionError::Other(message)
+    }
+}
+
+impl ExtractionError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ExtractionError::ZipSlip(_) => "ERR_ZIP_SLIP",
+            ExtractionError::TooManyEntries { .. } => "ERR_TOO_MANY_ENTRIES",
+            ExtractionError::TooLarge { .. } => "ERR_EXTRACTION_TOO_LARGE",
+            ExtractionError::EntryTooLarge { .. } => "ERR_ENTRY_TOO_LARGE",
+            ExtractionError::Other(_) => "ERR_OTHER",
+        }
+    }
+}
+
+impl Serialize for ExtractionError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExtractionError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+/// Copies from `reader` to `writer` in fixed-size chunks, aborting the moment the running
+/// total exceeds `max_bytes` instead of after the whole entry has been written. A zip entry's
+/// declared size lives in the (attacker-controlled) central directory, so without this a
+/// single lying entry could write arbitrarily far past any aggregate cap before the caller's
+/// post-copy check ever runs.
+fn copy_with_cap<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    entry_name: &str,
+    max_bytes: u64,
+) -> Result<u64, ExtractionError> {
+    const CHUNK_SIZE: usize = 64 * 1024;
+    let mut buf = [0u8; CHUNK_SIZE];
+    let mut total = 0u64;
+
+    loop {
+        let read = reader
+            .read(&mut buf)
+            .map_err(|e| ExtractionError::Other(format!("Failed to extract file: {}", e)))?;
+        if read == 0 {
+            break;
+        }
+
+        total += read as u64;
+        if total > max_bytes {
+            return Err(ExtractionError::EntryTooLarge {
+                name: entry_name.to_string(),
+                limit_bytes: max_bytes,
+            });
+        }
+
+        writer
+            .write_all(&buf[..read])
+            .map_err(|e| ExtractionError::Other(format!("Failed to extract file: {}", e)))?;
+    }
+
+    Ok(total)
+}
+
+/// Compressed-archive container formats `extract_archive`/`extract_archive_async` understand,
+/// detected by sniffing magic bytes rather than trusting the file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArchiveFormat {
+    Zip,
+    TarGz,
+    TarXz,
+    TarZst,
+    Tar,
+}
+
+impl ArchiveFormat {
+    /// Short label recorded in [`crate::types::GraphicsPackMetadata::source_format`] so a later
+    /// re-export can reconstruct the archive in the format it originally arrived in.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::TarXz => "tar.xz",
+            ArchiveFormat::TarZst => "tar.zst",
+            ArchiveFormat::Tar => "tar",
+        }
+    }
+}
+
+/// Sniffs `archive_path`'s container format by magic bytes (see [`sniff_archive_format`]) and
+/// returns its [`ArchiveFormat::label`], for callers (graphics-pack import, specifically) that
+/// need to record what format a pack arrived in without otherwise depending on `ArchiveFormat`.
+pub(crate) fn detect_archive_format_label(archive_path: &Path) -> Result<String, String> {
+    sniff_archive_format(archive_path).map(|format| format.label().to_string())
+}
+
+/// Sniffs `path`'s container format from its leading bytes (and, for plain tar, the `ustar`
+/// magic at offset 257), so a renamed or extension-less archive still extracts correctly.
+fn sniff_archive_format(path: &Path) -> Result<ArchiveFormat, String> {
+    let mut file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut header = [0u8; 262];
+    let n = file
+        .read(&mut header)
+        .map_err(|e| format!("Failed to read archive header: {}", e))?;
+    let header = &header[..n];
+
+    if header.starts_with(b"PK\x03\x04") {
+        Ok(ArchiveFormat::Zip)
+    } else if header.starts_with(&[0x1f, 0x8b]) {
+        Ok(ArchiveFormat::TarGz)
+    } else if header.starts_with(b"\xFD7zXZ\x00") {
+        Ok(ArchiveFormat::TarXz)
+    } else if header.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        Ok(ArchiveFormat::TarZst)
+    } else if header.len() >= 262 && &header[257..262] == b"ustar" {
+        Ok(ArchiveFormat::Tar)
+    } else {
+        Err("Unrecognized archive format (expected zip, tar.gz, tar.xz, tar.zst, or tar)"
+            .to_string())
+    }
+}
+
+/// Memory limit handed to the xz decompressor for `.tar.xz` packs. liblzma's default decoder
+/// memlimit is comfortably exceeded by the large dictionary sizes high-ratio megapack encoders
+/// pick for thousands of similar face/logo PNGs, which otherwise fails the decode outright rather
+/// than just running slower.
+const TAR_XZ_DECODER_MEMLIMIT: u64 = 64 * 1024 * 1024; // 64MB
+
+/// Wraps `reader` in the decoder matching `format`. `format` must not be `ArchiveFormat::Zip`.
+fn tar_decoder(format: ArchiveFormat, reader: fs::File) -> Result<Box<dyn Read>, String> {
+    match format {
+        ArchiveFormat::TarGz => Ok(Box::new(flate2::read::GzDecoder::new(reader))),
+        ArchiveFormat::TarXz => {
+            let stream = xz2::stream::Stream::new_stream_decoder(TAR_XZ_DECODER_MEMLIMIT, 0)
+                .map_err(|e| format!("Failed to configure xz decompressor: {}", e))?;
+            Ok(Box::new(xz2::read::XzDecoder::new_stream(reader, stream)))
+        }
+        ArchiveFormat::TarZst => Ok(Box::new(
+            zstd::Decoder::new(reader).map_err(|e| format!("Failed to open zstd stream: {}", e))?,
+        )),
+        ArchiveFormat::Tar => Ok(Box::new(reader)),
+        ArchiveFormat::Zip => unreachable!("zip is handled by the zip path, not the tar decoder"),
+    }
+}
+
+/// Format-dispatching entry point: sniffs `archive_path` and extracts it into `dest_dir`,
+/// routing to the zip path or the tar+{gzip,xz,zstd} path as appropriate.
+pub fn extract_archive(archive_path: &Path, dest_dir: &Path) -> Result<PathBuf, String> {
+    extract_archive_with_limits(archive_path, dest_dir, &ExtractionLimits::default())
+        .map_err(|e| e.to_string())
+}
+
+/// Same as [`extract_archive`], but with configurable [`ExtractionLimits`] and a typed
+/// [`ExtractionError`] so a caller (graphics-pack import, specifically) can explain which cap
+/// was hit instead of just showing a formatted string.
+pub fn extract_archive_with_limits(
+    archive_path: &Path,
+    dest_dir: &Path,
+    limits: &ExtractionLimits,
+) -> Result<PathBuf, ExtractionError> {
+    let format = sniff_archive_format(archive_path)?;
+    match format {
+        ArchiveFormat::Zip => {
+            let file = fs::File::open(archive_path)
+                .map_err(|e| format!("Failed to open zip file: {}", e))?;
+            let mut archive =
+                ZipArchive::new(file).map_err(|e| format!("Failed to read zip archive: {}", e))?;
+            fs::create_dir_all(dest_dir)
+                .map_err(|e| format!("Failed to create destination directory: {}", e))?;
+
+            if archive.len() > limits.max_entries {
+                return Err(ExtractionError::TooManyEntries {
+                    found: archive.len(),
+                    limit: limits.max_entries,
+                });
+            }
+
+            for i in 0..archive.len() {
+                let mut file = archive
+                    .by_index(i)
+                    .map_err(|e| format!("Failed to read file from archive: {}", e))?;
+
+                let outpath = match file.enclosed_name() {
+                    Some(path) => dest_dir.join(path),
+                    None => continue,
+                };
+
+                write_zip_entry(&mut file, &outpath, dest_dir, limits)?;
+            }
+
+            Ok(dest_dir.to_path_buf())
+        }
+        format => {
+            let file = fs::File::open(archive_path)
+                .map_err(|e| format!("Failed to open archive: {}", e))?;
+            let decoder = tar_decoder(format, file).map_err(ExtractionError::Other)?;
+            extract_tar_entries(decoder, dest_dir, limits, 1, 1, |_| {})
+        }
+    }
+}
+
+/// Shared tar-extraction loop: unpacks every entry under `dest_dir` (via `tar`'s own zip-slip
+/// protection) and reports running totals through `on_entry`, so both the sync and async
+/// entry points can enforce the same bomb-protection limits and emit the same progress shape.
+/// `stage`/`max_stage` are stamped onto every emitted [`ExtractionProgress`] as-is — this loop
+/// doesn't know where "extracting" sits in the caller's overall pipeline.
+fn extract_tar_entries<R: Read>(
+    reader: R,
+    dest_dir: &Path,
+    limits: &ExtractionLimits,
+    stage: usize,
+    max_stage: usize,
+    mut on_entry: impl FnMut(&ExtractionProgress),
+) -> Result<PathBuf, ExtractionError> {
+    fs::create_dir_all(dest_dir)
+        .map_err(|e| format!("Failed to create destination directory: {}", e))?;
+
+    let mut archive = tar::Archive::new(reader);
+    let mut entries_seen = 0usize;
+    let mut bytes_processed = 0u64;
+
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Failed to read tar entries: {}", e))?
+    {
+        let mut entry = entry.map_err(|e| format!("Failed to read tar entry: {}", e))?;
+
+        entries_seen += 1;
+        if entries_seen > limits.max_entries {
+            return Err(ExtractionError::TooManyEntries {
+                found: entries_seen,
+                limit: limits.max_entries,
+            });
+        }
+
+        let entry_path = entry
+            .path()
+            .map_err(|e| format!("Failed to read tar entry path: {}", e))?
+            .to_string_lossy()
+            .into_owned();
+
+        if entry.size() > limits.max_entry_bytes {
+            return Err(ExtractionError::EntryTooLarge {
+                name: entry_path,
+                limit_bytes: limits.max_entry_bytes,
+            });
+        }
+
+        bytes_processed += entry.size();
+        if bytes_processed > limits.max_total_bytes {
+            return Err(ExtractionError::TooLarge {
+                limit_bytes: limits.max_total_bytes,
+            });
+        }
+
+        entry
+            .unpack_in(dest_dir)
+            .map_err(|e| format!("Failed to extract '{}': {}", entry_path, e))?;
+
+        if entries_seen % 50 == 0 {
+            on_entry(&ExtractionProgress {
+                current_stage: stage,
+                max_stage,
+                stage_name: "extracting".to_string(),
+                entries_checked: entries_seen,
+                entries_total: 0,
+                current_file: entry_path,
+                bytes_processed,
+                audit_findings: Vec::new(),
+                duplicates_skipped: 0,
+                images_normalized: 0,
+                images_rejected: 0,
+                mixed_pack_routed: HashMap::new(),
+            });
+        }
+    }
+
+    on_entry(&ExtractionProgress {
+        current_stage: stage,
+        max_stage,
+        stage_name: "extracting".to_string(),
+        entries_checked: entries_seen,
+        entries_total: 0,
+        current_file: String::new(),
+        bytes_processed,
+        audit_findings: Vec::new(),
+        duplicates_skipped: 0,
+        images_normalized: 0,
+        images_rejected: 0,
+        mixed_pack_routed: HashMap::new(),
+    });
+
+    Ok(dest_dir.to_path_buf())
+}
+
+/// Async, format-dispatching counterpart to `extract_archive()`. Tar formats don't know their
+/// entry count up front, so progress is driven by bytes processed rather than a file index.
+/// `limits` is the same configurable cap set `extract_zip_async` enforces — pass
+/// `ExtractionLimits::default()` for the previous hardcoded behavior. `stage`/`max_stage` are
+/// stamped onto every emitted [`ExtractionProgress`] so a caller running extraction as one step
+/// of a larger staged pipeline (see [`crate::graphics::import_graphics_pack_with_type`]) gets
+/// progress events numbered consistently with its later stages.
+pub async fn extract_archive_async<F>(
+    archive_path: PathBuf,
+    dest_dir: PathBuf,
+    limits: ExtractionLimits,
+    stage: usize,
+    max_stage: usize,
+    mut progress_callback: F,
+) -> Result<PathBuf, ExtractionError>
+where
+    F: FnMut(ExtractionProgress) + Send + 'static,
+{
+    let format = sniff_archive_format(&archive_path).map_err(ExtractionError::Other)?;
+
+    if format == ArchiveFormat::Zip {
+        return extract_zip_async(archive_path, dest_dir, limits, stage, max_stage, progress_callback)
+            .await;
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let file = fs::File::open(&archive_path)
+            .map_err(|e| format!("Failed to open archive: {}", e))?;
+        let decoder = tar_decoder(format, file).map_err(ExtractionError::Other)?;
+        extract_tar_entries(decoder, &dest_dir, &limits, stage, max_stage, |progress| {
+            progress_callback(progress.clone());
+        })
+    })
+    .await
+    .map_err(|e| ExtractionError::Other(format!("Task join error: {}", e)))?
+}
+
+/// Extracts a single zip entry under `dest_dir`, guarding against zip-slip via symlinks: a
+/// Unix symlink entry (`unix_mode() & S_IFMT == S_IFLNK`) is never written as plain file bytes
+/// (its "contents" are just the link target string) and is only ever materialized as a real
+/// symlink when the target resolves inside `dest_dir`; every regular file write is additionally
+/// checked after its parent directory is created, so a symlink planted by an earlier entry
+/// can't redirect a later write outside the extraction root. Returns bytes written (0 for
+/// directories and symlinks).
+fn write_zip_entry(
+    file: &mut zip::read::ZipFile,
+    outpath: &Path,
+    dest_dir: &Path,
+    limits: &ExtractionLimits,
+) -> Result<u64, ExtractionError> {
+    #[cfg(unix)]
+    {
+        const S_IFMT: u32 = 0o170000;
+        const S_IFLNK: u32 = 0o120000;
+        if let Some(mode) = file.unix_mode() {
+            if mode & S_IFMT == S_IFLNK {
+                return write_zip_symlink(file, outpath, dest_dir);
+            }
+        }
+    }
+
+    let entry_name = file.name().to_string();
+    let bytes = if file.name().ends_with('/') {
+        create_dir_all_race_tolerant(outpath)?;
+        0
+    } else {
+        if let Some(p) = outpath.parent() {
+            create_dir_all_race_tolerant(p)?;
+            assert_within(p, dest_dir).map_err(ExtractionError::ZipSlip)?;
+        }
+        let mut outfile = fs::File::create(outpath)
+            .map_err(|e| format!("Failed to create output file: {}", e))?;
+        copy_with_cap(file, &mut outfile, &entry_name, limits.max_entry_bytes)?
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Some(mode) = file.unix_mode() {
+            fs::set_permissions(outpath, fs::Permissions::from_mode(mode)).ok();
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Materializes a zip symlink entry as a real symlink, refusing targets that are absolute or
+/// that lexically walk (via `..`) above `dest_dir`. The target is checked lexically rather than
+/// by canonicalizing, since the link target commonly doesn't exist on disk yet.
+#[cfg(unix)]
+fn write_zip_symlink(
+    file: &mut zip::read::ZipFile,
+    outpath: &Path,
+    dest_dir: &Path,
+) -> Result<u64, ExtractionError> {
+    let mut target = String::new();
+    io::Read::read_to_string(file, &mut target)
+        .map_err(|e| format!("Failed to read symlink target: {}", e))?;
+    let target_path = Path::new(target.trim());
+    let parent = outpath.parent().unwrap_or(dest_dir);
+
+    if target_path.is_absolute() || !lexically_resolves_within(parent, target_path, dest_dir) {
+        return Err(ExtractionError::ZipSlip(format!(
+            "symlink '{}' has unsafe target '{}'",
+            outpath.display(),
+            target
+        )));
+    }
+
+    if let Some(p) = outpath.parent() {
+        create_dir_all_race_tolerant(p)?;
+        assert_within(p, dest_dir).map_err(ExtractionError::ZipSlip)?;
+    }
+
+    // A previous run (or a colliding entry) may have already created a real file/dir here.
+    let _ = fs::remove_file(outpath);
+    std::os::unix::fs::symlink(target_path, outpath)
+        .map_err(|e| format!("Failed to create symlink: {}", e))?;
+
+    Ok(0)
+}
+
+/// Lexically resolves `rel` against `base` (which must be inside `root`) without touching the
+/// filesystem, returning `false` if it walks above `root` or is rooted/absolute.
+#[cfg(unix)]
+fn lexically_resolves_within(base: &Path, rel: &Path, root: &Path) -> bool {
+    let mut stack: Vec<std::ffi::OsString> = match base.strip_prefix(root) {
+        Ok(suffix) => suffix
+            .components()
+            .map(|c| c.as_os_str().to_os_string())
+            .collect(),
+        Err(_) => return false,
+    };
+
+    for component in rel.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                if stack.pop().is_none() {
+                    return false;
+                }
+            }
+            std::path::Component::Normal(part) => stack.push(part.to_os_string()),
+            std::path::Component::CurDir => {}
+            std::path::Component::RootDir | std::path::Component::Prefix(_) => return false,
+        }
+    }
+
+    true
+}
+
+/// Canonicalizes `path` and checks it is still a descendant of `root`'s canonical form. Used
+/// right after a parent directory is created, so a symlink planted by an earlier archive entry
+/// can't make a later write land outside `dest_dir`.
+fn assert_within(path: &Path, root: &Path) -> Result<(), String> {
+    let canonical_root = fs::canonicalize(root)
+        .map_err(|e| format!("Failed to canonicalize destination directory: {}", e))?;
+    let canonical_path = fs::canonicalize(path)
+        .map_err(|e| format!("Failed to canonicalize extracted path: {}", e))?;
+
+    if canonical_path.starts_with(&canonical_root) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Refusing to extract outside destination directory: {:?}",
+            path
+        ))
+    }
+}
+
 pub fn extract_zip(zip_path: &Path, dest_dir: &Path) -> Result<PathBuf, String> {
     let file = fs::File::open(zip_path).map_err(|e| format!("Failed to open zip file: {}", e))?;
 
@@ -43,6 +591,7 @@ pub fn extract_zip(zip_path: &Path, dest_dir: &Path) -> Result<PathBuf, String>
     fs::create_dir_all(dest_dir)
         .map_err(|e| format!("Failed to create destination directory: {}", e))?;
 
+    let limits = ExtractionLimits::default();
     for i in 0..archive.len() {
         let mut file = archive
             .by_index(i)
@@ -53,127 +602,307 @@ pub fn extract_zip(zip_path: &Path, dest_dir: &Path) -> Result<PathBuf, String>
             None => continue,
         };
 
-        if file.name().ends_with('/') {
-            fs::create_dir_all(&outpath)
-                .map_err(|e| format!("Failed to create directory: {}", e))?;
-        } else {
-            if let Some(p) = outpath.parent() {
-                fs::create_dir_all(p)
-                    .map_err(|e| format!("Failed to create parent directory: {}", e))?;
-            }
-            let mut outfile = fs::File::create(&outpath)
-                .map_err(|e| format!("Failed to create output file: {}", e))?;
-            io::copy(&mut file, &mut outfile)
-                .map_err(|e| format!("Failed to extract file: {}", e))?;
-        }
-
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            if let Some(mode) = file.unix_mode() {
-                fs::set_permissions(&outpath, fs::Permissions::from_mode(mode)).ok();
-            }
-        }
+        write_zip_entry(&mut file, &outpath, dest_dir, &limits).map_err(|e| e.to_string())?;
     }
 
     Ok(dest_dir.to_path_buf())
 }
 
-/// Async version of extract_zip that emits progress events
+/// Async version of extract_zip that emits progress events.
+///
+/// For large (5GB+) packs, decompression dominates wall time and is embarrassingly parallel
+/// per-entry, so the actual extraction fans out across rayon's global thread pool: each worker
+/// opens its own `File`/`ZipArchive` handle (the zip crate's reader isn't shareable across
+/// threads) and claims entry indices off a shared atomic counter. Bytes processed and completed
+/// counts are tracked with atomics so the `ExtractionProgress` callback — still throttled to
+/// every ~50 completed entries — and the `limits.max_total_bytes` bomb-protection limit stay correct
+/// under concurrent writers; the first worker to hit an error or the byte limit flips a shared
+/// abort flag so the rest wind down promptly instead of racing to finish.
 pub async fn extract_zip_async<F>(
     zip_path: PathBuf,
     dest_dir: PathBuf,
-    mut progress_callback: F,
-) -> Result<PathBuf, String>
+    limits: ExtractionLimits,
+    stage: usize,
+    max_stage: usize,
+    progress_callback: F,
+) -> Result<PathBuf, ExtractionError>
 where
     F: FnMut(ExtractionProgress) + Send + 'static,
 {
-    // Zip bomb protection limits
-    const MAX_TOTAL_BYTES: u64 = 50 * 1024 * 1024 * 1024; // 50GB max extraction size
-    const MAX_ENTRIES: usize = 500_000; // 500k files max
-
     tokio::task::spawn_blocking(move || {
-        let file = fs::File::open(&zip_path)
-            .map_err(|e| format!("Failed to open zip file: {}", e))?;
+        extract_zip_parallel(&zip_path, &dest_dir, &limits, stage, max_stage, progress_callback)
+    })
+    .await
+    .map_err(|e| ExtractionError::Other(format!("Task join error: {}", e)))?
+}
 
-        let mut archive = ZipArchive::new(file)
-            .map_err(|e| format!("Failed to read zip archive: {}", e))?;
+fn extract_zip_parallel<F>(
+    zip_path: &Path,
+    dest_dir: &Path,
+    limits: &ExtractionLimits,
+    stage: usize,
+    max_stage: usize,
+    progress_callback: F,
+) -> Result<PathBuf, ExtractionError>
+where
+    F: FnMut(ExtractionProgress) + Send + 'static,
+{
+    use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    let total = {
+        let file =
+            fs::File::open(zip_path).map_err(|e| format!("Failed to open zip file: {}", e))?;
+        ZipArchive::new(file)
+            .map_err(|e| format!("Failed to read zip archive: {}", e))?
+            .len()
+    };
 
-        fs::create_dir_all(&dest_dir)
-            .map_err(|e| format!("Failed to create destination directory: {}", e))?;
+    create_dir_all_race_tolerant(dest_dir)?;
 
-        let total = archive.len();
+    // Check for excessive entry count (zip bomb indicator)
+    if total > limits.max_entries {
+        return Err(ExtractionError::TooManyEntries {
+            found: total,
+            limit: limits.max_entries,
+        });
+    }
 
-        // Check for excessive entry count (zip bomb indicator)
-        if total > MAX_ENTRIES {
-            return Err(format!(
-                "Archive contains too many files ({}). Maximum allowed is {}. This may be a corrupted or malicious file.",
-                total, MAX_ENTRIES
-            ));
-        }
+    let next_index = AtomicUsize::new(0);
+    let completed = AtomicUsize::new(0);
+    let bytes_processed = AtomicU64::new(0);
+    let aborted = AtomicBool::new(false);
+    let first_error: Mutex<Option<ExtractionError>> = Mutex::new(None);
+    let progress_callback = Mutex::new(progress_callback);
+
+    let worker_count = rayon::current_num_threads().min(total).max(1);
+
+    rayon::scope(|scope| {
+        for _ in 0..worker_count {
+            scope.spawn(|_| {
+                let archive_file = match fs::File::open(zip_path) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        abort_with(
+                            &aborted,
+                            &first_error,
+                            ExtractionError::Other(format!("Failed to open zip file: {}", e)),
+                        );
+                        return;
+                    }
+                };
+                let mut archive = match ZipArchive::new(archive_file) {
+                    Ok(a) => a,
+                    Err(e) => {
+                        abort_with(
+                            &aborted,
+                            &first_error,
+                            ExtractionError::Other(format!("Failed to read zip archive: {}", e)),
+                        );
+                        return;
+                    }
+                };
 
-        let mut bytes_processed = 0u64;
+                loop {
+                    if aborted.load(Ordering::Relaxed) {
+                        return;
+                    }
 
-        for i in 0..total {
-            let mut file = archive.by_index(i)
-                .map_err(|e| format!("Failed to read file from archive: {}", e))?;
+                    let idx = next_index.fetch_add(1, Ordering::Relaxed);
+                    if idx >= total {
+                        return;
+                    }
 
-            let outpath = match file.enclosed_name() {
-                Some(path) => dest_dir.join(path),
-                None => continue,
-            };
+                    let mut entry = match archive.by_index(idx) {
+                        Ok(e) => e,
+                        Err(e) => {
+                            abort_with(
+                                &aborted,
+                                &first_error,
+                                ExtractionError::Other(format!("Failed to read file from archive: {}", e)),
+                            );
+                            return;
+                        }
+                    };
 
-            let file_name = file.name().to_string();
+                    let outpath = match entry.enclosed_name() {
+                        Some(path) => dest_dir.join(path),
+                        None => continue,
+                    };
+                    let entry_name = entry.name().to_string();
 
-            if file.name().ends_with('/') {
-                fs::create_dir_all(&outpath)
-                    .map_err(|e| format!("Failed to create directory: {}", e))?;
-            } else {
-                if let Some(p) = outpath.parent() {
-                    fs::create_dir_all(p)
-                        .map_err(|e| format!("Failed to create parent directory: {}", e))?;
-                }
-                let mut outfile = fs::File::create(&outpath)
-                    .map_err(|e| format!("Failed to create output file: {}", e))?;
-                let bytes_copied = io::copy(&mut file, &mut outfile)
-                    .map_err(|e| format!("Failed to extract file: {}", e))?;
-                bytes_processed += bytes_copied;
-
-                // Check for excessive extraction size (zip bomb indicator)
-                if bytes_processed > MAX_TOTAL_BYTES {
-                    return Err(format!(
-                        "Archive extraction exceeded size limit ({}GB). Extracted {}GB so far. This may be a corrupted or malicious file.",
-                        MAX_TOTAL_BYTES / 1024 / 1024 / 1024,
-                        bytes_processed / 1024 / 1024 / 1024
-                    ));
-                }
-            }
+                    let bytes_written = match write_zip_entry(&mut entry, &outpath, dest_dir, limits) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            abort_with(&aborted, &first_error, e);
+                            return;
+                        }
+                    };
 
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::PermissionsExt;
-                if let Some(mode) = file.unix_mode() {
-                    fs::set_permissions(&outpath, fs::Permissions::from_mode(mode))
-                        .ok();
+                    let running_total =
+                        bytes_processed.fetch_add(bytes_written, Ordering::Relaxed) + bytes_written;
+                    if running_total > limits.max_total_bytes {
+                        abort_with(
+                            &aborted,
+                            &first_error,
+                            ExtractionError::TooLarge {
+                                limit_bytes: limits.max_total_bytes,
+                            },
+                        );
+                        return;
+                    }
+
+                    let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                    if done % 50 == 0 || done == total {
+                        let mut callback = progress_callback.lock().unwrap();
+                        callback(ExtractionProgress {
+                            current_stage: stage,
+                            max_stage,
+                            stage_name: "extracting".to_string(),
+                            entries_checked: done,
+                            entries_total: total,
+                            current_file: entry_name,
+                            bytes_processed: bytes_processed.load(Ordering::Relaxed),
+                            audit_findings: Vec::new(),
+                            duplicates_skipped: 0,
+                            images_normalized: 0,
+                            images_rejected: 0,
+                            mixed_pack_routed: HashMap::new(),
+                        });
+                    }
                 }
-            }
+            });
+        }
+    });
 
-            // Emit progress every 50 files or on last file
-            if i % 50 == 0 || i == total - 1 {
-                progress_callback(ExtractionProgress {
-                    current: i + 1,
-                    total,
-                    current_file: file_name,
-                    bytes_processed,
-                    phase: "extracting".to_string(),
-                });
-            }
+    if let Some(error) = first_error.into_inner().unwrap() {
+        return Err(error);
+    }
+
+    let audit_findings = audit_and_sanitize_permissions(dest_dir);
+    if !audit_findings.is_empty() {
+        tracing::warn!(
+            "Permission audit sanitized {} file(s) with unsafe archive-supplied modes in {:?}",
+            audit_findings.len(),
+            dest_dir
+        );
+    }
+    {
+        let mut callback = progress_callback.lock().unwrap();
+        callback(ExtractionProgress {
+            current_stage: stage,
+            max_stage,
+            stage_name: "auditing".to_string(),
+            entries_checked: total,
+            entries_total: total,
+            current_file: String::new(),
+            bytes_processed: bytes_processed.load(Ordering::Relaxed),
+            audit_findings,
+            duplicates_skipped: 0,
+            images_normalized: 0,
+            images_rejected: 0,
+            mixed_pack_routed: HashMap::new(),
+        });
+    }
+
+    Ok(dest_dir.to_path_buf())
+}
+
+/// Bits stripped from every extracted file's mode regardless of what the archive requested:
+/// setuid (`04000`), setgid (`02000`), and other-write (`00002`). The sticky bit is left in
+/// place (harmless on a regular file) but still reported, since an archive has no legitimate
+/// reason to ship one.
+#[cfg(unix)]
+const UNSAFE_MODE_STRIP_MASK: u32 = 0o6002;
+
+/// Walks `dest_dir` after extraction, strips [`UNSAFE_MODE_STRIP_MASK`] bits from every
+/// regular file's mode, and returns the files that needed sanitizing (world-writable,
+/// setuid/setgid/sticky, or executable with no apparent reason to be) so the caller can show
+/// a security summary instead of silently trusting archive-supplied permissions.
+#[cfg(unix)]
+fn audit_and_sanitize_permissions(dest_dir: &Path) -> Vec<crate::types::PermissionAuditFinding> {
+    use crate::types::PermissionAuditFinding;
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let mut findings = Vec::new();
+
+    for entry in walkdir::WalkDir::new(dest_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        if !entry.file_type().is_file() {
+            continue;
         }
 
-        Ok(dest_dir)
-    })
-    .await
-    .map_err(|e| format!("Task join error: {}", e))?
+        let mode = match entry.metadata() {
+            Ok(metadata) => metadata.mode() & 0o7777,
+            Err(_) => continue,
+        };
+
+        let mut reasons = Vec::new();
+        if mode & 0o002 != 0 {
+            reasons.push("world-writable".to_string());
+        }
+        if mode & 0o6000 != 0 {
+            reasons.push("setuid/setgid".to_string());
+        }
+        if mode & 0o1000 != 0 {
+            reasons.push("sticky bit".to_string());
+        }
+        if mode & 0o111 != 0 {
+            reasons.push("executable without reason".to_string());
+        }
+
+        if reasons.is_empty() {
+            continue;
+        }
+
+        let sanitized_mode = mode & !UNSAFE_MODE_STRIP_MASK;
+        if sanitized_mode != mode {
+            let _ = fs::set_permissions(entry.path(), fs::Permissions::from_mode(sanitized_mode));
+        }
+
+        findings.push(PermissionAuditFinding {
+            path: entry.path().to_string_lossy().to_string(),
+            requested_mode: mode,
+            sanitized_mode,
+            reasons,
+        });
+    }
+
+    findings
+}
+
+#[cfg(not(unix))]
+fn audit_and_sanitize_permissions(_dest_dir: &Path) -> Vec<crate::types::PermissionAuditFinding> {
+    Vec::new()
+}
+
+/// Records `error` as the extraction's error (first writer wins) and signals every worker to
+/// stop claiming new entries.
+fn abort_with(
+    aborted: &std::sync::atomic::AtomicBool,
+    first_error: &std::sync::Mutex<Option<ExtractionError>>,
+    error: ExtractionError,
+) {
+    aborted.store(true, std::sync::atomic::Ordering::Relaxed);
+    let mut slot = first_error.lock().unwrap();
+    if slot.is_none() {
+        *slot = Some(error);
+    }
+}
+
+/// `fs::create_dir_all`, but tolerant of the race where another worker just created the same
+/// directory between our existence check and our own create call.
+fn create_dir_all_race_tolerant(path: &Path) -> Result<(), String> {
+    match fs::create_dir_all(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists || path.is_dir() => {
+            let _ = e;
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to create directory: {}", e)),
+    }
 }
 
 pub fn has_manifest(dir: &Path) -> bool {
@@ -210,6 +939,45 @@ pub fn find_mod_root(path: &Path) -> Result<PathBuf, String> {
     Ok(path.parent().ok_or("Invalid path")?.to_path_buf())
 }
 
+/// Kinds of content a file's leading bytes can reveal, independent of its extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentSignature {
+    Image,
+    Bundle,
+    Fmf,
+}
+
+/// Best-effort signature observed at the start of FM tactic files; not a documented format,
+/// just what packs in the wild consistently ship.
+const FMF_MAGIC: &[u8] = b"FMF\x00";
+
+/// Sniffs `path`'s first few bytes for a known magic number, so an extension-less or
+/// renamed file (a graphics pack with its PNGs stripped of extensions, a `.fmf` saved as
+/// `.dat`) can still be classified. Returns `None` on read failure or an unrecognized header.
+fn sniff_content_signature(path: &Path) -> Option<ContentSignature> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut header = [0u8; 8];
+    let n = file.read(&mut header).ok()?;
+    let header = &header[..n];
+
+    if header.starts_with(b"\x89PNG") || header.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(ContentSignature::Image)
+    } else if header.starts_with(b"PK") {
+        Some(ContentSignature::Bundle)
+    } else if header.starts_with(FMF_MAGIC) {
+        Some(ContentSignature::Fmf)
+    } else {
+        None
+    }
+}
+
+/// How many extension-less/ambiguous files `auto_detect_mod_type` will actually open and
+/// sniff per directory, to keep the scan cheap on packs with thousands of files.
+const MAX_CONTENT_SNIFF_SAMPLES: usize = 20;
+/// Number of sniffed images that counts as a confident "this is a graphics pack" signal,
+/// letting the scan stop sniffing early instead of reading every remaining file.
+const STRONG_IMAGE_SIGNAL: usize = 3;
+
 pub fn auto_detect_mod_type(path: &Path) -> String {
     // Handle single files
     if path.is_file() {
@@ -232,7 +1000,15 @@ pub fn auto_detect_mod_type(path: &Path) -> String {
                 _ => {}
             }
         }
-        return "misc".to_string();
+
+        // Extension missing or unrecognized — fall back to magic-byte sniffing before
+        // giving up and calling it "misc".
+        return match sniff_content_signature(path) {
+            Some(ContentSignature::Fmf) => "tactics".to_string(),
+            Some(ContentSignature::Bundle) => "bundle".to_string(),
+            Some(ContentSignature::Image) => "graphics".to_string(),
+            None => "misc".to_string(),
+        };
     }
 
     // For directories, check contents
@@ -241,6 +1017,9 @@ pub fn auto_detect_mod_type(path: &Path) -> String {
     let mut has_graphics = false;
     let mut has_editor_data = false;
 
+    let mut sniffed_samples = 0usize;
+    let mut sniffed_images = 0usize;
+
     if let Ok(entries) = walkdir::WalkDir::new(path)
         .into_iter()
         .collect::<Result<Vec<_>, _>>()
@@ -250,13 +1029,27 @@ pub fn auto_detect_mod_type(path: &Path) -> String {
 
             // Check for file extensions
             if entry_path.is_file() {
-                if let Some(ext) = entry_path.extension() {
-                    let ext_lower = ext.to_string_lossy().to_lowercase();
-                    match ext_lower.as_str() {
-                        "fmf" => has_fmf = true,
-                        "bundle" => has_bundle = true,
-                        _ => {}
+                let ext_lower = entry_path
+                    .extension()
+                    .map(|ext| ext.to_string_lossy().to_lowercase());
+
+                match ext_lower.as_deref() {
+                    Some("fmf") => has_fmf = true,
+                    Some("bundle") => has_bundle = true,
+                    // Extension-less or unrecognized: sniff a bounded sample of files,
+                    // short-circuiting once we already have a strong graphics signal.
+                    _ if sniffed_samples < MAX_CONTENT_SNIFF_SAMPLES
+                        && sniffed_images < STRONG_IMAGE_SIGNAL =>
+                    {
+                        sniffed_samples += 1;
+                        match sniff_content_signature(entry_path) {
+                            Some(ContentSignature::Fmf) => has_fmf = true,
+                            Some(ContentSignature::Bundle) => has_bundle = true,
+                            Some(ContentSignature::Image) => sniffed_images += 1,
+                            None => {}
+                        }
                     }
+                    _ => {}
                 }
             }
 
@@ -282,6 +1075,10 @@ pub fn auto_detect_mod_type(path: &Path) -> String {
         }
     }
 
+    if sniffed_images >= STRONG_IMAGE_SIGNAL {
+        has_graphics = true;
+    }
+
     // Determine type based on what we found
     // Editor data takes priority if we have FMF files in editor data folder
     if has_fmf && has_editor_data {
@@ -303,6 +1100,168 @@ pub fn auto_detect_mod_type(path: &Path) -> String {
     "misc".to_string()
 }
 
+/// Metadata for [`generate_manifest_from_config`], sourced from a `fmmloader.toml` or
+/// `fmmloader.json` dropped in the mod root. Every field is optional so authors only need to
+/// commit what they actually know; anything missing falls back to auto-detection.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct ManifestConfig {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub mod_type: Option<String>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Loads `dir/fmmloader.toml` (or `fmmloader.json` if no TOML file is present) and calls
+/// [`generate_manifest`] with its fields, falling back to auto-detected values for anything
+/// the config file doesn't specify: `mod_type` via [`auto_detect_mod_type`], `version`
+/// defaulting to `"1.0.0"`, and `name` defaulting to the directory name. This lets mod authors
+/// commit their metadata next to their files instead of re-typing it on every import.
+pub fn generate_manifest_from_config(dir: &Path) -> Result<(), String> {
+    let config = load_manifest_config(dir)?;
+
+    let name = config.name.unwrap_or_else(|| {
+        dir.file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "Unnamed Mod".to_string())
+    });
+    let version = config.version.unwrap_or_else(|| "1.0.0".to_string());
+    let mod_type = config
+        .mod_type
+        .unwrap_or_else(|| auto_detect_mod_type(dir));
+    let author = config.author.unwrap_or_default();
+    let description = config.description.unwrap_or_default();
+
+    generate_manifest(dir, name, version, mod_type, author, description)
+}
+
+/// Reads `fmmloader.toml`/`fmmloader.json` from `dir` if either exists, preferring TOML.
+/// Returns a default (all-`None`) config when neither file is present.
+fn load_manifest_config(dir: &Path) -> Result<ManifestConfig, String> {
+    let toml_path = dir.join("fmmloader.toml");
+    if toml_path.exists() {
+        let contents = fs::read_to_string(&toml_path)
+            .map_err(|e| format!("Failed to read fmmloader.toml: {}", e))?;
+        return toml::from_str(&contents)
+            .map_err(|e| format!("Failed to parse fmmloader.toml: {}", e));
+    }
+
+    let json_path = dir.join("fmmloader.json");
+    if json_path.exists() {
+        let contents = fs::read_to_string(&json_path)
+            .map_err(|e| format!("Failed to read fmmloader.json: {}", e))?;
+        return serde_json::from_str(&contents)
+            .map_err(|e| format!("Failed to parse fmmloader.json: {}", e));
+    }
+
+    Ok(ManifestConfig::default())
+}
+
+/// Walks `root` looking for every independent mod it contains, rather than assuming `root`
+/// itself is a single mod (the way `find_mod_root` does for imports that are already known to
+/// be one mod). A directory is recognized as a mod root if it already has a manifest, or
+/// directly contains a `.bundle`/`.fmf` file or a platform subfolder (`windows/`, etc.) —
+/// mirroring the signals `auto_detect_mod_type` itself looks for. Once a directory is claimed
+/// as a mod root its children are not also treated as independent mods (a `ui` mod's
+/// `windows/` subfolder shouldn't become its own entry).
+///
+/// Hidden/dot directories are skipped during the walk, except `root` itself, so a `.git` or
+/// `.DS_Store`-adjacent folder inside an extracted archive doesn't get misidentified.
+///
+/// Errors generating any individual manifest are collected rather than aborting the whole
+/// walk, analogous to how Cargo's workspace package discovery accumulates per-package errors
+/// instead of failing on the first bad manifest.
+pub fn discover_mods(root: &Path) -> (Vec<(PathBuf, ModManifest)>, Vec<String>) {
+    use crate::types::ModManifest;
+
+    let mut discovered = Vec::new();
+    let mut errors = Vec::new();
+
+    let mut walker = walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !is_hidden_entry(entry));
+
+    while let Some(entry) = walker.next() {
+        let entry = match entry {
+            Ok(e) => e,
+            Err(e) => {
+                errors.push(format!("Failed to walk '{}': {}", root.display(), e));
+                continue;
+            }
+        };
+
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        let dir = entry.path();
+        if !looks_like_mod_root(dir) {
+            continue;
+        }
+
+        if !has_manifest(dir) {
+            if let Err(e) = generate_manifest_from_config(dir) {
+                errors.push(format!("{}: {}", dir.display(), e));
+                continue;
+            }
+        }
+
+        match fs::read_to_string(dir.join("manifest.json"))
+            .map_err(|e| format!("Failed to read manifest: {}", e))
+            .and_then(|json| crate::mod_manager::parse_manifest_json(&json))
+        {
+            Ok(manifest) => discovered.push((dir.to_path_buf(), manifest)),
+            Err(e) => errors.push(format!("{}: {}", dir.display(), e)),
+        }
+
+        // `dir` is now a claimed mod root; its subfolders (platform folders, etc.) aren't
+        // independent mods in their own right.
+        walker.skip_current_dir();
+    }
+
+    (discovered, errors)
+}
+
+fn is_hidden_entry(entry: &walkdir::DirEntry) -> bool {
+    entry
+        .file_name()
+        .to_str()
+        .map(|name| name.starts_with('.'))
+        .unwrap_or(false)
+}
+
+/// Whether `dir` directly (non-recursively) contains a recognizable mod asset: an existing
+/// manifest, a `.bundle`/`.fmf` file, or a platform subfolder.
+fn looks_like_mod_root(dir: &Path) -> bool {
+    if has_manifest(dir) {
+        return true;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_file() {
+            if let Some(ext) = path.extension() {
+                let ext_lower = ext.to_string_lossy().to_lowercase();
+                if ext_lower == "bundle" || ext_lower == "fmf" {
+                    return true;
+                }
+            }
+        } else if path.is_dir() {
+            if let Some(name) = path.file_name() {
+                if is_platform_component(&name.to_string_lossy().to_lowercase()) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
 pub fn generate_manifest(
     dir: &Path,
     name: String,
@@ -426,10 +1385,20 @@ pub fn generate_manifest(
                         rel_joined.clone()
                     };
 
+                    let sha256 = match hash_file(path) {
+                        Ok(digest) => Some(digest),
+                        Err(e) => {
+                            tracing::warn!("Failed to hash '{}': {}", rel_joined, e);
+                            None
+                        }
+                    };
+
                     files.push(FileEntry {
                         source: rel_joined,
                         target_subpath,
                         platform,
+                        install_mode: None,
+                        sha256,
                     });
                 }
             }
@@ -438,6 +1407,7 @@ pub fn generate_manifest(
 
     let manifest = ModManifest {
         name,
+        schema_version: crate::mod_manager::CURRENT_MANIFEST_SCHEMA_VERSION,
         version,
         mod_type,
         author,
@@ -451,6 +1421,7 @@ pub fn generate_manifest(
         conflicts: Vec::new(),
         load_after: Vec::new(),
         files,
+        source_type: None,
     };
 
     let manifest_path = dir.join("manifest.json");
@@ -462,6 +1433,100 @@ pub fn generate_manifest(
     Ok(())
 }
 
+/// Streams `path` through a SHA-256 hasher and returns the lowercase hex digest, without
+/// holding the whole file in memory (graphics packs can run into the gigabytes).
+fn hash_file(path: &Path) -> Result<String, String> {
+    let mut file =
+        fs::File::open(path).map_err(|e| format!("Failed to open '{}': {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let read = file
+            .read(&mut buf)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Re-hashes every file listed in `dir/manifest.json` against what's actually on disk and
+/// reports which ones no longer match. Entries with no recorded `sha256` (manifests written
+/// before integrity hashing existed, or hand-authored ones) are skipped rather than flagged.
+///
+/// When `public_key` is supplied, also checks a detached Ed25519 signature at
+/// `manifest.json.sig` over the raw bytes of `manifest.json`; `signature_valid` is `None` if
+/// no key was given, so callers can tell "not checked" apart from "checked and failed".
+pub fn verify_manifest(
+    dir: &Path,
+    public_key: Option<&[u8]>,
+) -> Result<crate::types::ManifestVerification, String> {
+    use crate::types::{ManifestMismatch, ManifestVerification};
+
+    let manifest_path = dir.join("manifest.json");
+    let manifest_json = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    let manifest = crate::mod_manager::parse_manifest_json(&manifest_json)?;
+
+    let mut mismatches = Vec::new();
+    for file in &manifest.files {
+        let Some(expected) = &file.sha256 else {
+            continue;
+        };
+
+        match hash_file(&dir.join(&file.source)) {
+            Ok(actual) if &actual == expected => {}
+            Ok(actual) => mismatches.push(ManifestMismatch {
+                source: file.source.clone(),
+                reason: format!("hash mismatch: expected {}, found {}", expected, actual),
+            }),
+            Err(e) => mismatches.push(ManifestMismatch {
+                source: file.source.clone(),
+                reason: e,
+            }),
+        }
+    }
+
+    let signature_valid = match public_key {
+        Some(key) => Some(verify_manifest_signature(dir, &manifest_json, key)?),
+        None => None,
+    };
+
+    Ok(ManifestVerification {
+        mismatches,
+        signature_valid,
+    })
+}
+
+/// Checks `dir/manifest.json.sig` as an Ed25519 detached signature over `manifest_json`'s raw
+/// bytes. A missing signature file is treated as "not valid" rather than an error, since a
+/// caller that supplies a public key is explicitly asking to enforce signing.
+fn verify_manifest_signature(dir: &Path, manifest_json: &str, public_key: &[u8]) -> Result<bool, String> {
+    use ed25519_dalek::{Signature, VerifyingKey};
+
+    let sig_path = dir.join("manifest.json.sig");
+    let sig_bytes = match fs::read(&sig_path) {
+        Ok(bytes) => bytes,
+        Err(_) => return Ok(false),
+    };
+
+    let key_bytes: [u8; 32] = public_key
+        .try_into()
+        .map_err(|_| "Public key must be 32 bytes".to_string())?;
+    let verifying_key =
+        VerifyingKey::from_bytes(&key_bytes).map_err(|e| format!("Invalid public key: {}", e))?;
+    let signature = Signature::from_slice(&sig_bytes)
+        .map_err(|e| format!("Invalid signature file: {}", e))?;
+
+    Ok(verifying_key
+        .verify_strict(manifest_json.as_bytes(), &signature)
+        .is_ok())
+}
+
 /// Detect platform from path parts based on platform-specific folder names
 /// Supports common variations: windows/win, macos/mac/osx, linux
 fn detect_platform_from_parts(parts: &[String]) -> Option<String> {
@@ -782,6 +1847,159 @@ mod tests {
         let _ = fs::remove_dir_all(&temp_dir);
     }
 
+    #[test]
+    fn test_generate_manifest_records_sha256_and_verify_manifest_passes() {
+        let temp_dir = std::env::temp_dir().join(format!("test_manifest_sha_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let mut file = fs::File::create(temp_dir.join("test.bundle")).expect("create file");
+        file.write_all(b"test content").expect("write content");
+        drop(file);
+
+        generate_manifest(
+            &temp_dir,
+            "Test Mod".to_string(),
+            "1.0.0".to_string(),
+            "ui".to_string(),
+            "Test Author".to_string(),
+            "Test Description".to_string(),
+        )
+        .expect("generate_manifest should succeed");
+
+        let manifest_content =
+            fs::read_to_string(temp_dir.join("manifest.json")).expect("read manifest");
+        let manifest: crate::types::ModManifest =
+            serde_json::from_str(&manifest_content).expect("parse manifest");
+
+        let entry = manifest
+            .files
+            .iter()
+            .find(|f| f.source == "test.bundle")
+            .expect("file entry present");
+        assert_eq!(
+            entry.sha256.as_deref(),
+            Some("6ae8a75555209fd6c44157c0aed8016e763ff435a19cf186f76863140143ff72")
+        );
+
+        let verification = verify_manifest(&temp_dir, None).expect("verify_manifest should succeed");
+        assert!(verification.mismatches.is_empty());
+        assert_eq!(verification.signature_valid, None);
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_verify_manifest_detects_tampered_file() {
+        let temp_dir = std::env::temp_dir().join(format!("test_manifest_tamper_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let mut file = fs::File::create(temp_dir.join("test.bundle")).expect("create file");
+        file.write_all(b"test content").expect("write content");
+        drop(file);
+
+        generate_manifest(
+            &temp_dir,
+            "Test Mod".to_string(),
+            "1.0.0".to_string(),
+            "ui".to_string(),
+            "Test Author".to_string(),
+            "Test Description".to_string(),
+        )
+        .expect("generate_manifest should succeed");
+
+        fs::write(temp_dir.join("test.bundle"), b"tampered content").expect("tamper with file");
+
+        let verification = verify_manifest(&temp_dir, None).expect("verify_manifest should succeed");
+        assert_eq!(verification.mismatches.len(), 1);
+        assert_eq!(verification.mismatches[0].source, "test.bundle");
+        assert!(verification.mismatches[0].reason.contains("hash mismatch"));
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_generate_manifest_from_config_reads_fmmloader_json() {
+        let temp_dir = std::env::temp_dir().join(format!("test_manifest_cfg_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        fs::write(
+            temp_dir.join("fmmloader.json"),
+            r#"{"name": "Config Mod", "author": "Config Author"}"#,
+        )
+        .expect("write fmmloader.json");
+
+        let mut file = fs::File::create(temp_dir.join("tactic.fmf")).expect("create file");
+        file.write_all(b"test content").expect("write content");
+        drop(file);
+
+        generate_manifest_from_config(&temp_dir).expect("generate_manifest_from_config should succeed");
+
+        let manifest_content =
+            fs::read_to_string(temp_dir.join("manifest.json")).expect("read manifest");
+        let manifest: crate::types::ModManifest =
+            serde_json::from_str(&manifest_content).expect("parse manifest");
+
+        assert_eq!(manifest.name, "Config Mod");
+        assert_eq!(manifest.author, "Config Author");
+        assert_eq!(manifest.version, "1.0.0");
+        assert_eq!(manifest.mod_type, "tactics", "should fall back to auto-detection");
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_discover_mods_finds_multiple_independent_mods() {
+        let temp_dir = std::env::temp_dir().join(format!("test_discover_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let ui_mod = temp_dir.join("ui-mod");
+        fs::create_dir_all(&ui_mod).expect("create ui-mod dir");
+        fs::File::create(ui_mod.join("ui-test.bundle")).expect("create bundle file");
+
+        let tactics_mod = temp_dir.join("tactics-mod");
+        fs::create_dir_all(&tactics_mod).expect("create tactics-mod dir");
+        fs::File::create(tactics_mod.join("tactic.fmf")).expect("create fmf file");
+
+        let unrelated_dir = temp_dir.join("readme-only");
+        fs::create_dir_all(&unrelated_dir).expect("create unrelated dir");
+        fs::write(unrelated_dir.join("README.txt"), b"not a mod").expect("write readme");
+
+        let (discovered, errors) = discover_mods(&temp_dir);
+
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+        assert_eq!(discovered.len(), 2);
+
+        let names: Vec<&str> = discovered
+            .iter()
+            .map(|(path, _)| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert!(names.contains(&"ui-mod"));
+        assert!(names.contains(&"tactics-mod"));
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_discover_mods_does_not_descend_into_claimed_platform_folders() {
+        let temp_dir = std::env::temp_dir().join(format!("test_discover_{}", uuid::Uuid::new_v4()));
+        let windows_dir = temp_dir.join("windows");
+        fs::create_dir_all(&windows_dir).expect("create windows dir");
+        fs::File::create(windows_dir.join("test.bundle")).expect("create bundle file");
+
+        let (discovered, errors) = discover_mods(&temp_dir);
+
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+        assert_eq!(discovered.len(), 1);
+        assert_eq!(discovered[0].0, temp_dir);
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_auto_detect_mod_type_bundle() {
         let temp_dir = std::env::temp_dir().join(format!("test_detect_{}", uuid::Uuid::new_v4()));
@@ -811,4 +2029,106 @@ mod tests {
         // Cleanup
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn test_auto_detect_mod_type_sniffs_renamed_fmf() {
+        let temp_dir = std::env::temp_dir().join(format!("test_detect_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let renamed_path = temp_dir.join("tactic.dat");
+        let mut file = fs::File::create(&renamed_path).expect("Failed to create file");
+        file.write_all(FMF_MAGIC).unwrap();
+
+        let mod_type = auto_detect_mod_type(&renamed_path);
+        assert_eq!(mod_type, "tactics");
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_auto_detect_mod_type_sniffs_renamed_pngs_in_directory() {
+        let temp_dir = std::env::temp_dir().join(format!("test_detect_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        for i in 0..STRONG_IMAGE_SIGNAL {
+            let mut file = fs::File::create(temp_dir.join(format!("face{}.dat", i)))
+                .expect("Failed to create file");
+            file.write_all(b"\x89PNG\r\n\x1a\n").unwrap();
+        }
+
+        let mod_type = auto_detect_mod_type(&temp_dir);
+        assert_eq!(mod_type, "graphics");
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_auto_detect_mod_type_ignores_unrecognized_content() {
+        let temp_dir = std::env::temp_dir().join(format!("test_detect_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let mystery_path = temp_dir.join("readme.dat");
+        let mut file = fs::File::create(&mystery_path).expect("Failed to create file");
+        file.write_all(b"plain text notes").unwrap();
+
+        let mod_type = auto_detect_mod_type(&mystery_path);
+        assert_eq!(mod_type, "misc");
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_audit_strips_world_writable_and_setuid() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = std::env::temp_dir().join(format!("test_audit_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let unsafe_path = temp_dir.join("payload.sh");
+        fs::File::create(&unsafe_path).expect("Failed to create file");
+        fs::set_permissions(&unsafe_path, fs::Permissions::from_mode(0o4777)).unwrap();
+
+        let safe_path = temp_dir.join("face.png");
+        fs::File::create(&safe_path).expect("Failed to create file");
+        fs::set_permissions(&safe_path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let findings = audit_and_sanitize_permissions(&temp_dir);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].path, unsafe_path.to_string_lossy().to_string());
+        assert_eq!(findings[0].requested_mode, 0o4777);
+        assert_eq!(findings[0].sanitized_mode, 0o0775);
+        assert!(findings[0].reasons.contains(&"world-writable".to_string()));
+        assert!(findings[0]
+            .reasons
+            .contains(&"setuid/setgid".to_string()));
+
+        let applied_mode = fs::metadata(&unsafe_path).unwrap().permissions().mode() & 0o7777;
+        assert_eq!(applied_mode, 0o0775);
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_audit_ignores_ordinary_modes() {
+        let temp_dir = std::env::temp_dir().join(format!("test_audit_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&temp_dir).expect("Failed to create temp dir");
+
+        let safe_path = temp_dir.join("face.png");
+        fs::File::create(&safe_path).expect("Failed to create file");
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&safe_path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let findings = audit_and_sanitize_permissions(&temp_dir);
+        assert!(findings.is_empty());
+
+        // Cleanup
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
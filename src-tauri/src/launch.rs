@@ -0,0 +1,159 @@
+//! Launches Football Manager itself, applying enabled mods first so the user never has to
+//! remember to hit "Apply" before pressing "Play".
+
+use crate::config::load_config;
+use crate::messages::{code_error, code_only, CODE_GAME_TARGET_INVALID, CODE_GAME_TARGET_NOT_SET};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Finds the FM executable (or, on macOS, the `.app` bundle) from `target_path`, the
+/// StreamingAssets directory FMMLoader is configured against. Climbs the same directory levels
+/// as [`crate::name_fix::get_db_dir`] to reach the game root; on macOS it climbs one level
+/// further than `get_db_dir` (which stops at `fm.app/Contents`), to the `fm.app` bundle itself.
+fn find_game_executable(target_path: &str) -> Result<PathBuf, String> {
+    let target = PathBuf::from(target_path);
+
+    #[cfg(target_os = "windows")]
+    {
+        let game_root = target
+            .parent() // aa
+            .and_then(|p| p.parent()) // StreamingAssets
+            .and_then(|p| p.parent()) // fm_Data or data
+            .and_then(|p| p.parent()) // Football Manager 26
+            .ok_or("Could not determine game root directory")?;
+
+        let exe = game_root.join("Football Manager 26.exe");
+        if !exe.exists() {
+            return Err(format!("FM executable not found at: {}", exe.display()));
+        }
+
+        Ok(exe)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        // From: Football Manager 26/fm.app/Contents/Resources/Data/StreamingAssets/aa/StandaloneOSX
+        let app_bundle = target
+            .parent() // aa
+            .and_then(|p| p.parent()) // StreamingAssets
+            .and_then(|p| p.parent()) // Data
+            .and_then(|p| p.parent()) // Resources
+            .and_then(|p| p.parent()) // Contents
+            .and_then(|p| p.parent()) // fm.app
+            .ok_or("Could not determine fm.app bundle directory")?;
+
+        if !app_bundle.exists() {
+            return Err(format!("FM app bundle not found at: {}", app_bundle.display()));
+        }
+
+        Ok(app_bundle.to_path_buf())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let game_root = target
+            .parent() // aa
+            .and_then(|p| p.parent()) // StreamingAssets
+            .and_then(|p| p.parent()) // fm_Data or data
+            .and_then(|p| p.parent()) // Football Manager 26
+            .ok_or("Could not determine game root directory")?;
+
+        let exe = game_root.join("Football Manager 26");
+        if !exe.exists() {
+            return Err(format!("FM executable not found at: {}", exe.display()));
+        }
+
+        Ok(exe)
+    }
+}
+
+/// Applies every enabled mod (the same flow `apply_mods` runs on its own), then spawns Football
+/// Manager with `extra_args` appended after the persisted `launch_args`. When `launch_via_steam`
+/// is set, launches through `steam://rungameid/<steam_app_id>` instead of the raw executable,
+/// handing the game's process lifetime to Steam.
+#[tauri::command]
+pub async fn launch_game(
+    app: tauri::AppHandle,
+    extra_args: Option<Vec<String>>,
+) -> Result<String, String> {
+    let config = load_config()?;
+    let target_path = config
+        .target_path
+        .clone()
+        .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
+
+    let target = PathBuf::from(&target_path);
+    if !target.exists() {
+        return Err(code_error(
+            CODE_GAME_TARGET_INVALID,
+            "Game target path does not exist",
+        ));
+    }
+
+    crate::apply_mods(app).await.map_err(|e| e.to_string())?;
+
+    let mut args = config.launch_args.clone();
+    if let Some(extra) = extra_args {
+        args.extend(extra);
+    }
+
+    if config.launch_via_steam {
+        let app_id = config
+            .steam_app_id
+            .ok_or("launch_via_steam is set but no steam_app_id is configured")?;
+
+        let uri = format!("steam://rungameid/{}", app_id);
+
+        #[cfg(target_os = "windows")]
+        {
+            Command::new("cmd")
+                .args(["/C", "start", "", &uri])
+                .spawn()
+                .map_err(|e| format!("Failed to launch via Steam: {}", e))?;
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            Command::new("open")
+                .arg(&uri)
+                .spawn()
+                .map_err(|e| format!("Failed to launch via Steam: {}", e))?;
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            Command::new("xdg-open")
+                .arg(&uri)
+                .spawn()
+                .map_err(|e| format!("Failed to launch via Steam: {}", e))?;
+        }
+
+        return Ok(format!("Launched Football Manager via Steam ({})", uri));
+    }
+
+    let executable = find_game_executable(&target_path)?;
+
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open")
+            .arg(&executable)
+            .arg("--args")
+            .args(&args)
+            .spawn()
+            .map_err(|e| format!("Failed to launch Football Manager: {}", e))?;
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Command::new(&executable)
+            .args(&args)
+            .spawn()
+            .map_err(|e| format!("Failed to launch Football Manager: {}", e))?;
+    }
+
+    Ok(format!(
+        "Launched Football Manager ({}) with args: {}",
+        executable.display(),
+        args.join(" ")
+    ))
+}
@@ -1,8 +1,28 @@
+use crate::types::GameLogEntry;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing_appender::rolling;
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+/// Subfolders of the FM user dir (see [`crate::game_detection::get_fm_user_dir`]) that hold the
+/// game's own logs and crash dumps, checked in order — unlike FMMLoader's logs, these are where
+/// a mod-related crash actually surfaces. Borrowed from FlightCore's `get_log_list` approach.
+const GAME_LOG_SUBDIRS: &[&str] = &["crash dumps", "Logs"];
+
+/// Extensions [`list_game_logs`] treats as log/crash-dump files, so stray saves or config files
+/// living in the same folder don't show up as "logs" in the UI.
+const GAME_LOG_EXTENSIONS: &[&str] = &["log", "txt", "dmp"];
+
+/// Defaults for [`cleanup_old_logs`], applied on every startup in addition to the count
+/// limit: a 200 MiB total-size budget (big daily files shouldn't blow past a small disk) and
+/// a 30-day retention window (so a low-traffic install doesn't hoard ancient logs just
+/// because it never hit the count or size caps).
+const MAX_LOG_FILES: usize = 10;
+const MAX_LOG_TOTAL_BYTES: u64 = 200 * 1024 * 1024;
+const MAX_LOG_AGE_DAYS: u64 = 30;
+
 pub fn get_logs_dir() -> PathBuf {
     let app_dir = dirs::data_local_dir()
         .unwrap_or_else(|| PathBuf::from("."))
@@ -16,8 +36,12 @@ pub fn get_logs_dir() -> PathBuf {
 pub fn init_logging() -> Result<(), String> {
     let logs_dir = get_logs_dir();
 
-    // Clean up old log files (keep last 10)
-    cleanup_old_logs(&logs_dir, 10)?;
+    cleanup_old_logs(
+        &logs_dir,
+        MAX_LOG_FILES,
+        MAX_LOG_TOTAL_BYTES,
+        MAX_LOG_AGE_DAYS,
+    )?;
 
     // Create a file appender with daily rotation
     let file_appender = rolling::daily(&logs_dir, "fmmloader");
@@ -37,21 +61,35 @@ pub fn init_logging() -> Result<(), String> {
     Ok(())
 }
 
-fn log_system_info() {
-    tracing::info!("=== FMMLoader26 Started ===");
-    tracing::info!("Version: {}", env!("CARGO_PKG_VERSION"));
-    tracing::info!("OS: {}", std::env::consts::OS);
-    tracing::info!("Architecture: {}", std::env::consts::ARCH);
-    tracing::info!("Family: {}", std::env::consts::FAMILY);
+/// Logs the startup system-info header and returns it as text, so [`export_logs`] can bundle
+/// the same header into an exported archive without re-deriving it.
+fn log_system_info() -> String {
+    let header = format!(
+        "=== FMMLoader26 System Info ===\nVersion: {}\nOS: {}\nArchitecture: {}\nFamily: {}\nHostname: {:?}\n================================",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        std::env::consts::FAMILY,
+        hostname::get().unwrap_or_default(),
+    );
 
-    if let Ok(hostname) = hostname::get() {
-        tracing::info!("Hostname: {:?}", hostname);
+    for line in header.lines() {
+        tracing::info!("{}", line);
     }
 
-    tracing::info!("========================");
+    header
 }
 
-fn cleanup_old_logs(logs_dir: &PathBuf, keep_count: usize) -> Result<(), String> {
+/// Deletes log files beyond `keep_count`, a cumulative `max_total_bytes` budget, or a
+/// `max_age_days` retention window — whichever is hit first, scanning newest-first. Once a
+/// file fails any limit, every older file is removed too, since age only grows and the size
+/// budget can only be more exceeded from there.
+fn cleanup_old_logs(
+    logs_dir: &Path,
+    keep_count: usize,
+    max_total_bytes: u64,
+    max_age_days: u64,
+) -> Result<(), String> {
     if !logs_dir.exists() {
         return Ok(());
     }
@@ -71,10 +109,352 @@ fn cleanup_old_logs(logs_dir: &PathBuf, keep_count: usize) -> Result<(), String>
         b_time.cmp(&a_time)
     });
 
-    // Remove old files beyond keep_count
-    for old_file in log_files.iter().skip(keep_count) {
-        let _ = fs::remove_file(old_file.path());
+    let now = SystemTime::now();
+    let max_age = Duration::from_secs(max_age_days.saturating_mul(24 * 60 * 60));
+
+    let mut cumulative_bytes: u64 = 0;
+    let mut within_limits = true;
+
+    for (index, entry) in log_files.iter().enumerate() {
+        if within_limits {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let size = metadata.len();
+            let age_exceeded = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .map(|age| age > max_age)
+                .unwrap_or(false);
+
+            if index >= keep_count || cumulative_bytes + size > max_total_bytes || age_exceeded {
+                within_limits = false;
+            } else {
+                cumulative_bytes += size;
+            }
+        }
+
+        if !within_limits {
+            let _ = fs::remove_file(entry.path());
+        }
     }
 
     Ok(())
 }
+
+fn zip_options() -> zip::write::FileOptions {
+    zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated)
+}
+
+/// Zips the current logs directory together with a freshly generated system-info header into
+/// a single timestamped archive under `dest`, so filing a bug report is "attach one file"
+/// instead of hunting down individual log files. Returns the archive's path.
+pub fn export_logs(dest: &Path) -> Result<PathBuf, String> {
+    export_logs_from(&get_logs_dir(), dest)
+}
+
+fn export_logs_from(logs_dir: &Path, dest: &Path) -> Result<PathBuf, String> {
+    fs::create_dir_all(dest).map_err(|e| format!("Failed to create export dir: {}", e))?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let archive_path = dest.join(format!("fmmloader-logs-{}.zip", timestamp));
+
+    let file = fs::File::create(&archive_path)
+        .map_err(|e| format!("Failed to create log archive: {}", e))?;
+    let mut writer = zip::ZipWriter::new(file);
+
+    writer
+        .start_file("system-info.txt", zip_options())
+        .map_err(|e| format!("Failed to start log archive entry: {}", e))?;
+    writer
+        .write_all(log_system_info().as_bytes())
+        .map_err(|e| format!("Failed to write system info to archive: {}", e))?;
+
+    if logs_dir.exists() {
+        let entries = fs::read_dir(logs_dir)
+            .map_err(|e| format!("Failed to read logs directory: {}", e))?;
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let Some(name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+                continue;
+            };
+
+            writer
+                .start_file(&name, zip_options())
+                .map_err(|e| format!("Failed to start log archive entry '{}': {}", name, e))?;
+            let bytes = fs::read(&path)
+                .map_err(|e| format!("Failed to read log file '{}': {}", name, e))?;
+            writer
+                .write_all(&bytes)
+                .map_err(|e| format!("Failed to write log file '{}' to archive: {}", name, e))?;
+        }
+    }
+
+    writer
+        .finish()
+        .map_err(|e| format!("Failed to finalize log archive: {}", e))?;
+
+    Ok(archive_path)
+}
+
+/// Lists Football Manager's own log and crash-dump files under `user_dir`'s
+/// [`GAME_LOG_SUBDIRS`], newest-first, so the UI can point a player at the actual crash evidence
+/// instead of FMMLoader's own logs when a mod fails to load. Missing subfolders are skipped
+/// rather than treated as an error, since most installs only ever populate one of them.
+pub fn list_game_logs(user_dir: &Path) -> Result<Vec<GameLogEntry>, String> {
+    let mut entries = Vec::new();
+
+    for subdir in GAME_LOG_SUBDIRS {
+        let dir = user_dir.join(subdir);
+        if !dir.is_dir() {
+            continue;
+        }
+
+        let dir_entries =
+            fs::read_dir(&dir).map_err(|e| format!("Failed to read {:?}: {}", dir, e))?;
+
+        for entry in dir_entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let matches_extension = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| GAME_LOG_EXTENSIONS.iter().any(|allowed| ext.eq_ignore_ascii_case(allowed)))
+                .unwrap_or(false);
+            if !matches_extension {
+                continue;
+            }
+
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| {
+                    chrono::DateTime::<chrono::Utc>::from(UNIX_EPOCH + d)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            let Some(name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+                continue;
+            };
+
+            entries.push(GameLogEntry {
+                path,
+                name,
+                size_bytes: metadata.len(),
+                modified,
+            });
+        }
+    }
+
+    entries.sort_by(|a, b| b.modified.cmp(&a.modified));
+
+    Ok(entries)
+}
+
+/// Confirms `path` is a descendant of `user_dir` once both are canonicalized, so a caller can't
+/// pass `../../../etc/passwd` (or a symlink pointing outside the user dir) through
+/// [`read_game_log`] and read arbitrary files.
+fn assert_within_user_dir(path: &Path, user_dir: &Path) -> Result<(), String> {
+    let canonical_user_dir = fs::canonicalize(user_dir)
+        .map_err(|e| format!("Failed to canonicalize user dir: {}", e))?;
+    let canonical_path = fs::canonicalize(path)
+        .map_err(|e| format!("Failed to canonicalize log path: {}", e))?;
+
+    if canonical_path.starts_with(&canonical_user_dir) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Refusing to read log outside the FM user directory: {:?}",
+            path
+        ))
+    }
+}
+
+/// Reads the last `tail_lines` lines of the game log at `path` (all of them if `None`), so the
+/// UI can show a crash log inline without shelling out to a text viewer. Rejects `path`s outside
+/// `user_dir` — see [`assert_within_user_dir`].
+pub fn read_game_log(path: &Path, user_dir: &Path, tail_lines: Option<usize>) -> Result<String, String> {
+    assert_within_user_dir(path, user_dir)?;
+
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open log file: {}", e))?;
+    let lines: Vec<String> = BufReader::new(file)
+        .lines()
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    let tail = match tail_lines {
+        Some(n) if n < lines.len() => &lines[lines.len() - n..],
+        _ => &lines[..],
+    };
+
+    Ok(tail.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration as StdDuration;
+
+    fn test_logs_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("fmml_logs_test_{}", uuid::Uuid::new_v4()))
+    }
+
+    fn write_log(dir: &Path, name: &str, bytes: &[u8], age: StdDuration) {
+        let path = dir.join(name);
+        fs::write(&path, bytes).expect("write log file");
+
+        let file = fs::File::options()
+            .write(true)
+            .open(&path)
+            .expect("reopen log file");
+        file.set_modified(SystemTime::now() - age)
+            .expect("set mtime");
+    }
+
+    #[test]
+    fn test_cleanup_old_logs_respects_count_limit() {
+        let dir = test_logs_dir();
+        fs::create_dir_all(&dir).expect("create logs dir");
+
+        for i in 0..5 {
+            write_log(
+                &dir,
+                &format!("fmmloader.{}.log", i),
+                b"x",
+                StdDuration::from_secs(i as u64),
+            );
+        }
+
+        cleanup_old_logs(&dir, 2, u64::MAX, u64::MAX).expect("cleanup should succeed");
+
+        let remaining = fs::read_dir(&dir).unwrap().count();
+        assert_eq!(remaining, 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cleanup_old_logs_respects_size_cap() {
+        let dir = test_logs_dir();
+        fs::create_dir_all(&dir).expect("create logs dir");
+
+        write_log(&dir, "fmmloader.0.log", &[0u8; 100], StdDuration::from_secs(0));
+        write_log(&dir, "fmmloader.1.log", &[0u8; 100], StdDuration::from_secs(60));
+
+        cleanup_old_logs(&dir, 10, 150, u64::MAX).expect("cleanup should succeed");
+
+        assert!(dir.join("fmmloader.0.log").exists());
+        assert!(!dir.join("fmmloader.1.log").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cleanup_old_logs_respects_max_age() {
+        let dir = test_logs_dir();
+        fs::create_dir_all(&dir).expect("create logs dir");
+
+        write_log(&dir, "fmmloader.new.log", b"x", StdDuration::from_secs(60));
+        write_log(
+            &dir,
+            "fmmloader.old.log",
+            b"x",
+            StdDuration::from_secs(60 * 60 * 24 * 60),
+        );
+
+        cleanup_old_logs(&dir, 10, u64::MAX, 30).expect("cleanup should succeed");
+
+        assert!(dir.join("fmmloader.new.log").exists());
+        assert!(!dir.join("fmmloader.old.log").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_export_logs_bundles_system_info_and_log_files() {
+        let logs_dir = test_logs_dir();
+        fs::create_dir_all(&logs_dir).expect("create logs dir");
+        fs::write(logs_dir.join("fmmloader.log"), b"log contents").expect("write log file");
+
+        let dest = std::env::temp_dir().join(format!("fmml_export_test_{}", uuid::Uuid::new_v4()));
+        let archive_path =
+            export_logs_from(&logs_dir, &dest).expect("export_logs_from should succeed");
+
+        assert!(archive_path.exists());
+
+        let file = fs::File::open(&archive_path).expect("open archive");
+        let mut archive = zip::ZipArchive::new(file).expect("read archive");
+        assert!(archive.by_name("system-info.txt").is_ok());
+        assert!(archive.by_name("fmmloader.log").is_ok());
+
+        let _ = fs::remove_dir_all(&logs_dir);
+        let _ = fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_list_game_logs_finds_crash_dumps_newest_first() {
+        let user_dir = test_logs_dir();
+        let crash_dir = user_dir.join("crash dumps");
+        fs::create_dir_all(&crash_dir).expect("create crash dumps dir");
+
+        write_log(&crash_dir, "old.dmp", b"old", StdDuration::from_secs(120));
+        write_log(&crash_dir, "new.log", b"new", StdDuration::from_secs(0));
+        write_log(&crash_dir, "save.sav", b"ignored", StdDuration::from_secs(0));
+
+        let entries = list_game_logs(&user_dir).expect("list_game_logs should succeed");
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "new.log");
+        assert_eq!(entries[1].name, "old.dmp");
+
+        let _ = fs::remove_dir_all(&user_dir);
+    }
+
+    #[test]
+    fn test_read_game_log_returns_tail_lines() {
+        let user_dir = test_logs_dir();
+        let crash_dir = user_dir.join("crash dumps");
+        fs::create_dir_all(&crash_dir).expect("create crash dumps dir");
+
+        let log_path = crash_dir.join("crash.log");
+        fs::write(&log_path, "line1\nline2\nline3\n").expect("write log file");
+
+        let tail = read_game_log(&log_path, &user_dir, Some(2)).expect("read_game_log should succeed");
+        assert_eq!(tail, "line2\nline3");
+
+        let _ = fs::remove_dir_all(&user_dir);
+    }
+
+    #[test]
+    fn test_read_game_log_rejects_path_outside_user_dir() {
+        let user_dir = test_logs_dir();
+        fs::create_dir_all(&user_dir).expect("create user dir");
+
+        let outside_dir = std::env::temp_dir().join(format!("fmml_outside_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&outside_dir).expect("create outside dir");
+        let outside_file = outside_dir.join("secret.log");
+        fs::write(&outside_file, "top secret").expect("write outside file");
+
+        let result = read_game_log(&outside_file, &user_dir, None);
+        assert!(result.is_err());
+
+        let _ = fs::remove_dir_all(&user_dir);
+        let _ = fs::remove_dir_all(&outside_dir);
+    }
+}
@@ -1,34 +1,56 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod archive;
 mod config;
+mod conflicts;
+mod discovery;
+mod error;
+mod external_import;
 mod game_detection;
 mod graphics;
 mod graphics_analyzer;
 mod import;
+mod launch;
 mod logging;
 mod messages;
 mod mod_manager;
 mod name_fix;
+mod profiles;
+mod repo_client;
+mod resolver;
 mod restore;
+mod scaffold;
 mod types;
+mod update_checker;
 mod utils;
+mod verify;
 
 use config::{get_mods_dir, init_storage, load_config, save_config};
-use game_detection::get_default_candidates;
+use error::AppError;
+use game_detection::get_candidates_with_validation;
 use import::{auto_detect_mod_type, extract_zip, find_mod_root, generate_manifest, has_manifest};
 use messages::{
-    code_error, code_only, CODE_GAME_TARGET_INVALID, CODE_GAME_TARGET_NOT_SET,
-    CODE_METADATA_REQUIRED, CODE_MOD_ALREADY_EXISTS, CODE_MOD_NOT_FOUND, CODE_PATH_NOT_FOUND,
-    CODE_SOURCE_PATH_MISSING,
+    code_error, code_only, CODE_GAME_TARGET_INVALID, CODE_GAME_TARGET_NOT_SET, CODE_MOD_NOT_FOUND,
+    CODE_PATH_NOT_FOUND,
 };
+use conflicts::{find_conflicts, resolve_conflicts};
 use mod_manager::{
-    cleanup_old_backups, cleanup_old_restore_points, find_conflicts, get_mod_info, install_mod,
-    list_mods, preview_mod_install as compute_preview,
+    cleanup_old_backups, cleanup_old_restore_points, get_mod_info, install_mod,
+    install_mods_batch, list_mods, preview_mod_install as compute_preview,
+    set_mod_enabled as apply_mod_enabled,
 };
-use restore::{create_restore_point, list_restore_points, rollback_to_restore_point};
+use resolver::resolve_load_order;
+use restore::{
+    create_restore_point, list_restore_points, restore_entries, rollback_to_restore_point,
+    EntrySelector,
+};
+use std::collections::HashSet;
 use std::path::PathBuf;
-use types::{Config, ConflictInfo, FileEntry, ModInstallPreview, ModManifest, RestorePoint};
+use types::{
+    Config, ConflictInfo, ConflictResolution, FileEntry, InstallProgress, ModInstallPreview,
+    ModListEntry, ModLoadOrderResult, ModManifest, RestoreEntryResult, RestorePoint,
+};
 
 #[tauri::command]
 fn init_app() -> Result<(), String> {
@@ -36,6 +58,7 @@ fn init_app() -> Result<(), String> {
     init_storage()?;
     cleanup_old_backups(10)?;
     cleanup_old_restore_points(10)?;
+    name_fix::prune_backups(10)?;
     tracing::info!("Application initialized successfully");
     Ok(())
 }
@@ -55,9 +78,40 @@ fn update_config(config: Config) -> Result<(), String> {
     save_config(&config)
 }
 
-#[tauri::command]
-fn detect_game_path() -> Result<Vec<String>, String> {
-    let candidates = get_default_candidates();
+/// Candidates from [`detect_game_path`], split so the frontend can auto-select a `validated`
+/// hit but still offer `unvalidated` ones (found on disk, failed the addressable-assets check)
+/// as a manual-override choice instead of acting as if they were never found.
+#[derive(Debug, Clone, serde::Serialize)]
+struct GamePathCandidates {
+    validated: Vec<String>,
+    unvalidated: Vec<String>,
+}
+
+#[tauri::command]
+fn detect_game_path() -> Result<GamePathCandidates, String> {
+    let candidates = get_candidates_with_validation();
+    Ok(GamePathCandidates {
+        validated: candidates
+            .validated
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+        unvalidated: candidates
+            .unvalidated
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+    })
+}
+
+/// Like [`detect_game_path`], but casts a wider net: non-default Steam library folders, Epic,
+/// and (on Linux) Proton prefixes, with each candidate validated against the actual database
+/// layout rather than just checked for existence. Slower than [`detect_game_path`] since it
+/// touches disk for every candidate, so it's meant for an explicit "auto-detect" action rather
+/// than running on every app launch.
+#[tauri::command]
+fn discover_game_installs() -> Result<Vec<String>, String> {
+    let candidates = discovery::discover_install_candidates();
     Ok(candidates
         .iter()
         .map(|p| p.to_string_lossy().to_string())
@@ -80,6 +134,7 @@ fn detect_user_dir() -> Result<String, String> {
 
 #[tauri::command]
 fn preview_mod_install(
+    mod_name: String,
     mod_type: String,
     files: Option<Vec<FileEntry>>,
     game_target: Option<String>,
@@ -91,6 +146,7 @@ fn preview_mod_install(
         .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
 
     let preview = compute_preview(
+        &mod_name,
         &mod_type,
         &PathBuf::from(target_path),
         user_dir.as_deref().or(config.user_dir_path.as_deref()),
@@ -101,10 +157,25 @@ fn preview_mod_install(
 }
 
 #[tauri::command]
-fn get_mods_list() -> Result<Vec<String>, String> {
+fn get_mods_list() -> Result<Vec<ModListEntry>, String> {
     list_mods()
 }
 
+/// Toggles an already-installed mod's files between its live target location and the staging
+/// area, without needing a full uninstall/reinstall round-trip. Distinct from
+/// [`enable_mod`]/[`disable_mod`], which only edit which mods `apply_mods` will install next.
+#[tauri::command]
+fn set_mod_enabled(mod_name: String, enabled: bool) -> Result<String, String> {
+    let config = load_config()?;
+    apply_mod_enabled(
+        &mod_name,
+        enabled,
+        config.default_backup_mode,
+        config.backup_compression,
+        config.backup_compression_level,
+    )
+}
+
 #[tauri::command]
 fn get_mod_details(mod_name: String) -> Result<ModManifest, String> {
     get_mod_info(&mod_name)
@@ -115,7 +186,11 @@ fn enable_mod(mod_name: String) -> Result<(), String> {
     let mut config = load_config()?;
 
     if !config.enabled_mods.contains(&mod_name) {
-        config.enabled_mods.push(mod_name);
+        config.enabled_mods.push(mod_name.clone());
+        let active_profile = config.active_profile.clone();
+        if let Some(profile) = config.profiles.iter_mut().find(|p| p.id == active_profile) {
+            profile.enabled_mods.push(mod_name);
+        }
         save_config(&config)?;
     }
 
@@ -127,13 +202,98 @@ fn disable_mod(mod_name: String) -> Result<(), String> {
     let mut config = load_config()?;
 
     config.enabled_mods.retain(|m| m != &mod_name);
+    let active_profile = config.active_profile.clone();
+    if let Some(profile) = config.profiles.iter_mut().find(|p| p.id == active_profile) {
+        profile.enabled_mods.retain(|m| m != &mod_name);
+    }
     save_config(&config)?;
 
     Ok(())
 }
 
+/// Installs every enabled mod one at a time, tolerating per-mod failures (unlike
+/// [`apply_mods_transactional`]'s all-or-nothing batch). Runs on a blocking thread so a
+/// filesystem-heavy or panicking installer can't freeze or take down the whole app — a panic is
+/// caught per mod via [`std::panic::catch_unwind`] and recorded as that mod's failure line
+/// instead of propagating. Emits `apply-mods-progress` (mirroring [`InstallProgress`]) before and
+/// after each mod so the UI can show a per-mod progress bar instead of a frozen window.
 #[tauri::command]
-fn apply_mods() -> Result<String, String> {
+async fn apply_mods(app: tauri::AppHandle) -> Result<String, AppError> {
+    use tauri::{Emitter, Manager};
+
+    let config = load_config()?;
+
+    let target_path = config
+        .target_path
+        .clone()
+        .ok_or(AppError::GameTargetNotSet)?;
+    let target = std::path::PathBuf::from(&target_path);
+
+    if !target.exists() {
+        return Err(AppError::GameTargetInvalid);
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let total = config.enabled_mods.len();
+        let mut results = Vec::with_capacity(total);
+
+        for (index, mod_name) in config.enabled_mods.iter().enumerate() {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit(
+                    "apply-mods-progress",
+                    &InstallProgress {
+                        current: index,
+                        total,
+                        current_file: mod_name.clone(),
+                        operation: "mod-start".to_string(),
+                    },
+                );
+            }
+
+            let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                install_mod(
+                    mod_name,
+                    &target,
+                    config.user_dir_path.as_deref(),
+                    config.default_install_mode,
+                    config.default_backup_mode,
+                    config.backup_compression,
+                    config.backup_compression_level,
+                )
+            }));
+
+            results.push(match outcome {
+                Ok(Ok(msg)) => msg,
+                Ok(Err(e)) => format!("Failed to install {}: {}", mod_name, e),
+                Err(_) => format!("Failed to install {}: installer panicked", mod_name),
+            });
+
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit(
+                    "apply-mods-progress",
+                    &InstallProgress {
+                        current: index + 1,
+                        total,
+                        current_file: mod_name.clone(),
+                        operation: "mod-finish".to_string(),
+                    },
+                );
+            }
+        }
+
+        results.join("\n")
+    })
+    .await
+    .map_err(|e| AppError::Other(format!("apply_mods task panicked: {}", e)))
+}
+
+/// Like [`apply_mods`], but installs every enabled mod as one transactional batch via
+/// [`mod_manager::install_mods_batch`] instead of independent per-mod installs: conflicts
+/// between the enabled mods are resolved up front and either the whole batch lands or none of
+/// it does, rather than leaving the game patched with whichever mods happened to install before
+/// one of them failed.
+#[tauri::command]
+fn apply_mods_transactional() -> Result<String, String> {
     let config = load_config()?;
 
     let target_path = config
@@ -150,16 +310,15 @@ fn apply_mods() -> Result<String, String> {
         ));
     }
 
-    let mut results = Vec::new();
-
-    for mod_name in &config.enabled_mods {
-        match install_mod(mod_name, &target, config.user_dir_path.as_deref()) {
-            Ok(msg) => results.push(msg),
-            Err(e) => results.push(format!("Failed to install {}: {}", mod_name, e)),
-        }
-    }
-
-    Ok(results.join("\n"))
+    install_mods_batch(
+        &config.enabled_mods,
+        &target,
+        config.user_dir_path.as_deref(),
+        config.default_install_mode,
+        config.default_backup_mode,
+        config.backup_compression,
+        config.backup_compression_level,
+    )
 }
 
 #[tauri::command]
@@ -180,16 +339,20 @@ fn remove_mod(mod_name: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Imports a mod from an archive, single file, or directory. Runs on a blocking thread so a
+/// large archive's extraction and copy don't freeze the UI, emitting `import-mod-progress`
+/// (mirroring [`InstallProgress`]) for its `"extracting"` and `"copying"` phases.
 #[tauri::command]
-fn import_mod(
+async fn import_mod(
+    app: tauri::AppHandle,
     source_path: String,
     mod_name: Option<String>,
     version: Option<String>,
     mod_type: Option<String>,
     author: Option<String>,
     description: Option<String>,
-) -> Result<String, String> {
-    use std::fs;
+) -> Result<String, AppError> {
+    use tauri::{Emitter, Manager};
 
     tracing::info!("Starting mod import from: {}", source_path);
     tracing::debug!(
@@ -204,110 +367,126 @@ fn import_mod(
 
     if !source.exists() {
         tracing::error!("Source path does not exist: {}", source_path);
-        return Err(code_error(
-            CODE_SOURCE_PATH_MISSING,
-            "Source path does not exist",
-        ));
+        return Err(AppError::SourcePathMissing);
     }
 
-    tracing::info!(
-        "Source exists: {:?}, is_file: {}, is_dir: {}",
-        source,
-        source.is_file(),
-        source.is_dir()
-    );
-
-    // Handle different source types
-    let mod_root = if source.is_file() {
-        let ext = source.extension().and_then(|s| s.to_str());
-        tracing::info!("File extension: {:?}", ext);
-
-        if ext == Some("zip") {
-            // Extract ZIP to temp directory
-            let temp_dir =
-                std::env::temp_dir().join(format!("fmmloader_import_{}", uuid::Uuid::new_v4()));
-            tracing::info!("Extracting ZIP to: {:?}", temp_dir);
-            extract_zip(&source, &temp_dir)?;
-            let root = find_mod_root(&temp_dir)?;
-            tracing::info!("Found mod root in ZIP: {:?}", root);
-            root
+    tauri::async_runtime::spawn_blocking(move || {
+        use std::fs;
+
+        let emit_progress = |phase: &str, current_file: &str| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit(
+                    "import-mod-progress",
+                    &InstallProgress {
+                        current: 0,
+                        total: 0,
+                        current_file: current_file.to_string(),
+                        operation: phase.to_string(),
+                    },
+                );
+            }
+        };
+
+        tracing::info!(
+            "Source exists: {:?}, is_file: {}, is_dir: {}",
+            source,
+            source.is_file(),
+            source.is_dir()
+        );
+
+        // Handle different source types
+        let mod_root = if source.is_file() {
+            let ext = source.extension().and_then(|s| s.to_str());
+            tracing::info!("File extension: {:?}", ext);
+
+            if ext == Some("zip") {
+                // Extract ZIP to temp directory
+                let temp_dir = std::env::temp_dir()
+                    .join(format!("fmmloader_import_{}", uuid::Uuid::new_v4()));
+                tracing::info!("Extracting ZIP to: {:?}", temp_dir);
+                emit_progress("extracting", &source_path);
+                extract_zip(&source, &temp_dir)?;
+                let root = find_mod_root(&temp_dir)?;
+                tracing::info!("Found mod root in ZIP: {:?}", root);
+                root
+            } else {
+                // Single file (.bundle, .fmf, etc) - create temp dir with just this file
+                let temp_dir = std::env::temp_dir()
+                    .join(format!("fmmloader_import_{}", uuid::Uuid::new_v4()));
+                tracing::info!("Creating temp directory for single file: {:?}", temp_dir);
+                fs::create_dir_all(&temp_dir).map_err(|e| {
+                    tracing::error!("Failed to create temp directory: {}", e);
+                    format!("Failed to create temp directory: {}", e)
+                })?;
+
+                let file_name = source.file_name().ok_or("Invalid file name")?;
+                let dest_file = temp_dir.join(file_name);
+
+                tracing::info!("Copying file to: {:?}", dest_file);
+                emit_progress("copying", &source_path);
+                fs::copy(&source, &dest_file).map_err(|e| {
+                    tracing::error!("Failed to copy file: {}", e);
+                    format!("Failed to copy file: {}", e)
+                })?;
+
+                temp_dir
+            }
         } else {
-            // Single file (.bundle, .fmf, etc) - create temp dir with just this file
-            let temp_dir =
-                std::env::temp_dir().join(format!("fmmloader_import_{}", uuid::Uuid::new_v4()));
-            tracing::info!("Creating temp directory for single file: {:?}", temp_dir);
-            fs::create_dir_all(&temp_dir).map_err(|e| {
-                tracing::error!("Failed to create temp directory: {}", e);
-                format!("Failed to create temp directory: {}", e)
-            })?;
-
-            let file_name = source.file_name().ok_or("Invalid file name")?;
-            let dest_file = temp_dir.join(file_name);
-
-            tracing::info!("Copying file to: {:?}", dest_file);
-            fs::copy(&source, &dest_file).map_err(|e| {
-                tracing::error!("Failed to copy file: {}", e);
-                format!("Failed to copy file: {}", e)
-            })?;
-
-            temp_dir
-        }
-    } else {
-        // It's a directory
-        tracing::info!("Source is a directory, finding mod root");
-        let root = find_mod_root(&source)?;
-        tracing::info!("Found mod root: {:?}", root);
-        root
-    };
-
-    // Check if manifest exists
-    let needs_manifest = !has_manifest(&mod_root);
-    tracing::info!("Needs manifest: {}", needs_manifest);
-
-    // If no manifest and no metadata provided, return error asking for metadata
-    if needs_manifest {
-        if mod_name.is_none() || version.is_none() || mod_type.is_none() {
-            tracing::warn!("Manifest needed but metadata not provided");
-            // Return special error code indicating we need metadata
-            return Err(CODE_METADATA_REQUIRED.to_string());
+            // It's a directory
+            tracing::info!("Source is a directory, finding mod root");
+            let root = find_mod_root(&source)?;
+            tracing::info!("Found mod root: {:?}", root);
+            root
+        };
+
+        // Check if manifest exists
+        let needs_manifest = !has_manifest(&mod_root);
+        tracing::info!("Needs manifest: {}", needs_manifest);
+
+        // If no manifest and no metadata provided, return error asking for metadata
+        if needs_manifest {
+            if mod_name.is_none() || version.is_none() || mod_type.is_none() {
+                tracing::warn!("Manifest needed but metadata not provided");
+                return Err(AppError::MetadataRequired);
+            }
+
+            tracing::info!("Generating manifest with provided metadata");
+            // Generate manifest with provided metadata
+            generate_manifest(
+                &mod_root,
+                mod_name.clone().unwrap(),
+                version.unwrap(),
+                mod_type.unwrap(),
+                author.unwrap_or_default(),
+                description.unwrap_or_default(),
+            )?;
         }
 
-        tracing::info!("Generating manifest with provided metadata");
-        // Generate manifest with provided metadata
-        generate_manifest(
-            &mod_root,
-            mod_name.clone().unwrap(),
-            version.unwrap(),
-            mod_type.unwrap(),
-            author.unwrap_or_default(),
-            description.unwrap_or_default(),
-        )?;
-    }
-
-    // Read the manifest to get the mod name
-    tracing::info!("Reading manifest from mod root");
-    let manifest = mod_manager::read_manifest(&mod_root)?;
-    let final_mod_name = mod_name.unwrap_or(manifest.name.clone());
-    tracing::info!("Final mod name: {}", final_mod_name);
+        // Read the manifest to get the mod name
+        tracing::info!("Reading manifest from mod root");
+        let manifest = mod_manager::read_manifest(&mod_root)?;
+        let final_mod_name = mod_name.unwrap_or(manifest.name.clone());
+        tracing::info!("Final mod name: {}", final_mod_name);
 
-    // Copy to mods directory
-    let dest_dir = mods_dir.join(&final_mod_name);
-    tracing::info!("Destination directory: {:?}", dest_dir);
+        // Copy to mods directory
+        let dest_dir = mods_dir.join(&final_mod_name);
+        tracing::info!("Destination directory: {:?}", dest_dir);
 
-    if dest_dir.exists() {
-        tracing::error!("Mod already exists: {}", final_mod_name);
-        return Err(code_error(
-            CODE_MOD_ALREADY_EXISTS,
-            format!("Mod '{}' already exists", final_mod_name),
-        ));
-    }
+        if dest_dir.exists() {
+            tracing::error!("Mod already exists: {}", final_mod_name);
+            return Err(AppError::ModAlreadyExists(final_mod_name));
+        }
 
-    // Copy the mod files
-    tracing::info!("Copying mod files from {:?} to {:?}", mod_root, dest_dir);
-    utils::copy_dir_recursive(&mod_root, &dest_dir)?;
-    tracing::info!("Mod import completed successfully: {}", final_mod_name);
+        // Copy the mod files
+        tracing::info!("Copying mod files from {:?} to {:?}", mod_root, dest_dir);
+        emit_progress("copying", &final_mod_name);
+        utils::copy_dir_recursive(&mod_root, &dest_dir).map_err(|e| e.to_string())?;
+        tracing::info!("Mod import completed successfully: {}", final_mod_name);
 
-    Ok(final_mod_name)
+        Ok(final_mod_name)
+    })
+    .await
+    .map_err(|e| AppError::Other(format!("import_mod task panicked: {}", e)))?
 }
 
 #[tauri::command]
@@ -322,7 +501,25 @@ fn detect_mod_type(path: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-fn check_conflicts() -> Result<Vec<ConflictInfo>, String> {
+async fn check_conflicts() -> Result<Vec<ConflictInfo>, AppError> {
+    let config = load_config()?;
+
+    let target_path = config
+        .target_path
+        .clone()
+        .ok_or(AppError::GameTargetNotSet)?;
+    let target = PathBuf::from(target_path);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        find_conflicts(&config.enabled_mods, &target, config.user_dir_path.as_deref())
+    })
+    .await
+    .map_err(|e| AppError::Other(format!("check_conflicts task panicked: {}", e)))?
+    .map_err(AppError::from)
+}
+
+#[tauri::command]
+fn check_conflict_resolutions() -> Result<Vec<ConflictResolution>, String> {
     let config = load_config()?;
 
     let target_path = config
@@ -332,11 +529,32 @@ fn check_conflicts() -> Result<Vec<ConflictInfo>, String> {
 
     let target = PathBuf::from(target_path);
 
-    find_conflicts(
+    let conflicts = find_conflicts(
         &config.enabled_mods,
         &target,
         config.user_dir_path.as_deref(),
-    )
+    )?;
+
+    Ok(resolve_conflicts(&conflicts, &config.enabled_mods))
+}
+
+#[tauri::command]
+fn get_mod_load_order() -> Result<ModLoadOrderResult, String> {
+    let config = load_config()?;
+
+    let manifests: Vec<ModManifest> = config
+        .enabled_mods
+        .iter()
+        .filter_map(|name| get_mod_info(name).ok())
+        .collect();
+
+    let (order, diagnostics) =
+        resolve_load_order(&manifests).map_err(|e| e.to_string())?;
+
+    Ok(ModLoadOrderResult {
+        order,
+        diagnostics: diagnostics.iter().map(|d| d.to_string()).collect(),
+    })
 }
 
 #[tauri::command]
@@ -345,22 +563,59 @@ fn get_restore_points() -> Result<Vec<RestorePoint>, String> {
 }
 
 #[tauri::command]
-fn restore_from_point(point_path: String) -> Result<String, String> {
+async fn restore_from_point(point_path: String) -> Result<String, AppError> {
     let path = PathBuf::from(point_path);
-    rollback_to_restore_point(&path)
+
+    let message = tauri::async_runtime::spawn_blocking(move || rollback_to_restore_point(&path))
+        .await
+        .map_err(|e| AppError::Other(format!("restore_from_point task panicked: {}", e)))??;
+
+    Ok(message)
 }
 
+/// Restores some or all entries of a restore point. `target_dir` is an optional override root
+/// to restore into instead of clobbering the original paths (e.g. to preview a point in a
+/// scratch folder). `relative_paths`, when non-empty, limits the restore to just those entries
+/// (see [`restore::entry_relative_path`] for the format, e.g. `"ModA/textures/kit.png"`);
+/// empty means restore everything.
 #[tauri::command]
-fn create_backup_point(name: String) -> Result<String, String> {
+fn restore_point_entries(
+    point_path: String,
+    target_dir: Option<String>,
+    relative_paths: Vec<String>,
+) -> Result<Vec<RestoreEntryResult>, String> {
+    let point_path = PathBuf::from(point_path);
+    let override_root = target_dir.map(PathBuf::from);
+
+    let selector = if relative_paths.is_empty() {
+        EntrySelector::All
+    } else {
+        EntrySelector::RelativePaths(relative_paths.into_iter().map(PathBuf::from).collect::<HashSet<_>>())
+    };
+
+    restore_entries(&point_path, override_root.as_deref(), &selector)
+}
+
+#[tauri::command]
+async fn create_backup_point(name: String) -> Result<String, AppError> {
     let config = load_config()?;
 
     let target_path = config
         .target_path
-        .as_ref()
-        .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
-
+        .clone()
+        .ok_or(AppError::GameTargetNotSet)?;
     let target = PathBuf::from(target_path);
-    let point_dir = create_restore_point(&name, &[target])?;
+
+    let point_dir = tauri::async_runtime::spawn_blocking(move || {
+        create_restore_point(
+            &name,
+            &[target],
+            config.backup_compression,
+            config.backup_compression_level,
+        )
+    })
+    .await
+    .map_err(|e| AppError::Other(format!("create_backup_point task panicked: {}", e)))??;
 
     Ok(point_dir.to_string_lossy().to_string())
 }
@@ -416,6 +671,30 @@ fn get_logs_path() -> Result<String, String> {
     Ok(logs_dir.to_string_lossy().to_string())
 }
 
+/// Football Manager's own logs and crash dumps, as opposed to [`get_logs_path`]'s FMMLoader
+/// logs — where a mod-related crash actually surfaces.
+#[tauri::command]
+fn list_game_logs() -> Result<Vec<types::GameLogEntry>, String> {
+    let config = load_config()?;
+    let user_dir = game_detection::get_fm_user_dir(config.user_dir_path.as_deref());
+    logging::list_game_logs(&user_dir)
+}
+
+#[tauri::command]
+fn read_game_log(path: String, tail_lines: Option<usize>) -> Result<String, String> {
+    let config = load_config()?;
+    let user_dir = game_detection::get_fm_user_dir(config.user_dir_path.as_deref());
+    logging::read_game_log(&PathBuf::from(path), &user_dir, tail_lines)
+}
+
+/// Bundles the logs directory and a fresh system-info header into a single zip under
+/// `dest_dir`, so filing a bug report is "attach one file" instead of hunting down logs.
+#[tauri::command]
+fn export_logs(dest_dir: String) -> Result<String, String> {
+    let archive_path = logging::export_logs(&PathBuf::from(dest_dir))?;
+    Ok(archive_path.to_string_lossy().to_string())
+}
+
 #[tauri::command]
 fn open_mods_folder() -> Result<(), String> {
     let mods_dir = get_mods_dir();
@@ -483,9 +762,24 @@ fn check_name_fix_installed() -> Result<bool, String> {
 }
 
 #[tauri::command]
-fn install_name_fix() -> Result<String, String> {
+fn install_name_fix(
+    app: tauri::AppHandle,
+    force: Option<bool>,
+    conflict_resolutions: Option<std::collections::HashMap<String, crate::types::NameFixConflictAction>>,
+) -> Result<String, AppError> {
+    use tauri::{Emitter, Manager};
+
     // Install the GitHub name fix (backwards compatibility)
-    name_fix::install_name_fix(name_fix::GITHUB_NAME_FIX_ID.to_string())
+    Ok(name_fix::install_name_fix(
+        name_fix::GITHUB_NAME_FIX_ID.to_string(),
+        force.unwrap_or(false),
+        conflict_resolutions.unwrap_or_default(),
+        |progress| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("name-fix-install-progress", &progress);
+            }
+        },
+    )?)
 }
 
 #[tauri::command]
@@ -499,13 +793,52 @@ fn list_name_fixes() -> Result<Vec<crate::types::NameFixSource>, String> {
 }
 
 #[tauri::command]
-fn import_name_fix(file_path: String, name: String) -> Result<String, String> {
-    name_fix::import_name_fix(file_path, name)
+fn import_name_fix(app: tauri::AppHandle, file_path: String, name: String) -> Result<String, AppError> {
+    use tauri::{Emitter, Manager};
+
+    Ok(name_fix::import_name_fix(file_path, name, |progress| {
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.emit("name-fix-extract-progress", &progress);
+        }
+    })?)
+}
+
+#[tauri::command]
+fn import_name_fixes(
+    app: tauri::AppHandle,
+    files: Vec<(String, String)>,
+) -> Vec<Result<String, String>> {
+    use tauri::{Emitter, Manager};
+
+    name_fix::import_name_fixes(files, |index, progress| {
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.emit(
+                "name-fix-batch-extract-progress",
+                &(index, progress),
+            );
+        }
+    })
 }
 
 #[tauri::command]
-fn install_name_fix_by_id(name_fix_id: String) -> Result<String, String> {
-    name_fix::install_name_fix(name_fix_id)
+fn install_name_fix_by_id(
+    app: tauri::AppHandle,
+    name_fix_id: String,
+    force: Option<bool>,
+    conflict_resolutions: Option<std::collections::HashMap<String, crate::types::NameFixConflictAction>>,
+) -> Result<String, String> {
+    use tauri::{Emitter, Manager};
+
+    name_fix::install_name_fix(
+        name_fix_id,
+        force.unwrap_or(false),
+        conflict_resolutions.unwrap_or_default(),
+        |progress| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("name-fix-install-progress", &progress);
+            }
+        },
+    )
 }
 
 #[tauri::command]
@@ -518,6 +851,46 @@ fn get_active_name_fix() -> Result<Option<String>, String> {
     name_fix::get_active_name_fix()
 }
 
+#[tauri::command]
+fn verify_name_fix() -> Result<crate::types::NameFixVerifyReport, String> {
+    name_fix::verify()
+}
+
+#[tauri::command]
+fn repair_name_fix() -> Result<String, String> {
+    name_fix::repair()
+}
+
+#[tauri::command]
+fn scan_name_fix_conflicts() -> Result<crate::types::DbConflictReport, String> {
+    name_fix::scan_conflicts_for_active_config()
+}
+
+#[tauri::command]
+fn scan_name_fix_install_conflicts(name_fix_id: String) -> Result<crate::types::NameFixConflictReport, String> {
+    name_fix::scan_conflicts_for_fix(name_fix_id)
+}
+
+#[tauri::command]
+fn verify_name_fix_manifest(name_fix_id: String) -> Result<crate::types::NameFixManifestReport, String> {
+    name_fix::verify_name_fix(name_fix_id)
+}
+
+#[tauri::command]
+fn list_name_fix_backups() -> Result<Vec<crate::types::BackupInfo>, String> {
+    name_fix::list_backups()
+}
+
+#[tauri::command]
+fn restore_name_fix_backup(backup_id: Option<String>) -> Result<String, String> {
+    name_fix::restore_backup_for_active_config(backup_id.as_deref())
+}
+
+#[tauri::command]
+fn delete_name_fix_backup(backup_id: String) -> Result<(), String> {
+    name_fix::delete_backup(&backup_id)
+}
+
 fn main() {
     // Initialize logging first
     if let Err(e) = logging::init_logging() {
@@ -550,42 +923,81 @@ fn main() {
             get_config,
             update_config,
             detect_game_path,
+            discover_game_installs,
             set_game_target,
             detect_user_dir,
             get_mods_list,
             get_mod_details,
             enable_mod,
             disable_mod,
+            set_mod_enabled,
             apply_mods,
+            apply_mods_transactional,
             remove_mod,
             import_mod,
             detect_mod_type,
             check_conflicts,
+            check_conflict_resolutions,
+            get_mod_load_order,
             get_restore_points,
             restore_from_point,
+            restore_point_entries,
             create_backup_point,
             open_logs_folder,
             open_mods_folder,
             get_logs_path,
+            export_logs,
+            list_game_logs,
+            read_game_log,
             log_update_event,
             check_name_fix_installed,
             install_name_fix,
             uninstall_name_fix,
             list_name_fixes,
             import_name_fix,
+            import_name_fixes,
             install_name_fix_by_id,
             delete_name_fix,
             get_active_name_fix,
+            verify_name_fix,
+            repair_name_fix,
+            scan_name_fix_conflicts,
+            scan_name_fix_install_conflicts,
+            verify_name_fix_manifest,
+            list_name_fix_backups,
+            restore_name_fix_backup,
+            delete_name_fix_backup,
             graphics::import_graphics_pack,
             graphics::import_graphics_pack_with_type,
             graphics::list_graphics_packs,
             graphics::analyze_graphics_pack_cmd,
+            graphics::validate_graphics_pack_images,
             graphics::validate_graphics,
             graphics::prefix_graphics_files,
             graphics::migrate_graphics_pack,
             graphics::check_graphics_conflicts,
+            graphics::graphics_pack_format_version,
+            graphics::find_duplicate_graphics,
+            graphics::deduplicate_graphics,
+            graphics::export_graphics_pack,
+            repo_client::search_mod_repository,
+            repo_client::download_mod_from_repository,
+            profiles::create_profile,
+            profiles::clone_profile,
+            profiles::rename_profile,
+            profiles::delete_profile,
+            profiles::switch_profile,
+            profiles::list_profiles,
+            update_checker::refresh_update_status,
+            external_import::preview_external_pack_import,
+            external_import::import_external_pack,
             preview_mod_install,
             open_app_management_settings,
+            verify::verify_game_target,
+            verify::verify_installed_mods,
+            verify::repair_mods,
+            verify::disable_all_mods_and_restore,
+            launch::launch_game,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -10,6 +10,7 @@ pub const CODE_MOD_ALREADY_EXISTS: &str = "ERR_MOD_ALREADY_EXISTS";
 pub const CODE_SOURCE_PATH_MISSING: &str = "ERR_SOURCE_PATH_MISSING";
 pub const CODE_PATH_NOT_FOUND: &str = "ERR_PATH_NOT_FOUND";
 pub const CODE_METADATA_REQUIRED: &str = "NEEDS_METADATA";
+pub const CODE_CONFLICT_UNRESOLVED: &str = "ERR_CONFLICT_UNRESOLVED";
 
 /// Formats a code with an English fallback detail.
 pub fn code_error(code: &'static str, detail: impl Into<String>) -> String {
@@ -1,12 +1,69 @@
-use crate::config::{get_backup_dir, get_mods_dir, get_restore_points_dir};
+use crate::config::{
+    get_backup_dir, get_mods_dir, get_receipts_dir, get_restore_points_dir, get_staging_dir,
+    load_ownership_index, save_ownership_index,
+};
 use crate::game_detection::get_fm_user_dir;
-use crate::types::{FileEntry, ModInstallPreview, ModManifest, ResolvedFilePreview};
+use crate::messages::{code_error, CODE_CONFLICT_UNRESOLVED};
+use crate::types::{
+    BackupCompression, BackupMode, Compatibility, ConflictClassification, FileEntry,
+    FileOwnershipConflict, InstallMode, InstallReceipt, InstallReceiptEntry, ModInstallPreview,
+    ModListEntry, ModManifest, ResolvedFilePreview,
+};
 use chrono::Local;
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::io;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+/// Current `ModManifest` schema version this build understands. Bump this and add a
+/// `migrate_vN_to_vN1` step below whenever a field is added or reshaped in a way that isn't
+/// simply `#[serde(default)]`-compatible (e.g. per-file permissions, structured dependencies).
+pub const CURRENT_MANIFEST_SCHEMA_VERSION: u32 = 1;
+
+/// Manifests written before `schema_version` existed have no such field at all; treat that as
+/// implicit version 0 and migrate it up to v1 by stamping the field on.
+fn migrate_v0_to_v1(mut value: serde_json::Value) -> serde_json::Value {
+    if let serde_json::Value::Object(ref mut map) = value {
+        map.insert("schema_version".to_string(), serde_json::Value::from(1u32));
+    }
+    value
+}
+
+/// Parses `manifest.json` contents into a [`ModManifest`], migrating older `schema_version`s
+/// forward first so a format change never silently breaks mods authored for an older loader
+/// (or gets rejected by an older loader reading a newer mod). Newer-than-supported manifests
+/// are rejected with a message telling the user to update instead of failing deserialization
+/// with an opaque serde error.
+///
+/// Shared by [`read_manifest`], [`crate::import::discover_mods`], and
+/// [`crate::import::verify_manifest`] so every manifest read path migrates identically.
+pub fn parse_manifest_json(contents: &str) -> Result<ModManifest, String> {
+    let mut value: serde_json::Value =
+        serde_json::from_str(contents).map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    let mut version = value
+        .get("schema_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0) as u32;
+
+    if version == 0 {
+        value = migrate_v0_to_v1(value);
+        version = 1;
+    }
+
+    if version > CURRENT_MANIFEST_SCHEMA_VERSION {
+        return Err(format!(
+            "Manifest schema_version {} is newer than the {} this version of FMMLoader \
+             supports. Please update FMMLoader to install this mod.",
+            version, CURRENT_MANIFEST_SCHEMA_VERSION
+        ));
+    }
+
+    serde_json::from_value(value).map_err(|e| format!("Failed to parse manifest: {}", e))
+}
+
 pub fn read_manifest(mod_dir: &Path) -> Result<ModManifest, String> {
     let manifest_path = mod_dir.join("manifest.json");
 
@@ -17,8 +74,7 @@ pub fn read_manifest(mod_dir: &Path) -> Result<ModManifest, String> {
     let contents = fs::read_to_string(&manifest_path)
         .map_err(|e| format!("Failed to read manifest: {}", e))?;
 
-    let mut manifest: ModManifest =
-        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse manifest: {}", e))?;
+    let mut manifest = parse_manifest_json(&contents)?;
 
     // Set defaults
     if manifest.name.is_empty() {
@@ -32,7 +88,11 @@ pub fn read_manifest(mod_dir: &Path) -> Result<ModManifest, String> {
     Ok(manifest)
 }
 
-pub fn list_mods() -> Result<Vec<String>, String> {
+/// Lists every mod directory under [`crate::config::get_mods_dir`], alongside whether its
+/// installed files (if any) currently sit in the live target directories or have been moved
+/// to staging by [`set_mod_enabled`]. A mod with no install receipt yet — never installed, or
+/// installed before receipts existed — reports `enabled: true`, since there's nothing staged.
+pub fn list_mods() -> Result<Vec<ModListEntry>, String> {
     let mods_dir = get_mods_dir();
 
     if !mods_dir.exists() {
@@ -47,7 +107,11 @@ pub fn list_mods() -> Result<Vec<String>, String> {
     for entry in entries.flatten() {
         if entry.path().is_dir() {
             if let Some(name) = entry.file_name().to_str() {
-                mods.push(name.to_string());
+                let enabled = read_receipt(name)?.map(|r| r.enabled).unwrap_or(true);
+                mods.push(ModListEntry {
+                    name: name.to_string(),
+                    enabled,
+                });
             }
         }
     }
@@ -65,7 +129,154 @@ pub fn get_mod_info(mod_name: &str) -> Result<ModManifest, String> {
     read_manifest(&mod_dir)
 }
 
-pub fn backup_file(target_file: &Path) -> Result<Option<PathBuf>, String> {
+/// Lowercase hex SHA-256 of `path`'s bytes, used to verify a backup's content rather than
+/// trusting its name/mtime on disk.
+fn hash_file_contents(path: &Path) -> Result<String, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Failed to hash '{:?}': {}", path, e))?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn backup_hash_sidecar(backup_path: &Path) -> PathBuf {
+    let mut name = backup_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    name.push_str(".sha256");
+    backup_path.with_file_name(name)
+}
+
+fn read_backup_hash(backup_path: &Path) -> Option<String> {
+    fs::read_to_string(backup_hash_sidecar(backup_path))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+fn write_backup_hash(backup_path: &Path, hash: &str) -> Result<(), String> {
+    fs::write(backup_hash_sidecar(backup_path), hash)
+        .map_err(|e| format!("Failed to write backup hash: {}", e))
+}
+
+/// Extension [`backup_file`] appends to a backup's filename when writing it compressed, so the
+/// chosen codec travels with the path itself (stored verbatim in [`InstallReceiptEntry::backup_path`])
+/// rather than needing a separate sidecar to remember it.
+fn backup_compression_extension(compression: BackupCompression) -> Option<&'static str> {
+    match compression {
+        BackupCompression::None => None,
+        BackupCompression::Zstd => Some("zst"),
+        BackupCompression::Xz => Some("xz"),
+    }
+}
+
+/// Writes `bytes` to `backup_path` under `compression`, using `level` as the xz/zstd encoder
+/// level (`0` means "that encoder's own default preset").
+fn write_backup_contents(
+    backup_path: &Path,
+    bytes: &[u8],
+    compression: BackupCompression,
+    level: u32,
+) -> Result<(), String> {
+    match compression {
+        BackupCompression::None => {
+            fs::write(backup_path, bytes).map_err(|e| format!("Failed to backup file: {}", e))
+        }
+        BackupCompression::Xz => {
+            let file = fs::File::create(backup_path)
+                .map_err(|e| format!("Failed to create backup file: {}", e))?;
+            let preset = if level == 0 { 6 } else { level };
+            let mut encoder = xz2::write::XzEncoder::new(file, preset);
+            io::Write::write_all(&mut encoder, bytes)
+                .map_err(|e| format!("Failed to write backup file: {}", e))?;
+            encoder
+                .finish()
+                .map_err(|e| format!("Failed to finish xz backup: {}", e))?;
+            Ok(())
+        }
+        BackupCompression::Zstd => {
+            let file = fs::File::create(backup_path)
+                .map_err(|e| format!("Failed to create backup file: {}", e))?;
+            let preset = if level == 0 { 3 } else { level as i32 };
+            let mut encoder = zstd::Encoder::new(file, preset)
+                .map_err(|e| format!("Failed to start zstd backup: {}", e))?
+                .auto_finish();
+            io::Write::write_all(&mut encoder, bytes)
+                .map_err(|e| format!("Failed to write backup file: {}", e))
+        }
+    }
+}
+
+/// Reads `backup_path` back to plain bytes, decoding by its extension rather than the live
+/// config's `backup_compression` setting — a backup written under one setting must still restore
+/// correctly after the user switches to another.
+fn read_backup_contents(backup_path: &Path) -> Result<Vec<u8>, String> {
+    let extension = backup_path.extension().and_then(|e| e.to_str());
+    let file = fs::File::open(backup_path)
+        .map_err(|e| format!("Failed to open backup '{:?}': {}", backup_path, e))?;
+
+    let mut contents = Vec::new();
+    match extension {
+        Some("xz") => {
+            xz2::read::XzDecoder::new(file)
+                .read_to_end(&mut contents)
+                .map_err(|e| format!("Failed to decompress backup '{:?}': {}", backup_path, e))?;
+        }
+        Some("zst") => {
+            zstd::Decoder::new(file)
+                .map_err(|e| format!("Failed to open zstd backup '{:?}': {}", backup_path, e))?
+                .read_to_end(&mut contents)
+                .map_err(|e| format!("Failed to decompress backup '{:?}': {}", backup_path, e))?;
+        }
+        _ => {
+            io::BufReader::new(file)
+                .read_to_end(&mut contents)
+                .map_err(|e| format!("Failed to read backup '{:?}': {}", backup_path, e))?;
+        }
+    }
+
+    Ok(contents)
+}
+
+/// Restores `backup_path` (compressed or not, per [`read_backup_contents`]) over `dest`.
+fn restore_file_from_backup(backup_path: &Path, dest: &Path) -> Result<(), String> {
+    let contents = read_backup_contents(backup_path)?;
+    fs::write(dest, contents).map_err(|e| format!("Failed to restore {:?}: {}", dest, e))
+}
+
+/// Highest `N` already used by a `filename.~N~` numbered backup in `backup_dir`, or 0 if none
+/// exist yet, so the next call gets `N + 1` (coreutils `install --backup=numbered` semantics).
+/// A compressed backup's name carries a trailing `.zst`/`.xz` after the `~N~`, so that's
+/// stripped before matching the numbered-backup suffix.
+fn highest_numbered_backup(backup_dir: &Path, filename: &str) -> u32 {
+    let prefix = format!("{}.~", filename);
+
+    fs::read_dir(backup_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(|s| s.to_string()))
+        .filter_map(|name| {
+            let name = name.strip_suffix(".zst").or_else(|| name.strip_suffix(".xz")).unwrap_or(&name);
+            let suffix = name.strip_prefix(&prefix)?;
+            let number_str = suffix.strip_suffix('~')?;
+            number_str.parse::<u32>().ok()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Backs up `target_file` under [`crate::config::get_backup_dir`] per `mode`, alongside a
+/// `.sha256` sidecar of its content so [`crate::restore`]-style consumers can verify the backup
+/// is intact before restoring over good data, instead of trusting that a `.bak` file on disk
+/// still matches what was captured. If an existing backup at the computed path already has the
+/// exact same content hash, the copy is skipped entirely (most relevant to [`BackupMode::Simple`],
+/// where repeated installs would otherwise overwrite an identical file on every run).
+pub fn backup_file(
+    target_file: &Path,
+    mode: BackupMode,
+    compression: BackupCompression,
+    compression_level: u32,
+) -> Result<Option<PathBuf>, String> {
     if !target_file.exists() {
         return Ok(None);
     }
@@ -78,12 +289,34 @@ pub fn backup_file(target_file: &Path) -> Result<Option<PathBuf>, String> {
         .and_then(|n| n.to_str())
         .ok_or("Invalid filename")?;
 
-    // Create a unique backup filename
-    let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
-    let backup_name = format!("{}_{}.bak", filename, timestamp);
+    let mut backup_name = match mode {
+        BackupMode::Timestamped => {
+            let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
+            format!("{}_{}.bak", filename, timestamp)
+        }
+        BackupMode::Simple => format!("{}.bak", filename),
+        BackupMode::Numbered => {
+            let next = highest_numbered_backup(&backup_dir, filename) + 1;
+            format!("{}.~{}~", filename, next)
+        }
+    };
+    if let Some(extension) = backup_compression_extension(compression) {
+        backup_name.push('.');
+        backup_name.push_str(extension);
+    }
     let backup_path = backup_dir.join(&backup_name);
 
-    fs::copy(target_file, &backup_path).map_err(|e| format!("Failed to backup file: {}", e))?;
+    let content_hash = hash_file_contents(target_file)?;
+
+    let existing_hash_matches =
+        backup_path.exists() && read_backup_hash(&backup_path).as_deref() == Some(content_hash.as_str());
+    if existing_hash_matches {
+        return Ok(Some(backup_path));
+    }
+
+    let contents = fs::read(target_file).map_err(|e| format!("Failed to backup file: {}", e))?;
+    write_backup_contents(&backup_path, &contents, compression, compression_level)?;
+    write_backup_hash(&backup_path, &content_hash)?;
 
     Ok(Some(backup_path))
 }
@@ -123,6 +356,276 @@ fn copy_recursive(src: &Path, dst: &Path) -> io::Result<u64> {
     Ok(count)
 }
 
+/// Moves whatever `install_entry` placed at `src` (file, directory, or symlink/junction) to
+/// `dst`, creating `dst`'s parent as needed. Tries a plain rename first; falls back to a copy
+/// followed by removing `src` when `src` and `dst` are on different filesystems, the same
+/// `EXDEV` case [`install_entry`]'s hardlink path already has to handle.
+fn move_path(src: &Path, dst: &Path) -> io::Result<()> {
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    match fs::rename(src, dst) {
+        Ok(()) => Ok(()),
+        Err(e) if is_cross_device_error(&e) => {
+            if fs::symlink_metadata(src)?.file_type().is_symlink() {
+                let target = fs::read_link(src)?;
+                #[cfg(unix)]
+                std::os::unix::fs::symlink(&target, dst)?;
+                #[cfg(windows)]
+                {
+                    if target.is_dir() {
+                        fs::create_dir_all(dst)?;
+                        junction::create(&target, dst)?;
+                    } else {
+                        std::os::windows::fs::symlink_file(&target, dst)?;
+                    }
+                }
+            } else {
+                copy_recursive(src, dst)?;
+            }
+            remove_installed_path(src)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Recursively hardlinks `src` into `dst` instead of duplicating bytes. Directories are
+/// recreated for real (you can't hardlink a directory); only the files underneath are linked.
+fn hardlink_recursive(src: &Path, dst: &Path) -> io::Result<u64> {
+    let mut count = 0;
+
+    if src.is_dir() {
+        fs::create_dir_all(dst)?;
+
+        for entry in WalkDir::new(src) {
+            let entry = entry?;
+            let path = entry.path();
+
+            if let Ok(rel_path) = path.strip_prefix(src) {
+                let target_path = dst.join(rel_path);
+
+                if path.is_dir() {
+                    fs::create_dir_all(&target_path)?;
+                } else {
+                    if let Some(parent) = target_path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::hard_link(path, &target_path)?;
+                    count += 1;
+                }
+            }
+        }
+    } else {
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::hard_link(src, dst)?;
+        count = 1;
+    }
+
+    Ok(count)
+}
+
+/// Whether `fs::hard_link` failed because `src` and `dst` live on different filesystems
+/// (`EXDEV` on Unix, `ERROR_NOT_SAME_DEVICE` on Windows) rather than some other failure.
+pub(crate) fn is_cross_device_error(e: &io::Error) -> bool {
+    #[cfg(unix)]
+    {
+        e.raw_os_error() == Some(18) // EXDEV
+    }
+    #[cfg(windows)]
+    {
+        e.raw_os_error() == Some(17) // ERROR_NOT_SAME_DEVICE
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = e;
+        false
+    }
+}
+
+/// Points `dst` at `src` instead of placing real files there. Returns whether a Windows
+/// junction was used in place of a symlink.
+fn symlink_path(src: &Path, dst: &Path) -> io::Result<bool> {
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(src, dst)?;
+        Ok(false)
+    }
+
+    #[cfg(windows)]
+    {
+        if src.is_dir() {
+            // Directory symlinks require `SeCreateSymbolicLinkPrivilege`, which most players
+            // don't have. NTFS junctions point at another directory just as well and don't
+            // need that privilege, so prefer them for directories.
+            fs::create_dir_all(dst)?;
+            junction::create(src, dst)?;
+            Ok(true)
+        } else {
+            std::os::windows::fs::symlink_file(src, dst)?;
+            Ok(false)
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (src, dst);
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Symlink install mode is not supported on this platform",
+        ))
+    }
+}
+
+/// Installs `src` at `dst` according to `mode`, falling back to `Copy` when `Hardlink` can't
+/// cross a filesystem boundary. Returns the number of files placed and whether a Windows
+/// junction was created (only possible for `Symlink` directories).
+fn install_entry(src: &Path, dst: &Path, mode: InstallMode) -> io::Result<(u64, bool)> {
+    match mode {
+        InstallMode::Copy => Ok((copy_recursive(src, dst)?, false)),
+        InstallMode::Hardlink => match hardlink_recursive(src, dst) {
+            Ok(count) => Ok((count, false)),
+            Err(e) if is_cross_device_error(&e) => {
+                remove_installed_path(dst).ok();
+                Ok((copy_recursive(src, dst)?, false))
+            }
+            Err(e) => Err(e),
+        },
+        InstallMode::Symlink => {
+            let is_junction = symlink_path(src, dst)?;
+            Ok((1, is_junction))
+        }
+    }
+}
+
+/// Removes whatever `install_entry` placed at `dst` without following a symlink/junction into
+/// the source tree it points at.
+fn remove_installed_path(dst: &Path) -> io::Result<()> {
+    let metadata = match fs::symlink_metadata(dst) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    if metadata.file_type().is_symlink() {
+        // A directory symlink (or Windows junction) must be unlinked, never walked into with
+        // `remove_dir_all` — that would delete the source pack's real files.
+        if cfg!(windows) && dst.is_dir() {
+            fs::remove_dir(dst)
+        } else {
+            fs::remove_file(dst)
+        }
+    } else if metadata.is_dir() {
+        fs::remove_dir_all(dst)
+    } else {
+        fs::remove_file(dst)
+    }
+}
+
+fn receipt_path(mod_name: &str) -> PathBuf {
+    get_receipts_dir().join(format!("{}.json", mod_name))
+}
+
+fn write_receipt(receipt: &InstallReceipt) -> Result<(), String> {
+    let receipts_dir = get_receipts_dir();
+    fs::create_dir_all(&receipts_dir)
+        .map_err(|e| format!("Failed to create receipts dir: {}", e))?;
+
+    let json = serde_json::to_string_pretty(receipt)
+        .map_err(|e| format!("Failed to serialize install receipt: {}", e))?;
+
+    fs::write(receipt_path(&receipt.mod_name), json)
+        .map_err(|e| format!("Failed to write install receipt: {}", e))
+}
+
+pub(crate) fn read_receipt(mod_name: &str) -> Result<Option<InstallReceipt>, String> {
+    let path = receipt_path(mod_name);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read install receipt: {}", e))?;
+
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse install receipt: {}", e))
+}
+
+fn remove_receipt(mod_name: &str) -> Result<(), String> {
+    let path = receipt_path(mod_name);
+
+    if path.exists() {
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove install receipt: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Removes `dir` if it's now empty, then does the same for its parent, walking up until a
+/// non-empty directory (or `stop_at`) is reached. Used after an uninstall deletes files so a
+/// mod-specific subfolder it created (e.g. `graphics/my-pack/`) doesn't linger empty, without
+/// touching shared parents like `graphics/` that other mods still populate.
+fn prune_empty_dirs(start: &Path, stop_at: &Path) {
+    let mut current = start.to_path_buf();
+
+    while current != stop_at && current.starts_with(stop_at) {
+        let Ok(mut entries) = fs::read_dir(&current) else {
+            break;
+        };
+
+        if entries.next().is_some() {
+            break;
+        }
+
+        if fs::remove_dir(&current).is_err() {
+            break;
+        }
+
+        match current.parent() {
+            Some(parent) => current = parent.to_path_buf(),
+            None => break,
+        }
+    }
+}
+
+/// Records that `mod_name` now owns each of `paths` in the persisted ownership index, so a
+/// later install into the same path is reported as a conflict instead of silently clobbering it.
+fn record_ownership(mod_name: &str, paths: &[PathBuf]) -> Result<(), String> {
+    let mut index = load_ownership_index()?;
+
+    for path in paths {
+        index
+            .owners
+            .insert(path.to_string_lossy().to_string(), mod_name.to_string());
+    }
+
+    save_ownership_index(&index)
+}
+
+/// Removes `mod_name`'s ownership of each of `paths`, but only where it's still the recorded
+/// owner — a path `mod_name` once owned but that a later install has since taken over is left
+/// alone, since releasing it here would incorrectly un-attribute the newer owner.
+fn release_ownership(mod_name: &str, paths: &[PathBuf]) -> Result<(), String> {
+    let mut index = load_ownership_index()?;
+
+    for path in paths {
+        let key = path.to_string_lossy().to_string();
+        if index.owners.get(&key).map(String::as_str) == Some(mod_name) {
+            index.owners.remove(&key);
+        }
+    }
+
+    save_ownership_index(&index)
+}
+
 pub fn get_target_for_type(mod_type: &str, game_target: &Path, user_dir: Option<&str>) -> PathBuf {
     let user_path = get_fm_user_dir(user_dir);
 
@@ -136,33 +639,57 @@ pub fn get_target_for_type(mod_type: &str, game_target: &Path, user_dir: Option<
 }
 
 pub fn preview_mod_install(
+    mod_name: &str,
     mod_type: &str,
     game_target: &Path,
     user_dir: Option<&str>,
     files: &[FileEntry],
 ) -> ModInstallPreview {
     let base_target = get_target_for_type(mod_type, game_target, user_dir);
-    let resolved_files = files
-        .iter()
-        .map(|file| ResolvedFilePreview {
+    let ownership_index = load_ownership_index().unwrap_or_default();
+
+    let mut resolved_files = Vec::with_capacity(files.len());
+    let mut conflicts = Vec::with_capacity(files.len());
+
+    for file in files {
+        let resolved_path = base_target.join(&file.target_subpath);
+        let resolved_path_str = resolved_path.to_string_lossy().to_string();
+
+        let conflict = match ownership_index.owners.get(&resolved_path_str) {
+            Some(owner) if owner != mod_name => {
+                FileOwnershipConflict::OwnedByOtherMod(owner.clone())
+            }
+            Some(_) => FileOwnershipConflict::None,
+            None if resolved_path.exists() => FileOwnershipConflict::ExistsUnowned,
+            None => FileOwnershipConflict::None,
+        };
+
+        resolved_files.push(ResolvedFilePreview {
             target_subpath: file.target_subpath.clone(),
-            resolved_path: base_target
-                .join(&file.target_subpath)
-                .to_string_lossy()
-                .to_string(),
-        })
-        .collect();
+            resolved_path: resolved_path_str,
+        });
+        conflicts.push(conflict);
+    }
 
     ModInstallPreview {
         base_target: base_target.to_string_lossy().to_string(),
         resolved_files,
+        conflicts,
     }
 }
 
+/// Installs `mod_name`'s files, all-or-nothing: if any file fails to copy/link/symlink, every
+/// operation completed so far this call (including the failing entry's own backup, if it took
+/// one) is undone in reverse order via [`rollback_entries`] before the error is returned, so the
+/// game folder is left exactly as it was found rather than half-modded.
 pub fn install_mod(
     mod_name: &str,
     game_target: &Path,
     user_dir: Option<&str>,
+    default_install_mode: InstallMode,
+    backup_mode: BackupMode,
+    backup_compression: BackupCompression,
+    backup_compression_level: u32,
 ) -> Result<String, String> {
     let mod_dir = get_mods_dir().join(mod_name);
 
@@ -179,14 +706,13 @@ pub fn install_mod(
 
     let mut installed_count = 0;
     let current_platform = get_current_platform();
+    let mut receipt_entries = Vec::new();
 
     for file_entry in &manifest.files {
-        // Skip files that don't match the current platform
-        if let Some(ref platform) = file_entry.platform {
-            if platform != &current_platform {
-                continue;
-            }
-        }
+        let Some(dst) = resolve_install_path(file_entry, &target_base, &current_platform) else {
+            // Tagged for a platform other than the one we're installing on.
+            continue;
+        };
 
         let src = mod_dir.join(&file_entry.source);
 
@@ -194,18 +720,59 @@ pub fn install_mod(
             continue;
         }
 
-        let dst = target_base.join(&file_entry.target_subpath);
-
         // Backup existing file
-        if dst.exists() {
-            backup_file(&dst)?;
+        let overwrote_existing = dst.exists();
+        let backup_path = if overwrote_existing {
+            backup_file(&dst, backup_mode, backup_compression, backup_compression_level)?
+        } else {
+            None
+        };
+
+        let mode = file_entry.install_mode.unwrap_or(default_install_mode);
+
+        // Copy, hardlink, or symlink the file/directory, per `mode`
+        match install_entry(&src, &dst, mode) {
+            Ok((count, _is_junction)) => installed_count += count,
+            Err(e) => {
+                // Roll back this entry too: its backup (if any) may have been taken but the
+                // copy itself left `dst` partially written or untouched.
+                receipt_entries.push(InstallReceiptEntry {
+                    target_subpath: file_entry.target_subpath.clone(),
+                    resolved_path: dst,
+                    overwrote_existing,
+                    backup_path,
+                });
+                rollback_entries(&receipt_entries, &target_base).ok();
+                return Err(format!("Failed to install file: {}", e));
+            }
         }
 
-        // Copy file or directory
-        match copy_recursive(&src, &dst) {
-            Ok(count) => installed_count += count,
-            Err(e) => return Err(format!("Failed to install file: {}", e)),
-        }
+        receipt_entries.push(InstallReceiptEntry {
+            target_subpath: file_entry.target_subpath.clone(),
+            resolved_path: dst,
+            overwrote_existing,
+            backup_path,
+        });
+    }
+
+    let installed_paths: Vec<PathBuf> = receipt_entries
+        .iter()
+        .map(|entry| entry.resolved_path.clone())
+        .collect();
+
+    let receipt = InstallReceipt {
+        mod_name: mod_name.to_string(),
+        target_base,
+        entries: receipt_entries,
+        enabled: true,
+    };
+
+    write_receipt(&receipt)?;
+
+    if let Err(e) = record_ownership(mod_name, &installed_paths) {
+        rollback_entries(&receipt.entries, &receipt.target_base).ok();
+        remove_receipt(mod_name).ok();
+        return Err(e);
     }
 
     Ok(format!(
@@ -214,6 +781,259 @@ pub fn install_mod(
     ))
 }
 
+/// Enables a set of mods together as one transactional unit: resolve every conflict between
+/// them up front, stage each mod's files into a scratch directory to prove the batch can be
+/// written at all, then commit every mod's files into `game_target` in `mod_names` order (so
+/// last-enabled-wins, matching [`crate::conflicts::resolve_conflicts`]'s policy, falls out of
+/// plain sequential overwriting). If committing a later mod fails, every mod already committed
+/// in this batch is rolled back via its receipt, so a bad mod can't leave the game half-patched.
+///
+/// Unlike repeated calls to [`install_mod`], a conflict that [`crate::conflicts::resolve_conflicts`]
+/// can't assign a winner to aborts the whole batch before anything is written, surfaced as
+/// [`crate::messages::CODE_CONFLICT_UNRESOLVED`].
+pub fn install_mods_batch(
+    mod_names: &[String],
+    game_target: &Path,
+    user_dir: Option<&str>,
+    default_install_mode: InstallMode,
+    backup_mode: BackupMode,
+    backup_compression: BackupCompression,
+    backup_compression_level: u32,
+) -> Result<String, String> {
+    if mod_names.is_empty() {
+        return Err("No mods selected to install".to_string());
+    }
+
+    let conflicts = crate::conflicts::find_conflicts(mod_names, &game_target.to_path_buf(), user_dir)?;
+    let resolutions = crate::conflicts::resolve_conflicts(&conflicts, mod_names);
+    let divergent_count = conflicts
+        .iter()
+        .filter(|c| c.classification == ConflictClassification::Divergent)
+        .count();
+
+    if resolutions.len() != divergent_count {
+        return Err(code_error(
+            CODE_CONFLICT_UNRESOLVED,
+            format!(
+                "{} of {} divergent conflicts between {} could not be resolved by load order",
+                divergent_count - resolutions.len(),
+                divergent_count,
+                mod_names.join(", ")
+            ),
+        ));
+    }
+
+    let batch_id = format!("batch_{}", Local::now().format("%Y%m%d%H%M%S%3f"));
+    let staging_root = get_staging_dir().join(&batch_id);
+    fs::create_dir_all(&staging_root)
+        .map_err(|e| format!("Failed to create staging dir: {}", e))?;
+
+    let stage_result = stage_batch(mod_names, game_target, user_dir, &staging_root);
+    let staged = match stage_result {
+        Ok(staged) => staged,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging_root);
+            return Err(e);
+        }
+    };
+
+    let commit_result = commit_staged_batch(
+        &staged,
+        &staging_root,
+        default_install_mode,
+        backup_mode,
+        backup_compression,
+        backup_compression_level,
+    );
+    let _ = fs::remove_dir_all(&staging_root);
+
+    match commit_result {
+        Ok((committed, summaries)) => Ok(format!(
+            "Installed {} mods: {}",
+            committed.len(),
+            summaries.join(", ")
+        )),
+        Err((committed, e)) => {
+            for receipt in committed.iter().rev() {
+                uninstall_from_receipt(receipt).ok();
+            }
+            Err(e)
+        }
+    }
+}
+
+/// First phase of [`install_mods_batch`]: reads every mod's manifest and copies its files into
+/// `staging_root/<mod_name>/<target_subpath>`, proving every source file is readable and can be
+/// written out before the real game target is touched. Returns each mod's manifest and
+/// resolved target base for [`commit_staged_batch`] to replay.
+fn stage_batch(
+    mod_names: &[String],
+    game_target: &Path,
+    user_dir: Option<&str>,
+    staging_root: &Path,
+) -> Result<Vec<(String, ModManifest, PathBuf)>, String> {
+    let mut staged = Vec::with_capacity(mod_names.len());
+
+    for mod_name in mod_names {
+        let mod_dir = get_mods_dir().join(mod_name);
+
+        if !mod_dir.exists() {
+            return Err(format!("Mod not found: {}", mod_name));
+        }
+
+        let manifest = read_manifest(&mod_dir)?;
+
+        if manifest.files.is_empty() {
+            return Err(format!("Mod {} has no files to install", mod_name));
+        }
+
+        let target_base = get_target_for_type(&manifest.mod_type, game_target, user_dir);
+        let mod_staging = staging_root.join(mod_name);
+
+        for file_entry in &manifest.files {
+            let src = mod_dir.join(&file_entry.source);
+
+            if !src.exists() {
+                continue;
+            }
+
+            let staged_path = mod_staging.join(&file_entry.target_subpath);
+            copy_recursive(&src, &staged_path)
+                .map_err(|e| format!("Failed to stage {} for {}: {}", file_entry.target_subpath, mod_name, e))?;
+        }
+
+        staged.push((mod_name.clone(), manifest, target_base));
+    }
+
+    Ok(staged)
+}
+
+/// Second phase of [`install_mods_batch`]: installs each mod's already-validated staged files at
+/// their real resolved destinations under `game_target` per each file's install mode, backing up
+/// whatever they overwrite just like [`install_mod`]. Mods are committed in order, so a later mod
+/// in the batch naturally wins any shared path. Each mod's receipt is written and its ownership
+/// recorded as it commits. On success returns every committed receipt and a human-readable
+/// summary per mod; on failure returns the receipts already committed, for the caller to roll back.
+#[allow(clippy::type_complexity)]
+fn commit_staged_batch(
+    staged: &[(String, ModManifest, PathBuf)],
+    staging_root: &Path,
+    default_install_mode: InstallMode,
+    backup_mode: BackupMode,
+    backup_compression: BackupCompression,
+    backup_compression_level: u32,
+) -> Result<(Vec<InstallReceipt>, Vec<String>), (Vec<InstallReceipt>, String)> {
+    let current_platform = get_current_platform();
+    let mut committed = Vec::with_capacity(staged.len());
+    let mut summaries = Vec::with_capacity(staged.len());
+
+    for (mod_name, manifest, target_base) in staged {
+        let mod_staging = staging_root.join(mod_name);
+        let mut receipt_entries = Vec::new();
+        let mut installed_count = 0u64;
+
+        for file_entry in &manifest.files {
+            let Some(dst) = resolve_install_path(file_entry, target_base, &current_platform) else {
+                continue;
+            };
+
+            let staged_path = mod_staging.join(&file_entry.target_subpath);
+            if !staged_path.exists() {
+                continue;
+            }
+
+            let overwrote_existing = dst.exists();
+            let backup_path = if overwrote_existing {
+                match backup_file(&dst, backup_mode, backup_compression, backup_compression_level) {
+                    Ok(path) => path,
+                    Err(e) => return Err((committed, e)),
+                }
+            } else {
+                None
+            };
+
+            let mode = file_entry.install_mode.unwrap_or(default_install_mode);
+            // The staging directory is removed once the batch commits, so a symlink points at
+            // the mod's own copy of the file instead.
+            let install_src = if mode == InstallMode::Symlink {
+                get_mods_dir().join(mod_name).join(&file_entry.source)
+            } else {
+                staged_path.clone()
+            };
+
+            match install_entry(&install_src, &dst, mode) {
+                Ok((count, _is_junction)) => installed_count += count,
+                Err(e) => {
+                    receipt_entries.push(InstallReceiptEntry {
+                        target_subpath: file_entry.target_subpath.clone(),
+                        resolved_path: dst,
+                        overwrote_existing,
+                        backup_path,
+                    });
+                    rollback_entries(&receipt_entries, target_base).ok();
+                    return Err((
+                        committed,
+                        format!("Failed to install file for {}: {}", mod_name, e),
+                    ));
+                }
+            }
+
+            receipt_entries.push(InstallReceiptEntry {
+                target_subpath: file_entry.target_subpath.clone(),
+                resolved_path: dst,
+                overwrote_existing,
+                backup_path,
+            });
+        }
+
+        let installed_paths: Vec<PathBuf> = receipt_entries
+            .iter()
+            .map(|entry| entry.resolved_path.clone())
+            .collect();
+
+        let receipt = InstallReceipt {
+            mod_name: mod_name.clone(),
+            target_base: target_base.clone(),
+            entries: receipt_entries,
+            enabled: true,
+        };
+
+        if let Err(e) = write_receipt(&receipt) {
+            rollback_entries(&receipt.entries, target_base).ok();
+            remove_receipt(mod_name).ok();
+            return Err((committed, e));
+        }
+        if let Err(e) = record_ownership(mod_name, &installed_paths) {
+            rollback_entries(&receipt.entries, target_base).ok();
+            remove_receipt(mod_name).ok();
+            return Err((committed, e));
+        }
+
+        summaries.push(format!("{} ({} files)", mod_name, installed_count));
+        committed.push(receipt);
+    }
+
+    Ok((committed, summaries))
+}
+
+/// Resolves `file`'s destination under `target_base` for `current_os`, or `None` if `file` is
+/// tagged for a different platform and should be skipped on this install. `current_os` is
+/// taken as a parameter (rather than read via `cfg`/`get_current_platform` internally) so
+/// cross-platform resolution can be exercised in tests without compiling for every target.
+pub fn resolve_install_path(
+    file: &FileEntry,
+    target_base: &Path,
+    current_os: &str,
+) -> Option<PathBuf> {
+    if let Some(ref platform) = file.platform {
+        if platform != current_os {
+            return None;
+        }
+    }
+
+    Some(target_base.join(&file.target_subpath))
+}
+
 /// Get the current platform identifier
 fn get_current_platform() -> String {
     #[cfg(target_os = "windows")]
@@ -234,6 +1054,102 @@ fn get_current_platform() -> String {
     }
 }
 
+/// Toggles `mod_name` between installed-live and staged-off without touching its receipt's
+/// bookkeeping of what it overwrote, so re-enabling doesn't need to re-resolve or re-hash
+/// anything — it's a move back from [`crate::config::get_staging_dir`]. Mirrors FlightCore's
+/// `enabledmods.json`, except the toggle here takes effect immediately instead of waiting for
+/// a later "apply" pass.
+///
+/// Requires an install receipt, so a mod installed before receipts existed (or never
+/// installed) must be (re)installed first; there is no prior-manifest fallback like
+/// [`uninstall_mod`]'s, since staging a move needs to know exactly what to move.
+pub fn set_mod_enabled(
+    mod_name: &str,
+    enabled: bool,
+    backup_mode: BackupMode,
+    backup_compression: BackupCompression,
+    backup_compression_level: u32,
+) -> Result<String, String> {
+    let mut receipt = read_receipt(mod_name)?
+        .ok_or_else(|| format!("{} has no install receipt; reinstall it first", mod_name))?;
+
+    if receipt.enabled == enabled {
+        return Ok(format!(
+            "{} is already {}",
+            mod_name,
+            if enabled { "enabled" } else { "disabled" }
+        ));
+    }
+
+    let staging_root = get_staging_dir().join(mod_name);
+
+    if enabled {
+        for entry in &receipt.entries {
+            let staged_path = staging_root.join(&entry.target_subpath);
+            if !staged_path.exists() {
+                continue;
+            }
+
+            if entry.overwrote_existing {
+                // Whatever the original file's backup restored to when we disabled now needs
+                // backing up again before this mod's file goes back over it.
+                backup_file(&entry.resolved_path, backup_mode, backup_compression, backup_compression_level)?;
+            }
+
+            move_path(&staged_path, &entry.resolved_path).map_err(|e| {
+                format!("Failed to restore {:?} from staging: {}", entry.resolved_path, e)
+            })?;
+        }
+
+        let owned_paths: Vec<PathBuf> = receipt
+            .entries
+            .iter()
+            .map(|entry| entry.resolved_path.clone())
+            .collect();
+        record_ownership(mod_name, &owned_paths)?;
+    } else {
+        for entry in &receipt.entries {
+            if fs::symlink_metadata(&entry.resolved_path).is_err() {
+                continue;
+            }
+
+            let staged_path = staging_root.join(&entry.target_subpath);
+            move_path(&entry.resolved_path, &staged_path)
+                .map_err(|e| format!("Failed to stage {:?}: {}", entry.resolved_path, e))?;
+
+            if entry.overwrote_existing {
+                if let Some(backup_path) = &entry.backup_path {
+                    if backup_path.exists() {
+                        if let Some(parent) = entry.resolved_path.parent() {
+                            fs::create_dir_all(parent)
+                                .map_err(|e| format!("Failed to recreate {:?}: {}", parent, e))?;
+                        }
+                        restore_file_from_backup(backup_path, &entry.resolved_path)?;
+                    }
+                }
+            } else if let Some(parent) = entry.resolved_path.parent() {
+                prune_empty_dirs(parent, &receipt.target_base);
+            }
+        }
+
+        let owned_paths: Vec<PathBuf> = receipt
+            .entries
+            .iter()
+            .map(|entry| entry.resolved_path.clone())
+            .collect();
+        release_ownership(mod_name, &owned_paths)?;
+    }
+
+    receipt.enabled = enabled;
+    write_receipt(&receipt)?;
+
+    Ok(format!(
+        "{} {}",
+        mod_name,
+        if enabled { "enabled" } else { "disabled" }
+    ))
+}
+
 #[allow(dead_code)]
 pub fn uninstall_mod(
     mod_name: &str,
@@ -246,44 +1162,99 @@ pub fn uninstall_mod(
         return Err(format!("Mod not found: {}", mod_name));
     }
 
-    let manifest = read_manifest(&mod_dir)?;
-    let target_base = get_target_for_type(&manifest.mod_type, game_target, user_dir);
-
-    let mut removed_count = 0;
+    match read_receipt(mod_name)? {
+        Some(receipt) => uninstall_from_receipt(&receipt),
+        // Mod was installed before install receipts existed. Fall back to the old
+        // manifest-walk behavior rather than refusing to uninstall it.
+        None => {
+            let manifest = read_manifest(&mod_dir)?;
+            let target_base = get_target_for_type(&manifest.mod_type, game_target, user_dir);
+
+            let mut removed_count = 0;
+            let mut removed_paths = Vec::new();
+
+            for file_entry in &manifest.files {
+                let dst = target_base.join(&file_entry.target_subpath);
+
+                if fs::symlink_metadata(&dst).is_ok() {
+                    remove_installed_path(&dst)
+                        .map_err(|e| format!("Failed to remove {:?}: {}", dst, e))?;
+                    removed_count += 1;
+                    removed_paths.push(dst);
+                }
+            }
 
-    for file_entry in &manifest.files {
-        let dst = target_base.join(&file_entry.target_subpath);
+            release_ownership(mod_name, &removed_paths)?;
 
-        if dst.exists() {
-            if dst.is_dir() {
-                fs::remove_dir_all(&dst)
-                    .map_err(|e| format!("Failed to remove directory: {}", e))?;
-            } else {
-                fs::remove_file(&dst).map_err(|e| format!("Failed to remove file: {}", e))?;
-            }
-            removed_count += 1;
+            Ok(format!(
+                "Uninstalled {} - removed {} items",
+                mod_name, removed_count
+            ))
         }
     }
+}
+
+/// Undoes each entry of an [`InstallReceipt`] by delegating to [`rollback_entries`], then
+/// removes the receipt itself since the mod is no longer installed.
+fn uninstall_from_receipt(receipt: &InstallReceipt) -> Result<String, String> {
+    let (removed_count, restored_count) = rollback_entries(&receipt.entries, &receipt.target_base)?;
+
+    remove_receipt(&receipt.mod_name)?;
+
+    let owned_paths: Vec<PathBuf> = receipt
+        .entries
+        .iter()
+        .map(|entry| entry.resolved_path.clone())
+        .collect();
+    release_ownership(&receipt.mod_name, &owned_paths)?;
 
     Ok(format!(
-        "Uninstalled {} - removed {} items",
-        mod_name, removed_count
+        "Uninstalled {} - removed {} items, restored {} items",
+        receipt.mod_name, removed_count, restored_count
     ))
 }
 
-#[allow(dead_code)]
-pub fn create_restore_point(name: &str) -> Result<PathBuf, String> {
-    let restore_dir = get_restore_points_dir();
-    fs::create_dir_all(&restore_dir)
-        .map_err(|e| format!("Failed to create restore points dir: {}", e))?;
+/// Undoes `entries` in reverse order: deletes what each created, or restores the backup over
+/// what it overwrote. Only prunes directories left empty afterward, so shared target folders
+/// (e.g. `graphics/`) that still hold other mods' files are left alone. Shared by explicit
+/// uninstall ([`uninstall_from_receipt`]) and by [`install_mod`]'s rollback of a partially
+/// completed install, so both undo paths behave identically. Returns `(removed_count,
+/// restored_count)`.
+fn rollback_entries(
+    entries: &[InstallReceiptEntry],
+    target_base: &Path,
+) -> Result<(usize, usize), String> {
+    let mut restored_count = 0;
+    let mut removed_count = 0;
 
-    let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
-    let point_name = format!("{}_{}", timestamp, name);
-    let point_dir = restore_dir.join(&point_name);
+    for entry in entries.iter().rev() {
+        if entry.overwrote_existing {
+            match &entry.backup_path {
+                Some(backup_path) if backup_path.exists() => {
+                    if let Some(parent) = entry.resolved_path.parent() {
+                        fs::create_dir_all(parent)
+                            .map_err(|e| format!("Failed to recreate {:?}: {}", parent, e))?;
+                    }
+                    restore_file_from_backup(backup_path, &entry.resolved_path)?;
+                    restored_count += 1;
+                }
+                _ => {
+                    // Backup is missing; nothing safe to restore, so leave the file in place
+                    // rather than deleting content we didn't create.
+                }
+            }
+        } else if fs::symlink_metadata(&entry.resolved_path).is_ok() {
+            remove_installed_path(&entry.resolved_path)
+                .map_err(|e| format!("Failed to remove {:?}: {}", entry.resolved_path, e))?;
+            removed_count += 1;
 
-    fs::create_dir_all(&point_dir).map_err(|e| format!("Failed to create restore point: {}", e))?;
+            if let Some(parent) = entry.resolved_path.parent() {
+                prune_empty_dirs(parent, target_base);
+            }
+        }
+    }
 
-    Ok(point_dir)
+    Ok((removed_count, restored_count))
 }
 
 pub fn cleanup_old_backups(keep: usize) -> Result<(), String> {
@@ -296,7 +1267,10 @@ pub fn cleanup_old_backups(keep: usize) -> Result<(), String> {
     let mut backups: Vec<_> = fs::read_dir(&backup_dir)
         .map_err(|e| format!("Failed to read backup dir: {}", e))?
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file())
+        .filter(|e| {
+            e.path().is_file()
+                && e.path().extension().and_then(|x| x.to_str()) != Some("sha256")
+        })
         .collect();
 
     backups.sort_by_key(|e| {
@@ -309,11 +1283,16 @@ pub fn cleanup_old_backups(keep: usize) -> Result<(), String> {
 
     for old_backup in backups.iter().skip(keep) {
         let _ = fs::remove_file(old_backup.path());
+        let _ = fs::remove_file(backup_hash_sidecar(&old_backup.path()));
     }
 
     Ok(())
 }
 
+/// Prunes restore point directories beyond `keep`, newest-first, then garbage-collects any
+/// content blob no longer referenced by a surviving point. `blobs/` itself — the shared,
+/// content-addressed store every restore point's files live in — is never a candidate for
+/// pruning; it must only ever be emptied entry-by-entry via [`crate::restore::gc_unreferenced_blobs`].
 pub fn cleanup_old_restore_points(keep: usize) -> Result<(), String> {
     let restore_dir = get_restore_points_dir();
 
@@ -324,7 +1303,7 @@ pub fn cleanup_old_restore_points(keep: usize) -> Result<(), String> {
     let mut points: Vec<_> = fs::read_dir(&restore_dir)
         .map_err(|e| format!("Failed to read restore points dir: {}", e))?
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_dir())
+        .filter(|e| e.path().is_dir() && e.file_name() != "blobs")
         .collect();
 
     points.sort_by_key(|e| {
@@ -339,6 +1318,8 @@ pub fn cleanup_old_restore_points(keep: usize) -> Result<(), String> {
         let _ = fs::remove_dir_all(old_point.path());
     }
 
+    crate::restore::gc_unreferenced_blobs()?;
+
     Ok(())
 }
 
@@ -358,6 +1339,294 @@ mod tests {
         path
     }
 
+    /// Points `get_app_data_dir` at a fresh temp dir for the duration of a test, so
+    /// `install_mod`/`uninstall_mod` (which resolve backups/receipts under it) don't touch the
+    /// real app data directory. Caller must remove the returned dir and the env var afterward.
+    fn set_test_appdata() -> PathBuf {
+        let base = unique_temp_dir();
+        std::env::set_var("FMML_TEST_APPDATA", &base);
+        base
+    }
+
+    fn write_test_mod(mods_root: &Path, mod_name: &str, files: &[(&str, &[u8])]) -> PathBuf {
+        let mod_dir = mods_root.join(mod_name);
+        fs::create_dir_all(&mod_dir).expect("create mod dir");
+
+        let mut entries = Vec::new();
+        for (subpath, contents) in files {
+            let src_path = mod_dir.join(subpath);
+            if let Some(parent) = src_path.parent() {
+                fs::create_dir_all(parent).expect("create mod source parent");
+            }
+            fs::write(&src_path, contents).expect("write mod source file");
+            entries.push(FileEntry {
+                source: subpath.to_string(),
+                target_subpath: subpath.to_string(),
+                platform: None,
+                install_mode: None,
+                sha256: None,
+            });
+        }
+
+        let manifest = ModManifest {
+            name: mod_name.to_string(),
+            schema_version: CURRENT_MANIFEST_SCHEMA_VERSION,
+            version: String::new(),
+            mod_type: "bundle".to_string(),
+            author: String::new(),
+            homepage: String::new(),
+            description: String::new(),
+            license: String::new(),
+            compatibility: Compatibility::default(),
+            dependencies: Vec::new(),
+            conflicts: Vec::new(),
+            load_after: Vec::new(),
+            files: entries,
+            source_type: None,
+        };
+
+        fs::write(
+            mod_dir.join("manifest.json"),
+            serde_json::to_string(&manifest).expect("serialize manifest"),
+        )
+        .expect("write manifest");
+
+        mod_dir
+    }
+
+    #[test]
+    fn test_install_mod_writes_receipt_marking_created_and_overwritten_files() {
+        let base = set_test_appdata();
+        let game_target = base.join("game");
+        fs::create_dir_all(&game_target).expect("create game target");
+
+        // Pre-existing file that the mod will overwrite.
+        fs::write(game_target.join("existing.txt"), b"original").expect("seed existing file");
+
+        write_test_mod(
+            &get_mods_dir(),
+            "ReceiptMod",
+            &[
+                ("existing.txt", b"from mod" as &[u8]),
+                ("new.txt", b"brand new" as &[u8]),
+            ],
+        );
+
+        install_mod(
+            "ReceiptMod",
+            &game_target,
+            None,
+            InstallMode::Copy,
+            BackupMode::default(),
+            BackupCompression::default(),
+            0,
+        )
+        .expect("install_mod should succeed");
+
+        let receipt = read_receipt("ReceiptMod")
+            .expect("read_receipt should succeed")
+            .expect("receipt should exist after install");
+
+        assert_eq!(receipt.entries.len(), 2);
+
+        let existing_entry = receipt
+            .entries
+            .iter()
+            .find(|e| e.target_subpath == "existing.txt")
+            .expect("existing.txt entry present");
+        assert!(existing_entry.overwrote_existing);
+        assert!(existing_entry.backup_path.is_some());
+
+        let new_entry = receipt
+            .entries
+            .iter()
+            .find(|e| e.target_subpath == "new.txt")
+            .expect("new.txt entry present");
+        assert!(!new_entry.overwrote_existing);
+        assert!(new_entry.backup_path.is_none());
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_set_mod_enabled_stages_and_restores_files_without_losing_the_receipt() {
+        let base = set_test_appdata();
+        let game_target = base.join("game");
+        fs::create_dir_all(&game_target).expect("create game target");
+
+        fs::write(game_target.join("existing.txt"), b"original").expect("seed existing file");
+
+        write_test_mod(
+            &get_mods_dir(),
+            "ToggleMod",
+            &[
+                ("existing.txt", b"from mod" as &[u8]),
+                ("new.txt", b"brand new" as &[u8]),
+            ],
+        );
+
+        install_mod(
+            "ToggleMod",
+            &game_target,
+            None,
+            InstallMode::Copy,
+            BackupMode::default(),
+            BackupCompression::default(),
+            0,
+        )
+        .expect("install_mod should succeed");
+
+        set_mod_enabled("ToggleMod", false, BackupMode::default(), BackupCompression::default(), 0)
+            .expect("disabling should succeed");
+
+        assert_eq!(
+            fs::read(game_target.join("existing.txt")).expect("read restored original"),
+            b"original"
+        );
+        assert!(!game_target.join("new.txt").exists());
+        assert!(!read_receipt("ToggleMod")
+            .expect("read_receipt should succeed")
+            .expect("receipt should survive disabling")
+            .enabled);
+
+        set_mod_enabled("ToggleMod", true, BackupMode::default(), BackupCompression::default(), 0)
+            .expect("re-enabling should succeed");
+
+        assert_eq!(
+            fs::read(game_target.join("existing.txt")).expect("read mod's file again"),
+            b"from mod"
+        );
+        assert_eq!(
+            fs::read(game_target.join("new.txt")).expect("read recreated new.txt"),
+            b"brand new"
+        );
+        assert!(read_receipt("ToggleMod")
+            .expect("read_receipt should succeed")
+            .expect("receipt should still exist")
+            .enabled);
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_uninstall_mod_restores_overwritten_file_and_removes_created_file() {
+        let base = set_test_appdata();
+        let game_target = base.join("game");
+        fs::create_dir_all(&game_target).expect("create game target");
+
+        fs::write(game_target.join("existing.txt"), b"original").expect("seed existing file");
+
+        write_test_mod(
+            &get_mods_dir(),
+            "RollbackMod",
+            &[
+                ("existing.txt", b"from mod" as &[u8]),
+                ("sub/new.txt", b"brand new" as &[u8]),
+            ],
+        );
+
+        install_mod(
+            "RollbackMod",
+            &game_target,
+            None,
+            InstallMode::Copy,
+            BackupMode::default(),
+            BackupCompression::default(),
+            0,
+        )
+        .expect("install_mod should succeed");
+
+        uninstall_mod("RollbackMod", &game_target, None).expect("uninstall_mod should succeed");
+
+        assert_eq!(
+            fs::read(game_target.join("existing.txt")).expect("read restored file"),
+            b"original"
+        );
+        assert!(!game_target.join("sub/new.txt").exists());
+        assert!(!game_target.join("sub").exists());
+        assert!(read_receipt("RollbackMod")
+            .expect("read_receipt should succeed")
+            .is_none());
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_install_mod_rolls_back_completed_operations_when_a_later_file_fails() {
+        let base = set_test_appdata();
+        let game_target = base.join("game");
+        fs::create_dir_all(&game_target).expect("create game target");
+
+        // Pre-existing file the mod will overwrite and that rollback must restore.
+        fs::write(game_target.join("a.txt"), b"original a").expect("seed existing file");
+        // A regular file sitting where the second entry needs a directory, so its copy fails.
+        fs::write(game_target.join("b"), b"blocking file").expect("seed blocking file");
+
+        write_test_mod(
+            &get_mods_dir(),
+            "PartialFailMod",
+            &[
+                ("a.txt", b"from mod" as &[u8]),
+                ("b/c.txt", b"never lands" as &[u8]),
+            ],
+        );
+
+        let result = install_mod(
+            "PartialFailMod",
+            &game_target,
+            None,
+            InstallMode::Copy,
+            BackupMode::default(),
+            BackupCompression::default(),
+            0,
+        );
+        assert!(result.is_err());
+
+        assert_eq!(
+            fs::read(game_target.join("a.txt")).expect("read a.txt after rollback"),
+            b"original a"
+        );
+        assert_eq!(
+            fs::read(game_target.join("b")).expect("read blocking file"),
+            b"blocking file"
+        );
+        assert!(read_receipt("PartialFailMod")
+            .expect("read_receipt should succeed")
+            .is_none());
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_uninstall_mod_without_receipt_falls_back_to_manifest_walk() {
+        let base = set_test_appdata();
+        let game_target = base.join("game");
+        fs::create_dir_all(&game_target).expect("create game target");
+
+        let mod_dir = write_test_mod(
+            &get_mods_dir(),
+            "LegacyMod",
+            &[("legacy.txt", b"legacy contents" as &[u8])],
+        );
+        let manifest = read_manifest(&mod_dir).expect("read manifest");
+        let dst = game_target.join("legacy.txt");
+        fs::copy(mod_dir.join("legacy.txt"), &dst).expect("simulate legacy install");
+        assert_eq!(manifest.files.len(), 1);
+
+        let result =
+            uninstall_mod("LegacyMod", &game_target, None).expect("uninstall_mod should succeed");
+
+        assert_eq!(result, "Uninstalled LegacyMod - removed 1 items");
+        assert!(!dst.exists());
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
     #[test]
     fn test_get_current_platform() {
         let platform = get_current_platform();
@@ -382,6 +1651,43 @@ mod tests {
         assert_eq!(platform, "linux");
     }
 
+    #[test]
+    fn test_resolve_install_path_skips_other_platform() {
+        let target_base = PathBuf::from("/game/target");
+        let file = FileEntry {
+            source: "macos/test.bundle".to_string(),
+            target_subpath: "test.bundle".to_string(),
+            platform: Some("macos".to_string()),
+            install_mode: None,
+            sha256: None,
+        };
+
+        assert_eq!(resolve_install_path(&file, &target_base, "windows"), None);
+        assert_eq!(
+            resolve_install_path(&file, &target_base, "macos"),
+            Some(target_base.join("test.bundle"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_install_path_platformless_file_installs_everywhere() {
+        let target_base = PathBuf::from("/game/target");
+        let file = FileEntry {
+            source: "test.bundle".to_string(),
+            target_subpath: "test.bundle".to_string(),
+            platform: None,
+            install_mode: None,
+            sha256: None,
+        };
+
+        for os in ["windows", "macos", "linux"] {
+            assert_eq!(
+                resolve_install_path(&file, &target_base, os),
+                Some(target_base.join("test.bundle"))
+            );
+        }
+    }
+
     #[test]
     fn test_get_target_for_type_skins() {
         let game_target = PathBuf::from("/test/game/path");
@@ -449,6 +1755,7 @@ mod tests {
 
     #[test]
     fn test_preview_mod_install_maps_paths() {
+        let appdata = set_test_appdata();
         let game_target = PathBuf::from("/test/game/path");
         let user_dir = unique_temp_dir();
         let user_dir_str = user_dir.to_string_lossy().to_string();
@@ -458,21 +1765,35 @@ mod tests {
                 source: "src/file1".to_string(),
                 target_subpath: "graphics/faces/config.xml".to_string(),
                 platform: None,
+                install_mode: None,
+                sha256: None,
             },
             FileEntry {
                 source: "src/file2".to_string(),
                 target_subpath: "graphics/faces/face.png".to_string(),
                 platform: None,
+                install_mode: None,
+                sha256: None,
             },
         ];
 
-        let preview = preview_mod_install("graphics", &game_target, Some(&user_dir_str), &files);
+        let preview = preview_mod_install(
+            "SomeMod",
+            "graphics",
+            &game_target,
+            Some(&user_dir_str),
+            &files,
+        );
 
         assert_eq!(
             preview.base_target,
             user_dir.join("graphics").to_string_lossy().to_string()
         );
         assert_eq!(preview.resolved_files.len(), 2);
+        assert_eq!(preview.conflicts, vec![
+            FileOwnershipConflict::None,
+            FileOwnershipConflict::None,
+        ]);
         assert_eq!(
             preview.resolved_files[0].resolved_path,
             user_dir
@@ -483,5 +1804,98 @@ mod tests {
         );
 
         let _ = std::fs::remove_dir_all(&user_dir);
+        let _ = fs::remove_dir_all(&appdata);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_preview_mod_install_flags_conflicts_with_other_owned_and_unowned_existing_files() {
+        let appdata = set_test_appdata();
+        let game_target = appdata.join("game");
+        fs::create_dir_all(&game_target).expect("create game target");
+
+        // `owned.txt` was previously installed by "OtherMod"; `unowned.txt` exists on disk but
+        // was never tracked by any mod's install.
+        fs::write(game_target.join("owned.txt"), b"other mod's content")
+            .expect("seed owned file");
+        fs::write(game_target.join("unowned.txt"), b"pre-existing content")
+            .expect("seed unowned file");
+        record_ownership("OtherMod", &[game_target.join("owned.txt")])
+            .expect("record_ownership should succeed");
+
+        let files = vec![
+            FileEntry {
+                source: "owned.txt".to_string(),
+                target_subpath: "owned.txt".to_string(),
+                platform: None,
+                install_mode: None,
+                sha256: None,
+            },
+            FileEntry {
+                source: "unowned.txt".to_string(),
+                target_subpath: "unowned.txt".to_string(),
+                platform: None,
+                install_mode: None,
+                sha256: None,
+            },
+            FileEntry {
+                source: "fresh.txt".to_string(),
+                target_subpath: "fresh.txt".to_string(),
+                platform: None,
+                install_mode: None,
+                sha256: None,
+            },
+        ];
+
+        let preview = preview_mod_install("NewMod", "bundle", &game_target, None, &files);
+
+        assert_eq!(
+            preview.conflicts,
+            vec![
+                FileOwnershipConflict::OwnedByOtherMod("OtherMod".to_string()),
+                FileOwnershipConflict::ExistsUnowned,
+                FileOwnershipConflict::None,
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&appdata);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_cleanup_old_restore_points_never_prunes_the_shared_blobs_dir() {
+        let appdata = set_test_appdata();
+
+        let source_dir = appdata.join("source");
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        fs::write(source_dir.join("a.txt"), b"restore point content").expect("write a.txt");
+
+        let old_point = crate::restore::create_restore_point("old", &[source_dir.clone()], BackupCompression::None, 0)
+            .expect("create old restore point");
+        let new_point = crate::restore::create_restore_point("new", &[source_dir.clone()], BackupCompression::None, 0)
+            .expect("create new restore point");
+
+        // Age the older point so it's the one `keep = 1` prunes, and back-date `blobs/` even
+        // further so it would be the very first thing pruned if it were ever treated as a
+        // restore point directory instead of the shared store.
+        let restore_dir = get_restore_points_dir();
+        let far_past = SystemTime::now() - std::time::Duration::from_secs(60 * 60 * 24 * 7);
+        let recent_past = SystemTime::now() - std::time::Duration::from_secs(60 * 60);
+        fs::File::open(restore_dir.join("blobs"))
+            .and_then(|f| f.set_modified(far_past))
+            .expect("age blobs dir");
+        fs::File::open(&old_point)
+            .and_then(|f| f.set_modified(recent_past))
+            .expect("age old restore point");
+
+        cleanup_old_restore_points(1).expect("cleanup_old_restore_points should succeed");
+
+        assert!(!old_point.exists());
+        assert!(new_point.exists());
+        assert!(restore_dir.join("blobs").exists());
+        assert_eq!(fs::read_dir(restore_dir.join("blobs")).unwrap().count(), 1);
+
+        let _ = fs::remove_dir_all(&appdata);
+        std::env::remove_var("FMML_TEST_APPDATA");
     }
 }
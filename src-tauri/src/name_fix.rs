@@ -1,8 +1,23 @@
+use crate::archive::open_archive;
 use crate::config::{load_config, save_config, get_app_data_dir, get_name_fixes_dir};
-use crate::types::{NameFixSource, NameFixSourceType, NameFixInstallType};
+use crate::types::{
+    ArchiveEntryReport, ArchiveEntryStatus, ArchiveReport, BackupInfo, Config, DbConflictEntry,
+    DbConflictReport, DbFileOwnership, DownloadProgress, ExtractProgress, InstallProgress,
+    LineEndingStyle, NameFixBackupCompression, NameFixBackupMode, NameFixConflictAction, NameFixConflictEntry, NameFixConflictReport,
+    NameFixFileStatus, NameFixInstallMapEntry, NameFixInstallType, NameFixManifestCheckEntry, NameFixManifestEntry,
+    NameFixManifestReport, NameFixManifestStatus, NameFixSource, NameFixSourceType,
+    NameFixVerifyEntry, NameFixVerifyReport,
+};
+use blake2::Blake2b512;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::panic;
 use std::path::{Path, PathBuf};
+use rayon::prelude::*;
 use reqwest::blocking::Client;
+use sha2::{Digest, Sha256};
 use zip::ZipArchive;
 use std::io::{Read, Write};
 
@@ -10,6 +25,45 @@ const NAME_FIX_RELEASE_URL: &str = "https://github.com/jo13310/NameFixFM26/archi
 const NAME_FIX_FILE: &str = "FM26-open-names.lnc";
 pub const GITHUB_NAME_FIX_ID: &str = "github-namefix";
 
+/// Current `NameFixSource.schema_version`, mirroring [`crate::graphics::GRAPHICS_PACK_FORMAT_VERSION`]'s
+/// role for graphics packs. Bump this whenever `metadata.json`'s shape changes in a way
+/// [`gate_and_migrate_schema_version`] needs to handle, and add the matching migration step there.
+const NAME_FIX_SCHEMA_VERSION: u32 = 1;
+
+/// Refuses to install a name fix authored for a newer `NameFixSource.schema_version` than this
+/// build understands (rather than silently mis-handling fields it doesn't know about), and
+/// upgrades one authored for an older version in place before `install_name_fix` proceeds.
+/// `schema_version` defaults to `0` for `metadata.json` written before this field existed, which
+/// migrates the same as any other pre-1 fix.
+fn gate_and_migrate_schema_version(source: &mut NameFixSource) -> Result<(), String> {
+    if source.schema_version > NAME_FIX_SCHEMA_VERSION {
+        return Err(format!(
+            "Name fix '{}' was packaged for a newer FMMLoader (schema version {}, this build \
+            supports up to {}). Please update FMMLoader before installing it.",
+            source.name, source.schema_version, NAME_FIX_SCHEMA_VERSION
+        ));
+    }
+
+    // No migrations exist yet beyond bumping the stamp itself — schema_version 0 (the implicit
+    // value for metadata.json predating this field) and 1 have the same shape. Add a real
+    // transformation step here (and a match on `source.schema_version`) the first time that stops
+    // being true.
+    if source.schema_version < NAME_FIX_SCHEMA_VERSION {
+        tracing::info!(
+            "Migrating name fix '{}' metadata from schema version {} to {}",
+            source.name, source.schema_version, NAME_FIX_SCHEMA_VERSION
+        );
+        source.schema_version = NAME_FIX_SCHEMA_VERSION;
+    }
+
+    Ok(())
+}
+
+/// Below this many files, `copy_dir_recursive`'s serial walk is plenty fast and not worth
+/// spinning up rayon's thread pool for. The dbc/edt/lnc folders this gates for regularly carry
+/// thousands of small files, where parallel copying cuts backup/restore time substantially.
+const PARALLEL_COPY_THRESHOLD: usize = 64;
+
 // Files to delete as part of the installation
 const FILES_TO_DELETE: &[(&str, &[&str])] = &[
     // From lnc/all/
@@ -51,13 +105,67 @@ const FILES_TO_DELETE: &[(&str, &[&str])] = &[
     ]),
 ];
 
+/// Schema folder name to fall back to when `db/` contains no numeric subdirectories at all
+/// (e.g. a fresh/odd install). Matches the current FM26 schema at time of writing.
+const FALLBACK_SCHEMA_DIR: &str = "2600";
+
+/// Picks the database schema subdirectory inside `db_dir` (e.g. `.../database/db/`).
+///
+/// SI bumps this numeric folder (`2600`, `2601`, ...) with FM26 patches, so instead of
+/// hardcoding one we scan `db_dir`'s entries, keep the ones that parse as integers, and take
+/// the numerically highest. Falls back to [`FALLBACK_SCHEMA_DIR`] if it's present but nothing
+/// parsed as a number. Returns an error listing whatever folders were actually found otherwise.
+fn select_schema_dir(db_dir: &Path) -> Result<PathBuf, String> {
+    let entries = fs::read_dir(db_dir).map_err(|e| {
+        format!(
+            "Failed to read database directory {}: {}",
+            db_dir.display(),
+            e
+        )
+    })?;
+
+    let mut found_names = Vec::new();
+    let mut numeric_dirs: Vec<(u64, PathBuf)> = Vec::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            found_names.push(name.to_string());
+            if let Ok(schema) = name.parse::<u64>() {
+                numeric_dirs.push((schema, path.clone()));
+            }
+        }
+    }
+
+    if let Some((_, path)) = numeric_dirs.into_iter().max_by_key(|(schema, _)| *schema) {
+        return Ok(path);
+    }
+
+    let fallback = db_dir.join(FALLBACK_SCHEMA_DIR);
+    if fallback.exists() {
+        return Ok(fallback);
+    }
+
+    Err(format!(
+        "No FM26 database schema folder found under {}. Folders present: [{}]. Please ensure FM26 is installed and you've launched it at least once.",
+        db_dir.display(),
+        found_names.join(", ")
+    ))
+}
+
 /// Get the FM26 database directory based on game installation path (target_path)
 ///
 /// The database directory structure differs by platform:
-/// - Windows: <game_root>/shared/data/database/db/2600/
-/// - macOS: <game_root>/fm.app/Contents/PlugIns/game_plugin.bundle/Contents/Resources/shared/data/database/db/2600/
-/// - Linux: <game_root>/shared/data/database/db/2600/
-fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
+/// - Windows: <game_root>/shared/data/database/db/<schema>/
+/// - macOS: <game_root>/fm.app/Contents/PlugIns/game_plugin.bundle/Contents/Resources/shared/data/database/db/<schema>/
+/// - Linux: <game_root>/shared/data/database/db/<schema>/
+///
+/// `<schema>` is whichever numeric folder under `db/` is highest (see [`select_schema_dir`]),
+/// so this stays forward-compatible with future FM26 patches that bump the schema number.
+pub(crate) fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
     let target_path = target_path.ok_or(
         "Game target path not set. Please detect or set your FM26 game directory first."
     )?;
@@ -91,8 +199,7 @@ fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
             .join("shared")
             .join("data")
             .join("database")
-            .join("db")
-            .join("2600");
+            .join("db");
 
         if !db_dir.exists() {
             return Err(format!(
@@ -101,7 +208,7 @@ fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
             ));
         }
 
-        Ok(db_dir)
+        select_schema_dir(&db_dir)
     }
 
     #[cfg(target_os = "macos")]
@@ -126,8 +233,7 @@ fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
             .join("shared")
             .join("data")
             .join("database")
-            .join("db")
-            .join("2600");
+            .join("db");
 
         if !db_dir.exists() {
             return Err(format!(
@@ -136,7 +242,7 @@ fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
             ));
         }
 
-        Ok(db_dir)
+        select_schema_dir(&db_dir)
     }
 
     #[cfg(target_os = "linux")]
@@ -155,8 +261,7 @@ fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
             .join("shared")
             .join("data")
             .join("database")
-            .join("db")
-            .join("2600");
+            .join("db");
 
         if !db_dir.exists() {
             return Err(format!(
@@ -165,7 +270,7 @@ fn get_db_dir(target_path: Option<&str>) -> Result<PathBuf, String> {
             ));
         }
 
-        Ok(db_dir)
+        select_schema_dir(&db_dir)
     }
 }
 
@@ -178,7 +283,7 @@ pub fn check_installed(target_path: Option<&str>) -> Result<bool, String> {
 }
 
 /// Download the FM Name Fix archive from GitHub
-fn download_name_fix() -> Result<Vec<u8>, String> {
+fn download_name_fix(mut on_progress: impl FnMut(DownloadProgress)) -> Result<Vec<u8>, String> {
     tracing::info!("Downloading FM Name Fix from {}", NAME_FIX_RELEASE_URL);
 
     let client = Client::builder()
@@ -186,7 +291,7 @@ fn download_name_fix() -> Result<Vec<u8>, String> {
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
-    let response = client
+    let mut response = client
         .get(NAME_FIX_RELEASE_URL)
         .send()
         .map_err(|e| format!("Failed to download FM Name Fix: {}", e))?;
@@ -195,20 +300,39 @@ fn download_name_fix() -> Result<Vec<u8>, String> {
         return Err(format!("Failed to download FM Name Fix: HTTP {}", response.status()));
     }
 
-    let bytes = response
-        .bytes()
-        .map_err(|e| format!("Failed to read download data: {}", e))?;
+    let total_bytes = response.content_length();
+
+    let mut bytes = Vec::new();
+    let mut buffer = [0u8; 65536];
+    loop {
+        let read = response
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read download data: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&buffer[..read]);
+        on_progress(DownloadProgress {
+            bytes_downloaded: bytes.len() as u64,
+            total_bytes,
+        });
+    }
 
     tracing::info!("Downloaded {} bytes", bytes.len());
-    Ok(bytes.to_vec())
+    Ok(bytes)
 }
 
 /// Extract the FM26-open-names.lnc file from the zip archive
-fn extract_lnc_file(zip_data: &[u8]) -> Result<Vec<u8>, String> {
+fn extract_lnc_file(zip_data: &[u8], mut on_progress: impl FnMut(ExtractProgress)) -> Result<Vec<u8>, String> {
     let cursor = std::io::Cursor::new(zip_data);
     let mut archive = ZipArchive::new(cursor)
         .map_err(|e| format!("Failed to read zip archive: {}", e))?;
 
+    on_progress(ExtractProgress {
+        files_done: 0,
+        files_total: 1,
+    });
+
     // Look for the .lnc file in the archive
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)
@@ -220,6 +344,10 @@ fn extract_lnc_file(zip_data: &[u8]) -> Result<Vec<u8>, String> {
                 .map_err(|e| format!("Failed to read .lnc file from archive: {}", e))?;
 
             tracing::info!("Extracted {} ({} bytes)", file.name(), contents.len());
+            on_progress(ExtractProgress {
+                files_done: 1,
+                files_total: 1,
+            });
             return Ok(contents);
         }
     }
@@ -227,23 +355,194 @@ fn extract_lnc_file(zip_data: &[u8]) -> Result<Vec<u8>, String> {
     Err("FM26-open-names.lnc not found in downloaded archive".to_string())
 }
 
-/// Create backups of files that will be modified or deleted
-fn create_backups(db_dir: &Path) -> Result<(), String> {
-    let app_data_dir = get_app_data_dir();
-    let backup_dir = app_data_dir.join("name_fix_backup");
+/// Where every captured backup snapshot lives, one timestamped subdirectory per call to
+/// [`create_backups`]/[`create_folder_backups`].
+fn backup_history_dir() -> PathBuf {
+    get_app_data_dir().join("name_fix_backup")
+}
+
+/// Provenance recorded in a backup's `backup.json`, letting [`list_backups`]/[`restore_backup`]
+/// work out what a snapshot is and how to restore it without guessing from its folder name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupMetadata {
+    timestamp: String,
+    fix_id: String,
+    install_type: NameFixInstallType,
+    /// How this snapshot is stored on disk: a mirrored directory tree, or a single
+    /// `name_fix.tar.{xz,zst}` archive inside the backup dir. Missing on backups written before
+    /// archive support existed, which were always directory trees.
+    #[serde(default)]
+    compression: NameFixBackupCompression,
+}
+
+fn read_backup_metadata(backup_dir: &Path) -> Option<BackupMetadata> {
+    fs::read_to_string(backup_dir.join("backup.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+fn backup_id_from_dir(backup_dir: &Path) -> String {
+    backup_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
 
-    // Clean up old backup if it exists
-    if backup_dir.exists() {
-        tracing::info!("Removing old backup at {:?}", backup_dir);
-        fs::remove_dir_all(&backup_dir)
-            .map_err(|e| format!("Failed to remove old backup: {}", e))?;
+/// True if [`backup_history_dir`] already holds a timestamped ([`NameFixBackupMode::Numbered`])
+/// snapshot, i.e. any entry other than the `current` dir [`NameFixBackupMode::Simple`] writes to.
+/// Drives [`NameFixBackupMode::Existing`]'s GNU-`install`-style fallback.
+fn history_has_numbered_backup() -> Result<bool, String> {
+    let history_dir = backup_history_dir();
+    if !history_dir.exists() {
+        return Ok(false);
     }
 
+    let has_numbered = fs::read_dir(&history_dir)
+        .map_err(|e| format!("Failed to read backup history dir: {}", e))?
+        .filter_map(|e| e.ok())
+        .any(|entry| entry.path().is_dir() && entry.file_name() != "current");
+
+    Ok(has_numbered)
+}
+
+/// Creates a directory under [`backup_history_dir`] for `fix_id` and writes its `backup.json`,
+/// returning the directory for the caller to populate with the actual file or folder copies.
+/// Returns `Ok(None)` under [`NameFixBackupMode::None`], meaning the caller should skip the
+/// backup entirely. Under [`NameFixBackupMode::Simple`] the directory is always named `current`
+/// and wiped before reuse, so there is only ever one slot instead of a growing history.
+///
+/// In [`NameFixBackupMode::Numbered`] mode, prunes down to the most recent `retention` snapshots
+/// (via [`prune_backups`]) once the new one is registered, so a long `name_fix_stack` doesn't
+/// grow `name_fix_backup/` without bound. `retention == 0` disables pruning.
+fn new_backup_dir(
+    fix_id: &str,
+    install_type: NameFixInstallType,
+    mode: NameFixBackupMode,
+    compression: NameFixBackupCompression,
+    retention: usize,
+) -> Result<Option<PathBuf>, String> {
+    let mode = match mode {
+        NameFixBackupMode::Existing if history_has_numbered_backup()? => {
+            NameFixBackupMode::Numbered
+        }
+        NameFixBackupMode::Existing => NameFixBackupMode::Simple,
+        other => other,
+    };
+
+    let backup_dir = match mode {
+        NameFixBackupMode::None => {
+            tracing::info!("Name fix backup mode is None, skipping backup for {}", fix_id);
+            return Ok(None);
+        }
+        NameFixBackupMode::Numbered => {
+            let dir_name = format!("{}_{}", Local::now().format("%Y%m%d_%H%M%S"), fix_id);
+            backup_history_dir().join(dir_name)
+        }
+        NameFixBackupMode::Simple => {
+            let backup_dir = backup_history_dir().join("current");
+            if backup_dir.exists() {
+                fs::remove_dir_all(&backup_dir)
+                    .map_err(|e| format!("Failed to clear previous backup: {}", e))?;
+            }
+            backup_dir
+        }
+        NameFixBackupMode::Existing => unreachable!("resolved to Numbered/Simple above"),
+    };
+
     fs::create_dir_all(&backup_dir)
         .map_err(|e| format!("Failed to create backup directory: {}", e))?;
 
+    let metadata = BackupMetadata {
+        timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        fix_id: fix_id.to_string(),
+        install_type,
+        compression,
+    };
+    let metadata_json = serde_json::to_string_pretty(&metadata)
+        .map_err(|e| format!("Failed to serialize backup metadata: {}", e))?;
+    fs::write(backup_dir.join("backup.json"), metadata_json)
+        .map_err(|e| format!("Failed to write backup metadata: {}", e))?;
+
+    if mode == NameFixBackupMode::Numbered && retention > 0 {
+        prune_backups(retention)?;
+    }
+
+    Ok(Some(backup_dir))
+}
+
+/// True if `a` and `b` both exist, have equal length, and hash identically — checked before
+/// copying a file into or out of a backup so re-running an install/restore doesn't needlessly
+/// rewrite a file that's already correct.
+fn files_identical(a: &Path, b: &Path) -> bool {
+    let (Ok(a_meta), Ok(b_meta)) = (fs::metadata(a), fs::metadata(b)) else {
+        return false;
+    };
+    if a_meta.len() != b_meta.len() {
+        return false;
+    }
+    matches!(
+        (hash_file_streaming(a), hash_file_streaming(b)),
+        (Ok(hash_a), Ok(hash_b)) if hash_a == hash_b
+    )
+}
+
+/// Clears the read-only bit on `path` if it's set, returning the original permissions so the
+/// caller can put them back afterward with [`restore_permissions`]. FM ships some of its database
+/// files read-only on Windows, which makes plain `fs::copy`/`fs::remove_file` onto them fail.
+fn clear_readonly(path: &Path) -> Result<Option<fs::Permissions>, String> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return Ok(None),
+    };
+    let permissions = metadata.permissions();
+    if !permissions.readonly() {
+        return Ok(None);
+    }
+
+    let mut writable = permissions.clone();
+    writable.set_readonly(false);
+    fs::set_permissions(path, writable)
+        .map_err(|e| format!("Failed to clear read-only flag on {:?}: {}", path, e))?;
+    Ok(Some(permissions))
+}
+
+/// Restores permissions captured by [`clear_readonly`], best-effort: if `path` no longer exists
+/// (e.g. it was just deleted) there's nothing left to restore.
+fn restore_permissions(path: &Path, original: Option<fs::Permissions>) {
+    if let Some(permissions) = original {
+        if path.exists() {
+            let _ = fs::set_permissions(path, permissions);
+        }
+    }
+}
+
+/// Create backups of files that will be modified or deleted. Returns `None` if `mode` is
+/// [`NameFixBackupMode::None`], meaning nothing was backed up. Reports a `"backing_up"`
+/// [`InstallProgress`] per file so the caller can drive a determinate progress bar.
+fn create_backups(
+    db_dir: &Path,
+    fix_id: &str,
+    mode: NameFixBackupMode,
+    retention: usize,
+    on_progress: &mut dyn FnMut(InstallProgress),
+) -> Result<Option<PathBuf>, String> {
+    // File-based backups are a handful of small licensing files; not worth archiving.
+    let Some(backup_dir) = new_backup_dir(
+        fix_id,
+        NameFixInstallType::Files,
+        mode,
+        NameFixBackupCompression::None,
+        retention,
+    )?
+    else {
+        return Ok(None);
+    };
+
     tracing::info!("Creating backups at {:?}", backup_dir);
 
+    let total: usize = FILES_TO_DELETE.iter().map(|(_, files)| files.len()).sum();
+    let mut done = 0;
+
     // Backup files that will be deleted
     for (subdir, files) in FILES_TO_DELETE {
         let source_dir = db_dir.join(subdir);
@@ -256,99 +555,289 @@ fn create_backups(db_dir: &Path) -> Result<(), String> {
             let source_file = source_dir.join(file);
             if source_file.exists() {
                 let backup_file = backup_subdir.join(file);
-                fs::copy(&source_file, &backup_file)
-                    .map_err(|e| format!("Failed to backup {}: {}", file, e))?;
-                tracing::debug!("Backed up {}", file);
+                if files_identical(&source_file, &backup_file) {
+                    tracing::debug!("{} already backed up identically, skipping copy", file);
+                } else {
+                    let original_perms = clear_readonly(&backup_file)?;
+                    fs::copy(&source_file, &backup_file)
+                        .map_err(|e| format!("Failed to backup {}: {}", file, e))?;
+                    restore_permissions(&backup_file, original_perms);
+                    tracing::debug!("Backed up {}", file);
+                }
+                done += 1;
+                on_progress(InstallProgress {
+                    current: done,
+                    total,
+                    current_file: file.to_string(),
+                    operation: "backing_up".to_string(),
+                });
             }
         }
     }
 
     tracing::info!("Backups created successfully");
-    Ok(())
+    Ok(Some(backup_dir))
 }
 
-/// Create backups for folder-based name fixes (Sortitoutsi style)
-/// Backs up entire dbc, edt, lnc folders
-fn create_folder_backups(db_dir: &Path) -> Result<(), String> {
-    let app_data_dir = get_app_data_dir();
-    let backup_dir = app_data_dir.join("name_fix_backup");
-
-    // Clean up old backup if it exists
-    if backup_dir.exists() {
-        tracing::info!("Removing old backup at {:?}", backup_dir);
-        fs::remove_dir_all(&backup_dir)
-            .map_err(|e| format!("Failed to remove old backup: {}", e))?;
+/// Name of the single-archive backup file inside a folder-backup's `backup_dir`, when
+/// `compression` is not [`NameFixBackupCompression::None`].
+fn archive_backup_file_name(compression: NameFixBackupCompression) -> Option<&'static str> {
+    match compression {
+        NameFixBackupCompression::None => None,
+        NameFixBackupCompression::Xz => Some("name_fix.tar.xz"),
+        NameFixBackupCompression::Zstd => Some("name_fix.tar.zst"),
     }
+}
 
-    fs::create_dir_all(&backup_dir)
-        .map_err(|e| format!("Failed to create backup directory: {}", e))?;
-
-    tracing::info!("Creating folder backups at {:?}", backup_dir);
-
-    // Backup entire dbc, edt, lnc folders
+/// Appends the dbc/edt/lnc folders under `db_dir` (whichever exist) into `tar_builder`,
+/// reporting a `"backing_up"` [`InstallProgress`] per folder. Generic over the tar writer so the
+/// same loop drives both the xz and zstd archive branches of [`create_folder_backups`].
+fn append_folders_to_archive<W: Write>(
+    tar_builder: &mut tar::Builder<W>,
+    db_dir: &Path,
+    archive_path: &Path,
+    total: usize,
+    done: &mut usize,
+    on_progress: &mut dyn FnMut(InstallProgress),
+) -> Result<(), String> {
     for folder_name in &["dbc", "edt", "lnc"] {
         let source_folder = db_dir.join(folder_name);
         if source_folder.exists() {
-            let backup_folder = backup_dir.join(folder_name);
-            tracing::info!("Backing up {} folder: {:?} -> {:?}", folder_name, source_folder, backup_folder);
-            copy_dir_recursive(&source_folder, &backup_folder)?;
+            tracing::info!("Archiving {} folder into {:?}", folder_name, archive_path);
+            tar_builder
+                .append_dir_all(*folder_name, &source_folder)
+                .map_err(|e| format!("Failed to archive {} folder: {}", folder_name, e))?;
+            *done += count_files_recursive(&source_folder);
+            on_progress(InstallProgress {
+                current: *done,
+                total,
+                current_file: format!("{}/", folder_name),
+                operation: "backing_up".to_string(),
+            });
         } else {
             tracing::warn!("{} folder does not exist, skipping backup", folder_name);
         }
     }
+    Ok(())
+}
+
+/// Create backups for folder-based name fixes (Sortitoutsi style)
+/// Backs up entire dbc, edt, lnc folders. Returns `None` if `mode` is
+/// [`NameFixBackupMode::None`], meaning nothing was backed up. Reports a `"backing_up"`
+/// [`InstallProgress`] per file (per folder when archiving), since these folders can hold
+/// thousands of entries.
+///
+/// Under [`NameFixBackupCompression::None`] (the default) the folders are mirrored verbatim into
+/// the directory [`new_backup_dir`] returns, which is always empty, so every file is copied;
+/// unchanged files are not skipped. Under `Xz`/`Zstd` they're streamed into a single archive
+/// instead, trading some CPU for far less disk use on the largely-duplicated dbc/edt/lnc trees.
+fn create_folder_backups(
+    db_dir: &Path,
+    fix_id: &str,
+    mode: NameFixBackupMode,
+    compression: NameFixBackupCompression,
+    compression_level: u32,
+    retention: usize,
+    on_progress: &mut dyn FnMut(InstallProgress),
+) -> Result<Option<PathBuf>, String> {
+    let Some(backup_dir) = new_backup_dir(
+        fix_id,
+        NameFixInstallType::Folders,
+        mode,
+        compression,
+        retention,
+    )?
+    else {
+        return Ok(None);
+    };
+
+    tracing::info!("Creating folder backups at {:?}", backup_dir);
+
+    let total: usize = ["dbc", "edt", "lnc"]
+        .iter()
+        .map(|folder_name| count_files_recursive(&db_dir.join(folder_name)))
+        .sum();
+    let mut done = 0;
+
+    if let Some(archive_name) = archive_backup_file_name(compression) {
+        let archive_path = backup_dir.join(archive_name);
+        let archive_file = fs::File::create(&archive_path)
+            .map_err(|e| format!("Failed to create backup archive {:?}: {}", archive_path, e))?;
+
+        match compression {
+            NameFixBackupCompression::Xz => {
+                let level = if compression_level == 0 { 6 } else { compression_level };
+                let mut tar_builder =
+                    tar::Builder::new(xz2::write::XzEncoder::new(archive_file, level));
+                append_folders_to_archive(&mut tar_builder, db_dir, &archive_path, total, &mut done, on_progress)?;
+                tar_builder
+                    .into_inner()
+                    .map_err(|e| format!("Failed to finish backup archive: {}", e))?
+                    .finish()
+                    .map_err(|e| format!("Failed to finish xz stream: {}", e))?;
+            }
+            NameFixBackupCompression::Zstd => {
+                let level = if compression_level == 0 { 3 } else { compression_level as i32 };
+                let encoder = zstd::Encoder::new(archive_file, level)
+                    .map_err(|e| format!("Failed to start zstd stream: {}", e))?;
+                let mut tar_builder = tar::Builder::new(encoder);
+                append_folders_to_archive(&mut tar_builder, db_dir, &archive_path, total, &mut done, on_progress)?;
+                tar_builder
+                    .into_inner()
+                    .map_err(|e| format!("Failed to finish backup archive: {}", e))?
+                    .finish()
+                    .map_err(|e| format!("Failed to finish zstd stream: {}", e))?;
+            }
+            NameFixBackupCompression::None => unreachable!("archive_backup_file_name returned Some"),
+        }
+    } else {
+        // Backup entire dbc, edt, lnc folders
+        for folder_name in &["dbc", "edt", "lnc"] {
+            let source_folder = db_dir.join(folder_name);
+            if source_folder.exists() {
+                let backup_folder = backup_dir.join(folder_name);
+                tracing::info!("Backing up {} folder: {:?} -> {:?}", folder_name, source_folder, backup_folder);
+                copy_dir_recursive_gated(&source_folder, &backup_folder, &mut done, total, &mut |current, total, current_file| {
+                    on_progress(InstallProgress {
+                        current,
+                        total,
+                        current_file: current_file.to_string(),
+                        operation: "backing_up".to_string(),
+                    });
+                })?;
+            } else {
+                tracing::warn!("{} folder does not exist, skipping backup", folder_name);
+            }
+        }
+    }
 
     tracing::info!("Folder backups created successfully");
-    Ok(())
+    Ok(Some(backup_dir))
+}
+
+/// Counts every file (not directory) under `dir`, recursively, for precounting a
+/// [`copy_dir_recursive`] call's `total` before it starts copying. Missing `dir` counts as 0
+/// rather than erroring, matching how callers already treat a missing source folder as "nothing
+/// to back up/install" instead of a failure.
+fn count_files_recursive(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                count_files_recursive(&path)
+            } else {
+                1
+            }
+        })
+        .sum()
 }
 
-/// Restore files from backup
-fn restore_from_backup(db_dir: &Path) -> Result<(), String> {
-    let app_data_dir = get_app_data_dir();
-    let backup_dir = app_data_dir.join("name_fix_backup");
+/// Lists every captured backup under [`backup_history_dir`], newest first.
+pub fn list_backups() -> Result<Vec<BackupInfo>, String> {
+    let history_dir = backup_history_dir();
+
+    if !history_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups: Vec<BackupInfo> = fs::read_dir(&history_dir)
+        .map_err(|e| format!("Failed to read backup history dir: {}", e))?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let metadata = read_backup_metadata(&path)?;
+            Some(BackupInfo {
+                id: backup_id_from_dir(&path),
+                fix_id: metadata.fix_id,
+                install_type: metadata.install_type,
+                timestamp: metadata.timestamp,
+            })
+        })
+        .collect();
+
+    backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+    Ok(backups)
+}
 
+fn find_backup_dir(id: &str) -> Result<PathBuf, String> {
+    let backup_dir = backup_history_dir().join(id);
     if !backup_dir.exists() {
-        return Err("No backup found. Cannot uninstall FM Name Fix.".to_string());
+        return Err(format!("Backup not found: {}", id));
     }
+    Ok(backup_dir)
+}
+
+/// Restores `db_dir` from backup `id`, or the most recently captured backup if `id` is `None`.
+/// Unlike the old single-slot behavior, the backup is left in place afterward rather than
+/// deleted, so it stays available to restore again or to compare against.
+pub fn restore_backup(db_dir: &Path, id: Option<&str>) -> Result<(), String> {
+    let backup_dir = match id {
+        Some(id) => find_backup_dir(id)?,
+        None => list_backups()?
+            .first()
+            .map(|b| backup_history_dir().join(&b.id))
+            .ok_or_else(|| "No backup found. Cannot restore FM Name Fix.".to_string())?,
+    };
+
+    let metadata = read_backup_metadata(&backup_dir)
+        .ok_or_else(|| format!("Backup at {:?} is missing its backup.json", backup_dir))?;
 
     tracing::info!("Restoring from backup at {:?}", backup_dir);
 
-    // Get the active name fix to determine restore type
-    let config = load_config()?;
-    if let Some(active_fix_id) = config.active_name_fix {
-        let name_fixes_dir = get_name_fixes_dir();
-        let fix_dir = name_fixes_dir.join(&active_fix_id);
-        
-        if fix_dir.exists() {
-            let metadata_file = fix_dir.join("metadata.json");
-            if let Ok(metadata_str) = fs::read_to_string(&metadata_file) {
-                if let Ok(source) = serde_json::from_str::<NameFixSource>(&metadata_str) {
-                    match source.install_type {
-                        NameFixInstallType::Files => restore_files_backup(db_dir, &backup_dir, &fix_dir)?,
-                        NameFixInstallType::Folders => restore_folders_backup(db_dir, &backup_dir)?,
-                    }
-                } else {
-                    // Fallback to files type if can't read metadata
-                    restore_files_backup(db_dir, &backup_dir, &fix_dir)?;
-                }
-            } else {
-                // Fallback to files type if can't read metadata
+    let fix_dir = get_name_fixes_dir().join(&metadata.fix_id);
+
+    match metadata.install_type {
+        NameFixInstallType::Files => {
+            if fix_dir.exists() {
                 restore_files_backup(db_dir, &backup_dir, &fix_dir)?;
+            } else {
+                tracing::warn!("Name fix directory {:?} not found, restoring licensing files only", fix_dir);
+                restore_files_backup_without_fix_dir(db_dir, &backup_dir)?;
             }
-        } else {
-            tracing::warn!("Active name fix directory not found, assuming files type");
-            // Can't determine type, try files restore
-            restore_files_backup_without_fix_dir(db_dir, &backup_dir)?;
+        }
+        NameFixInstallType::Folders => {
+            restore_folders_backup(db_dir, &backup_dir, metadata.compression)?
         }
     }
 
-    // Remove backup directory
-    fs::remove_dir_all(&backup_dir)
-        .map_err(|e| format!("Failed to remove backup directory: {}", e))?;
-
     tracing::info!("Restore completed successfully");
     Ok(())
 }
 
+/// Tauri-facing wrapper around [`restore_backup`] that resolves the db dir from config the
+/// same way [`install`]/[`uninstall`] do, rather than leaving callers to plumb it through.
+pub fn restore_backup_for_active_config(id: Option<&str>) -> Result<String, String> {
+    let config = load_config()?;
+    let db_dir = get_db_dir(config.target_path.as_deref())?;
+    if let Some(fix_id) = &config.active_name_fix {
+        warn_if_game_build_changed(fix_id, &db_dir);
+    }
+    restore_backup(&db_dir, id)?;
+    Ok("Backup restored successfully".to_string())
+}
+
+/// Deletes backup `id` entirely.
+pub fn delete_backup(id: &str) -> Result<(), String> {
+    let backup_dir = find_backup_dir(id)?;
+    fs::remove_dir_all(&backup_dir).map_err(|e| format!("Failed to delete backup {}: {}", id, e))
+}
+
+/// Keeps only the `keep` most recently captured backups (sorted by the timestamp recorded in
+/// each backup's `backup.json`), deleting the rest.
+pub fn prune_backups(keep: usize) -> Result<(), String> {
+    for backup in list_backups()?.into_iter().skip(keep) {
+        delete_backup(&backup.id)?;
+    }
+    Ok(())
+}
+
 /// Restore file-based name fix
 fn restore_files_backup(db_dir: &Path, backup_dir: &Path, fix_dir: &Path) -> Result<(), String> {
     let mut restored_count = 0;
@@ -362,8 +851,15 @@ fn restore_files_backup(db_dir: &Path, backup_dir: &Path, fix_dir: &Path) -> Res
             let backup_file = backup_subdir.join(file);
             if backup_file.exists() {
                 let dest_file = dest_dir.join(file);
+                if files_identical(&backup_file, &dest_file) {
+                    tracing::debug!("{} already matches backup, skipping restore copy", file);
+                    restored_count += 1;
+                    continue;
+                }
+                let original_perms = clear_readonly(&dest_file)?;
                 fs::copy(&backup_file, &dest_file)
                     .map_err(|e| format!("Failed to restore {}: {}", file, e))?;
+                restore_permissions(&dest_file, original_perms);
                 restored_count += 1;
                 tracing::info!("Restored licensing file: {}", file);
             } else {
@@ -410,7 +906,9 @@ fn restore_files_backup(db_dir: &Path, backup_dir: &Path, fix_dir: &Path) -> Res
             };
             
             if installed_path.exists() {
+                let original_perms = clear_readonly(&installed_path)?;
                 if let Err(e) = fs::remove_file(&installed_path) {
+                    restore_permissions(&installed_path, original_perms);
                     tracing::warn!("Failed to remove {}: {}", filename, e);
                 } else {
                     removed_count += 1;
@@ -437,8 +935,15 @@ fn restore_files_backup_without_fix_dir(db_dir: &Path, backup_dir: &Path) -> Res
             let backup_file = backup_subdir.join(file);
             if backup_file.exists() {
                 let dest_file = dest_dir.join(file);
+                if files_identical(&backup_file, &dest_file) {
+                    tracing::debug!("{} already matches backup, skipping restore copy", file);
+                    restored_count += 1;
+                    continue;
+                }
+                let original_perms = clear_readonly(&dest_file)?;
                 fs::copy(&backup_file, &dest_file)
                     .map_err(|e| format!("Failed to restore {}: {}", file, e))?;
+                restore_permissions(&dest_file, original_perms);
                 restored_count += 1;
                 tracing::info!("Restored licensing file: {}", file);
             }
@@ -450,9 +955,13 @@ fn restore_files_backup_without_fix_dir(db_dir: &Path, backup_dir: &Path) -> Res
 }
 
 /// Restore folder-based name fix
-fn restore_folders_backup(db_dir: &Path, backup_dir: &Path) -> Result<(), String> {
+fn restore_folders_backup(
+    db_dir: &Path,
+    backup_dir: &Path,
+    compression: NameFixBackupCompression,
+) -> Result<(), String> {
     tracing::info!("Restoring folder-based name fix");
-    
+
     // Delete current dbc, edt, lnc folders
     for folder_name in &["dbc", "edt", "lnc"] {
         let folder_path = db_dir.join(folder_name);
@@ -462,30 +971,298 @@ fn restore_folders_backup(db_dir: &Path, backup_dir: &Path) -> Result<(), String
                 .map_err(|e| format!("Failed to delete {} folder: {}", folder_name, e))?;
         }
     }
-    
-    // Restore backed up folders
-    let mut restored_count = 0;
-    for folder_name in &["dbc", "edt", "lnc"] {
-        let backup_folder = backup_dir.join(folder_name);
-        if backup_folder.exists() {
-            let dest_folder = db_dir.join(folder_name);
-            tracing::info!("Restoring {} folder: {:?} -> {:?}", folder_name, backup_folder, dest_folder);
-            copy_dir_recursive(&backup_folder, &dest_folder)?;
-            restored_count += 1;
-        } else {
-            tracing::warn!("Backup {} folder not found", folder_name);
+
+    if let Some(archive_name) = archive_backup_file_name(compression) {
+        let archive_path = backup_dir.join(archive_name);
+        let archive_file = fs::File::open(&archive_path)
+            .map_err(|e| format!("Failed to open backup archive {:?}: {}", archive_path, e))?;
+
+        let decoder: Box<dyn Read> = match compression {
+            NameFixBackupCompression::Xz => Box::new(xz2::read::XzDecoder::new(archive_file)),
+            NameFixBackupCompression::Zstd => Box::new(
+                zstd::Decoder::new(archive_file)
+                    .map_err(|e| format!("Failed to open zstd stream: {}", e))?,
+            ),
+            NameFixBackupCompression::None => unreachable!("archive_backup_file_name returned Some"),
+        };
+
+        tracing::info!("Extracting backup archive {:?} into {:?}", archive_path, db_dir);
+        tar::Archive::new(decoder)
+            .unpack(db_dir)
+            .map_err(|e| format!("Failed to extract backup archive: {}", e))?;
+
+        tracing::info!("Restored dbc/edt/lnc folders from archive");
+    } else {
+        // Restore backed up folders
+        let total: usize = ["dbc", "edt", "lnc"]
+            .iter()
+            .map(|folder_name| count_files_recursive(&backup_dir.join(folder_name)))
+            .sum();
+        let mut done = 0;
+
+        let mut restored_count = 0;
+        for folder_name in &["dbc", "edt", "lnc"] {
+            let backup_folder = backup_dir.join(folder_name);
+            if backup_folder.exists() {
+                let dest_folder = db_dir.join(folder_name);
+                tracing::info!("Restoring {} folder: {:?} -> {:?}", folder_name, backup_folder, dest_folder);
+                copy_dir_recursive_gated(&backup_folder, &dest_folder, &mut done, total, &mut |_, _, _| {})?;
+                restored_count += 1;
+            } else {
+                tracing::warn!("Backup {} folder not found", folder_name);
+            }
         }
+
+        tracing::info!("Restored {} folders", restored_count);
     }
-    
-    tracing::info!("Restored {} folders", restored_count);
-    
+
     // Note: Editor data files are not removed on uninstall as they don't interfere
     // User can manually delete them if desired
     tracing::info!("Note: Editor data files in user directory were not removed");
-    
+
+    Ok(())
+}
+
+/// Streams `path` through SHA-256 in 64KB chunks instead of reading it whole, since folder-based
+/// name fixes can carry database files much larger than a single licensing patch.
+fn hash_file_streaming(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open {:?} for hashing: {}", path, e))?;
+
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 65536];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read {:?} while hashing: {}", path, e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Size and BLAKE2b-512 digest of `path`, streamed in 4 KB blocks. Used to recheck a fix's
+/// on-disk manifest later in [`verify_name_fix`], where the file already exists and has to be
+/// read back from disk regardless.
+fn hash_file_blake2b(path: &Path) -> Result<(u64, String), String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open {:?} for hashing: {}", path, e))?;
+
+    let mut hasher = Blake2b512::new();
+    let mut buffer = [0u8; 4096];
+    let mut size = 0u64;
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read {:?} while hashing: {}", path, e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+    }
+
+    Ok((size, format!("{:x}", hasher.finalize())))
+}
+
+/// Size and BLAKE2b-512 digest of an already-in-memory buffer. Used while importing: archive
+/// entries are read into memory whole before being written out, so hashing the buffer directly
+/// builds [`NameFixSource::files`] for free instead of re-reading the just-written file from disk.
+fn hash_bytes_blake2b(bytes: &[u8]) -> (u64, String) {
+    let mut hasher = Blake2b512::new();
+    hasher.update(bytes);
+    (bytes.len() as u64, format!("{:x}", hasher.finalize()))
+}
+
+/// Detects the dominant line ending in `contents`, strips a leading UTF-8 BOM, and rewrites
+/// every line ending to `target` in place. Name-fix sites mix CRLF and LF (and occasionally
+/// leave a stray BOM) depending on where the file was packaged, which can trip up the game's
+/// parser on some platforms. Leaves `contents` untouched and returns `false` if it looks binary
+/// (contains a NUL byte) or if it's already uniformly using `target` with no BOM.
+fn normalize_text_contents(contents: &mut Vec<u8>, target: LineEndingStyle) -> bool {
+    if contents.contains(&0) {
+        return false;
+    }
+
+    let had_bom = contents.starts_with(&[0xEF, 0xBB, 0xBF]);
+    let body: &[u8] = if had_bom { &contents[3..] } else { &contents[..] };
+
+    let crlf_count = body.windows(2).filter(|w| *w == b"\r\n").count();
+    let lone_lf_count = body.iter().filter(|&&b| b == b'\n').count().saturating_sub(crlf_count);
+    let dominant_is_crlf = crlf_count > lone_lf_count;
+
+    let target_bytes: &[u8] = match target {
+        LineEndingStyle::Lf => b"\n",
+        LineEndingStyle::Crlf => b"\r\n",
+        LineEndingStyle::Native => {
+            if cfg!(windows) {
+                b"\r\n"
+            } else {
+                b"\n"
+            }
+        }
+    };
+
+    let already_uniform = if dominant_is_crlf { lone_lf_count == 0 } else { crlf_count == 0 };
+    let already_target_ending = if dominant_is_crlf { target_bytes == b"\r\n" } else { target_bytes == b"\n" };
+    if !had_bom && already_uniform && already_target_ending {
+        return false;
+    }
+
+    let mut normalized = Vec::with_capacity(body.len());
+    let mut i = 0;
+    while i < body.len() {
+        match body[i] {
+            b'\r' => {
+                normalized.extend_from_slice(target_bytes);
+                i += if body.get(i + 1) == Some(&b'\n') { 2 } else { 1 };
+            }
+            b'\n' => {
+                normalized.extend_from_slice(target_bytes);
+                i += 1;
+            }
+            b => {
+                normalized.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    *contents = normalized;
+    true
+}
+
+/// Hashes the sorted `(rel_path, blake2b_hex)` pairs of `manifest` into one BLAKE2b-512 digest
+/// identifying the whole fix's contents, independent of the order files happened to appear in
+/// the source archive. Used by [`import_name_fix`] to detect re-imports of an already-known fix.
+fn compute_aggregate_hash(manifest: &[NameFixManifestEntry]) -> String {
+    let mut sorted: Vec<&NameFixManifestEntry> = manifest.iter().collect();
+    sorted.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
+
+    let mut hasher = Blake2b512::new();
+    for entry in sorted {
+        hasher.update(entry.rel_path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(entry.blake2b_hex.as_bytes());
+        hasher.update(b"\n");
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
+/// Scans every already-imported fix's `metadata.json` for one whose `aggregate_hash` matches,
+/// so [`import_name_fix`] can reuse it instead of extracting a duplicate. `skip_dir_name` excludes
+/// the in-progress import's own staging directory from the scan.
+fn find_fix_by_aggregate_hash(aggregate_hash: &str, skip_dir_name: &str) -> Option<NameFixSource> {
+    if aggregate_hash.is_empty() {
+        return None;
+    }
+
+    let entries = fs::read_dir(get_name_fixes_dir()).ok()?;
+
+    for entry in entries.flatten() {
+        let dir_name = entry.file_name();
+        if dir_name == skip_dir_name {
+            continue;
+        }
+
+        let metadata_file = entry.path().join("metadata.json");
+        let Ok(metadata_str) = fs::read_to_string(&metadata_file) else {
+            continue;
+        };
+        let Ok(source) = serde_json::from_str::<NameFixSource>(&metadata_str) else {
+            continue;
+        };
+
+        if source.aggregate_hash == aggregate_hash {
+            return Some(source);
+        }
+    }
+
+    None
+}
+
+/// Walks `dir` (a folder just copied into the db dir) recording each file's SHA-256 keyed by
+/// its path relative to `base` (the db dir), so [`verify`] has something to check later.
+fn collect_file_hashes(
+    dir: &Path,
+    base: &Path,
+    out: &mut HashMap<String, String>,
+) -> Result<(), String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory {:?}: {}", dir, e))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_file_hashes(&path, base, out)?;
+        } else {
+            let relative_path = path
+                .strip_prefix(base)
+                .map_err(|e| format!("Failed to compute relative path for {:?}: {}", path, e))?
+                .to_string_lossy()
+                .replace('\\', "/");
+            out.insert(relative_path, hash_file_streaming(&path)?);
+        }
+    }
+
     Ok(())
 }
 
+/// The FM26 database schema folder name (e.g. `"2600"`) `db_dir` resolved to, for stamping onto
+/// a fix's `game_build` at install time. Falls back to the full path if, oddly, `db_dir` has no
+/// final component.
+fn game_build_from_db_dir(db_dir: &Path) -> String {
+    db_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| db_dir.to_string_lossy().into_owned())
+}
+
+/// Records the one file the built-in GitHub fix places into the db dir, so [`verify`]/[`repair`]
+/// have the same metadata.json substrate to work from as imported fixes.
+fn write_github_name_fix_metadata(db_dir: &Path, installed_file: &Path) -> Result<(), String> {
+    let fix_dir = get_name_fixes_dir().join(GITHUB_NAME_FIX_ID);
+    fs::create_dir_all(&fix_dir)
+        .map_err(|e| format!("Failed to create name fix directory: {}", e))?;
+
+    let relative_path = installed_file
+        .strip_prefix(db_dir)
+        .map_err(|e| format!("Failed to compute relative path for {:?}: {}", installed_file, e))?
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    let mut file_hashes = HashMap::new();
+    file_hashes.insert(relative_path, hash_file_streaming(installed_file)?);
+
+    let source = NameFixSource {
+        id: GITHUB_NAME_FIX_ID.to_string(),
+        name: "FM Name Fix (GitHub)".to_string(),
+        source_type: NameFixSourceType::GitHub,
+        install_type: NameFixInstallType::Files,
+        description: "Official FM26 open names fix, downloaded from GitHub".to_string(),
+        imported_date: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        file_hashes,
+        // This legacy path writes straight into the db dir rather than keeping an extracted
+        // copy in the fix's own storage directory, so there's nothing for `verify_name_fix` to
+        // check against.
+        files: Vec::new(),
+        aggregate_hash: String::new(),
+        files_normalized: 0,
+        install_map: Vec::new(),
+        schema_version: NAME_FIX_SCHEMA_VERSION,
+        game_build: game_build_from_db_dir(db_dir),
+    };
+
+    let metadata_json = serde_json::to_string_pretty(&source)
+        .map_err(|e| format!("Failed to serialize metadata: {}", e))?;
+    fs::write(fix_dir.join("metadata.json"), metadata_json)
+        .map_err(|e| format!("Failed to save metadata: {}", e))
+}
+
 /// Delete licensing files as part of installation
 fn delete_licensing_files(db_dir: &Path) -> Result<(), String> {
     tracing::info!("Deleting licensing files from: {:?}", db_dir);
@@ -505,8 +1282,11 @@ fn delete_licensing_files(db_dir: &Path) -> Result<(), String> {
         for file in *files {
             let file_path = dir.join(file);
             if file_path.exists() {
-                fs::remove_file(&file_path)
-                    .map_err(|e| format!("Failed to delete {}: {}", file, e))?;
+                let original_perms = clear_readonly(&file_path)?;
+                if let Err(e) = fs::remove_file(&file_path) {
+                    restore_permissions(&file_path, original_perms);
+                    return Err(format!("Failed to delete {}: {}", file, e));
+                }
                 deleted_count += 1;
                 tracing::info!("Deleted licensing file: {}", file);
             } else {
@@ -520,21 +1300,93 @@ fn delete_licensing_files(db_dir: &Path) -> Result<(), String> {
     Ok(())
 }
 
-/// Install FM Name Fix
-pub fn install() -> Result<String, String> {
-    let config = load_config()?;
-    let db_dir = get_db_dir(config.target_path.as_deref())?;
+/// Builds a human-readable refusal message for [`require_no_unknown_conflicts`], listing the
+/// files nothing here recognizes so the user (or the frontend prompting them) knows what's at
+/// stake before passing `force`.
+fn conflict_error_message(report: &DbConflictReport) -> String {
+    let unknown: Vec<&str> = report
+        .entries
+        .iter()
+        .filter(|e| e.ownership == DbFileOwnership::Unknown)
+        .map(|e| e.relative_path.as_str())
+        .collect();
+
+    format!(
+        "Refusing to install: {} file(s) in the database directory aren't stock licensing files \
+        or a tracked name fix, and installing would silently overwrite or delete them: {}. \
+        Re-run with force to proceed anyway.",
+        unknown.len(),
+        unknown.join(", ")
+    )
+}
 
-    tracing::info!("Starting FM Name Fix installation");
+/// Line to fold into an install's success message describing what [`create_backups`]/
+/// [`create_folder_backups`] did, so the message stays accurate when the user has opted out of
+/// backups via [`NameFixBackupMode::None`].
+fn backup_note(backup_dir: &Option<PathBuf>) -> String {
+    match backup_dir {
+        Some(dir) => format!("- Created backup at {}\n", dir.display()),
+        None => "- Skipped backup (backup mode is set to None)\n".to_string(),
+    }
+}
 
-    // Create backups before making any changes
-    create_backups(&db_dir)?;
+/// Sets `config.active_name_fix` to `fix_id` and pushes it onto `name_fix_stack`, moving it to
+/// the top if it was already stacked further down. [`uninstall`] pops this stack so layering
+/// fixes A then B then uninstalling B falls back to A instead of straight to stock.
+fn push_active_fix(config: &mut Config, fix_id: &str) {
+    config.name_fix_stack.retain(|id| id != fix_id);
+    config.name_fix_stack.push(fix_id.to_string());
+    config.active_name_fix = Some(fix_id.to_string());
+}
 
-    // Download the name fix
-    let zip_data = download_name_fix()?;
+/// Scans `db_dir` for conflicts before an install, warning about files that belong to a
+/// different tracked fix and refusing outright (unless `force`) when any file is untracked.
+fn require_no_unknown_conflicts(db_dir: &Path, active_fix_id: Option<&str>, force: bool) -> Result<(), String> {
+    let report = scan_db_conflicts(db_dir, active_fix_id)?;
+
+    for entry in &report.entries {
+        if let DbFileOwnership::BelongsToOtherImportedFix { fix_id, fix_name } = &entry.ownership {
+            tracing::warn!(
+                "{} belongs to another imported name fix ({}, {}) and will be overwritten",
+                entry.relative_path, fix_name, fix_id
+            );
+        }
+    }
 
-    // Extract the .lnc file
-    let lnc_data = extract_lnc_file(&zip_data)?;
+    if !force && report.entries.iter().any(|e| e.ownership == DbFileOwnership::Unknown) {
+        return Err(conflict_error_message(&report));
+    }
+
+    Ok(())
+}
+
+/// Install FM Name Fix
+pub fn install(
+    force: bool,
+    mut on_download_progress: impl FnMut(DownloadProgress),
+    mut on_extract_progress: impl FnMut(ExtractProgress),
+) -> Result<String, String> {
+    let config = load_config()?;
+    let db_dir = get_db_dir(config.target_path.as_deref())?;
+
+    tracing::info!("Starting FM Name Fix installation");
+
+    require_no_unknown_conflicts(&db_dir, config.active_name_fix.as_deref(), force)?;
+
+    // Create backups before making any changes
+    let backup_dir = create_backups(
+        &db_dir,
+        GITHUB_NAME_FIX_ID,
+        config.name_fix_backup_mode,
+        config.name_fix_backup_retention,
+        &mut |_| {},
+    )?;
+
+    // Download the name fix
+    let zip_data = download_name_fix(&mut on_download_progress)?;
+
+    // Extract the .lnc file
+    let lnc_data = extract_lnc_file(&zip_data, &mut on_extract_progress)?;
 
     // Write the .lnc file to the correct location
     let lnc_dir = db_dir.join("lnc").join("all");
@@ -553,19 +1405,52 @@ pub fn install() -> Result<String, String> {
     // Delete licensing files
     delete_licensing_files(&db_dir)?;
 
+    write_github_name_fix_metadata(&db_dir, &lnc_file)?;
+
+    let mut config = load_config()?;
+    push_active_fix(&mut config, GITHUB_NAME_FIX_ID);
+    save_config(&config)?;
+
     tracing::info!("FM Name Fix installation completed successfully");
-    let app_data_dir = get_app_data_dir();
     Ok(format!(
         "FM Name Fix installed successfully! The following changes were made:\n\
         - Installed {} to fix licensing issues\n\
         - Removed stock licensing files\n\
-        - Created backup at {}\n\n\
+        {}\n\
         Note: For existing saves, Brazilian clubs will update after you start a new save.",
         NAME_FIX_FILE,
-        app_data_dir.join("name_fix_backup").display()
+        backup_note(&backup_dir)
     ))
 }
 
+/// Reads `fix_id`'s `metadata.json` and warns if its recorded `game_build` doesn't match the
+/// schema folder `db_dir` resolves to right now — meaning an FM update has moved the schema out
+/// from under this fix since it was installed, so a restore/uninstall may be touching a
+/// different tree than the one it backed up. Best-effort: a missing/unreadable metadata.json
+/// (e.g. the built-in GitHub fix's legacy-format one) just skips the check.
+fn warn_if_game_build_changed(fix_id: &str, db_dir: &Path) {
+    let metadata_file = get_name_fixes_dir().join(fix_id).join("metadata.json");
+    let Ok(metadata_str) = fs::read_to_string(&metadata_file) else {
+        return;
+    };
+    let Ok(source) = serde_json::from_str::<NameFixSource>(&metadata_str) else {
+        return;
+    };
+
+    if source.game_build.is_empty() {
+        return;
+    }
+
+    let current_build = game_build_from_db_dir(db_dir);
+    if source.game_build != current_build {
+        tracing::warn!(
+            "Name fix '{}' was installed against game build {}, but the game now resolves to \
+            build {} — restore/uninstall may not match what was actually backed up",
+            fix_id, source.game_build, current_build
+        );
+    }
+}
+
 /// Uninstall FM Name Fix
 pub fn uninstall() -> Result<String, String> {
     let config = load_config()?;
@@ -573,17 +1458,331 @@ pub fn uninstall() -> Result<String, String> {
 
     tracing::info!("Starting FM Name Fix uninstallation");
 
-    restore_from_backup(&db_dir)?;
+    if let Some(fix_id) = &config.active_name_fix {
+        warn_if_game_build_changed(fix_id, &db_dir);
+    }
 
-    // Clear active name fix from config
+    let consumed_backup_id = list_backups()?.first().map(|b| b.id.clone());
+
+    restore_backup(&db_dir, None)?;
+
+    // The most recent backup captures exactly what was in place before the top-of-stack fix was
+    // installed, so popping it and falling back to whatever's now on top restores that fix
+    // rather than unconditionally clearing to stock.
     let mut config = load_config()?;
-    config.active_name_fix = None;
+    config.name_fix_stack.pop();
+    config.active_name_fix = config.name_fix_stack.last().cloned();
     save_config(&config)?;
 
+    // The snapshot just restored from only ever captured state for the layer we just removed, so
+    // it has nothing left to offer once we're past it. Once the whole stack is gone we're back at
+    // the true stock state, so every remaining snapshot (older, layered installs included) is
+    // pruned too rather than left to accumulate in `name_fix_backup/` forever.
+    if config.name_fix_stack.is_empty() {
+        prune_backups(0)?;
+    } else if let Some(id) = consumed_backup_id {
+        delete_backup(&id)?;
+    }
+
     tracing::info!("FM Name Fix uninstallation completed successfully");
     Ok("FM Name Fix uninstalled successfully! Original licensing files have been restored.".to_string())
 }
 
+/// Recomputes SHA-256 for every file the active name fix recorded in its metadata.json and
+/// compares it against the digest captured at install time, so a corrupted or FM-update-
+/// overwritten fix can be detected without the user noticing broken licensing first.
+pub fn verify() -> Result<NameFixVerifyReport, String> {
+    let config = load_config()?;
+    let db_dir = get_db_dir(config.target_path.as_deref())?;
+
+    let active_fix_id = config
+        .active_name_fix
+        .ok_or_else(|| "No name fix is currently active".to_string())?;
+
+    let metadata_file = get_name_fixes_dir().join(&active_fix_id).join("metadata.json");
+    let source: NameFixSource = serde_json::from_str(
+        &fs::read_to_string(&metadata_file)
+            .map_err(|e| format!("Failed to read metadata: {}", e))?,
+    )
+    .map_err(|e| format!("Failed to parse metadata: {}", e))?;
+
+    let mut entries: Vec<NameFixVerifyEntry> = source
+        .file_hashes
+        .iter()
+        .map(|(relative_path, expected_hash)| {
+            let path = db_dir.join(relative_path);
+            let status = if !path.exists() {
+                NameFixFileStatus::Missing
+            } else {
+                match hash_file_streaming(&path) {
+                    Ok(actual_hash) if &actual_hash == expected_hash => NameFixFileStatus::Ok,
+                    _ => NameFixFileStatus::Modified,
+                }
+            };
+            NameFixVerifyEntry {
+                relative_path: relative_path.clone(),
+                status,
+            }
+        })
+        .collect();
+
+    entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    tracing::info!(
+        "Verified name fix {}: {} file(s) tracked",
+        active_fix_id,
+        entries.len()
+    );
+
+    Ok(NameFixVerifyReport {
+        name_fix_id: active_fix_id,
+        entries,
+    })
+}
+
+/// Re-extracts only the files [`verify`] found missing or modified, from the cached `fix_dir`
+/// for an imported fix or a fresh download for the built-in GitHub fix, so a user can recover
+/// from an FM update overwriting the fix without a full uninstall/reinstall cycle.
+pub fn repair() -> Result<String, String> {
+    let config = load_config()?;
+    let db_dir = get_db_dir(config.target_path.as_deref())?;
+
+    let active_fix_id = config
+        .active_name_fix
+        .ok_or_else(|| "No name fix is currently active".to_string())?;
+
+    let report = verify()?;
+    let bad: Vec<&NameFixVerifyEntry> = report
+        .entries
+        .iter()
+        .filter(|e| e.status != NameFixFileStatus::Ok)
+        .collect();
+
+    if bad.is_empty() {
+        return Ok("Name fix is intact, no repair needed".to_string());
+    }
+
+    let fix_dir = get_name_fixes_dir().join(&active_fix_id);
+    let metadata_file = fix_dir.join("metadata.json");
+    let mut source: NameFixSource = serde_json::from_str(
+        &fs::read_to_string(&metadata_file)
+            .map_err(|e| format!("Failed to read metadata: {}", e))?,
+    )
+    .map_err(|e| format!("Failed to parse metadata: {}", e))?;
+
+    // The built-in GitHub fix has nothing cached on disk to re-extract from, so pull a fresh
+    // copy the same way `install` does.
+    let lnc_data = if active_fix_id == GITHUB_NAME_FIX_ID {
+        Some(extract_lnc_file(&download_name_fix(|_| {})?, |_| {})?)
+    } else {
+        None
+    };
+
+    let mut repaired = 0;
+    for entry in &bad {
+        let dest_path = db_dir.join(&entry.relative_path);
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory {:?}: {}", parent, e))?;
+        }
+
+        if let Some(data) = &lnc_data {
+            fs::write(&dest_path, data)
+                .map_err(|e| format!("Failed to repair {}: {}", entry.relative_path, e))?;
+        } else {
+            let src_path = match source.install_type {
+                NameFixInstallType::Files => {
+                    let filename = Path::new(&entry.relative_path).file_name().ok_or_else(|| {
+                        format!("Invalid relative path: {}", entry.relative_path)
+                    })?;
+                    fix_dir.join(filename)
+                }
+                NameFixInstallType::Folders => fix_dir.join(&entry.relative_path),
+            };
+            fs::copy(&src_path, &dest_path).map_err(|e| {
+                format!("Failed to repair {} from cache: {}", entry.relative_path, e)
+            })?;
+        }
+
+        source
+            .file_hashes
+            .insert(entry.relative_path.clone(), hash_file_streaming(&dest_path)?);
+        repaired += 1;
+    }
+
+    let metadata_json = serde_json::to_string_pretty(&source)
+        .map_err(|e| format!("Failed to serialize metadata: {}", e))?;
+    fs::write(&metadata_file, metadata_json)
+        .map_err(|e| format!("Failed to save metadata: {}", e))?;
+
+    tracing::info!("Repaired {} file(s) for name fix {}", repaired, active_fix_id);
+    Ok(format!(
+        "Repaired {} file(s) that were missing or modified",
+        repaired
+    ))
+}
+
+/// Scans the licensing-relevant subtrees from [`FILES_TO_DELETE`] for files already present in
+/// `db_dir`, classifying each against every imported fix's recorded `file_hashes` so a caller can
+/// warn before an install clobbers a different community fix (or something nothing here tracks).
+pub fn scan_db_conflicts(db_dir: &Path, active_fix_id: Option<&str>) -> Result<DbConflictReport, String> {
+    let sources = list_name_fixes()?;
+
+    let mut entries = Vec::new();
+
+    for (subdir, stock_files) in FILES_TO_DELETE {
+        let dir = db_dir.join(subdir);
+        if !dir.exists() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to read directory {:?}: {}", dir, e))?
+        {
+            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let filename = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let relative_path = format!("{}/{}", subdir, filename);
+
+            let owner = sources.iter().find(|source| source.file_hashes.contains_key(&relative_path));
+
+            let ownership = if let Some(owner) = owner {
+                if Some(owner.id.as_str()) == active_fix_id {
+                    DbFileOwnership::BelongsToActiveFix
+                } else {
+                    DbFileOwnership::BelongsToOtherImportedFix {
+                        fix_id: owner.id.clone(),
+                        fix_name: owner.name.clone(),
+                    }
+                }
+            } else if stock_files.contains(&filename.as_str()) {
+                DbFileOwnership::StockLicensing
+            } else {
+                DbFileOwnership::Unknown
+            };
+
+            entries.push(DbConflictEntry { relative_path, ownership });
+        }
+    }
+
+    Ok(DbConflictReport { entries })
+}
+
+/// Tauri-facing wrapper around [`scan_db_conflicts`] that resolves the db dir and active fix from
+/// config, the same way [`install`]/[`install_name_fix`] do.
+pub fn scan_conflicts_for_active_config() -> Result<DbConflictReport, String> {
+    let config = load_config()?;
+    let db_dir = get_db_dir(config.target_path.as_deref())?;
+    scan_db_conflicts(&db_dir, config.active_name_fix.as_deref())
+}
+
+/// Computes every destination path installing `source` (the fix `fix_id` is about to become)
+/// would write — reusing [`files_type_dest_path`]'s extension routing for Files-type fixes and
+/// `source.files`' recorded `dbc/`/`edt`/`lnc/` entries for Folders-type ones, since
+/// `install_folders_type` replaces those subtrees wholesale — and checks each one against every
+/// other imported fix's `file_hashes` to find paths a different fix already claims.
+pub fn scan_install_conflicts(
+    db_dir: &Path,
+    fix_id: &str,
+    source: &NameFixSource,
+) -> Result<NameFixConflictReport, String> {
+    let fix_dir = get_name_fixes_dir().join(fix_id);
+    let other_sources: Vec<NameFixSource> = list_name_fixes()?
+        .into_iter()
+        .filter(|s| s.id != fix_id)
+        .collect();
+
+    // Pairs of (path relative to db_dir, this fix's own copy of the file that would land there).
+    let targets: Vec<(String, PathBuf)> = match source.install_type {
+        NameFixInstallType::Files => {
+            let entries = fs::read_dir(&fix_dir)
+                .map_err(|e| format!("Failed to read name fix directory: {}", e))?;
+            let mut out = Vec::new();
+            for entry in entries.flatten() {
+                let own_path = entry.path();
+                if !own_path.is_file() {
+                    continue;
+                }
+                let filename = own_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                if filename == "metadata.json" {
+                    continue;
+                }
+                let Some(dest_path) = files_type_dest_path(db_dir, &filename, &source.install_map) else {
+                    continue;
+                };
+                let relative_path = dest_path
+                    .strip_prefix(db_dir)
+                    .map_err(|e| format!("Failed to compute relative path for {:?}: {}", dest_path, e))?
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                out.push((relative_path, own_path));
+            }
+            out
+        }
+        NameFixInstallType::Folders => source
+            .files
+            .iter()
+            .filter(|entry| {
+                entry.rel_path.starts_with("dbc/")
+                    || entry.rel_path.starts_with("edt/")
+                    || entry.rel_path.starts_with("lnc/")
+            })
+            .map(|entry| (entry.rel_path.clone(), fix_dir.join(&entry.rel_path)))
+            .collect(),
+    };
+
+    let mut entries = Vec::new();
+    for (relative_path, own_path) in targets {
+        let dest_path = db_dir.join(&relative_path);
+        if !dest_path.exists() {
+            continue;
+        }
+        let Some(owner) = other_sources
+            .iter()
+            .find(|s| s.file_hashes.contains_key(&relative_path))
+        else {
+            continue;
+        };
+
+        let bytes_differ = match (hash_file_streaming(&own_path), hash_file_streaming(&dest_path)) {
+            (Ok(own_hash), Ok(dest_hash)) => own_hash != dest_hash,
+            _ => true,
+        };
+
+        entries.push(NameFixConflictEntry {
+            relative_path,
+            owning_fix_id: owner.id.clone(),
+            owning_fix_name: owner.name.clone(),
+            bytes_differ,
+        });
+    }
+
+    Ok(NameFixConflictReport {
+        fix_id: fix_id.to_string(),
+        entries,
+    })
+}
+
+/// Tauri-facing wrapper around [`scan_install_conflicts`] that loads `fix_id`'s metadata and
+/// resolves the db dir from config, the same way [`install_name_fix`] does.
+pub fn scan_conflicts_for_fix(fix_id: String) -> Result<NameFixConflictReport, String> {
+    let config = load_config()?;
+    let db_dir = get_db_dir(config.target_path.as_deref())?;
+
+    let metadata_file = get_name_fixes_dir().join(&fix_id).join("metadata.json");
+    let source: NameFixSource = serde_json::from_str(
+        &fs::read_to_string(&metadata_file)
+            .map_err(|e| format!("Failed to read metadata: {}", e))?,
+    )
+    .map_err(|e| format!("Failed to parse metadata: {}", e))?;
+
+    scan_install_conflicts(&db_dir, &fix_id, &source)
+}
+
 /// Get list of all available name fix sources
 pub fn list_name_fixes() -> Result<Vec<NameFixSource>, String> {
     let name_fixes_dir = get_name_fixes_dir();
@@ -612,8 +1811,69 @@ pub fn list_name_fixes() -> Result<Vec<NameFixSource>, String> {
     Ok(sources)
 }
 
+/// Reads every entry of `archive_path` into a throwaway buffer and records whether it came back
+/// clean, so a truncated or corrupt download (common from mirror sites) can be rejected before
+/// [`import_name_fix`] extracts a half-broken fix instead of failing deep into extraction after
+/// partial writes. Some archive backends panic on malformed data rather than returning an error,
+/// so each read is wrapped in [`std::panic::catch_unwind`].
+pub fn validate_archive(archive_path: &Path) -> Result<ArchiveReport, String> {
+    let mut archive = open_archive(archive_path)?;
+    let names = archive.entry_names().to_vec();
+    let mut entries = Vec::with_capacity(names.len());
+
+    for (i, file_name) in names.into_iter().enumerate() {
+        let outcome = panic::catch_unwind(panic::AssertUnwindSafe(|| archive.read_entry(i)));
+
+        let status = match outcome {
+            Ok(Ok(_)) => ArchiveEntryStatus::Ok,
+            Ok(Err(e)) => {
+                if e.to_lowercase().contains("crc") {
+                    ArchiveEntryStatus::CrcMismatch
+                } else {
+                    ArchiveEntryStatus::DecompressError
+                }
+            }
+            Err(_) => ArchiveEntryStatus::Truncated,
+        };
+
+        entries.push(ArchiveEntryReport { file_name, status });
+    }
+
+    Ok(ArchiveReport { entries })
+}
+
+/// Runs [`validate_archive`] and turns a damaged archive into an error — rejecting the import
+/// with a report of the bad entries — instead of letting [`import_name_fix`] continue into a
+/// half-broken extraction.
+fn require_valid_archive(archive_path: &Path) -> Result<(), String> {
+    let report = validate_archive(archive_path)?;
+
+    let bad: Vec<String> = report
+        .entries
+        .iter()
+        .filter(|e| e.status != ArchiveEntryStatus::Ok)
+        .map(|e| format!("{} ({:?})", e.file_name, e.status))
+        .collect();
+
+    if bad.is_empty() {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Refusing to import: {} of {} archive entries are corrupt or truncated: {}. \
+        The download may be incomplete — try re-downloading the file.",
+        bad.len(),
+        report.entries.len(),
+        bad.join(", ")
+    ))
+}
+
 /// Import a name fix from a ZIP file
-pub fn import_name_fix(file_path: String, name: String) -> Result<String, String> {
+pub fn import_name_fix(
+    file_path: String,
+    name: String,
+    mut on_progress: impl FnMut(ExtractProgress),
+) -> Result<String, String> {
     tracing::info!("=== IMPORT NAME FIX CALLED ===");
     tracing::info!("File path: {}", file_path);
     tracing::info!("Name: {}", name);
@@ -623,43 +1883,79 @@ pub fn import_name_fix(file_path: String, name: String) -> Result<String, String
         tracing::error!("Source file does not exist: {:?}", source_path);
         return Err("Source file does not exist".to_string());
     }
-    
+
     tracing::info!("Source file exists: {:?}", source_path);
 
+    require_valid_archive(&source_path)?;
+
+    let line_ending = load_config()?.name_fix_line_ending;
+
     // Detect the install type from ZIP structure
     let install_type = detect_install_type(&source_path)?;
     tracing::info!("Detected install type: {:?}", install_type);
 
-    // Generate a unique ID for this name fix
-    let id = format!("imported-{}", uuid::Uuid::new_v4());
-    tracing::info!("Generated ID: {}", id);
-    
-    // Create directory for this name fix
+    // Extract into a staging directory first rather than the final `imported-<uuid>` id, so a
+    // duplicate of an already-imported archive can be detected from its contents before a second
+    // copy is committed to disk.
+    let uuid = uuid::Uuid::new_v4();
     let name_fixes_dir = get_name_fixes_dir();
-    tracing::info!("Name fixes directory: {:?}", name_fixes_dir);
-    
-    let fix_dir = name_fixes_dir.join(&id);
-    tracing::info!("Creating directory: {:?}", fix_dir);
-    
-    fs::create_dir_all(&fix_dir)
-        .map_err(|e| format!("Failed to create name fix directory: {}", e))?;
-    
-    tracing::info!("Directory created successfully");
+    let staging_dir_name = format!("importing-{}", uuid);
+    let staging_dir = name_fixes_dir.join(&staging_dir_name);
+    tracing::info!("Staging directory: {:?}", staging_dir);
 
-    // Extract files based on install type
+    fs::create_dir_all(&staging_dir)
+        .map_err(|e| format!("Failed to create name fix staging directory: {}", e))?;
+
+    // Extract files based on install type, building a BLAKE2b manifest of everything written
+    // into the fix's own storage directory so `verify_name_fix` can later tell if it's rotted.
     tracing::info!("Starting extraction...");
-    let file_count = match install_type {
-        NameFixInstallType::Files => extract_files_type(&source_path, &fix_dir)?,
-        NameFixInstallType::Folders => extract_folders_type(&source_path, &fix_dir)?,
+    let extraction = match install_type {
+        NameFixInstallType::Files => {
+            extract_files_type(&source_path, &staging_dir, line_ending, &mut on_progress)
+        }
+        NameFixInstallType::Folders => {
+            extract_folders_type(&source_path, &staging_dir, line_ending, &mut on_progress)
+        }
     };
-    tracing::info!("Extraction complete, {} items extracted", file_count);
+    let (file_count, files_normalized, manifest) = match extraction {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging_dir);
+            return Err(e);
+        }
+    };
+    tracing::info!(
+        "Extraction complete, {} items extracted ({} normalized)",
+        file_count, files_normalized
+    );
+
+    let aggregate_hash = compute_aggregate_hash(&manifest);
+
+    if let Some(existing) = find_fix_by_aggregate_hash(&aggregate_hash, &staging_dir_name) {
+        let _ = fs::remove_dir_all(&staging_dir);
+        tracing::info!(
+            "'{}' matches already-imported fix '{}' ({}); reusing it instead of re-extracting",
+            name, existing.name, existing.id
+        );
+        return Ok(format!(
+            "'{}' is already imported as '{}' ({} items) - reusing it",
+            name, existing.name, file_count
+        ));
+    }
+
+    let id = format!("imported-{}", uuid);
+    let fix_dir = name_fixes_dir.join(&id);
+    if let Err(e) = fs::rename(&staging_dir, &fix_dir) {
+        let _ = fs::remove_dir_all(&staging_dir);
+        return Err(format!("Failed to finalize name fix directory: {}", e));
+    }
 
     // Create metadata
     let type_desc = match install_type {
         NameFixInstallType::Files => "File-based",
         NameFixInstallType::Folders => "Folder-based",
     };
-    
+
     let source = NameFixSource {
         id: id.clone(),
         name: name.clone(),
@@ -667,37 +1963,70 @@ pub fn import_name_fix(file_path: String, name: String) -> Result<String, String
         install_type,
         description: format!("{} - Imported from {} ({} items)", type_desc, source_path.file_name().unwrap_or_default().to_string_lossy(), file_count),
         imported_date: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        // Populated once this fix is actually installed (see `install_name_fix`), since only
+        // then do we know the resolved db-dir paths the hashes should key off of.
+        file_hashes: HashMap::new(),
+        files: manifest,
+        aggregate_hash,
+        files_normalized,
+        install_map: Vec::new(),
+        schema_version: NAME_FIX_SCHEMA_VERSION,
+        // Populated once this fix is actually installed (see `install_name_fix`), since only
+        // then do we know which schema folder it landed under.
+        game_build: String::new(),
     };
 
     // Save metadata
     let metadata_file = fix_dir.join("metadata.json");
-    let metadata_json = serde_json::to_string_pretty(&source)
-        .map_err(|e| format!("Failed to serialize metadata: {}", e))?;
-    fs::write(&metadata_file, metadata_json)
-        .map_err(|e| format!("Failed to save metadata: {}", e))?;
+    let write_result = serde_json::to_string_pretty(&source)
+        .map_err(|e| format!("Failed to serialize metadata: {}", e))
+        .and_then(|metadata_json| {
+            fs::write(&metadata_file, metadata_json)
+                .map_err(|e| format!("Failed to save metadata: {}", e))
+        });
+
+    if let Err(e) = write_result {
+        let _ = fs::remove_dir_all(&fix_dir);
+        return Err(e);
+    }
 
     tracing::info!("Name fix imported successfully: {}", name);
-    Ok(format!("Successfully imported '{}' ({}) with {} items", name, type_desc, file_count))
+    let normalized_note = if files_normalized > 0 {
+        format!(", {} line-ending normalized", files_normalized)
+    } else {
+        String::new()
+    };
+    Ok(format!(
+        "Successfully imported '{}' ({}) with {} items{}",
+        name, type_desc, file_count, normalized_note
+    ))
 }
 
-/// Detect whether this is a file-based or folder-based name fix
-fn detect_install_type(zip_path: &Path) -> Result<NameFixInstallType, String> {
-    let file = fs::File::open(zip_path)
-        .map_err(|e| format!("Failed to open file: {}", e))?;
+/// Imports each `(file_path, name)` pair via [`import_name_fix`] independently, so a bad archive
+/// partway through a batch doesn't stop the rest from importing. Returns one `Result` per input
+/// item, in the same order, for the caller to report per-archive success/failure back to the UI.
+pub fn import_name_fixes(
+    files: Vec<(String, String)>,
+    mut on_progress: impl FnMut(usize, ExtractProgress),
+) -> Vec<Result<String, String>> {
+    files
+        .into_iter()
+        .enumerate()
+        .map(|(index, (file_path, name))| {
+            import_name_fix(file_path, name, |progress| on_progress(index, progress))
+        })
+        .collect()
+}
 
-    let mut archive = ZipArchive::new(file)
-        .map_err(|e| format!("Failed to read ZIP archive: {}", e))?;
+/// Detect whether this is a file-based or folder-based name fix
+fn detect_install_type(archive_path: &Path) -> Result<NameFixInstallType, String> {
+    let archive = open_archive(archive_path)?;
 
     let mut has_folders = false;
     let mut has_editor_data = false;
     let mut has_individual_files = false;
 
-    for i in 0..archive.len() {
-        let file = archive.by_index(i)
-            .map_err(|e| format!("Failed to read ZIP entry: {}", e))?;
-        
-        let name = file.name();
-        
+    for name in archive.entry_names() {
         // Check for Sortitoutsi style (folders + editor data) at any depth
         if name.contains("dbc/") || name.contains("edt/") || name.contains("lnc/") {
             has_folders = true;
@@ -726,169 +2055,362 @@ fn detect_install_type(zip_path: &Path) -> Result<NameFixInstallType, String> {
 }
 
 /// Extract file-based name fix (FMScout style)
-fn extract_files_type(zip_path: &Path, dest_dir: &Path) -> Result<usize, String> {
+fn extract_files_type(
+    archive_path: &Path,
+    dest_dir: &Path,
+    line_ending: LineEndingStyle,
+    on_progress: impl FnMut(ExtractProgress),
+) -> Result<(usize, usize, Vec<NameFixManifestEntry>), String> {
     tracing::info!("Extracting file-based name fix");
-    extract_all_namefix_files(zip_path, dest_dir)
+    extract_all_namefix_files(archive_path, dest_dir, line_ending, on_progress)
+}
+
+fn is_namefix_entry(file_name: &str) -> bool {
+    !file_name.ends_with('/')
+        && (file_name.ends_with(".lnc") || file_name.ends_with(".edt") || file_name.ends_with(".dbc"))
 }
 
 /// Extract all name fix files from a ZIP archive
 /// Extracts all .lnc, .edt, and .dbc files from the ZIP
-fn extract_all_namefix_files(zip_path: &Path, dest_dir: &Path) -> Result<usize, String> {
-    tracing::info!("Extracting name fix files from: {:?} to {:?}", zip_path, dest_dir);
-    
-    let file = fs::File::open(zip_path)
-        .map_err(|e| format!("Failed to open file: {}", e))?;
-
-    let mut archive = ZipArchive::new(file)
-        .map_err(|e| format!("Failed to read ZIP archive: {}", e))?;
-
-    tracing::info!("ZIP archive contains {} entries", archive.len());
-    
-    let mut file_count = 0;
-
-    // Extract all .lnc, .edt, and .dbc files
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)
-            .map_err(|e| format!("Failed to read ZIP entry {}: {}", i, e))?;
-
-        let file_name = file.name().to_string(); // Clone the name before using the file
-        
-        tracing::debug!("Processing ZIP entry: {}", file_name);
-        
-        // Skip directories
+fn extract_all_namefix_files(
+    archive_path: &Path,
+    dest_dir: &Path,
+    line_ending: LineEndingStyle,
+    mut on_progress: impl FnMut(ExtractProgress),
+) -> Result<(usize, usize, Vec<NameFixManifestEntry>), String> {
+    tracing::info!("Extracting name fix files from: {:?} to {:?}", archive_path, dest_dir);
+
+    let mut archive = open_archive(archive_path)?;
+    let names = archive.entry_names().to_vec();
+
+    tracing::info!("Archive contains {} entries", names.len());
+
+    // Figure out which entries are relevant (and what filename each one extracts to) before
+    // touching any I/O, so the actual reads can be fanned out to `read_entries_parallel` in one
+    // batch rather than one archive lookup at a time.
+    let mut relevant = Vec::new();
+    for (i, file_name) in names.iter().enumerate() {
         if file_name.ends_with('/') {
             tracing::debug!("Skipping directory: {}", file_name);
             continue;
         }
-        
-        // Check if this is a relevant file type
-        if file_name.ends_with(".lnc") || file_name.ends_with(".edt") || file_name.ends_with(".dbc") {
-            // Extract just the filename (remove any directory structure from the ZIP)
-            let path = PathBuf::from(&file_name);
-            let filename = path.file_name()
-                .ok_or_else(|| format!("Invalid file name in archive: {}", file_name))?;
-            
-            let dest_file = dest_dir.join(filename);
-            
-            let mut contents = Vec::new();
-            file.read_to_end(&mut contents)
-                .map_err(|e| format!("Failed to read file from archive: {}", e))?;
-
-            fs::write(&dest_file, &contents)
-                .map_err(|e| format!("Failed to write file: {}", e))?;
-
-            tracing::info!("Extracted: {} ({} bytes) -> {:?}", filename.to_string_lossy(), contents.len(), dest_file);
-            file_count += 1;
-        } else {
+        if !is_namefix_entry(file_name) {
             tracing::debug!("Skipping non-namefix file: {}", file_name);
+            continue;
         }
+        let path = PathBuf::from(file_name);
+        let filename = path
+            .file_name()
+            .ok_or_else(|| format!("Invalid file name in archive: {}", file_name))?
+            .to_string_lossy()
+            .to_string();
+        relevant.push((i, filename));
     }
 
-    if file_count == 0 {
-        return Err("No valid name fix files (.lnc, .edt, or .dbc) found in ZIP archive".to_string());
+    let files_total = relevant.len();
+    on_progress(ExtractProgress { files_done: 0, files_total });
+
+    if files_total == 0 {
+        return Err("No valid name fix files (.lnc, .edt, or .dbc) found in archive".to_string());
     }
 
-    tracing::info!("Successfully extracted {} files", file_count);
-    Ok(file_count)
+    let indices: Vec<usize> = relevant.iter().map(|(i, _)| *i).collect();
+    let contents = archive.read_entries_parallel(&indices)?;
+
+    // Hashing and writing are independent per file, so run them concurrently; a deterministic
+    // first error (by archive-entry order, not completion order) falls out of re-collecting the
+    // `Result`s sequentially afterwards.
+    let normalized_count = std::sync::atomic::AtomicUsize::new(0);
+    let manifest: Vec<NameFixManifestEntry> = relevant
+        .par_iter()
+        .zip(contents.par_iter())
+        .map(|((_, filename), contents)| {
+            let mut contents = contents.clone();
+            if normalize_text_contents(&mut contents, line_ending) {
+                normalized_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+            let (size, blake2b_hex) = hash_bytes_blake2b(&contents);
+            let dest_file = dest_dir.join(filename);
+            fs::write(&dest_file, &contents).map_err(|e| format!("Failed to write file: {}", e))?;
+            tracing::info!("Extracted: {} ({} bytes) -> {:?}", filename, contents.len(), dest_file);
+            Ok(NameFixManifestEntry {
+                rel_path: filename.clone(),
+                size,
+                blake2b_hex,
+            })
+        })
+        .collect::<Vec<Result<NameFixManifestEntry, String>>>()
+        .into_iter()
+        .collect::<Result<Vec<NameFixManifestEntry>, String>>()?;
+
+    let file_count = manifest.len();
+    let files_normalized = normalized_count.load(std::sync::atomic::Ordering::Relaxed);
+    on_progress(ExtractProgress { files_done: file_count, files_total });
+
+    tracing::info!("Successfully extracted {} files ({} normalized)", file_count, files_normalized);
+    Ok((file_count, files_normalized, manifest))
+}
+
+/// If `file_name` sits under one of the target folders (at any depth), returns the path relative
+/// to that folder's start — e.g. `"some/prefix/dbc/permanent/x.dbc"` -> `"dbc/permanent/x.dbc"`.
+fn relevant_folder_path(file_name: &str) -> Option<&str> {
+    if file_name.ends_with('/') {
+        return None;
+    }
+    for folder in ["dbc/", "edt/", "lnc/", "editor data/"] {
+        if let Some(idx) = file_name.find(folder) {
+            return Some(&file_name[idx..]);
+        }
+    }
+    None
 }
 
 /// Extract folder-based name fix (Sortitoutsi style)
 /// Extracts dbc, edt, lnc folders and editor data folder
-fn extract_folders_type(zip_path: &Path, dest_dir: &Path) -> Result<usize, String> {
+fn extract_folders_type(
+    archive_path: &Path,
+    dest_dir: &Path,
+    line_ending: LineEndingStyle,
+    mut on_progress: impl FnMut(ExtractProgress),
+) -> Result<(usize, usize, Vec<NameFixManifestEntry>), String> {
     tracing::info!("Extracting folder-based name fix (Sortitoutsi style)");
-    
-    let file = fs::File::open(zip_path)
-        .map_err(|e| format!("Failed to open file: {}", e))?;
 
-    let mut archive = ZipArchive::new(file)
-        .map_err(|e| format!("Failed to read ZIP archive: {}", e))?;
+    let mut archive = open_archive(archive_path)?;
+    let names = archive.entry_names().to_vec();
 
-    tracing::info!("ZIP archive contains {} entries", archive.len());
-    
-    let mut item_count = 0;
+    tracing::info!("Archive contains {} entries", names.len());
 
-    // Extract all files, preserving folder structure but stripping leading path
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)
-            .map_err(|e| format!("Failed to read ZIP entry {}: {}", i, e))?;
+    // Figure out which entries are relevant (and their destination-relative path) before
+    // touching any I/O, so the actual reads can be fanned out to `read_entries_parallel` in one
+    // batch rather than one archive lookup at a time.
+    let relevant: Vec<(usize, String)> = names
+        .iter()
+        .enumerate()
+        .filter_map(|(i, file_name)| relevant_folder_path(file_name).map(|p| (i, p.to_string())))
+        .collect();
 
-        let file_name = file.name().to_string();
-        
-        // Skip directories
-        if file_name.ends_with('/') {
-            continue;
+    let files_total = relevant.len();
+    on_progress(ExtractProgress { files_done: 0, files_total });
+
+    if files_total == 0 {
+        return Err("No valid name fix folders (dbc/, edt/, lnc/, editor data/) found in archive".to_string());
+    }
+
+    // Parent directories are created up front, sequentially, since concurrent `create_dir_all`
+    // calls on overlapping ancestor paths would race.
+    for (_, rel_path) in &relevant {
+        if let Some(parent) = dest_dir.join(rel_path).parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
         }
-        
-        // Check if this file is in one of the target folders (at any depth)
-        let relevant_path = if let Some(idx) = file_name.find("dbc/") {
-            Some(&file_name[idx..])
-        } else if let Some(idx) = file_name.find("edt/") {
-            Some(&file_name[idx..])
-        } else if let Some(idx) = file_name.find("lnc/") {
-            Some(&file_name[idx..])
-        } else if let Some(idx) = file_name.find("editor data/") {
-            Some(&file_name[idx..])
-        } else {
-            None
-        };
-        
-        if let Some(rel_path) = relevant_path {
-            let dest_file = dest_dir.join(rel_path);
-            
-            // Create parent directories
-            if let Some(parent) = dest_file.parent() {
-                fs::create_dir_all(parent)
-                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+
+    let indices: Vec<usize> = relevant.iter().map(|(i, _)| *i).collect();
+    let contents = archive.read_entries_parallel(&indices)?;
+
+    // Hashing and writing are independent per file, so run them concurrently; a deterministic
+    // first error (by archive-entry order, not completion order) falls out of re-collecting the
+    // `Result`s sequentially afterwards.
+    let normalized_count = std::sync::atomic::AtomicUsize::new(0);
+    let manifest: Vec<NameFixManifestEntry> = relevant
+        .par_iter()
+        .zip(contents.par_iter())
+        .map(|((_, rel_path), contents)| {
+            let mut contents = contents.clone();
+            // Folders also carry non-text payloads (e.g. editor data images), so only text-type
+            // entries get normalized.
+            if is_namefix_entry(rel_path) && normalize_text_contents(&mut contents, line_ending) {
+                normalized_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
             }
-            
-            let mut contents = Vec::new();
-            file.read_to_end(&mut contents)
-                .map_err(|e| format!("Failed to read file from archive: {}", e))?;
+            let (size, blake2b_hex) = hash_bytes_blake2b(&contents);
+            let dest_file = dest_dir.join(rel_path);
+            fs::write(&dest_file, &contents).map_err(|e| format!("Failed to write file: {}", e))?;
+            tracing::debug!("Extracted: {} -> {:?}", rel_path, dest_file);
+            Ok(NameFixManifestEntry {
+                rel_path: rel_path.clone(),
+                size,
+                blake2b_hex,
+            })
+        })
+        .collect::<Vec<Result<NameFixManifestEntry, String>>>()
+        .into_iter()
+        .collect::<Result<Vec<NameFixManifestEntry>, String>>()?;
+
+    let item_count = manifest.len();
+    let files_normalized = normalized_count.load(std::sync::atomic::Ordering::Relaxed);
+    on_progress(ExtractProgress { files_done: item_count, files_total });
+
+    tracing::info!("Successfully extracted {} items ({} normalized)", item_count, files_normalized);
+    Ok((item_count, files_normalized, manifest))
+}
 
-            fs::write(&dest_file, &contents)
-                .map_err(|e| format!("Failed to write file: {}", e))?;
+/// Extract .lnc file from a name-fix archive
+fn extract_lnc_from_file(archive_path: &Path) -> Result<Vec<u8>, String> {
+    let mut archive = open_archive(archive_path)?;
+    let names = archive.entry_names().to_vec();
 
-            tracing::debug!("Extracted: {} -> {:?}", file_name, dest_file);
-            item_count += 1;
+    // Look for any .lnc file in the archive
+    for (i, file_name) in names.into_iter().enumerate() {
+        if file_name.ends_with(".lnc") {
+            let contents = archive.read_entry(i)?;
+
+            tracing::info!("Found .lnc file: {} ({} bytes)", file_name, contents.len());
+            return Ok(contents);
         }
     }
 
-    if item_count == 0 {
-        return Err("No valid name fix folders (dbc/, edt/, lnc/, editor data/) found in ZIP archive".to_string());
-    }
+    Err("No .lnc file found in archive".to_string())
+}
 
-    tracing::info!("Successfully extracted {} items", item_count);
-    Ok(item_count)
+/// True if `dest`'s current size and BLAKE2b digest match `entry`, i.e. reinstalling it would
+/// write back the exact bytes already there.
+fn files_identical_to_manifest(dest: &Path, entry: &NameFixManifestEntry) -> bool {
+    let Ok(metadata) = fs::metadata(dest) else {
+        return false;
+    };
+    if metadata.len() != entry.size {
+        return false;
+    }
+    matches!(hash_file_blake2b(dest), Ok((_, hash)) if hash == entry.blake2b_hex)
 }
 
-/// Extract .lnc file from a ZIP archive
-fn extract_lnc_from_file(zip_path: &Path) -> Result<Vec<u8>, String> {
-    let file = fs::File::open(zip_path)
-        .map_err(|e| format!("Failed to open file: {}", e))?;
+/// Verifies every file [`install_name_fix`] is about to copy still matches the manifest captured
+/// at import time (same size + BLAKE2b digest [`files_identical_to_manifest`] checks), so a
+/// partially-downloaded or tampered fix is caught before it ever touches the db dir. No-op for
+/// fixes imported before the manifest existed (`files` empty) — nothing to check against.
+fn verify_source_integrity(fix_dir: &Path, files: &[NameFixManifestEntry]) -> Result<(), String> {
+    let mut problems = Vec::new();
+
+    for entry in files {
+        let path = fix_dir.join(&entry.rel_path);
+        if !files_identical_to_manifest(&path, entry) {
+            problems.push(if path.exists() {
+                format!("{} (hash/size mismatch)", entry.rel_path)
+            } else {
+                format!("{} (missing)", entry.rel_path)
+            });
+        }
+    }
 
-    let mut archive = ZipArchive::new(file)
-        .map_err(|e| format!("Failed to read ZIP archive: {}", e))?;
+    if problems.is_empty() {
+        return Ok(());
+    }
 
-    // Look for any .lnc file in the archive
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)
-            .map_err(|e| format!("Failed to read ZIP entry: {}", e))?;
+    Err(format!(
+        "Refusing to install: {} file(s) in this name fix failed integrity verification against \
+        its manifest (possibly a partial download or tampering). The database was not touched. \
+        Affected files:\n{}",
+        problems.len(),
+        problems.join("\n")
+    ))
+}
 
-        if file.name().ends_with(".lnc") {
-            let mut contents = Vec::new();
-            file.read_to_end(&mut contents)
-                .map_err(|e| format!("Failed to read .lnc file from archive: {}", e))?;
+/// True if every file in `source`'s manifest already sits at its destination in `db_dir` with a
+/// matching hash, meaning [`install_name_fix`] would have nothing to do. Fixes imported before
+/// the manifest existed (`source.files` empty) never short-circuit.
+fn already_installed(db_dir: &Path, source: &NameFixSource) -> bool {
+    if source.files.is_empty() {
+        return false;
+    }
 
-            tracing::info!("Found .lnc file: {} ({} bytes)", file.name(), contents.len());
-            return Ok(contents);
+    source.files.iter().all(|entry| {
+        let dest_path = match source.install_type {
+            NameFixInstallType::Files => match files_type_dest_path(db_dir, &entry.rel_path, &source.install_map) {
+                Some(path) => path,
+                None => return false,
+            },
+            NameFixInstallType::Folders => db_dir.join(&entry.rel_path),
+        };
+        files_identical_to_manifest(&dest_path, entry)
+    })
+}
+
+/// Recursively lists every file under `dir`, returning each one's path relative to `dir` (with
+/// `/` separators) paired with its absolute path, for [`verify_name_fix`] to rehash and to spot
+/// files the manifest doesn't know about.
+fn list_files_relative(dir: &Path, base: &Path, out: &mut Vec<(String, PathBuf)>) -> Result<(), String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory {:?}: {}", dir, e))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            list_files_relative(&path, base, out)?;
+        } else {
+            let relative_path = path
+                .strip_prefix(base)
+                .map_err(|e| format!("Failed to compute relative path for {:?}: {}", path, e))?
+                .to_string_lossy()
+                .replace('\\', "/");
+            if relative_path == "metadata.json" {
+                continue;
+            }
+            out.push((relative_path, path));
         }
     }
 
-    Err("No .lnc file found in ZIP archive".to_string())
+    Ok(())
+}
+
+/// Rehashes the files currently sitting in an imported fix's storage directory and compares them
+/// against the manifest captured at import time, so a user can confirm a fix on disk hasn't
+/// rotted (or been tampered with) without having to reinstall it first.
+pub fn verify_name_fix(name_fix_id: String) -> Result<NameFixManifestReport, String> {
+    let fix_dir = get_name_fixes_dir().join(&name_fix_id);
+    if !fix_dir.exists() {
+        return Err("Name fix not found".to_string());
+    }
+
+    let metadata_file = fix_dir.join("metadata.json");
+    let metadata_str = fs::read_to_string(&metadata_file)
+        .map_err(|e| format!("Failed to read metadata: {}", e))?;
+    let source: NameFixSource = serde_json::from_str(&metadata_str)
+        .map_err(|e| format!("Failed to parse metadata: {}", e))?;
+
+    let mut on_disk = Vec::new();
+    list_files_relative(&fix_dir, &fix_dir, &mut on_disk)?;
+    let mut remaining: HashMap<String, PathBuf> = on_disk.into_iter().collect();
+
+    let mut entries = Vec::with_capacity(source.files.len());
+
+    for manifest_entry in &source.files {
+        let status = match remaining.remove(&manifest_entry.rel_path) {
+            Some(path) if files_identical_to_manifest(&path, manifest_entry) => {
+                NameFixManifestStatus::Ok
+            }
+            Some(_) => NameFixManifestStatus::Modified,
+            None => NameFixManifestStatus::Removed,
+        };
+        entries.push(NameFixManifestCheckEntry {
+            rel_path: manifest_entry.rel_path.clone(),
+            status,
+        });
+    }
+
+    // Anything left in `remaining` exists on disk but isn't in the manifest.
+    for rel_path in remaining.into_keys() {
+        entries.push(NameFixManifestCheckEntry {
+            rel_path,
+            status: NameFixManifestStatus::Added,
+        });
+    }
+
+    Ok(NameFixManifestReport {
+        name_fix_id,
+        entries,
+    })
 }
 
-/// Install a specific name fix by ID
-pub fn install_name_fix(name_fix_id: String) -> Result<String, String> {
+/// Install a specific name fix by ID. `conflict_resolutions` maps a conflicting destination path
+/// (as reported by [`scan_install_conflicts`]) to how to handle it; a path with no entry defaults
+/// to [`NameFixConflictAction::Overwrite`], matching the old unconditional-overwrite behavior.
+/// Reports an [`InstallProgress`] per file backed up or installed, so the caller can drive a
+/// determinate progress bar on large folder-based (Sortitoutsi) fixes.
+pub fn install_name_fix(
+    name_fix_id: String,
+    force: bool,
+    conflict_resolutions: HashMap<String, NameFixConflictAction>,
+    mut on_progress: impl FnMut(InstallProgress),
+) -> Result<String, String> {
     let config = load_config()?;
     let db_dir = get_db_dir(config.target_path.as_deref())?;
 
@@ -897,103 +2419,284 @@ pub fn install_name_fix(name_fix_id: String) -> Result<String, String> {
     // Get the name fix metadata to determine install type
     let name_fixes_dir = get_name_fixes_dir();
     let fix_dir = name_fixes_dir.join(&name_fix_id);
-    
+
     if !fix_dir.exists() {
         return Err("Name fix not found".to_string());
     }
-    
+
     let metadata_file = fix_dir.join("metadata.json");
     let metadata_str = fs::read_to_string(&metadata_file)
         .map_err(|e| format!("Failed to read metadata: {}", e))?;
-    let source: NameFixSource = serde_json::from_str(&metadata_str)
+    let mut source: NameFixSource = serde_json::from_str(&metadata_str)
         .map_err(|e| format!("Failed to parse metadata: {}", e))?;
 
+    gate_and_migrate_schema_version(&mut source)?;
+
     tracing::info!("Install type: {:?}", source.install_type);
 
-    // Create backups before making any changes
-    match source.install_type {
-        NameFixInstallType::Files => create_backups(&db_dir)?,
-        NameFixInstallType::Folders => create_folder_backups(&db_dir)?,
+    if already_installed(&db_dir, &source) {
+        tracing::info!("Name fix {} already installed, files identical", name_fix_id);
+        let mut config = load_config()?;
+        push_active_fix(&mut config, &name_fix_id);
+        save_config(&config)?;
+        return Ok("Name fix already installed, files identical".to_string());
     }
 
-    // Install based on type
-    match source.install_type {
-        NameFixInstallType::Files => install_files_type(&fix_dir, &db_dir)?,
-        NameFixInstallType::Folders => install_folders_type(&fix_dir, &db_dir, config.user_dir_path.as_deref())?,
+    require_no_unknown_conflicts(&db_dir, config.active_name_fix.as_deref(), force)?;
+
+    // Resolve conflicts with other installed fixes' destination paths before touching anything.
+    let conflict_report = scan_install_conflicts(&db_dir, &name_fix_id, &source)?;
+    let mut skip_paths: HashSet<String> = HashSet::new();
+    for entry in &conflict_report.entries {
+        let action = conflict_resolutions
+            .get(&entry.relative_path)
+            .copied()
+            .unwrap_or_default();
+        match action {
+            NameFixConflictAction::Abort => {
+                return Err(format!(
+                    "Aborted: {} is owned by name fix '{}' ({}). Choose Skip or Overwrite for \
+                    this path to proceed.",
+                    entry.relative_path, entry.owning_fix_name, entry.owning_fix_id
+                ));
+            }
+            NameFixConflictAction::Skip => {
+                if source.install_type == NameFixInstallType::Folders {
+                    // install_folders_type replaces the whole dbc/edt/lnc subtree, so there's no
+                    // way to leave just this one path alone — fall back to overwriting it.
+                    tracing::warn!(
+                        "Cannot honor Skip for {} in a folder-based install; overwriting instead",
+                        entry.relative_path
+                    );
+                } else {
+                    skip_paths.insert(entry.relative_path.clone());
+                }
+            }
+            NameFixConflictAction::Overwrite => {}
+        }
     }
 
+    // Create backups before making any changes
+    let backup_dir = match source.install_type {
+        NameFixInstallType::Files => create_backups(
+            &db_dir,
+            &name_fix_id,
+            config.name_fix_backup_mode,
+            config.name_fix_backup_retention,
+            &mut on_progress,
+        )?,
+        NameFixInstallType::Folders => create_folder_backups(
+            &db_dir,
+            &name_fix_id,
+            config.name_fix_backup_mode,
+            config.name_fix_backup_compression,
+            config.name_fix_backup_compression_level,
+            config.name_fix_backup_retention,
+            &mut on_progress,
+        )?,
+    };
+
+    // Backups are captured, so from here on the db dir itself hasn't been touched yet — verify
+    // the source files in fix_dir still match what was recorded at import time before copying
+    // any of them in, so a partial download or tampered fix aborts cleanly instead of bricking
+    // the database.
+    verify_source_integrity(&fix_dir, &source.files)?;
+
+    // Install based on type, recording the SHA-256 of each file placed into the db dir so
+    // `verify`/`repair` can detect later corruption or an FM update overwriting the fix.
+    source.file_hashes = match source.install_type {
+        NameFixInstallType::Files => install_files_type(
+            &fix_dir,
+            &db_dir,
+            &skip_paths,
+            &source.install_map,
+            &mut on_progress,
+        )?,
+        NameFixInstallType::Folders => install_folders_type(
+            &fix_dir,
+            &db_dir,
+            config.user_dir_path.as_deref(),
+            &mut on_progress,
+        )?,
+    };
+    source.game_build = game_build_from_db_dir(&db_dir);
+
+    let metadata_json = serde_json::to_string_pretty(&source)
+        .map_err(|e| format!("Failed to update metadata: {}", e))?;
+    fs::write(&metadata_file, metadata_json)
+        .map_err(|e| format!("Failed to update metadata: {}", e))?;
+
     // Update config to track active name fix
     let mut config = load_config()?;
-    config.active_name_fix = Some(name_fix_id);
+    push_active_fix(&mut config, &name_fix_id);
     save_config(&config)?;
 
     tracing::info!("Name fix installation completed successfully");
-    let app_data_dir = get_app_data_dir();
-    
+
     let message = match source.install_type {
         NameFixInstallType::Files => format!(
             "Name fix installed successfully! The following changes were made:\n\
             - Installed name fix files to fix licensing issues\n\
             - Removed stock licensing files\n\
-            - Created backup at {}\n\n\
+            {}\n\
             Note: For existing saves, Brazilian clubs will update after you start a new save.",
-            app_data_dir.join("name_fix_backup").display()
+            backup_note(&backup_dir)
         ),
         NameFixInstallType::Folders => format!(
             "Name fix installed successfully! The following changes were made:\n\
             - Replaced dbc, edt, and lnc folders\n\
             - Added editor data files\n\
-            - Created backup at {}\n\n\
+            {}\n\
             Note: You must restart FM26 for changes to take effect. For existing saves, some changes require a new game.",
-            app_data_dir.join("name_fix_backup").display()
+            backup_note(&backup_dir)
         ),
     };
-    
+
     Ok(message)
 }
 
-/// Install file-based name fix (FMScout style)
-fn install_files_type(fix_dir: &Path, db_dir: &Path) -> Result<(), String> {
-    tracing::info!("Installing file-based name fix");
-    
-    let mut installed_count = 0;
+/// Path, relative to the db dir, a file-based (FMScout-style) name fix file with this filename
+/// would land at. Returns `None` for extensions [`install_files_type`] skips.
+fn files_type_dest_relpath(filename: &str) -> Option<PathBuf> {
+    if filename.ends_with(".lnc") {
+        Some(Path::new("lnc").join("all").join(filename))
+    } else if filename.ends_with(".edt") {
+        Some(Path::new("edt").join("permanent").join(filename))
+    } else if filename.ends_with(".dbc") {
+        // Language files typically have _chn suffix or contain "licensing" without "_post_"
+        let lower = filename.to_lowercase();
+        if lower.contains("_chn") || (lower.contains("licensing") && !filename.contains("_post_")) {
+            Some(Path::new("dbc").join("language").join(filename))
+        } else {
+            Some(Path::new("dbc").join("permanent").join(filename))
+        }
+    } else {
+        None
+    }
+}
 
-    // Read all files from the imported name fix directory
-    let entries = fs::read_dir(fix_dir)
-        .map_err(|e| format!("Failed to read name fix directory: {}", e))?;
+/// Matches a single glob segment (may contain `*` wildcards) against `value`, case-insensitively.
+fn glob_match_segment(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let value = value.to_lowercase();
 
-    for entry in entries.flatten() {
-        let file_path = entry.path();
-        if !file_path.is_file() {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return value == pattern;
+    }
+
+    let mut pos = 0;
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() {
             continue;
         }
+        if i == 0 {
+            if !value[pos..].starts_with(part) {
+                return false;
+            }
+            pos += part.len();
+        } else if i == parts.len() - 1 {
+            if !value[pos..].ends_with(part) {
+                return false;
+            }
+        } else {
+            match value[pos..].find(part) {
+                Some(found) => pos += found + part.len(),
+                None => return false,
+            }
+        }
+    }
+    true
+}
 
+/// Matches a `/`-separated glob `pattern` against `candidate` segment by segment, case-
+/// insensitively. Segment counts must match exactly; there's no recursive `**`.
+fn glob_match_path(pattern: &str, candidate: &str) -> bool {
+    let pattern_segments: Vec<&str> = pattern.split('/').collect();
+    let candidate_segments: Vec<&str> = candidate.split('/').collect();
+
+    pattern_segments.len() == candidate_segments.len()
+        && pattern_segments
+            .iter()
+            .zip(candidate_segments.iter())
+            .all(|(p, c)| glob_match_segment(p, c))
+}
+
+/// Checks `filename` against a [`NameFixSource::install_map`] and returns the destination
+/// path of the first entry whose glob matches, if any.
+fn install_map_dest_relpath(filename: &str, install_map: &[NameFixInstallMapEntry]) -> Option<PathBuf> {
+    install_map
+        .iter()
+        .find(|entry| glob_match_path(&entry.pattern, filename))
+        .map(|entry| PathBuf::from(entry.dest.replace('\\', "/")))
+}
+
+/// `db_dir`-joined form of [`files_type_dest_relpath`], checking `install_map` first so an
+/// author-supplied routing table takes precedence over the extension/filename heuristics.
+fn files_type_dest_path(
+    db_dir: &Path,
+    filename: &str,
+    install_map: &[NameFixInstallMapEntry],
+) -> Option<PathBuf> {
+    if let Some(mapped) = install_map_dest_relpath(filename, install_map) {
+        return Some(db_dir.join(mapped).join(filename));
+    }
+    Some(db_dir.join(files_type_dest_relpath(filename)?))
+}
+
+/// Installs every `.lnc`/`.edt`/`.dbc` file from `fix_dir`, except paths in `skip_paths`
+/// (relative to `db_dir`, as chosen by the caller of `install_name_fix` to resolve a conflict
+/// with another installed fix). Reports an `"installing"` [`InstallProgress`] per file copied.
+/// Returns the SHA-256 of each installed file keyed by its path relative to `db_dir`, for
+/// `verify`/`repair` to check against later.
+fn install_files_type(
+    fix_dir: &Path,
+    db_dir: &Path,
+    skip_paths: &HashSet<String>,
+    install_map: &[NameFixInstallMapEntry],
+    on_progress: &mut dyn FnMut(InstallProgress),
+) -> Result<HashMap<String, String>, String> {
+    tracing::info!("Installing file-based name fix");
+
+    let mut installed_count = 0;
+    let mut file_hashes = HashMap::new();
+
+    // Read all files from the imported name fix directory
+    let entries: Vec<PathBuf> = fs::read_dir(fix_dir)
+        .map_err(|e| format!("Failed to read name fix directory: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    let total = entries.len();
+
+    for (index, file_path) in entries.into_iter().enumerate() {
         let filename = file_path.file_name()
             .ok_or_else(|| "Invalid file name".to_string())?
-            .to_string_lossy();
+            .to_string_lossy()
+            .into_owned();
 
         // Skip metadata.json
         if filename == "metadata.json" {
             continue;
         }
 
-        // Determine destination based on file extension
-        let dest_path = if filename.ends_with(".lnc") {
-            db_dir.join("lnc").join("all").join(filename.as_ref())
-        } else if filename.ends_with(".edt") {
-            db_dir.join("edt").join("permanent").join(filename.as_ref())
-        } else if filename.ends_with(".dbc") {
-            // Language files typically have _chn suffix or contain "licensing" without "_post_"
-            if filename.to_lowercase().contains("_chn") || 
-               (filename.to_lowercase().contains("licensing") && !filename.contains("_post_")) {
-                db_dir.join("dbc").join("language").join(filename.as_ref())
-            } else {
-                db_dir.join("dbc").join("permanent").join(filename.as_ref())
-            }
-        } else {
+        // Determine destination: install_map first, extension/filename heuristics otherwise
+        let Some(dest_path) = files_type_dest_path(db_dir, &filename, install_map) else {
             continue; // Skip unknown file types
         };
 
+        let relative_path = dest_path
+            .strip_prefix(db_dir)
+            .map_err(|e| format!("Failed to compute relative path for {:?}: {}", dest_path, e))?
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        if skip_paths.contains(&relative_path) {
+            tracing::info!("Skipping {} (kept the other fix's version)", relative_path);
+            continue;
+        }
+
         // Create parent directory if needed
         if let Some(parent) = dest_path.parent() {
             fs::create_dir_all(parent)
@@ -1004,8 +2707,16 @@ fn install_files_type(fix_dir: &Path, db_dir: &Path) -> Result<(), String> {
         fs::copy(&file_path, &dest_path)
             .map_err(|e| format!("Failed to copy {}: {}", filename, e))?;
 
+        file_hashes.insert(relative_path, hash_file_streaming(&dest_path)?);
+
         tracing::info!("Installed: {} -> {:?}", filename, dest_path);
         installed_count += 1;
+        on_progress(InstallProgress {
+            current: index + 1,
+            total,
+            current_file: filename,
+            operation: "installing".to_string(),
+        });
     }
 
     if installed_count == 0 {
@@ -1013,17 +2724,26 @@ fn install_files_type(fix_dir: &Path, db_dir: &Path) -> Result<(), String> {
     }
 
     tracing::info!("Installed {} files from imported name fix", installed_count);
-    
+
     // Delete stock licensing files
     delete_licensing_files(db_dir)?;
-    
-    Ok(())
+
+    Ok(file_hashes)
 }
 
-/// Install folder-based name fix (Sortitoutsi style)
-fn install_folders_type(fix_dir: &Path, db_dir: &Path, user_dir: Option<&str>) -> Result<(), String> {
+/// Install folder-based name fix (Sortitoutsi style). Returns the SHA-256 of each file placed
+/// into `db_dir` (editor data copied into the user dir is out of scope for `verify`/`repair`,
+/// which only track what lives in the db dir), keyed by its path relative to `db_dir`. Replaces
+/// the dbc/edt/lnc subtrees wholesale, so unlike `install_files_type` it has no `skip_paths`
+/// parameter — `install_name_fix` only offers Overwrite or Abort for folder-based conflicts.
+fn install_folders_type(
+    fix_dir: &Path,
+    db_dir: &Path,
+    user_dir: Option<&str>,
+    on_progress: &mut dyn FnMut(InstallProgress),
+) -> Result<HashMap<String, String>, String> {
     tracing::info!("Installing folder-based name fix (Sortitoutsi style)");
-    
+
     // Delete the existing dbc, edt, lnc folders
     for folder_name in &["dbc", "edt", "lnc"] {
         let folder_path = db_dir.join(folder_name);
@@ -1033,15 +2753,31 @@ fn install_folders_type(fix_dir: &Path, db_dir: &Path, user_dir: Option<&str>) -
                 .map_err(|e| format!("Failed to delete {} folder: {}", folder_name, e))?;
         }
     }
-    
-    // Copy the new folders from the imported name fix
+
+    // Copy the new folders from the imported name fix, reporting progress per file since these
+    // can hold thousands of entries.
+    let total: usize = ["dbc", "edt", "lnc"]
+        .iter()
+        .map(|folder_name| count_files_recursive(&fix_dir.join(folder_name)))
+        .sum();
+    let mut done = 0;
+
     let mut installed_count = 0;
+    let mut file_hashes = HashMap::new();
     for folder_name in &["dbc", "edt", "lnc"] {
         let src_folder = fix_dir.join(folder_name);
         if src_folder.exists() {
             let dest_folder = db_dir.join(folder_name);
             tracing::info!("Copying {} folder: {:?} -> {:?}", folder_name, src_folder, dest_folder);
-            copy_dir_recursive(&src_folder, &dest_folder)?;
+            copy_dir_recursive(&src_folder, &dest_folder, &mut done, total, &mut |current, total, current_file| {
+                on_progress(InstallProgress {
+                    current,
+                    total,
+                    current_file: current_file.to_string(),
+                    operation: "installing".to_string(),
+                });
+            })?;
+            collect_file_hashes(&dest_folder, db_dir, &mut file_hashes)?;
             installed_count += 1;
         }
     }
@@ -1082,31 +2818,128 @@ fn install_folders_type(fix_dir: &Path, db_dir: &Path, user_dir: Option<&str>) -
             tracing::warn!("User directory not set, skipping editor data installation");
         }
     }
-    
-    Ok(())
+
+    Ok(file_hashes)
 }
 
-/// Recursively copy a directory
-fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<(), String> {
+/// Recursively copy a directory. `done`/`total` (file counts, from [`count_files_recursive`])
+/// and `on_progress` let a caller report progress per file instead of just logging, since a
+/// folder-based (Sortitoutsi) fix's dbc/edt/lnc folders can hold thousands of entries.
+fn copy_dir_recursive(
+    src: &Path,
+    dest: &Path,
+    done: &mut usize,
+    total: usize,
+    on_progress: &mut dyn FnMut(usize, usize, &str),
+) -> Result<(), String> {
     fs::create_dir_all(dest)
         .map_err(|e| format!("Failed to create directory {:?}: {}", dest, e))?;
-    
+
     let entries = fs::read_dir(src)
         .map_err(|e| format!("Failed to read directory {:?}: {}", src, e))?;
-    
+
     for entry in entries.flatten() {
         let src_path = entry.path();
         let filename = entry.file_name();
         let dest_path = dest.join(&filename);
-        
+
         if src_path.is_dir() {
-            copy_dir_recursive(&src_path, &dest_path)?;
+            copy_dir_recursive(&src_path, &dest_path, done, total, on_progress)?;
         } else {
-            fs::copy(&src_path, &dest_path)
-                .map_err(|e| format!("Failed to copy file {:?}: {}", src_path, e))?;
+            copy_one_file(&src_path, &dest_path)?;
+            *done += 1;
+            on_progress(*done, total, &filename.to_string_lossy());
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies one file, skipping it if already identical and clearing/restoring the read-only flag
+/// around the copy. Shared by [`copy_dir_recursive`]'s serial walk and
+/// [`copy_dir_recursive_parallel`]'s `par_iter` jobs.
+fn copy_one_file(src_path: &Path, dest_path: &Path) -> Result<(), String> {
+    if files_identical(src_path, dest_path) {
+        tracing::debug!("{:?} already up to date, skipping copy", dest_path);
+        return Ok(());
+    }
+
+    let original_perms = clear_readonly(dest_path)?;
+    fs::copy(src_path, dest_path)
+        .map_err(|e| format!("Failed to copy file {:?}: {}", src_path, e))?;
+    restore_permissions(dest_path, original_perms);
+    Ok(())
+}
+
+/// Picks between [`copy_dir_recursive`]'s serial walk and a rayon-parallel one depending on
+/// `total`. Below [`PARALLEL_COPY_THRESHOLD`] files the serial walk is plenty fast and spinning
+/// up the thread pool isn't worth it; above it — the thousands-of-files case
+/// `create_folder_backups`/`restore_folders_backup` hit with dbc/edt/lnc — copies run across
+/// rayon's pool instead.
+fn copy_dir_recursive_gated(
+    src: &Path,
+    dest: &Path,
+    done: &mut usize,
+    total: usize,
+    on_progress: &mut dyn FnMut(usize, usize, &str),
+) -> Result<(), String> {
+    if total > PARALLEL_COPY_THRESHOLD {
+        copy_dir_recursive_parallel(src, dest, done, total, on_progress)
+    } else {
+        copy_dir_recursive(src, dest, done, total, on_progress)
+    }
+}
+
+/// Parallel counterpart to [`copy_dir_recursive`]: walks `src` up front to build a flat list of
+/// file copy jobs, creating every destination directory sequentially along the way (mkdir races
+/// are a correctness hazard, not a perf one worth parallelizing), then copies all files
+/// concurrently with `par_iter`. Every file's error is collected instead of bailing on the
+/// first, then joined into one aggregated `Result` that still names each failing file.
+fn copy_dir_recursive_parallel(
+    src: &Path,
+    dest: &Path,
+    done: &mut usize,
+    total: usize,
+    on_progress: &mut dyn FnMut(usize, usize, &str),
+) -> Result<(), String> {
+    let mut jobs = Vec::new();
+    collect_copy_jobs(src, dest, &mut jobs)?;
+
+    let errors: Vec<String> = jobs
+        .par_iter()
+        .filter_map(|(src_path, dest_path)| copy_one_file(src_path, dest_path).err())
+        .collect();
+
+    *done += jobs.len();
+    on_progress(*done, total, &dest.to_string_lossy());
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// Recursively lists every file under `src` as a `(source, destination)` pair mirrored under
+/// `dest`, creating each destination directory along the way.
+fn collect_copy_jobs(
+    src: &Path,
+    dest: &Path,
+    jobs: &mut Vec<(PathBuf, PathBuf)>,
+) -> Result<(), String> {
+    fs::create_dir_all(dest).map_err(|e| format!("Failed to create directory {:?}: {}", dest, e))?;
+
+    let entries =
+        fs::read_dir(src).map_err(|e| format!("Failed to read directory {:?}: {}", src, e))?;
+    for entry in entries.flatten() {
+        let src_path = entry.path();
+        let dest_path = dest.join(entry.file_name());
+        if src_path.is_dir() {
+            collect_copy_jobs(&src_path, &dest_path, jobs)?;
+        } else {
+            jobs.push((src_path, dest_path));
         }
     }
-    
     Ok(())
 }
 
@@ -1131,6 +2964,12 @@ pub fn delete_name_fix(name_fix_id: String) -> Result<String, String> {
     fs::remove_dir_all(&fix_dir)
         .map_err(|e| format!("Failed to delete name fix: {}", e))?;
 
+    // If this fix was stacked further down (not currently active), drop it from the stack too,
+    // so a later uninstall doesn't fall back to a fix that no longer exists.
+    let mut config = load_config()?;
+    config.name_fix_stack.retain(|id| id != &name_fix_id);
+    save_config(&config)?;
+
     tracing::info!("Deleted name fix: {}", name_fix_id);
     Ok("Name fix deleted successfully".to_string())
 }
@@ -1140,3 +2979,81 @@ pub fn get_active_name_fix() -> Result<Option<String>, String> {
     let config = load_config()?;
     Ok(config.active_name_fix)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn unique_temp_dir() -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("fmml_name_fix_test_{}", nanos));
+        let _ = fs::create_dir_all(&path);
+        path
+    }
+
+    /// Points `get_app_data_dir` at a fresh temp dir for the duration of a test. Caller must
+    /// remove the returned dir and the env var afterward.
+    fn set_test_appdata() -> PathBuf {
+        let base = unique_temp_dir();
+        std::env::set_var("FMML_TEST_APPDATA", &base);
+        base
+    }
+
+    fn set_readonly(path: &Path, readonly: bool) {
+        let mut perms = fs::metadata(path).expect("stat").permissions();
+        perms.set_readonly(readonly);
+        fs::set_permissions(path, perms).expect("set permissions");
+    }
+
+    #[test]
+    fn test_round_trips_a_read_only_game_file_through_backup_and_restore() {
+        let base = set_test_appdata();
+
+        // FILES_TO_DELETE's first entry is lnc/all/ac milan (wom).lnc; use it so the restore loop below
+        // actually picks the file back up.
+        let (subdir, file) = FILES_TO_DELETE[0];
+        let file = file[0];
+
+        let db_dir = base.join("db");
+        let source_dir = db_dir.join(subdir);
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        let source_file = source_dir.join(file);
+        fs::write(&source_file, b"stock game contents").expect("write stock file");
+        set_readonly(&source_file, true);
+
+        let backup_dir = create_backups(&db_dir, "test-fix", NameFixBackupMode::Simple, 10, &mut |_| {})
+            .expect("create_backups should succeed over a read-only source")
+            .expect("Simple mode should produce a backup dir");
+
+        assert!(fs::metadata(&source_file).unwrap().permissions().readonly());
+        let backed_up = backup_dir.join(subdir).join(file);
+        assert_eq!(
+            fs::read(&backed_up).expect("read backup"),
+            b"stock game contents"
+        );
+
+        // Simulate the name fix overwriting the read-only game file with its own content.
+        set_readonly(&source_file, false);
+        fs::write(&source_file, b"name fix contents").expect("overwrite with name fix contents");
+        set_readonly(&source_file, true);
+
+        restore_files_backup_without_fix_dir(&db_dir, &backup_dir)
+            .expect("restore should succeed over a read-only destination");
+
+        assert_eq!(
+            fs::read(&source_file).expect("read restored file"),
+            b"stock game contents"
+        );
+        assert!(
+            fs::metadata(&source_file).unwrap().permissions().readonly(),
+            "restore should put the read-only flag back afterward"
+        );
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+}
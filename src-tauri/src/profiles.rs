@@ -0,0 +1,219 @@
+//! Named profiles ("loadouts") of mods, e.g. "Career Save" versus "Screenshot Mode".
+//!
+//! Switching profiles diffs the outgoing and incoming `enabled_mods` so only the mods that
+//! actually changed are re-installed or removed, rather than reinstalling everything.
+
+use crate::config::{load_config, save_config, DEFAULT_PROFILE_ID};
+use crate::messages::{code_error, code_only, CODE_GAME_TARGET_INVALID, CODE_GAME_TARGET_NOT_SET};
+use crate::mod_manager::{install_mod, uninstall_mod};
+use crate::name_fix;
+use crate::types::{Config, InstallProgress, Profile};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use tauri::{Emitter, Manager};
+use uuid::Uuid;
+
+/// Lists every saved profile, in storage order. The UI can already get these via `get_config`,
+/// but this gives callers that only care about profiles (e.g. a profile switcher) a narrower
+/// endpoint than pulling the whole `Config`.
+#[tauri::command]
+pub fn list_profiles() -> Result<Vec<Profile>, String> {
+    Ok(load_config()?.profiles)
+}
+
+fn find_profile(config: &Config, profile_id: &str) -> Result<Profile, String> {
+    config
+        .profiles
+        .iter()
+        .find(|p| p.id == profile_id)
+        .cloned()
+        .ok_or_else(|| format!("Profile not found: {}", profile_id))
+}
+
+#[tauri::command]
+pub fn create_profile(name: String) -> Result<Profile, String> {
+    let mut config = load_config()?;
+
+    let profile = Profile {
+        id: Uuid::new_v4().to_string(),
+        name,
+        enabled_mods: Vec::new(),
+        active_name_fix: None,
+        target_path_override: None,
+    };
+
+    config.profiles.push(profile.clone());
+    save_config(&config)?;
+
+    Ok(profile)
+}
+
+#[tauri::command]
+pub fn clone_profile(profile_id: String, new_name: String) -> Result<Profile, String> {
+    let mut config = load_config()?;
+    let source = find_profile(&config, &profile_id)?;
+
+    let cloned = Profile {
+        id: Uuid::new_v4().to_string(),
+        name: new_name,
+        enabled_mods: source.enabled_mods,
+        active_name_fix: source.active_name_fix,
+        target_path_override: source.target_path_override,
+    };
+
+    config.profiles.push(cloned.clone());
+    save_config(&config)?;
+
+    Ok(cloned)
+}
+
+#[tauri::command]
+pub fn rename_profile(profile_id: String, new_name: String) -> Result<(), String> {
+    let mut config = load_config()?;
+
+    let profile = config
+        .profiles
+        .iter_mut()
+        .find(|p| p.id == profile_id)
+        .ok_or_else(|| format!("Profile not found: {}", profile_id))?;
+
+    profile.name = new_name;
+    save_config(&config)?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_profile(profile_id: String) -> Result<(), String> {
+    let mut config = load_config()?;
+
+    if config.active_profile == profile_id {
+        return Err("Cannot delete the active profile".to_string());
+    }
+    if profile_id == DEFAULT_PROFILE_ID {
+        return Err("Cannot delete the default profile".to_string());
+    }
+
+    let before = config.profiles.len();
+    config.profiles.retain(|p| p.id != profile_id);
+
+    if config.profiles.len() == before {
+        return Err(format!("Profile not found: {}", profile_id));
+    }
+
+    save_config(&config)?;
+
+    Ok(())
+}
+
+fn emit_switch_progress(
+    app: &tauri::AppHandle,
+    current: usize,
+    total: usize,
+    current_file: &str,
+    operation: &str,
+) {
+    if let Some(window) = app.get_webview_window("main") {
+        let progress = InstallProgress {
+            current,
+            total,
+            current_file: current_file.to_string(),
+            operation: operation.to_string(),
+        };
+        let _ = window.emit("profile-switch-progress", &progress);
+    }
+}
+
+/// Switches to `profile_id`, installing only the mods newly enabled by it and removing only
+/// the ones the outgoing profile had that it doesn't, so a swap is fast rather than a full
+/// reinstall. Emits `InstallProgress` events as each delta mod is applied.
+#[tauri::command]
+pub async fn switch_profile(app: tauri::AppHandle, profile_id: String) -> Result<String, String> {
+    let mut config = load_config()?;
+    let target_profile = find_profile(&config, &profile_id)?;
+
+    let target_path_str = target_profile
+        .target_path_override
+        .clone()
+        .or_else(|| config.target_path.clone())
+        .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
+
+    let target_path = PathBuf::from(&target_path_str);
+    if !target_path.exists() {
+        return Err(code_error(
+            CODE_GAME_TARGET_INVALID,
+            "Game target path does not exist",
+        ));
+    }
+
+    let outgoing: HashSet<&String> = config.enabled_mods.iter().collect();
+    let incoming: HashSet<&String> = target_profile.enabled_mods.iter().collect();
+
+    let to_remove: Vec<String> = outgoing.difference(&incoming).map(|s| s.to_string()).collect();
+    let to_install: Vec<String> = incoming.difference(&outgoing).map(|s| s.to_string()).collect();
+
+    let total = to_remove.len() + to_install.len();
+    let mut current = 0;
+    let mut results = Vec::new();
+
+    for mod_name in &to_remove {
+        current += 1;
+        emit_switch_progress(&app, current, total, mod_name, "removing");
+
+        match uninstall_mod(mod_name, &target_path, config.user_dir_path.as_deref()) {
+            Ok(msg) => results.push(msg),
+            Err(e) => results.push(format!("Failed to remove {}: {}", mod_name, e)),
+        }
+    }
+
+    for mod_name in &to_install {
+        current += 1;
+        emit_switch_progress(&app, current, total, mod_name, "installing");
+
+        match install_mod(
+            mod_name,
+            &target_path,
+            config.user_dir_path.as_deref(),
+            config.default_install_mode,
+            config.default_backup_mode,
+            config.backup_compression,
+            config.backup_compression_level,
+        ) {
+            Ok(msg) => results.push(msg),
+            Err(e) => results.push(format!("Failed to install {}: {}", mod_name, e)),
+        }
+    }
+
+    // Swap the active name fix too, if this profile wants a different one.
+    if target_profile.active_name_fix != config.active_name_fix {
+        if config.active_name_fix.is_some() {
+            if let Err(e) = name_fix::uninstall() {
+                results.push(format!("Failed to uninstall previous name fix: {}", e));
+            }
+        }
+        if let Some(fix_id) = &target_profile.active_name_fix {
+            // The previous fix was just uninstalled above, so force past the conflict check
+            // rather than fail an automatic profile switch over leftover untracked files.
+            if let Err(e) = name_fix::install_name_fix(fix_id.clone(), true, HashMap::new(), |_| {}) {
+                results.push(format!("Failed to install name fix: {}", e));
+            }
+        }
+    }
+
+    // Reload: the name-fix install/uninstall calls above each did their own load_config/
+    // save_config round trip (updating name_fix_stack among other things), so saving the
+    // `config` loaded at the top of this function would silently undo that.
+    let mut config = load_config()?;
+    config.enabled_mods = target_profile.enabled_mods.clone();
+    config.active_name_fix = target_profile.active_name_fix.clone();
+    config.active_profile = target_profile.id.clone();
+    save_config(&config)?;
+
+    Ok(format!(
+        "Switched to profile '{}' ({} installed, {} removed):\n{}",
+        target_profile.name,
+        to_install.len(),
+        to_remove.len(),
+        results.join("\n")
+    ))
+}
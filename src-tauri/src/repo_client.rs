@@ -0,0 +1,230 @@
+//! Client for a remote mod repository's JSON index — search and archive download.
+//!
+//! The index is just an HTTP API returning JSON; `RemoteModSummary` wraps the `ModManifest`
+//! it deserializes into plus the repository-only metadata a manifest doesn't carry (download
+//! URL, file size, download count). Every request carries a unique `User-Agent`, since the
+//! index rejects or rate-limits anonymous clients.
+
+use crate::types::ModManifest;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const USER_AGENT: &str = concat!("FMMLoader26/", env!("CARGO_PKG_VERSION"));
+
+/// One hit in a repository search result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteModSummary {
+    pub id: String,
+    pub manifest: ModManifest,
+    pub download_url: String,
+    pub file_size: u64,
+    pub downloads: u64,
+}
+
+/// Facet filters for a search call. `fm_version` is matched against each mod's
+/// `Compatibility.fm_version` server-side, so users only see packs compatible with their
+/// installed Football Manager version.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchFacets {
+    pub mod_type: Option<String>,
+    pub fm_version: Option<String>,
+    pub author: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchResults {
+    pub hits: Vec<RemoteModSummary>,
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+/// Error parsed from a failed repository request: either the server responded with a
+/// non-2xx status (carrying its own error body), or the request never got a response at all.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepositoryError {
+    Http { status: u16, message: String },
+    Transport(String),
+}
+
+impl std::fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepositoryError::Http { status, message } => {
+                write!(f, "Repository returned {}: {}", status, message)
+            }
+            RepositoryError::Transport(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Shape of the error body the index is expected to send on a non-2xx response. Either field
+/// may be present; falls back to the raw body if neither parses.
+#[derive(Debug, Deserialize)]
+struct ApiErrorBody {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// Turns a non-2xx response's body into a `RepositoryError::Http`, preferring `message` over
+/// `error` over the raw body text.
+fn parse_error_body(status: u16, body: &str) -> RepositoryError {
+    let message = match serde_json::from_str::<ApiErrorBody>(body) {
+        Ok(parsed) if !parsed.message.is_empty() => parsed.message,
+        Ok(parsed) if !parsed.error.is_empty() => parsed.error,
+        _ => body.to_string(),
+    };
+
+    RepositoryError::Http { status, message }
+}
+
+fn client() -> Result<reqwest::blocking::Client, RepositoryError> {
+    reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| RepositoryError::Transport(e.to_string()))
+}
+
+/// Searches `index_url` for mods matching `query` and `facets`, returning one page of hits.
+pub fn search_mods(
+    index_url: &str,
+    query: &str,
+    facets: &SearchFacets,
+    page: u32,
+) -> Result<SearchResults, RepositoryError> {
+    let mut request = client()?
+        .get(format!("{}/search", index_url.trim_end_matches('/')))
+        .header("User-Agent", USER_AGENT)
+        .query(&[("q", query), ("page", &page.to_string())]);
+
+    if let Some(mod_type) = &facets.mod_type {
+        request = request.query(&[("mod_type", mod_type)]);
+    }
+    if let Some(fm_version) = &facets.fm_version {
+        request = request.query(&[("fm_version", fm_version)]);
+    }
+    if let Some(author) = &facets.author {
+        request = request.query(&[("author", author)]);
+    }
+
+    let response = request.send().map_err(|e| {
+        RepositoryError::Transport(format!("Failed to reach mod repository: {}", e))
+    })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        return Err(parse_error_body(status.as_u16(), &body));
+    }
+
+    response
+        .json::<SearchResults>()
+        .map_err(|e| RepositoryError::Transport(format!("Failed to parse search results: {}", e)))
+}
+
+/// Downloads the archive at `download_url` to `dest`.
+pub fn download_mod_archive(download_url: &str, dest: &Path) -> Result<(), RepositoryError> {
+    let mut response = client()?
+        .get(download_url)
+        .header("User-Agent", USER_AGENT)
+        .send()
+        .map_err(|e| RepositoryError::Transport(format!("Failed to download mod: {}", e)))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        return Err(parse_error_body(status.as_u16(), &body));
+    }
+
+    let mut file = std::fs::File::create(dest).map_err(|e| {
+        RepositoryError::Transport(format!("Failed to create destination file: {}", e))
+    })?;
+
+    std::io::copy(&mut response, &mut file).map_err(|e| {
+        RepositoryError::Transport(format!("Failed to write downloaded archive: {}", e))
+    })?;
+
+    Ok(())
+}
+
+/// Search the configured mod repository. Exposed to the frontend as a thin wrapper over
+/// [`search_mods`] so errors reach the UI as plain strings like the rest of the app's commands.
+#[tauri::command]
+pub fn search_mod_repository(
+    index_url: String,
+    query: String,
+    mod_type: Option<String>,
+    fm_version: Option<String>,
+    author: Option<String>,
+    page: u32,
+) -> Result<SearchResults, String> {
+    let facets = SearchFacets {
+        mod_type,
+        fm_version,
+        author,
+    };
+
+    search_mods(&index_url, &query, &facets, page).map_err(|e| e.to_string())
+}
+
+/// Download a mod archive from the repository into the local mods directory, ready for
+/// `import_mod` to pick up.
+#[tauri::command]
+pub fn download_mod_from_repository(
+    download_url: String,
+    mod_name: String,
+) -> Result<String, String> {
+    let dest = crate::config::get_mods_dir().join(format!("{}.zip", mod_name));
+
+    download_mod_archive(&download_url, &dest).map_err(|e| e.to_string())?;
+
+    Ok(dest.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_body_prefers_message_over_error() {
+        let err = parse_error_body(429, r#"{"error":"rate_limited","message":"Too many requests"}"#);
+        assert_eq!(
+            err,
+            RepositoryError::Http {
+                status: 429,
+                message: "Too many requests".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_error_body_falls_back_to_error_field() {
+        let err = parse_error_body(404, r#"{"error":"not_found"}"#);
+        assert_eq!(
+            err,
+            RepositoryError::Http {
+                status: 404,
+                message: "not_found".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_error_body_falls_back_to_raw_body() {
+        let err = parse_error_body(500, "internal server error");
+        assert_eq!(
+            err,
+            RepositoryError::Http {
+                status: 500,
+                message: "internal server error".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn user_agent_is_non_empty_and_identifies_the_app() {
+        assert!(USER_AGENT.starts_with("FMMLoader26/"));
+    }
+}
@@ -0,0 +1,361 @@
+//! Dependency/load-order resolution for `ModManifest`.
+//!
+//! `dependencies`, `conflicts`, and `load_after` are plain strings such as
+//! `"db-fix >=2.1, <3.0"` or bare mod names. This module parses those entries into a
+//! name plus an optional [`VersionReq`], builds a load-order graph from `load_after`
+//! (and the implicit load-before relationship of `dependencies`), and topologically
+//! sorts it with Kahn's algorithm to produce a safe install order.
+
+use crate::types::ModManifest;
+use semver::{Version, VersionReq};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A single dependency/conflict entry: a mod name plus an optional version requirement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModRequirement {
+    pub name: String,
+    pub version_req: Option<VersionReq>,
+}
+
+impl ModRequirement {
+    /// Parses an entry like `"db-fix >=2.1, <3.0"` or a bare `"db-fix"`.
+    pub fn parse(entry: &str) -> Result<ModRequirement, String> {
+        let entry = entry.trim();
+
+        match entry.find(|c: char| "=<>^~".contains(c)) {
+            Some(idx) => {
+                let name = entry[..idx].trim().to_string();
+                let req_str = entry[idx..].trim();
+
+                if name.is_empty() {
+                    return Err(format!("Dependency entry is missing a mod name: '{}'", entry));
+                }
+
+                let version_req = VersionReq::parse(req_str).map_err(|e| {
+                    format!("Invalid version requirement in '{}': {}", entry, e)
+                })?;
+
+                Ok(ModRequirement {
+                    name,
+                    version_req: Some(version_req),
+                })
+            }
+            None => {
+                if entry.is_empty() {
+                    return Err("Dependency entry is empty".to_string());
+                }
+                Ok(ModRequirement {
+                    name: entry.to_string(),
+                    version_req: None,
+                })
+            }
+        }
+    }
+}
+
+/// Non-fatal findings surfaced alongside a successful load order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolutionDiagnostic {
+    /// `mod_name` depends on `depends_on`, but either it's missing or its version
+    /// doesn't satisfy `required`.
+    UnsatisfiedDependency {
+        mod_name: String,
+        depends_on: String,
+        required: String,
+        found: Option<String>,
+    },
+    /// `mod_name` and `conflicts_with` are both enabled, but `mod_name` declares a
+    /// conflict with it.
+    Conflict {
+        mod_name: String,
+        conflicts_with: String,
+    },
+}
+
+impl std::fmt::Display for ResolutionDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolutionDiagnostic::UnsatisfiedDependency {
+                mod_name,
+                depends_on,
+                required,
+                found,
+            } => match found {
+                Some(found_version) => write!(
+                    f,
+                    "{} requires {} {} but found {}",
+                    mod_name, depends_on, required, found_version
+                ),
+                None => write!(
+                    f,
+                    "{} requires {} {} but it is not enabled",
+                    mod_name, depends_on, required
+                ),
+            },
+            ResolutionDiagnostic::Conflict {
+                mod_name,
+                conflicts_with,
+            } => write!(f, "{} conflicts with {}", mod_name, conflicts_with),
+        }
+    }
+}
+
+/// Fatal resolution failure.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolverError {
+    /// The load-order graph contains a cycle; lists every mod that could not be ordered (those
+    /// on a cycle and any that must load after one), in no particular order.
+    Cycle(Vec<String>),
+    /// A dependency/conflict/load_after entry could not be parsed.
+    InvalidRequirement(String),
+}
+
+impl std::fmt::Display for ResolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolverError::Cycle(mods) => {
+                write!(f, "Load-order cycle detected among: {}", mods.join(" -> "))
+            }
+            ResolverError::InvalidRequirement(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Resolves a safe install/load order for a set of enabled manifests.
+///
+/// Returns the mod names in install order, plus any non-fatal diagnostics. A cycle in
+/// the `load_after`/`dependencies` graph, or a `dependencies`/`conflicts`/`load_after` entry
+/// that fails to parse, is fatal.
+pub fn resolve_load_order(
+    manifests: &[ModManifest],
+) -> Result<(Vec<String>, Vec<ResolutionDiagnostic>), ResolverError> {
+    let by_name: HashMap<&str, &ModManifest> =
+        manifests.iter().map(|m| (m.name.as_str(), m)).collect();
+
+    let mut diagnostics = Vec::new();
+
+    // edges[a] = set of mods that must load after `a` (i.e. `a -> b` means a before b)
+    let mut edges: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut in_degree: HashMap<String, usize> = HashMap::new();
+
+    for manifest in manifests {
+        edges.entry(manifest.name.clone()).or_default();
+        in_degree.entry(manifest.name.clone()).or_insert(0);
+    }
+
+    let mut add_edge = |edges: &mut HashMap<String, HashSet<String>>,
+                        in_degree: &mut HashMap<String, usize>,
+                        before: &str,
+                        after: &str| {
+        if before == after {
+            return;
+        }
+        if edges.entry(before.to_string()).or_default().insert(after.to_string()) {
+            *in_degree.entry(after.to_string()).or_insert(0) += 1;
+        }
+    };
+
+    for manifest in manifests {
+        // `dependencies` must load before this mod.
+        for entry in &manifest.dependencies {
+            let req = ModRequirement::parse(entry).map_err(ResolverError::InvalidRequirement)?;
+
+            match by_name.get(req.name.as_str()) {
+                Some(dep_manifest) => {
+                    let satisfied = match (&req.version_req, Version::parse(&dep_manifest.version))
+                    {
+                        (Some(version_req), Ok(found_version)) => version_req.matches(&found_version),
+                        (Some(_), Err(_)) => false,
+                        (None, _) => true,
+                    };
+
+                    if !satisfied {
+                        diagnostics.push(ResolutionDiagnostic::UnsatisfiedDependency {
+                            mod_name: manifest.name.clone(),
+                            depends_on: req.name.clone(),
+                            required: req
+                                .version_req
+                                .as_ref()
+                                .map(|r| r.to_string())
+                                .unwrap_or_else(|| "any".to_string()),
+                            found: Some(dep_manifest.version.clone()),
+                        });
+                    }
+
+                    add_edge(&mut edges, &mut in_degree, &req.name, &manifest.name);
+                }
+                None => {
+                    diagnostics.push(ResolutionDiagnostic::UnsatisfiedDependency {
+                        mod_name: manifest.name.clone(),
+                        depends_on: req.name.clone(),
+                        required: req
+                            .version_req
+                            .as_ref()
+                            .map(|r| r.to_string())
+                            .unwrap_or_else(|| "any".to_string()),
+                        found: None,
+                    });
+                }
+            }
+        }
+
+        // `load_after` must load before this mod, but is not a hard dependency.
+        for entry in &manifest.load_after {
+            let req = ModRequirement::parse(entry).map_err(ResolverError::InvalidRequirement)?;
+            if by_name.contains_key(req.name.as_str()) {
+                add_edge(&mut edges, &mut in_degree, &req.name, &manifest.name);
+            }
+        }
+
+        // `conflicts` only matters if the other mod is also enabled.
+        for entry in &manifest.conflicts {
+            let req = ModRequirement::parse(entry).map_err(ResolverError::InvalidRequirement)?;
+            if by_name.contains_key(req.name.as_str()) {
+                diagnostics.push(ResolutionDiagnostic::Conflict {
+                    mod_name: manifest.name.clone(),
+                    conflicts_with: req.name.clone(),
+                });
+            }
+        }
+    }
+
+    // Kahn's algorithm: repeatedly emit nodes with in-degree zero.
+    let mut queue: VecDeque<String> = in_degree
+        .iter()
+        .filter(|(_, &deg)| deg == 0)
+        .map(|(name, _)| name.clone())
+        .collect();
+    // Keep deterministic output regardless of HashMap iteration order.
+    let mut queue: Vec<String> = queue.drain(..).collect();
+    queue.sort();
+    let mut queue: VecDeque<String> = queue.into();
+
+    let mut order = Vec::with_capacity(manifests.len());
+
+    while let Some(name) = queue.pop_front() {
+        order.push(name.clone());
+
+        if let Some(successors) = edges.get(&name) {
+            let mut ready = Vec::new();
+            for succ in successors {
+                let deg = in_degree.get_mut(succ).expect("successor must be tracked");
+                *deg -= 1;
+                if *deg == 0 {
+                    ready.push(succ.clone());
+                }
+            }
+            ready.sort();
+            for succ in ready {
+                queue.push_back(succ);
+            }
+        }
+    }
+
+    if order.len() != manifests.len() {
+        let remaining: Vec<String> = in_degree
+            .into_iter()
+            .filter(|(name, _)| !order.contains(name))
+            .map(|(name, _)| name)
+            .collect();
+        return Err(ResolverError::Cycle(remaining));
+    }
+
+    Ok((order, diagnostics))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Compatibility;
+
+    fn manifest(name: &str, version: &str) -> ModManifest {
+        ModManifest {
+            name: name.to_string(),
+            schema_version: crate::mod_manager::CURRENT_MANIFEST_SCHEMA_VERSION,
+            version: version.to_string(),
+            mod_type: "misc".to_string(),
+            author: String::new(),
+            homepage: String::new(),
+            description: String::new(),
+            license: String::new(),
+            compatibility: Compatibility::default(),
+            dependencies: Vec::new(),
+            conflicts: Vec::new(),
+            load_after: Vec::new(),
+            files: Vec::new(),
+            source_type: None,
+        }
+    }
+
+    #[test]
+    fn parses_bare_name() {
+        let req = ModRequirement::parse("tactic-pack").unwrap();
+        assert_eq!(req.name, "tactic-pack");
+        assert!(req.version_req.is_none());
+    }
+
+    #[test]
+    fn parses_name_with_version_req() {
+        let req = ModRequirement::parse("db-fix >=2.1, <3.0").unwrap();
+        assert_eq!(req.name, "db-fix");
+        assert!(req.version_req.unwrap().matches(&Version::parse("2.5.0").unwrap()));
+    }
+
+    #[test]
+    fn rejects_invalid_requirement() {
+        assert!(ModRequirement::parse(">=2.1").is_err());
+    }
+
+    #[test]
+    fn orders_load_after_before_dependent() {
+        let mut base = manifest("base", "1.0.0");
+        let mut addon = manifest("addon", "1.0.0");
+        addon.load_after.push("base".to_string());
+        base.name = "base".to_string();
+
+        let (order, diagnostics) = resolve_load_order(&[addon, base]).unwrap();
+        assert_eq!(order, vec!["base".to_string(), "addon".to_string()]);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn flags_unsatisfied_dependency_version() {
+        let mut addon = manifest("addon", "1.0.0");
+        addon.dependencies.push("db-fix >=2.0".to_string());
+        let db_fix = manifest("db-fix", "1.5.0");
+
+        let (_, diagnostics) = resolve_load_order(&[addon, db_fix]).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(matches!(
+            diagnostics[0],
+            ResolutionDiagnostic::UnsatisfiedDependency { .. }
+        ));
+    }
+
+    #[test]
+    fn flags_conflict_between_enabled_mods() {
+        let mut a = manifest("a", "1.0.0");
+        a.conflicts.push("b".to_string());
+        let b = manifest("b", "1.0.0");
+
+        let (_, diagnostics) = resolve_load_order(&[a, b]).unwrap();
+        assert_eq!(
+            diagnostics[0],
+            ResolutionDiagnostic::Conflict {
+                mod_name: "a".to_string(),
+                conflicts_with: "b".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn detects_cycle() {
+        let mut a = manifest("a", "1.0.0");
+        a.load_after.push("b".to_string());
+        let mut b = manifest("b", "1.0.0");
+        b.load_after.push("a".to_string());
+
+        let result = resolve_load_order(&[a, b]);
+        assert!(matches!(result, Err(ResolverError::Cycle(_))));
+    }
+}
@@ -1,50 +1,340 @@
+//! Content-addressed restore points.
+//!
+//! `create_restore_point` used to do a full recursive copy of every source path on every
+//! call, so taking many restore points of large mod/game directories wasted huge amounts of
+//! disk on files that hadn't actually changed between snapshots. Instead, file content is
+//! stored once in a shared `blobs/` directory under the restore-points root, keyed by the
+//! blake3 hash of its bytes; a restore point itself is a versioned `manifest.json` mapping
+//! original paths to blob hashes, plus capture timing and size so the UI can show how big a
+//! point is and how long it took. Taking a new restore point when nothing changed costs
+//! almost nothing, since every file's blob already exists.
+//!
+//! [`gc_unreferenced_blobs`] is a mark-and-sweep collector: it unions the blob hashes
+//! referenced by every remaining restore point's manifest, then deletes any blob not in that
+//! set. Run it after deleting a restore point to reclaim the space.
+
 use crate::config::get_restore_points_dir;
-use crate::types::RestorePoint;
-use crate::utils;
+use crate::types::{BackupCompression, RestoreEntryResult, RestoreEntryStatus, RestorePoint};
 use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::time::UNIX_EPOCH;
+use walkdir::WalkDir;
+
+const MANIFEST_VERSION: u32 = 1;
+
+/// One of the paths passed to `create_restore_point`, recorded so rollback knows whether to
+/// wipe it wholesale (it was a directory) before materializing its files from blobs, or just
+/// overwrite it directly (it was a single file).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RestorePointRoot {
+    path: PathBuf,
+    is_dir: bool,
+}
+
+/// A single filesystem item captured by a restore point: a file or a (possibly empty)
+/// directory nested under one of its roots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RestorePointEntry {
+    original_path: PathBuf,
+    is_dir: bool,
+    size_bytes: u64,
+    /// blake3 hex hash of the file's content at capture time, keying its blob under
+    /// `blobs/`. `None` for directory entries, which have no content of their own.
+    #[serde(default)]
+    checksum: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RestorePointManifest {
+    version: u32,
+    name: String,
+    /// RFC 3339 timestamps bracketing the capture, so the UI can show how long it took.
+    created_at: String,
+    completed_at: String,
+    total_size_bytes: u64,
+    roots: Vec<RestorePointRoot>,
+    entries: Vec<RestorePointEntry>,
+}
+
+fn blobs_dir() -> PathBuf {
+    get_restore_points_dir().join("blobs")
+}
+
+/// Names the sidecar recording which codec a blob's bytes on disk are stored under. Kept
+/// separate from the blob's own filename (the content hash) since that hash is shared by every
+/// restore point referencing the same content and can't also encode a per-write setting.
+fn blob_codec_sidecar(blobs_dir: &Path, hash: &str) -> PathBuf {
+    blobs_dir.join(format!("{}.codec", hash))
+}
+
+/// The codec a blob was written under, or [`BackupCompression::None`] if its sidecar is missing
+/// (every blob written before this setting existed).
+fn blob_codec(blobs_dir: &Path, hash: &str) -> BackupCompression {
+    match fs::read_to_string(blob_codec_sidecar(blobs_dir, hash)).ok().as_deref() {
+        Some("zstd") => BackupCompression::Zstd,
+        Some("xz") => BackupCompression::Xz,
+        _ => BackupCompression::None,
+    }
+}
+
+/// Hashes `source`'s content and ensures it exists as a blob, skipping the copy if a blob with
+/// that hash is already on disk (its codec sidecar, if any, is left as-is in that case — the
+/// content is identical either way). Returns the hash.
+fn store_blob(
+    blobs_dir: &Path,
+    source: &Path,
+    compression: BackupCompression,
+    compression_level: u32,
+) -> Result<String, String> {
+    let bytes =
+        fs::read(source).map_err(|e| format!("Failed to read '{}': {}", source.display(), e))?;
+    let hash = blake3::hash(&bytes).to_hex().to_string();
+
+    let blob_path = blobs_dir.join(&hash);
+    if !blob_path.exists() {
+        match compression {
+            BackupCompression::None => {
+                fs::write(&blob_path, &bytes)
+                    .map_err(|e| format!("Failed to write blob '{}': {}", hash, e))?;
+            }
+            BackupCompression::Xz => {
+                let file = fs::File::create(&blob_path)
+                    .map_err(|e| format!("Failed to create blob '{}': {}", hash, e))?;
+                let preset = if compression_level == 0 { 6 } else { compression_level };
+                let mut encoder = xz2::write::XzEncoder::new(file, preset);
+                std::io::Write::write_all(&mut encoder, &bytes)
+                    .map_err(|e| format!("Failed to write blob '{}': {}", hash, e))?;
+                encoder
+                    .finish()
+                    .map_err(|e| format!("Failed to finish blob '{}': {}", hash, e))?;
+                fs::write(blob_codec_sidecar(blobs_dir, &hash), "xz")
+                    .map_err(|e| format!("Failed to write blob codec for '{}': {}", hash, e))?;
+            }
+            BackupCompression::Zstd => {
+                let file = fs::File::create(&blob_path)
+                    .map_err(|e| format!("Failed to create blob '{}': {}", hash, e))?;
+                let preset = if compression_level == 0 { 3 } else { compression_level as i32 };
+                let mut encoder = zstd::Encoder::new(file, preset)
+                    .map_err(|e| format!("Failed to start blob '{}': {}", hash, e))?
+                    .auto_finish();
+                std::io::Write::write_all(&mut encoder, &bytes)
+                    .map_err(|e| format!("Failed to write blob '{}': {}", hash, e))?;
+                drop(encoder);
+                fs::write(blob_codec_sidecar(blobs_dir, &hash), "zstd")
+                    .map_err(|e| format!("Failed to write blob codec for '{}': {}", hash, e))?;
+            }
+        }
+    }
+
+    Ok(hash)
+}
+
+/// Reads a blob's content back to plain bytes, decoding with whatever codec its
+/// [`blob_codec_sidecar`] names.
+fn read_blob_contents(blobs_dir: &Path, hash: &str) -> Result<Vec<u8>, String> {
+    let blob_path = blobs_dir.join(hash);
+    let file = fs::File::open(&blob_path)
+        .map_err(|e| format!("Failed to open blob '{}': {}", hash, e))?;
+
+    let mut contents = Vec::new();
+    match blob_codec(blobs_dir, hash) {
+        BackupCompression::None => {
+            std::io::BufReader::new(file)
+                .read_to_end(&mut contents)
+                .map_err(|e| format!("Failed to read blob '{}': {}", hash, e))?;
+        }
+        BackupCompression::Xz => {
+            xz2::read::XzDecoder::new(file)
+                .read_to_end(&mut contents)
+                .map_err(|e| format!("Failed to decompress blob '{}': {}", hash, e))?;
+        }
+        BackupCompression::Zstd => {
+            zstd::Decoder::new(file)
+                .map_err(|e| format!("Failed to open blob '{}': {}", hash, e))?
+                .read_to_end(&mut contents)
+                .map_err(|e| format!("Failed to decompress blob '{}': {}", hash, e))?;
+        }
+    }
+
+    Ok(contents)
+}
 
-pub fn create_restore_point(name: &str, source_paths: &[PathBuf]) -> Result<PathBuf, String> {
+pub fn create_restore_point(
+    name: &str,
+    source_paths: &[PathBuf],
+    compression: BackupCompression,
+    compression_level: u32,
+) -> Result<PathBuf, String> {
     let restore_dir = get_restore_points_dir();
-    fs::create_dir_all(&restore_dir)
-        .map_err(|e| format!("Failed to create restore points dir: {}", e))?;
+    let blobs_dir = blobs_dir();
+    fs::create_dir_all(&blobs_dir).map_err(|e| format!("Failed to create blobs dir: {}", e))?;
 
-    let timestamp = Local::now().format("%Y%m%d_%H%M%S").to_string();
-    let point_name = format!("{}_{}", timestamp, name);
+    let created_at = Local::now();
+    let point_name = format!("{}_{}", created_at.format("%Y%m%d_%H%M%S"), name);
     let point_dir = restore_dir.join(&point_name);
 
     fs::create_dir_all(&point_dir).map_err(|e| format!("Failed to create restore point: {}", e))?;
 
-    // Copy all source paths to restore point
-    for (i, source_path) in source_paths.iter().enumerate() {
+    let mut roots = Vec::new();
+    let mut entries = Vec::new();
+    let mut total_size_bytes: u64 = 0;
+
+    for source_path in source_paths {
         if !source_path.exists() {
             continue;
         }
 
-        let dest_name = format!("backup_{}", i);
-        let dest_path = point_dir.join(&dest_name);
+        roots.push(RestorePointRoot {
+            path: source_path.clone(),
+            is_dir: source_path.is_dir(),
+        });
 
         if source_path.is_dir() {
-            utils::copy_dir_recursive(source_path, &dest_path)?;
-        } else {
-            if let Some(parent) = dest_path.parent() {
-                fs::create_dir_all(parent)
-                    .map_err(|e| format!("Failed to create parent dir: {}", e))?;
+            for walk_entry in WalkDir::new(source_path).into_iter().filter_map(Result::ok) {
+                let path = walk_entry.path();
+                if path == source_path {
+                    continue; // already tracked as a root
+                }
+
+                if walk_entry.file_type().is_dir() {
+                    entries.push(RestorePointEntry {
+                        original_path: path.to_path_buf(),
+                        is_dir: true,
+                        size_bytes: 0,
+                        checksum: None,
+                    });
+                } else if walk_entry.file_type().is_file() {
+                    let size_bytes = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                    let hash = store_blob(&blobs_dir, path, compression, compression_level)?;
+                    total_size_bytes += size_bytes;
+                    entries.push(RestorePointEntry {
+                        original_path: path.to_path_buf(),
+                        is_dir: false,
+                        size_bytes,
+                        checksum: Some(hash),
+                    });
+                }
             }
-            fs::copy(source_path, &dest_path).map_err(|e| format!("Failed to copy file: {}", e))?;
+        } else {
+            let size_bytes = fs::metadata(source_path).map(|m| m.len()).unwrap_or(0);
+            let hash = store_blob(&blobs_dir, source_path, compression, compression_level)?;
+            total_size_bytes += size_bytes;
+            entries.push(RestorePointEntry {
+                original_path: source_path.clone(),
+                is_dir: false,
+                size_bytes,
+                checksum: Some(hash),
+            });
         }
-
-        // Save metadata about original location
-        let metadata_path = point_dir.join(format!("{}.meta", dest_name));
-        fs::write(&metadata_path, source_path.to_string_lossy().as_bytes())
-            .map_err(|e| format!("Failed to write metadata: {}", e))?;
     }
 
+    let completed_at = Local::now();
+
+    let manifest = RestorePointManifest {
+        version: MANIFEST_VERSION,
+        name: name.to_string(),
+        created_at: created_at.to_rfc3339(),
+        completed_at: completed_at.to_rfc3339(),
+        total_size_bytes,
+        roots,
+        entries,
+    };
+
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize restore point manifest: {}", e))?;
+    fs::write(point_dir.join("manifest.json"), json)
+        .map_err(|e| format!("Failed to write restore point manifest: {}", e))?;
+
     Ok(point_dir)
 }
 
+/// Builds a [`RestorePoint`] from a parsed manifest: `timestamp` is `created_at` reformatted
+/// to match the legacy folder-name display (`YYYY-MM-DD HH:MM:SS`) so old and new points look
+/// the same in the UI, and `duration_ms`/`size_bytes` come straight from the manifest.
+fn restore_point_from_manifest(path: PathBuf, manifest: &RestorePointManifest) -> RestorePoint {
+    let timestamp = chrono::DateTime::parse_from_rfc3339(&manifest.created_at)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|_| manifest.created_at.clone());
+
+    let duration_ms = match (
+        chrono::DateTime::parse_from_rfc3339(&manifest.created_at),
+        chrono::DateTime::parse_from_rfc3339(&manifest.completed_at),
+    ) {
+        (Ok(start), Ok(end)) => {
+            Some(end.signed_duration_since(start).num_milliseconds().max(0) as u64)
+        }
+        _ => None,
+    };
+
+    RestorePoint {
+        name: manifest.name.clone(),
+        timestamp,
+        path,
+        size_bytes: Some(manifest.total_size_bytes),
+        duration_ms,
+    }
+}
+
+/// Reconstructs a [`RestorePoint`] by string-slicing the `YYYYMMDD_HHMMSS_Name` folder name,
+/// for restore points captured before `manifest.json` existed.
+fn restore_point_from_folder_name(entry: &fs::DirEntry, path: PathBuf) -> Option<RestorePoint> {
+    let folder_name = path.file_name()?;
+    let folder_name_str = folder_name.to_string_lossy();
+
+    let (timestamp, name) = if folder_name_str.len() >= 16 {
+        // Try to parse the timestamp part (first 15 chars: YYYYMMDD_HHMMSS)
+        let ts_part = &folder_name_str[..15];
+        let name_part = if folder_name_str.len() > 16 {
+            folder_name_str[16..].to_string()
+        } else {
+            "Unnamed".to_string()
+        };
+
+        // Format timestamp nicely: YYYYMMDD_HHMMSS -> YYYY-MM-DD HH:MM:SS
+        let formatted_ts = if ts_part.len() == 15 && ts_part.chars().nth(8) == Some('_') {
+            format!(
+                "{}-{}-{} {}:{}:{}",
+                &ts_part[0..4],   // Year
+                &ts_part[4..6],   // Month
+                &ts_part[6..8],   // Day
+                &ts_part[9..11],  // Hour
+                &ts_part[11..13], // Minute
+                &ts_part[13..15]  // Second
+            )
+        } else {
+            ts_part.to_string()
+        };
+
+        (formatted_ts, name_part)
+    } else {
+        // Fallback to file modification time if folder name doesn't match expected format
+        let timestamp = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| {
+                let datetime = chrono::DateTime::<chrono::Utc>::from(UNIX_EPOCH + d);
+                datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        (timestamp, folder_name_str.to_string())
+    };
+
+    Some(RestorePoint {
+        name,
+        timestamp,
+        path,
+        size_bytes: None,
+        duration_ms: None,
+    })
+}
+
 pub fn list_restore_points() -> Result<Vec<RestorePoint>, String> {
     let restore_dir = get_restore_points_dir();
 
@@ -54,65 +344,25 @@ pub fn list_restore_points() -> Result<Vec<RestorePoint>, String> {
 
     let mut points = Vec::new();
 
-    let entries = fs::read_dir(&restore_dir)
+    let dir_entries = fs::read_dir(&restore_dir)
         .map_err(|e| format!("Failed to read restore points dir: {}", e))?;
 
-    for entry in entries.flatten() {
+    for entry in dir_entries.flatten() {
         let path = entry.path();
-        if path.is_dir() {
-            if let Some(folder_name) = path.file_name() {
-                let folder_name_str = folder_name.to_string_lossy();
-
-                // Folder name format: YYYYMMDD_HHMMSS_Name
-                // Parse timestamp and name from folder name
-                let (timestamp, name) = if folder_name_str.len() >= 16 {
-                    // Try to parse the timestamp part (first 15 chars: YYYYMMDD_HHMMSS)
-                    let ts_part = &folder_name_str[..15];
-                    let name_part = if folder_name_str.len() > 16 {
-                        folder_name_str[16..].to_string()
-                    } else {
-                        "Unnamed".to_string()
-                    };
-
-                    // Format timestamp nicely: YYYYMMDD_HHMMSS -> YYYY-MM-DD HH:MM:SS
-                    let formatted_ts = if ts_part.len() == 15 && ts_part.chars().nth(8) == Some('_')
-                    {
-                        format!(
-                            "{}-{}-{} {}:{}:{}",
-                            &ts_part[0..4],   // Year
-                            &ts_part[4..6],   // Month
-                            &ts_part[6..8],   // Day
-                            &ts_part[9..11],  // Hour
-                            &ts_part[11..13], // Minute
-                            &ts_part[13..15]  // Second
-                        )
-                    } else {
-                        ts_part.to_string()
-                    };
-
-                    (formatted_ts, name_part)
-                } else {
-                    // Fallback to file modification time if folder name doesn't match expected format
-                    let timestamp = entry
-                        .metadata()
-                        .and_then(|m| m.modified())
-                        .ok()
-                        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
-                        .map(|d| {
-                            let datetime = chrono::DateTime::<chrono::Utc>::from(UNIX_EPOCH + d);
-                            datetime.format("%Y-%m-%d %H:%M:%S").to_string()
-                        })
-                        .unwrap_or_else(|| "Unknown".to_string());
-
-                    (timestamp, folder_name_str.to_string())
-                };
-
-                points.push(RestorePoint {
-                    name,
-                    timestamp,
-                    path,
-                });
-            }
+        if !path.is_dir() || path.file_name() == Some(std::ffi::OsStr::new("blobs")) {
+            continue;
+        }
+
+        let point = match fs::read_to_string(path.join("manifest.json"))
+            .ok()
+            .and_then(|json| serde_json::from_str::<RestorePointManifest>(&json).ok())
+        {
+            Some(manifest) => Some(restore_point_from_manifest(path.clone(), &manifest)),
+            None => restore_point_from_folder_name(&entry, path.clone()),
+        };
+
+        if let Some(point) = point {
+            points.push(point);
         }
     }
 
@@ -122,57 +372,423 @@ pub fn list_restore_points() -> Result<Vec<RestorePoint>, String> {
     Ok(points)
 }
 
-pub fn rollback_to_restore_point(point_path: &PathBuf) -> Result<String, String> {
-    if !point_path.exists() {
+/// Which entries of a restore point to act on. Indices are positions into the manifest's
+/// `entries` array (as returned alongside a restore point listing); relative paths are each
+/// entry's path under its root, e.g. `ModA/textures/kit.png` (see [`entry_relative_path`]).
+pub enum EntrySelector {
+    All,
+    Indices(HashSet<usize>),
+    RelativePaths(HashSet<PathBuf>),
+}
+
+impl EntrySelector {
+    fn includes(&self, index: usize, relative_path: &Path) -> bool {
+        match self {
+            EntrySelector::All => true,
+            EntrySelector::Indices(indices) => indices.contains(&index),
+            EntrySelector::RelativePaths(paths) => paths.contains(relative_path),
+        }
+    }
+}
+
+/// Strips everything but the `Normal` components of an absolute path, so it can be rejoined
+/// under an arbitrary override root without escaping it (e.g. a leading `/` or `C:\`).
+fn strip_absolute_prefix(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|c| matches!(c, std::path::Component::Normal(_)))
+        .collect()
+}
+
+/// The entry's path relative to the root it was captured under, e.g. `ModA/textures/kit.png`
+/// for an entry under the root `/home/user/mods/ModA`. Falls back to stripping the absolute
+/// path down to its normal components if no root claims it (shouldn't happen in practice).
+fn entry_relative_path(original_path: &Path, roots: &[RestorePointRoot]) -> PathBuf {
+    for root in roots {
+        if original_path == root.path {
+            return PathBuf::from(root.path.file_name().unwrap_or_default());
+        }
+        if let Ok(rel) = original_path.strip_prefix(&root.path) {
+            let root_name = root.path.file_name().unwrap_or_default();
+            return PathBuf::from(root_name).join(rel);
+        }
+    }
+    strip_absolute_prefix(original_path)
+}
+
+/// Restores entries from a captured point. `override_root`, when given, remaps every
+/// restored entry's original absolute path under that root instead of overwriting it in
+/// place — the directory-wipe that a full in-place restore does to the original roots is
+/// skipped in that case, so this is safe to use as a "dry-run restore to a temp folder to
+/// inspect it" workflow. `selector` limits which entries are touched; entries outside the
+/// selection are reported as `Skipped` rather than omitted, so the caller gets a complete
+/// picture of the point's contents.
+pub fn restore_entries(
+    point_path: &Path,
+    override_root: Option<&Path>,
+    selector: &EntrySelector,
+) -> Result<Vec<RestoreEntryResult>, String> {
+    let manifest_path = point_path.join("manifest.json");
+    if !manifest_path.exists() {
         return Err("Restore point not found".to_string());
     }
 
-    let entries =
-        fs::read_dir(point_path).map_err(|e| format!("Failed to read restore point: {}", e))?;
+    let json = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read restore point manifest: {}", e))?;
+    let manifest: RestorePointManifest = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse restore point manifest: {}", e))?;
+
+    // Wiping the original roots wholesale only makes sense for a full, in-place restore —
+    // doing it for a selective restore would destroy entries the caller chose to leave
+    // alone, and doing it for an override root would destroy the live install instead of
+    // leaving it untouched.
+    if override_root.is_none() && matches!(selector, EntrySelector::All) {
+        for root in &manifest.roots {
+            if root.is_dir && root.path.exists() {
+                fs::remove_dir_all(&root.path).map_err(|e| {
+                    format!("Failed to remove existing dir '{}': {}", root.path.display(), e)
+                })?;
+            }
+        }
+    }
 
-    let mut restored_count = 0;
+    let blobs_dir = blobs_dir();
+    let mut results = Vec::with_capacity(manifest.entries.len());
 
-    for entry in entries.flatten() {
-        let path = entry.path();
-        let file_name = match path.file_name() {
-            Some(n) => n.to_string_lossy().to_string(),
-            None => continue,
+    for (index, entry) in manifest.entries.iter().enumerate() {
+        let relative_path = entry_relative_path(&entry.original_path, &manifest.roots);
+        let restored_path = match override_root {
+            Some(root) => root.join(&relative_path),
+            None => entry.original_path.clone(),
         };
 
-        // Skip metadata files
-        if file_name.ends_with(".meta") {
+        if !selector.includes(index, &relative_path) {
+            results.push(RestoreEntryResult {
+                original_path: entry.original_path.clone(),
+                restored_path,
+                status: RestoreEntryStatus::Skipped,
+                error: None,
+            });
             continue;
         }
 
-        // Read metadata to get original location
-        let meta_path = point_path.join(format!("{}.meta", file_name));
-        if !meta_path.exists() {
-            continue;
-        }
+        let outcome = if entry.is_dir {
+            fs::create_dir_all(&restored_path).map_err(|e| {
+                format!("Failed to recreate dir '{}': {}", restored_path.display(), e)
+            })
+        } else {
+            restore_file_entry(&blobs_dir, entry, &restored_path)
+        };
+
+        results.push(match outcome {
+            Ok(()) => RestoreEntryResult {
+                original_path: entry.original_path.clone(),
+                restored_path,
+                status: RestoreEntryStatus::Restored,
+                error: None,
+            },
+            Err(e) => RestoreEntryResult {
+                original_path: entry.original_path.clone(),
+                restored_path,
+                status: RestoreEntryStatus::Failed,
+                error: Some(e),
+            },
+        });
+    }
+
+    Ok(results)
+}
+
+/// Decodes a single file entry's blob, re-hashes the decoded bytes and verifies them against the
+/// recorded checksum before writing them to `restored_path` rather than trusting a blind write —
+/// catches a blob corrupted on disk since it was stored, and leaves `restored_path` unwritten
+/// when the check fails.
+fn restore_file_entry(
+    blobs_dir: &Path,
+    entry: &RestorePointEntry,
+    restored_path: &Path,
+) -> Result<(), String> {
+    let Some(hash) = &entry.checksum else {
+        return Err("Entry has no recorded content".to_string());
+    };
+
+    if let Some(parent) = restored_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create parent dir: {}", e))?;
+    }
+
+    let contents = read_blob_contents(blobs_dir, hash).map_err(|e| {
+        format!(
+            "Failed to restore '{}' from blob '{}': {}",
+            restored_path.display(),
+            hash,
+            e
+        )
+    })?;
+
+    let actual_hash = blake3::hash(&contents).to_hex().to_string();
+    if &actual_hash != hash {
+        return Err(format!(
+            "Checksum mismatch restoring '{}': expected {}, found {}",
+            restored_path.display(),
+            hash,
+            actual_hash
+        ));
+    }
+
+    fs::write(restored_path, &contents).map_err(|e| {
+        format!(
+            "Failed to restore '{}' from blob '{}': {}",
+            restored_path.display(),
+            hash,
+            e
+        )
+    })?;
+
+    Ok(())
+}
+
+/// Restores every entry back to its original location, wiping directory roots first so
+/// files deleted or renamed since capture don't linger. Thin wrapper over
+/// [`restore_entries`] for the common "roll back everything in place" case.
+pub fn rollback_to_restore_point(point_path: &PathBuf) -> Result<String, String> {
+    let results = restore_entries(point_path, None, &EntrySelector::All)?;
+
+    if let Some(failed) = results
+        .iter()
+        .find(|r| r.status == RestoreEntryStatus::Failed)
+    {
+        return Err(failed
+            .error
+            .clone()
+            .unwrap_or_else(|| "Restore failed".to_string()));
+    }
+
+    let restored_count = results
+        .iter()
+        .filter(|r| r.status == RestoreEntryStatus::Restored)
+        .count();
+
+    Ok(format!("Restored {} items", restored_count))
+}
+
+/// Mark-and-sweep collector: unions the blob hashes referenced by every remaining restore
+/// point's manifest, then deletes any blob under `blobs/` not in that set. Run after deleting
+/// a restore point to reclaim the space its unique files held.
+pub fn gc_unreferenced_blobs() -> Result<usize, String> {
+    let restore_dir = get_restore_points_dir();
+    let blobs_dir = blobs_dir();
+
+    if !blobs_dir.exists() {
+        return Ok(0);
+    }
 
-        let original_location = fs::read_to_string(&meta_path)
-            .map_err(|e| format!("Failed to read metadata: {}", e))?;
+    let mut live_hashes = HashSet::new();
 
-        let original_path = PathBuf::from(original_location.trim());
+    if restore_dir.exists() {
+        let entries = fs::read_dir(&restore_dir)
+            .map_err(|e| format!("Failed to read restore points dir: {}", e))?;
 
-        // Restore the file/directory
-        if path.is_dir() {
-            if original_path.exists() {
-                fs::remove_dir_all(&original_path)
-                    .map_err(|e| format!("Failed to remove existing dir: {}", e))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() || path.file_name() == Some(std::ffi::OsStr::new("blobs")) {
+                continue;
             }
-            utils::copy_dir_recursive(&path, &original_path)?;
-        } else {
-            if let Some(parent) = original_path.parent() {
-                fs::create_dir_all(parent)
-                    .map_err(|e| format!("Failed to create parent dir: {}", e))?;
+
+            let Ok(json) = fs::read_to_string(path.join("manifest.json")) else {
+                continue;
+            };
+            let Ok(manifest) = serde_json::from_str::<RestorePointManifest>(&json) else {
+                continue;
+            };
+
+            for entry in manifest.entries {
+                if let Some(hash) = entry.checksum {
+                    live_hashes.insert(hash);
+                }
             }
-            fs::copy(&path, &original_path)
-                .map_err(|e| format!("Failed to restore file: {}", e))?;
         }
+    }
+
+    let mut removed = 0;
+    let blob_entries =
+        fs::read_dir(&blobs_dir).map_err(|e| format!("Failed to read blobs dir: {}", e))?;
 
-        restored_count += 1;
+    for entry in blob_entries.flatten() {
+        let path = entry.path();
+        let Some(hash) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+            continue;
+        };
+
+        // `.codec` sidecars ride along with their blob and aren't hashes themselves; they're
+        // cleaned up below, alongside the blob they describe, rather than matched here.
+        if hash.ends_with(".codec") {
+            continue;
+        }
+
+        if !live_hashes.contains(&hash) && fs::remove_file(&path).is_ok() {
+            let _ = fs::remove_file(blob_codec_sidecar(&blobs_dir, &hash));
+            removed += 1;
+        }
     }
 
-    Ok(format!("Restored {} items", restored_count))
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_test_appdata() -> PathBuf {
+        let base = std::env::temp_dir().join(format!("fmml_restore_test_{}", uuid::Uuid::new_v4()));
+        std::env::set_var("FMML_TEST_APPDATA", &base);
+        base
+    }
+
+    #[test]
+    fn test_create_and_rollback_restore_point_deduplicates_blobs() {
+        let base = set_test_appdata();
+
+        let source_dir = base.join("source");
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        let file_a = source_dir.join("a.txt");
+        let file_b = source_dir.join("b.txt");
+        fs::write(&file_a, b"shared content").expect("write a.txt");
+        fs::write(&file_b, b"shared content").expect("write b.txt");
+
+        let point_dir = create_restore_point("test", &[source_dir.clone()], BackupCompression::None, 0)
+            .expect("create_restore_point should succeed");
+
+        // Both files hash identically, so only one blob should exist.
+        let blob_count = fs::read_dir(blobs_dir()).unwrap().count();
+        assert_eq!(blob_count, 1, "identical files should share a single blob");
+
+        // Mutate the source after capturing the restore point.
+        fs::write(&file_a, b"tampered").expect("tamper a.txt");
+        fs::remove_file(&file_b).expect("remove b.txt");
+
+        let result = rollback_to_restore_point(&point_dir).expect("rollback should succeed");
+        assert_eq!(result, "Restored 2 items");
+        assert_eq!(fs::read(&file_a).unwrap(), b"shared content");
+        assert_eq!(fs::read(&file_b).unwrap(), b"shared content");
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_list_restore_points_reports_size_and_duration() {
+        let base = set_test_appdata();
+
+        let source_dir = base.join("source");
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        fs::write(source_dir.join("a.txt"), b"12345").expect("write a.txt");
+
+        create_restore_point("sized", &[source_dir.clone()], BackupCompression::None, 0).expect("create restore point");
+
+        let points = list_restore_points().expect("list_restore_points should succeed");
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].name, "sized");
+        assert_eq!(points[0].size_bytes, Some(5));
+        assert!(points[0].duration_ms.is_some());
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_restore_entries_to_override_root_leaves_original_untouched() {
+        let base = set_test_appdata();
+
+        let source_dir = base.join("source").join("ModA");
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        fs::write(source_dir.join("a.txt"), b"original content").expect("write a.txt");
+
+        let point_dir =
+            create_restore_point("preview", &[source_dir.clone()], BackupCompression::None, 0).expect("create restore point");
+
+        // Mutate the live source after capture; an override-root restore must not touch it.
+        fs::write(source_dir.join("a.txt"), b"live edit").expect("edit a.txt");
+
+        let scratch = base.join("scratch");
+        let results = restore_entries(&point_dir, Some(&scratch), &EntrySelector::All)
+            .expect("restore_entries should succeed");
+
+        assert!(results
+            .iter()
+            .all(|r| r.status == RestoreEntryStatus::Restored));
+        assert_eq!(fs::read(source_dir.join("a.txt")).unwrap(), b"live edit");
+        assert_eq!(
+            fs::read(scratch.join("ModA").join("a.txt")).unwrap(),
+            b"original content"
+        );
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_restore_entries_selective_skips_unselected() {
+        let base = set_test_appdata();
+
+        let source_dir = base.join("source").join("ModA");
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        fs::write(source_dir.join("a.txt"), b"keep me").expect("write a.txt");
+        fs::write(source_dir.join("b.txt"), b"leave me alone").expect("write b.txt");
+
+        let point_dir =
+            create_restore_point("partial", &[source_dir.clone()], BackupCompression::None, 0).expect("create restore point");
+
+        fs::write(source_dir.join("a.txt"), b"tampered").expect("tamper a.txt");
+        fs::write(source_dir.join("b.txt"), b"also tampered").expect("tamper b.txt");
+
+        let selector = EntrySelector::RelativePaths(
+            [PathBuf::from("ModA").join("a.txt")].into_iter().collect(),
+        );
+        let results = restore_entries(&point_dir, None, &selector)
+            .expect("restore_entries should succeed");
+
+        let a_result = results
+            .iter()
+            .find(|r| r.original_path.ends_with("a.txt"))
+            .expect("a.txt result present");
+        let b_result = results
+            .iter()
+            .find(|r| r.original_path.ends_with("b.txt"))
+            .expect("b.txt result present");
+
+        assert_eq!(a_result.status, RestoreEntryStatus::Restored);
+        assert_eq!(b_result.status, RestoreEntryStatus::Skipped);
+        assert_eq!(fs::read(source_dir.join("a.txt")).unwrap(), b"keep me");
+        assert_eq!(
+            fs::read(source_dir.join("b.txt")).unwrap(),
+            b"also tampered"
+        );
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
+
+    #[test]
+    fn test_gc_unreferenced_blobs_removes_orphans() {
+        let base = set_test_appdata();
+
+        let source_dir = base.join("source");
+        fs::create_dir_all(&source_dir).expect("create source dir");
+        let file_a = source_dir.join("a.txt");
+        fs::write(&file_a, b"point one content").expect("write a.txt");
+
+        let point_dir =
+            create_restore_point("one", &[source_dir.clone()], BackupCompression::None, 0).expect("create restore point");
+
+        // Simulate deleting that restore point without running gc.
+        fs::remove_dir_all(&point_dir).expect("remove restore point dir");
+
+        assert_eq!(fs::read_dir(blobs_dir()).unwrap().count(), 1);
+
+        let removed = gc_unreferenced_blobs().expect("gc should succeed");
+        assert_eq!(removed, 1);
+        assert_eq!(fs::read_dir(blobs_dir()).unwrap().count(), 0);
+
+        let _ = fs::remove_dir_all(&base);
+        std::env::remove_var("FMML_TEST_APPDATA");
+    }
 }
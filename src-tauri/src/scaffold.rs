@@ -0,0 +1,164 @@
+//! Mod Scaffolding - `fmmloader new <type>`
+//!
+//! Generates a ready-to-fill mod skeleton for each supported type (`ui`, `tactics`, `bundle`):
+//! the platform subfolders a mod of that type is expected to carry, a placeholder asset of
+//! the right extension, and an `fmmloader.toml` with starter metadata. The `fmmloader.toml`
+//! bodies are embedded into the binary at compile time via `rust-embed` so scaffolding works
+//! offline without shipping loose template files next to the executable.
+//!
+//! This complements `auto_detect_mod_type`: a scaffolded mod is immediately a valid starting
+//! point for that detector rather than an empty folder the author has to structure by hand.
+
+use crate::import::generate_manifest_from_config;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(rust_embed::RustEmbed)]
+#[folder = "templates/"]
+struct TemplateAssets;
+
+/// A supported `fmmloader new` scaffold kind, one per embedded `templates/<name>/` folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    Ui,
+    Tactics,
+    Bundle,
+}
+
+impl Template {
+    /// Every template, in a stable order, for the `fmmloader` usage text and the unknown-template
+    /// error message.
+    pub fn all() -> &'static [Template] {
+        &[Template::Ui, Template::Tactics, Template::Bundle]
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Template::Ui => "ui",
+            Template::Tactics => "tactics",
+            Template::Bundle => "bundle",
+        }
+    }
+
+    /// Platform subfolders a mod of this type is expected to carry. Tactics mods are a single
+    /// `.fmf` file with no platform split.
+    fn platform_folders(self) -> &'static [&'static str] {
+        match self {
+            Template::Ui | Template::Bundle => &["windows", "macos", "linux"],
+            Template::Tactics => &[],
+        }
+    }
+
+    /// Extension of the placeholder asset dropped into the skeleton.
+    fn asset_extension(self) -> &'static str {
+        match self {
+            Template::Ui | Template::Bundle => "bundle",
+            Template::Tactics => "fmf",
+        }
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::all()
+            .iter()
+            .copied()
+            .find(|t| t.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "Unknown template '{}', expected one of: {}",
+                    s,
+                    Template::all()
+                        .iter()
+                        .map(|t| t.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Expands `template` into a fresh skeleton at `dest`: the platform subfolders the type
+/// expects, a placeholder asset of the right extension in each, an `fmmloader.toml` with
+/// starter metadata (embedded from `templates/<type>/fmmloader.toml`), and a `manifest.json`
+/// generated from that config so the skeleton is immediately valid for install/preview.
+pub fn scaffold(template: Template, dest: &Path) -> Result<(), String> {
+    fs::create_dir_all(dest).map_err(|e| format!("Failed to create '{}': {}", dest.display(), e))?;
+
+    let toml_path = format!("{}/fmmloader.toml", template.name());
+    let toml_bytes = TemplateAssets::get(&toml_path)
+        .ok_or_else(|| format!("Missing embedded template '{}'", toml_path))?;
+    fs::write(dest.join("fmmloader.toml"), toml_bytes.data.as_ref())
+        .map_err(|e| format!("Failed to write fmmloader.toml: {}", e))?;
+
+    let asset_name = format!("placeholder.{}", template.asset_extension());
+    let platform_folders = template.platform_folders();
+    if platform_folders.is_empty() {
+        fs::write(dest.join(&asset_name), b"")
+            .map_err(|e| format!("Failed to write placeholder asset: {}", e))?;
+    } else {
+        for folder in platform_folders {
+            let folder_path = dest.join(folder);
+            fs::create_dir_all(&folder_path)
+                .map_err(|e| format!("Failed to create '{}': {}", folder_path.display(), e))?;
+            fs::write(folder_path.join(&asset_name), b"")
+                .map_err(|e| format!("Failed to write placeholder asset: {}", e))?;
+        }
+    }
+
+    generate_manifest_from_config(dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_from_str_is_case_insensitive() {
+        assert_eq!(Template::from_str("UI").unwrap(), Template::Ui);
+        assert_eq!(Template::from_str("tactics").unwrap(), Template::Tactics);
+        assert!(Template::from_str("missiles").is_err());
+    }
+
+    #[test]
+    fn test_scaffold_ui_creates_platform_folders_and_manifest() {
+        let dest = std::env::temp_dir().join(format!("test_scaffold_{}", uuid::Uuid::new_v4()));
+
+        scaffold(Template::Ui, &dest).expect("scaffold should succeed");
+
+        assert!(dest.join("fmmloader.toml").exists());
+        assert!(dest.join("windows/placeholder.bundle").exists());
+        assert!(dest.join("macos/placeholder.bundle").exists());
+        assert!(dest.join("linux/placeholder.bundle").exists());
+
+        let manifest_content = fs::read_to_string(dest.join("manifest.json")).expect("read manifest");
+        let manifest: crate::types::ModManifest =
+            serde_json::from_str(&manifest_content).expect("parse manifest");
+        assert_eq!(manifest.name, "My UI Mod");
+        assert_eq!(manifest.mod_type, "ui");
+
+        let _ = fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn test_scaffold_tactics_has_no_platform_folders() {
+        let dest = std::env::temp_dir().join(format!("test_scaffold_{}", uuid::Uuid::new_v4()));
+
+        scaffold(Template::Tactics, &dest).expect("scaffold should succeed");
+
+        assert!(dest.join("placeholder.fmf").exists());
+        assert!(!dest.join("windows").exists());
+
+        let _ = fs::remove_dir_all(&dest);
+    }
+}
@@ -4,11 +4,19 @@
 //! Types are organized by domain: mod management, configuration, graphics packs, and progress tracking.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModManifest {
     pub name: String,
+    /// Format version of this manifest shape, stamped by [`crate::mod_manager::parse_manifest_json`]
+    /// so future field additions can migrate old manifests forward instead of breaking them.
+    /// `#[serde(default)]` here is just a safety net; every manifest that reaches this struct
+    /// has already had its version normalized to
+    /// [`crate::mod_manager::CURRENT_MANIFEST_SCHEMA_VERSION`] by the migration chain.
+    #[serde(default)]
+    pub schema_version: u32,
     #[serde(default)]
     pub version: String,
     #[serde(default)]
@@ -31,6 +39,11 @@ pub struct ModManifest {
     pub load_after: Vec<String>,
     #[serde(default)]
     pub files: Vec<FileEntry>,
+    /// Provenance of this manifest, reusing the same GitHub/Imported marker as
+    /// `NameFixSource`. `None` means it was authored locally (e.g. via `fmmloader new` or
+    /// `generate_manifest`), not pulled in from an external pack format.
+    #[serde(default)]
+    pub source_type: Option<NameFixSourceType>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -45,6 +58,111 @@ pub struct FileEntry {
     pub target_subpath: String,
     #[serde(default)]
     pub platform: Option<String>,
+    /// Overrides `Config::default_install_mode` for this file/directory. `None` means
+    /// "use the configured default".
+    #[serde(default)]
+    pub install_mode: Option<InstallMode>,
+    /// Lowercase hex SHA-256 of the file's bytes at the time `generate_manifest` ran.
+    /// `None` for manifests written before integrity hashing existed, or hand-authored ones.
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+/// How a mod's files are placed into the game directory.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum InstallMode {
+    /// Duplicate file bytes into the target. Always safe, always works across filesystems.
+    #[default]
+    Copy,
+    /// Link to the same file data. Falls back to `Copy` if the source and target are on
+    /// different filesystems (EXDEV).
+    Hardlink,
+    /// Point at the source path instead of placing real files. On Windows, directories are
+    /// linked with an NTFS junction rather than a symlink, since junctions don't require
+    /// elevated privileges.
+    Symlink,
+}
+
+/// How [`crate::mod_manager::backup_file`] names the copy it makes of a file about to be
+/// overwritten, mirroring coreutils `install`'s `--backup` control methods.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum BackupMode {
+    /// `file_20240102_150405.bak`: one backup per overwrite, all kept until
+    /// `cleanup_old_backups` prunes by mtime. The original, and still default, behavior.
+    #[default]
+    Timestamped,
+    /// `file.~1~`, `file.~2~`, ...: numbered like `install --backup=numbered`, always one past
+    /// the highest existing number for that file.
+    Numbered,
+    /// `file.bak`: a single backup per file, overwritten by the next backup of that file.
+    Simple,
+}
+
+/// How [`crate::name_fix::install_name_fix`]/`install` back up the db dir before overwriting it.
+/// Distinct from [`BackupMode`] because a name-fix backup is a whole-directory snapshot (catalogued
+/// in `name_fix_backup/`, see [`BackupInfo`]) rather than a per-file rename.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum NameFixBackupMode {
+    /// Every install keeps its own timestamped snapshot in the history, restorable individually.
+    /// The original, and still default, behavior.
+    #[default]
+    Numbered,
+    /// One "current" snapshot, overwritten by the next install — trades rollback history for
+    /// using less disk space.
+    Simple,
+    /// Skip the backup entirely. Faster installs, but `uninstall`/`restore_backup` have nothing
+    /// to roll back to.
+    None,
+    /// `Numbered` if the history already holds a prior snapshot, `Simple` otherwise — mirrors
+    /// GNU `install --backup=existing`, so a first install doesn't start a history nobody asked
+    /// for but a second one doesn't silently start discarding rollback points either.
+    Existing,
+}
+
+/// How [`crate::name_fix::create_folder_backups`] stores the folder-based (Sortitoutsi-style)
+/// backup: a mirrored directory tree or a single compressed archive. The dbc/edt/lnc folders it
+/// covers can be large, so archiving trades install-time CPU for disk space on small SSDs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum NameFixBackupCompression {
+    /// Mirror dbc/edt/lnc verbatim, one file per source file. The original, and still default,
+    /// behavior; also what every pre-existing backup on disk is assumed to be.
+    #[default]
+    None,
+    /// A single `tar.xz`: slower to write, smallest on disk.
+    Xz,
+    /// A single `tar.zst`: faster to write than `Xz` at a comparable ratio.
+    Zstd,
+}
+
+/// How [`crate::mod_manager::backup_file`] and [`crate::restore`]'s blob store compress the
+/// bytes they keep on disk. Users with many FM saves/mods can accumulate a large `backups/` and
+/// `restore_points/blobs/` footprint over time, so this trades write-time CPU for disk usage.
+/// Recorded per backup/blob (not assumed from the live config) so a restore always picks the
+/// right decoder even after the setting has since changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum BackupCompression {
+    /// Store bytes verbatim. Fastest, largest on disk; what every backup/blob written before
+    /// this setting existed is assumed to be.
+    None,
+    /// A single `zstd` stream: fast to write at a good ratio. The default.
+    #[default]
+    Zstd,
+    /// A single `xz` stream: slower to write, smallest on disk.
+    Xz,
+}
+
+/// The line ending [`crate::name_fix::normalize_text_contents`] rewrites extracted `.lnc`/`.edt`/
+/// `.dbc` text files to. Different name-fix sites ship a mix of CRLF and LF (and the occasional
+/// stray UTF-8 BOM), which can trip up the game's parser depending on platform.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum LineEndingStyle {
+    /// CRLF on Windows, LF everywhere else. The default.
+    #[default]
+    Native,
+    /// Always `\n`.
+    Lf,
+    /// Always `\r\n`.
+    Crlf,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +175,30 @@ pub struct ResolvedFilePreview {
 pub struct ModInstallPreview {
     pub base_target: String,
     pub resolved_files: Vec<ResolvedFilePreview>,
+    /// Ownership status for each entry in `resolved_files`, same index, same order.
+    pub conflicts: Vec<FileOwnershipConflict>,
+}
+
+/// Per-file conflict status computed by [`crate::mod_manager::preview_mod_install`] against the
+/// persisted [`OwnershipIndex`] and live disk state, so the UI can warn before a mod silently
+/// overwrites another mod's files or an untracked pre-existing one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FileOwnershipConflict {
+    /// Unowned (and not yet installed), or already owned by this same mod.
+    None,
+    /// Already installed by a different mod.
+    OwnedByOtherMod(String),
+    /// Exists on disk but isn't tracked by any mod's install.
+    ExistsUnowned,
+}
+
+/// Maps a resolved install path (as its string form) to the name of the mod that installed it
+/// there. Persisted to `ownership_index.json` so [`crate::mod_manager::preview_mod_install`]
+/// can flag conflicts even across process restarts, since many mod types funnel into shared
+/// directories like `graphics/` via [`crate::mod_manager::get_target_for_type`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OwnershipIndex {
+    pub owners: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +215,68 @@ pub struct Config {
     pub language: Option<String>,
     #[serde(default)]
     pub active_name_fix: Option<String>,
+    #[serde(default)]
+    pub default_install_mode: InstallMode,
+    #[serde(default)]
+    pub default_backup_mode: BackupMode,
+    #[serde(default)]
+    pub backup_compression: BackupCompression,
+    /// Level passed to the xz/zstd encoder when `backup_compression` is `Xz`/`Zstd` (xz: 0-9,
+    /// zstd: 1-22). `0` means "use that encoder's own default preset".
+    #[serde(default)]
+    pub backup_compression_level: u32,
+    #[serde(default)]
+    pub name_fix_backup_mode: NameFixBackupMode,
+    #[serde(default)]
+    pub name_fix_backup_compression: NameFixBackupCompression,
+    /// Level passed to the xz/zstd encoder when `name_fix_backup_compression` is `Xz`/`Zstd`
+    /// (xz: 0-9, zstd: 1-22). `0` means "use that encoder's own default preset".
+    #[serde(default)]
+    pub name_fix_backup_compression_level: u32,
+    /// How many [`NameFixBackupMode::Numbered`] snapshots [`crate::name_fix::create_backups`]/
+    /// `create_folder_backups` keep before pruning the oldest, so a long `name_fix_stack` doesn't
+    /// grow `name_fix_backup/` without bound. `0` disables pruning (keep every snapshot).
+    #[serde(default = "default_name_fix_backup_retention")]
+    pub name_fix_backup_retention: usize,
+    /// Line ending [`crate::name_fix::import_name_fix`] rewrites extracted text files to.
+    #[serde(default)]
+    pub name_fix_line_ending: LineEndingStyle,
+    /// Ids of every currently-installed fix, oldest first, with `active_name_fix` always the
+    /// last entry. Lets [`crate::name_fix::uninstall`] fall back to the fix installed just
+    /// before the one it's removing instead of only ever restoring stock.
+    #[serde(default)]
+    pub name_fix_stack: Vec<String>,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub active_profile: String,
+    /// Extra command-line arguments [`crate::launch::launch_game`] appends to every launch, on
+    /// top of whatever's passed to that call directly (e.g. `-screenres 1920 1080`).
+    #[serde(default)]
+    pub launch_args: Vec<String>,
+    /// When true, [`crate::launch::launch_game`] launches via `steam://rungameid/<steam_app_id>`
+    /// instead of spawning the FM executable directly.
+    #[serde(default)]
+    pub launch_via_steam: bool,
+    /// Steam app id used to build the `steam://rungameid/` URI when `launch_via_steam` is set.
+    #[serde(default)]
+    pub steam_app_id: Option<String>,
+}
+
+/// A named loadout of mods, e.g. "Career Save" versus "Screenshot Mode". Switching the
+/// active profile diffs `enabled_mods` against the outgoing profile so only the delta of
+/// mods is re-installed/removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub enabled_mods: Vec<String>,
+    #[serde(default)]
+    pub active_name_fix: Option<String>,
+    /// Overrides `Config::target_path` for this profile. `None` means "use the global target".
+    #[serde(default)]
+    pub target_path_override: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +287,70 @@ pub struct NameFixSource {
     pub install_type: NameFixInstallType,
     pub description: String,
     pub imported_date: String,
+    /// SHA-256 of every file this fix placed into the db dir, keyed by its path relative to
+    /// the db dir, captured at install time by [`crate::name_fix::install`]/`install_name_fix`.
+    /// `#[serde(default)]` so fixes installed before this existed still parse;
+    /// [`crate::name_fix::verify`] just has nothing to check for those.
+    #[serde(default)]
+    pub file_hashes: HashMap<String, String>,
+    /// Manifest of every file extracted into this fix's own storage directory (under
+    /// `name_fixes/<id>/`), captured at import time by [`crate::name_fix::import_name_fix`].
+    /// Unlike `file_hashes` (which tracks what ended up in the db dir after install), this
+    /// tracks the fix's own cached copy, so [`crate::name_fix::verify_name_fix`] can tell if it
+    /// has rotted on disk, and `install_name_fix` can skip a reinstall that would be a no-op.
+    /// `#[serde(default)]` so fixes imported before this existed still parse.
+    #[serde(default)]
+    pub files: Vec<NameFixManifestEntry>,
+    /// BLAKE2b-512 of the sorted `(rel_path, blake2b_hex)` pairs in `files`, identifying this
+    /// fix's whole extracted contents in one hash. [`crate::name_fix::import_name_fix`] checks
+    /// this against every existing fix's `metadata.json` before extracting, so re-importing the
+    /// same archive (even re-zipped, or with entries reordered) reuses the existing fix instead
+    /// of minting a duplicate `imported-<uuid>` directory. `#[serde(default)]` so fixes imported
+    /// before this existed still parse; they just never match as a dedup candidate.
+    #[serde(default)]
+    pub aggregate_hash: String,
+    /// How many of `files` had their line endings rewritten and/or a leading BOM stripped by
+    /// [`crate::name_fix::normalize_text_contents`] during import, surfaced so the import summary
+    /// can report it. `#[serde(default)]` so fixes imported before this existed still parse.
+    #[serde(default)]
+    pub files_normalized: usize,
+    /// Optional author-supplied routing table, checked by
+    /// [`crate::name_fix::install_files_type`] before its extension/filename heuristics: each
+    /// entry's glob is matched against a file in `fix_dir` and, if it matches, wins over the
+    /// built-in guess. Lets a fix author state exactly where a file belongs instead of the
+    /// loader reverse-engineering it from the filename. `#[serde(default)]` so fixes without one
+    /// fall back entirely to the existing heuristics.
+    #[serde(default)]
+    pub install_map: Vec<NameFixInstallMapEntry>,
+    /// `metadata.json` schema shape this fix was authored for, checked before install: too new
+    /// and install is refused with an "update FMMLoader" error, too old and it's migrated in
+    /// place first.
+    /// `#[serde(default)]` (reading as `0`, the implicit version before this field existed) so
+    /// fixes imported before this existed still parse as "needs migrating".
+    #[serde(default)]
+    pub schema_version: u32,
+    /// FM26 database schema folder (e.g. `"2600"`, see [`crate::name_fix::get_db_dir`]) that was
+    /// active when this fix was installed, so `uninstall`/`restore_backup` can tell if an FM
+    /// update has since moved the schema out from under it. `#[serde(default)]` so fixes
+    /// installed before this existed just read as unknown (`""`).
+    #[serde(default)]
+    pub game_build: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixManifestEntry {
+    pub rel_path: String,
+    pub size: u64,
+    pub blake2b_hex: String,
+}
+
+/// One entry of a [`NameFixSource::install_map`]: a glob `pattern` (matched case-insensitively,
+/// segment by segment, against a file's name) and the `dest` subpath under the db dir it should
+/// land at, e.g. `{ pattern: "*licensing*.dbc", dest: "dbc/language" }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixInstallMapEntry {
+    pub pattern: String,
+    pub dest: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -97,6 +365,141 @@ pub enum NameFixInstallType {
     Folders, // Sortitoutsi style: Replace entire dbc/edt/lnc folders + editor data
 }
 
+/// One file's outcome from [`crate::name_fix::verify`] comparing live bytes in the db dir
+/// against the digest recorded when the active name fix was installed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NameFixFileStatus {
+    Ok,
+    Modified,
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixVerifyEntry {
+    pub relative_path: String,
+    pub status: NameFixFileStatus,
+}
+
+/// Result of [`crate::name_fix::verify`]: every tracked file of the active name fix paired
+/// with whether it still matches what was installed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixVerifyReport {
+    pub name_fix_id: String,
+    pub entries: Vec<NameFixVerifyEntry>,
+}
+
+/// How an existing file in the db dir's licensing-relevant subtrees relates to the name fixes
+/// FMMLoader knows about, as classified by [`crate::name_fix::scan_db_conflicts`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DbFileOwnership {
+    /// Recorded in the currently active fix's `file_hashes`.
+    BelongsToActiveFix,
+    /// Recorded in a different imported fix's `file_hashes` — installing over it would clobber
+    /// that fix's files without uninstalling it first.
+    BelongsToOtherImportedFix { fix_id: String, fix_name: String },
+    /// One of FM's own shipped licensing files, listed in `FILES_TO_DELETE`.
+    StockLicensing,
+    /// Not recorded anywhere FMMLoader tracks — could be a manually dropped-in file, or a fix
+    /// installed before `file_hashes` existed. Deleting it silently would be a surprise.
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbConflictEntry {
+    pub relative_path: String,
+    pub ownership: DbFileOwnership,
+}
+
+/// Result of [`crate::name_fix::scan_db_conflicts`], run before an install so the caller can warn
+/// about (or refuse to silently clobber) files it doesn't recognize.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbConflictReport {
+    pub entries: Vec<DbConflictEntry>,
+}
+
+/// What [`crate::name_fix::install_name_fix`] should do with one conflicting destination path,
+/// chosen per-entry by the caller from a [`NameFixConflictReport`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum NameFixConflictAction {
+    /// Leave the file the owning fix placed there untouched, and don't install this path.
+    Skip,
+    /// Replace it with this fix's file, same as before conflict detection existed.
+    #[default]
+    Overwrite,
+    /// Refuse to install at all. Checked before anything is backed up or written.
+    Abort,
+}
+
+/// One destination path a fix about to be installed would land on that's already owned by a
+/// different installed fix, found by [`crate::name_fix::scan_install_conflicts`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixConflictEntry {
+    pub relative_path: String,
+    pub owning_fix_id: String,
+    pub owning_fix_name: String,
+    /// Whether the owning fix's recorded hash for this path differs from the bytes the fix being
+    /// installed would write — a conflict where both fixes agree on the bytes is lower-stakes.
+    pub bytes_differ: bool,
+}
+
+/// Result of [`crate::name_fix::scan_install_conflicts`]: every destination path `fix_id` would
+/// write that some other already-installed fix also claims, for the caller to resolve one by one
+/// via [`NameFixConflictAction`] before `install_name_fix` proceeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixConflictReport {
+    pub fix_id: String,
+    pub entries: Vec<NameFixConflictEntry>,
+}
+
+/// One archive entry's outcome from [`crate::name_fix::validate_archive`] reading it to a
+/// throwaway buffer. `Truncated` covers both an entry that couldn't even be opened and one that
+/// panicked mid-read (some archive backends panic on malformed data rather than erroring).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ArchiveEntryStatus {
+    Ok,
+    CrcMismatch,
+    DecompressError,
+    Truncated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveEntryReport {
+    pub file_name: String,
+    pub status: ArchiveEntryStatus,
+}
+
+/// Result of [`crate::name_fix::validate_archive`]: every entry in the archive paired with
+/// whether it read back cleanly, so a corrupt or truncated download can be rejected before
+/// `import_name_fix` extracts a half-broken fix.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveReport {
+    pub entries: Vec<ArchiveEntryReport>,
+}
+
+/// One file's outcome from [`crate::name_fix::verify_name_fix`] comparing a fix's storage
+/// directory against the manifest recorded when it was imported.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NameFixManifestStatus {
+    Ok,
+    Modified,
+    Removed,
+    Added,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixManifestCheckEntry {
+    pub rel_path: String,
+    pub status: NameFixManifestStatus,
+}
+
+/// Result of [`crate::name_fix::verify_name_fix`]: every file recorded in the fix's manifest,
+/// plus any unexpected extras found in its storage directory, paired with its current status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameFixManifestReport {
+    pub name_fix_id: String,
+    pub entries: Vec<NameFixManifestCheckEntry>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct ModInfo {
@@ -113,33 +516,356 @@ pub struct RestorePoint {
     pub name: String,
     pub timestamp: String,
     pub path: PathBuf,
+    /// Total bytes captured, read from the restore point's `manifest.json`. `None` for a
+    /// point captured before manifests recorded size (falls back to folder-name parsing).
+    #[serde(default)]
+    pub size_bytes: Option<u64>,
+    /// How long capture took, in milliseconds. `None` for the same pre-manifest reason.
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// One backup captured by [`crate::name_fix::create_backups`]/`create_folder_backups` before
+/// installing a name fix over whatever was there before, addressable by `id` for
+/// [`crate::name_fix::restore_backup`]/`delete_backup` instead of the old all-or-nothing
+/// single slot that got wiped on every install.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupInfo {
+    pub id: String,
+    pub fix_id: String,
+    pub install_type: NameFixInstallType,
+    pub timestamp: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConflictInfo {
     pub file_path: String,
     pub conflicting_mods: Vec<String>,
+    /// Blake3 hash of the source file each conflicting mod would install, so the caller can
+    /// tell `Identical` conflicts (safe to ignore) from `Divergent` ones without re-reading
+    /// the files itself.
+    pub content_hashes: Vec<ModContentHash>,
+    pub classification: ConflictClassification,
+}
+
+/// One mod's contribution to a [`ConflictInfo`]: the hash of the file it would install at
+/// the conflicting path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModContentHash {
+    pub mod_name: String,
+    pub hash: String,
+}
+
+/// Whether a [`ConflictInfo`]'s conflicting mods actually disagree on file content.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConflictClassification {
+    /// Every conflicting mod ships byte-identical content at this path; harmless overlap.
+    Identical,
+    /// The conflicting mods ship different content; installing all of them means only one
+    /// actually wins.
+    Divergent,
+}
+
+/// Resolution of a single `Divergent` [`ConflictInfo`] via last-enabled-wins: whichever
+/// conflicting mod sits latest in `enabled_mods` load order is the one whose file ends up on
+/// disk, same as a mod manager applying mods in list order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictResolution {
+    pub file_path: String,
+    pub winning_mod: String,
+    pub shadowed_mods: Vec<String>,
+}
+
+/// A single mod's contribution to a content-hash conflict/duplicate report: the file it
+/// installs to `target_path`, the blake3 hash of its bytes, and the byte size used to
+/// cheaply pre-group files before hashing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileHashEntry {
+    pub mod_name: String,
+    pub target_path: String,
+    pub hash: String,
+    pub size: u64,
+}
+
+/// Mods claiming the same `target_path` whose bytes differ (a real install-time
+/// conflict, as opposed to [`ConflictInfo`] which only looks at path overlap).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HashConflictGroup {
+    pub target_path: String,
+    pub entries: Vec<FileHashEntry>,
+}
+
+/// Byte-identical files shipped by more than one mod, grouped by `(size, hash)`.
+/// These don't conflict at install time but waste disk and are worth surfacing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    pub hash: String,
+    pub size: u64,
+    pub files: Vec<FileHashEntry>,
+}
+
+/// Report produced by hashing the extracted trees of the enabled mods.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentConflictReport {
+    pub hard_conflicts: Vec<HashConflictGroup>,
+    pub duplicates: Vec<DuplicateGroup>,
+}
+
+/// Outcome of restoring a single entry via [`crate::restore::restore_entries`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RestoreEntryStatus {
+    Restored,
+    /// Not included in the caller's selection, so left untouched.
+    Skipped,
+    Failed,
+}
+
+/// Per-entry outcome of [`crate::restore::restore_entries`], so a selective or dry-run
+/// restore can report exactly what happened to each item instead of a single count string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreEntryResult {
+    pub original_path: PathBuf,
+    /// Where the entry was actually written: `original_path` unless an override root was
+    /// given, in which case it's `original_path` remapped under that root.
+    pub restored_path: PathBuf,
+    pub status: RestoreEntryStatus,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Outcome of [`crate::import::verify_manifest`] re-hashing a manifest's files against disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestVerification {
+    pub mismatches: Vec<ManifestMismatch>,
+    /// `None` when no public key was supplied to check the manifest's detached signature.
+    #[serde(default)]
+    pub signature_valid: Option<bool>,
+}
+
+/// A single manifest file entry whose on-disk content is missing or no longer matches the
+/// `sha256` recorded at generation time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestMismatch {
+    pub source: String,
+    pub reason: String,
+}
+
+/// A single image recorded by [`crate::graphics_analyzer::generate_pack_manifest`]: its path
+/// relative to the pack root, byte size, and a CRC32 of its content. CRC32 (not blake3/sha256)
+/// is deliberate here — this manifest is for integrity/change detection on re-installs, not
+/// security, so a cheap checksum is the right tool.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PackManifestEntry {
+    pub relative_path: PathBuf,
+    pub size_bytes: u64,
+    pub crc32: u32,
+}
+
+/// Durable record of a graphics pack's contents at analysis time, so a later re-install or
+/// update can diff against it instead of rescanning and recopying everything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackManifest {
+    pub pack_type: crate::graphics_analyzer::GraphicsPackType,
+    /// type -> file count, mirroring [`crate::graphics_analyzer::GraphicsPackAnalysis::subdirectory_breakdown`].
+    pub type_counts: HashMap<String, usize>,
+    pub entries: Vec<PackManifestEntry>,
+}
+
+/// A manifest entry whose on-disk content no longer matches what [`PackManifest`] recorded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackManifestMismatch {
+    pub relative_path: PathBuf,
+    pub reason: String,
+}
+
+/// Outcome of [`crate::graphics_analyzer::verify_pack_manifest`] re-checksumming a pack
+/// against its recorded manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackManifestVerification {
+    pub mismatches: Vec<PackManifestMismatch>,
+}
+
+/// Result of [`crate::graphics_analyzer::diff_manifests`] comparing two generations of the
+/// same pack's manifest, so an installer can copy only what changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestDiff {
+    pub added: Vec<PackManifestEntry>,
+    pub removed: Vec<PackManifestEntry>,
+    /// Entries present in both manifests under the same `relative_path` but with a
+    /// different `size_bytes` or `crc32`.
+    pub changed: Vec<PackManifestEntry>,
+}
+
+/// What a known graphics pack release actually contains, as recorded in
+/// [`crate::graphics_analyzer::KNOWN_PACKS`]. Distinct from
+/// [`crate::graphics_analyzer::GraphicsPackType`] because a signature always names a single
+/// kind — a known release is never "Mixed" at the fingerprint level, even if installing it
+/// later touches more than one of the loader's content folders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DetectedPackKind {
+    Faces,
+    Logos,
+    Kits,
+    Badges,
+    Unknown,
+}
+
+/// Result of [`crate::graphics_analyzer::detect_known_pack`] matching a pack's fingerprint
+/// files against [`crate::graphics_analyzer::KNOWN_PACKS`], modeled on ScummVM's signature-table
+/// game detection: a handful of small, fast content checks identify which known release a
+/// folder is, rather than trusting its name or a claimed version string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectedPack {
+    pub id: String,
+    pub kind: DetectedPackKind,
+    pub version: Option<String>,
+    /// `1.0` for an exact fingerprint match, `0.0` for the "unknown" fallback.
+    pub confidence: f32,
+}
+
+/// Result of resolving a safe install order for the enabled mods.
+/// `diagnostics` are non-fatal warnings (unsatisfied dependencies, conflicts) for the UI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModLoadOrderResult {
+    pub order: Vec<String>,
+    pub diagnostics: Vec<String>,
 }
 
-/// Progress tracking for archive extraction operations.
-/// Emitted via Tauri events during async extraction of graphics packs.
+/// One filesystem entry [`crate::utils::copy_dir_recursive`] couldn't copy because it wasn't a
+/// regular file, directory, or followable symlink — a character/block device, FIFO, or unix
+/// socket. Recorded instead of aborting the whole copy, so a migration still completes around
+/// odd entries in user data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedCopyEntry {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// Outcome of a [`crate::utils::copy_dir_recursive`] run: how many regular files it copied, and
+/// which entries it skipped (and why) rather than failing the whole traversal over them.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CopyReport {
+    pub files_copied: usize,
+    pub skipped: Vec<SkippedCopyEntry>,
+    /// How many incoming images were recognized as a near-duplicate of an already-installed
+    /// image (via dHash, see [`crate::graphics_analyzer::compute_dhash`]) and skipped rather
+    /// than copied. `0` when the copy ran without dedup enabled. Also counted in `skipped` with
+    /// a human-readable reason, so this is purely a convenience total for the UI.
+    #[serde(default)]
+    pub duplicates_skipped: usize,
+    /// How many non-PNG images were decoded and re-encoded to PNG by the copy's normalize-images
+    /// mode. `0` when normalization wasn't enabled for this copy.
+    #[serde(default)]
+    pub images_normalized: usize,
+    /// How many images normalize-images mode rejected outright (0-byte/corrupt decodes, or
+    /// dimensions outside the pack type's sane bounds) rather than installing. Also counted in
+    /// `skipped` with a human-readable reason.
+    #[serde(default)]
+    pub images_rejected: usize,
+    /// How many destination files [`crate::utils::copy_dir_recursive_incremental`] left in place
+    /// because they already matched the source (by size/modified time, or content hash when
+    /// that was ambiguous). `0` for a non-incremental copy. Not counted in `skipped`, since
+    /// leaving an already-current file alone isn't a failure to copy it.
+    #[serde(default)]
+    pub unchanged_skipped: usize,
+}
+
+/// One image [`crate::graphics_analyzer::validate_pack_images`] flagged, with a human-readable
+/// reason (decode failure, out-of-range dimensions, missing alpha channel, extension/content
+/// mismatch, or a broken/orphaned `config.xml` mapping).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageValidationIssue {
+    pub path: String,
+    pub reason: String,
+}
+
+/// Result of a [`crate::graphics_analyzer::validate_pack_images`] run. `images_checked` may be
+/// less than `images_total` when the scan was sampled rather than exhaustive.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ImageValidationReport {
+    pub issues: Vec<ImageValidationIssue>,
+    pub images_checked: usize,
+    pub images_total: usize,
+}
+
+/// Staged progress for archive extraction/graphics-pack-install pipelines, emitted via Tauri
+/// events. A pipeline like graphics-pack import runs several cheap-to-expensive stages in
+/// sequence (extracting, analyzing, writing config, copying) rather than one flat counter, so
+/// `current_stage`/`max_stage`/`stage_name` tell the UI which numbered stage is running — e.g.
+/// "Step 3 of 4: copying" — while `entries_checked`/`entries_total` track progress within that
+/// stage. Mirrors [`DuplicateScanProgress`]'s staged shape.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractionProgress {
-    pub current: usize,
-    pub total: usize,
+    pub current_stage: usize,
+    pub max_stage: usize,
+    pub stage_name: String, // "extracting", "analyzing", "writing config", "copying", "dedup", "auditing", or "complete"
+    pub entries_checked: usize,
+    pub entries_total: usize,
     pub current_file: String,
     pub bytes_processed: u64,
-    pub phase: String, // "extracting" or "installing"
+    /// Populated on the single event emitted with `stage_name: "auditing"`, once the
+    /// post-extraction permission audit has finished sanitizing unsafe modes.
+    #[serde(default)]
+    pub audit_findings: Vec<PermissionAuditFinding>,
+    /// How many incoming images the dHash dedup pass (see
+    /// [`crate::graphics_analyzer::compute_dhash`]) recognized as near-duplicates of an
+    /// already-installed image and skipped. Only meaningful on the `"complete"` event of a
+    /// graphics-pack import; `0` everywhere dedup doesn't apply.
+    #[serde(default)]
+    pub duplicates_skipped: usize,
+    /// How many non-PNG images the normalize-images mode has decoded and re-encoded to PNG so
+    /// far. Only meaningful on graphics-pack import events; `0` everywhere normalization doesn't
+    /// apply.
+    #[serde(default)]
+    pub images_normalized: usize,
+    /// How many images the normalize-images mode has rejected so far (see
+    /// [`CopyReport::images_rejected`]'s equivalent). `0` everywhere normalization doesn't apply.
+    #[serde(default)]
+    pub images_rejected: usize,
+    /// How many files `split_mixed_pack` routed to each category (`"faces"`, `"logos"`,
+    /// `"kits"`), keyed by category name. Only populated on the `"complete"` event of a `Mixed`
+    /// pack import that was split; empty everywhere else.
+    #[serde(default)]
+    pub mixed_pack_routed: HashMap<String, usize>,
+}
+
+/// A single file flagged by the post-extraction permission audit. The archive requested
+/// `requested_mode`, but `sanitized_mode` — with setuid/setgid and other-write stripped — is
+/// what was actually applied on disk, so `requested_mode != sanitized_mode` means the archive
+/// was trying to ship something unsafe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionAuditFinding {
+    pub path: String,
+    pub requested_mode: u32,
+    pub sanitized_mode: u32,
+    pub reasons: Vec<String>,
+}
+
+/// Reported while [`crate::name_fix::download_name_fix`] streams the GitHub release zip, so the
+/// frontend can show a determinate progress bar instead of a spinner. `total_bytes` is `None`
+/// when the server didn't send a `Content-Length` header.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DownloadProgress {
+    pub bytes_downloaded: u64,
+    pub total_bytes: Option<u64>,
+}
+
+/// Reported while extracting a name fix archive. `files_total` comes from a precount pass over
+/// the `ZipArchive` before any file is written, so it reflects only the entries that will
+/// actually be extracted (not the archive's raw entry count).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExtractProgress {
+    pub files_done: usize,
+    pub files_total: usize,
 }
 
 /// Progress tracking for file installation operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[allow(dead_code)]
 pub struct InstallProgress {
     pub current: usize,
     pub total: usize,
     pub current_file: String,
-    pub operation: String, // "copying", "validating", etc.
+    pub operation: String, // "copying", "validating", "installing", "removing", etc.
 }
 
 /// Metadata for an installed graphics pack.
@@ -153,6 +879,34 @@ pub struct GraphicsPackMetadata {
     pub source_filename: String,
     pub pack_type: String, // "faces", "logos", "kits", "mixed"
     pub installed_to: String,
+    /// Strategy used to place the pack's files, so uninstall knows whether `installed_to`
+    /// holds real files, hardlinks, or a link back into the source pack.
+    #[serde(default)]
+    pub install_mode: InstallMode,
+    /// Whether `installed_to` is an NTFS junction (only possible when `install_mode` is
+    /// `Symlink` on Windows).
+    #[serde(default)]
+    pub is_junction: bool,
+    /// On-disk layout version this pack was installed with, against
+    /// [`crate::graphics::GRAPHICS_PACK_FORMAT_VERSION`]. `#[serde(default)]` so packs
+    /// installed before this existed read as `0` (legacy/unversioned) — a flat pack with an
+    /// unprefixed `config.xml` is exactly the layout `import_graphics_pack_with_type` already
+    /// migrates on import, now tagged so the UI can tell which packs went through it.
+    #[serde(default)]
+    pub format_version: u32,
+    /// 64-bit dHash ([`crate::graphics_analyzer::compute_dhash`]) of every image file this pack
+    /// installed, so a later import can skip near-duplicate portraits/logos already on disk
+    /// without re-decoding this pack's files. `#[serde(default)]` so packs installed before
+    /// this existed read as an empty list (no prior-install dedup data available for them).
+    #[serde(default)]
+    pub image_hashes: Vec<u64>,
+    /// Container format the pack's archive was sniffed as at import time (`"zip"`, `"tar.gz"`,
+    /// `"tar.xz"`, `"tar.zst"`, or `"tar"` — see
+    /// [`crate::import::ArchiveFormat`]), so a re-export can reconstruct the pack in the same
+    /// format it originally arrived in. `#[serde(default)]` so packs installed before this
+    /// existed read as an empty string (format unknown).
+    #[serde(default)]
+    pub source_format: String,
 }
 
 /// Registry of all installed graphics packs.
@@ -170,3 +924,191 @@ pub struct GraphicsConflictInfo {
     pub existing_file_count: usize,
     pub pack_name: String,
 }
+
+/// Result of [`crate::graphics::import_graphics_pack_with_type`]. `migrated` is set when the
+/// imported pack used a legacy/unversioned layout (a flat pack with unprefixed `config.xml`
+/// entries) and had to be upgraded to [`crate::graphics::GRAPHICS_PACK_FORMAT_VERSION`] before
+/// install, so the UI can prompt the user to re-run `validate_graphics` on it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphicsImportResult {
+    pub message: String,
+    pub migrated: bool,
+    /// Incoming images recognized as a near-duplicate of an already-installed image (via dHash)
+    /// and skipped rather than copied. See [`crate::graphics_analyzer::compute_dhash`].
+    #[serde(default)]
+    pub duplicates_skipped: usize,
+    /// Non-PNG images decoded and re-encoded to PNG by the optional normalize-images mode. `0`
+    /// when normalization wasn't requested for this import.
+    #[serde(default)]
+    pub images_normalized: usize,
+    /// Images the normalize-images mode rejected outright (0-byte/corrupt decodes, or
+    /// dimensions outside the pack type's sane bounds) rather than installing.
+    #[serde(default)]
+    pub images_rejected: usize,
+    /// How many files `split_mixed_pack` routed to each category (`"faces"`, `"logos"`,
+    /// `"kits"`), keyed by category name. Empty unless this import was a split `Mixed` pack.
+    #[serde(default)]
+    pub mixed_pack_routed: HashMap<String, usize>,
+}
+
+/// One group of byte-identical files found under the `graphics` directory by
+/// [`crate::graphics::find_duplicate_graphics`], keyed by their shared content hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGraphicsGroup {
+    pub hash: String,
+    pub size_bytes: u64,
+    pub paths: Vec<String>,
+}
+
+/// Result of [`crate::graphics::find_duplicate_graphics`]: every group of duplicates found,
+/// plus the totals the UI needs to show potential savings before the user commits to
+/// [`crate::graphics::deduplicate_graphics`]. `bytes_reclaimable` counts every member past the
+/// first in each group, since one copy per group has to stay on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGraphicsReport {
+    pub groups: Vec<DuplicateGraphicsGroup>,
+    pub duplicate_count: usize,
+    pub bytes_reclaimable: u64,
+}
+
+/// Result of [`crate::graphics::deduplicate_graphics`] actually replacing duplicates with hard
+/// links. `skipped_cross_device` counts groups whose canonical file and duplicates didn't share
+/// a filesystem, where hard-linking isn't possible and the duplicate was left untouched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphicsDedupeResult {
+    pub files_linked: usize,
+    pub bytes_reclaimed: u64,
+    pub skipped_cross_device: usize,
+}
+
+/// Staged progress for [`crate::graphics::find_duplicate_graphics`]/
+/// [`crate::graphics::deduplicate_graphics`], emitted over the `duplicate-scan-progress` event —
+/// this scan runs in three cheap-to-expensive stages (group by size, partial hash, full hash),
+/// so `current_stage`/`max_stage`/`stage_name` tell the UI which pass is running while
+/// `entries_checked`/`entries_total` track progress within it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateScanProgress {
+    pub current_stage: usize,
+    pub max_stage: usize,
+    pub stage_name: String,
+    pub entries_checked: usize,
+    pub entries_total: usize,
+}
+
+/// One destination [`crate::mod_manager::install_mod`] wrote to, recorded so
+/// [`crate::mod_manager::uninstall_mod`] can undo exactly that write instead of deleting
+/// whatever now lives at `target_subpath` — which may belong to another mod sharing the same
+/// folder (e.g. `graphics/`). Mirrors FlightCore's `enabledmods.json` bookkeeping.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallReceiptEntry {
+    pub target_subpath: String,
+    pub resolved_path: PathBuf,
+    /// Whether `resolved_path` already existed before this install (and was backed up) or was
+    /// created fresh by it.
+    pub overwrote_existing: bool,
+    /// Set when `overwrote_existing` is true: the path `backup_file` copied the prior contents
+    /// of `resolved_path` to, so uninstall can restore it instead of just deleting.
+    #[serde(default)]
+    pub backup_path: Option<PathBuf>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_name_fix_backup_retention() -> usize {
+    10
+}
+
+/// Per-install journal written by [`crate::mod_manager::install_mod`] to `receipts/<mod_name>.json`
+/// and consumed by [`crate::mod_manager::uninstall_mod`]. Replaces walking the mod's manifest
+/// at uninstall time, which can't tell a file the mod created from one it merely overwrote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallReceipt {
+    pub mod_name: String,
+    /// Directory the mod's files were resolved under (e.g. the user's `graphics/` folder).
+    /// Empty-directory pruning after uninstall never climbs above this, so shared game
+    /// folders other mods populate are never at risk of being removed.
+    pub target_base: PathBuf,
+    pub entries: Vec<InstallReceiptEntry>,
+    /// Whether this mod's files currently live at their resolved paths (`true`) or have been
+    /// moved into the staging area by [`crate::mod_manager::set_mod_enabled`] (`false`).
+    /// Defaults to `true` for receipts written before this existed — every install was "enabled"
+    /// by definition.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+/// One entry of [`crate::mod_manager::list_mods`], pairing a mod's directory name with whether
+/// its installed files (if any) currently sit in the live target directories or in staging.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModListEntry {
+    pub name: String,
+    pub enabled: bool,
+}
+
+/// One [`InstallReceiptEntry`]'s outcome under [`crate::verify::verify_installed_mods`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ModFileVerifyStatus {
+    /// Still present at `resolved_path`. Whether another mod now owns it (per
+    /// `ownership_index.json`) is reported separately in [`ModFileVerifyEntry::overwritten_by`].
+    Ok,
+    /// No longer present at `resolved_path`.
+    Missing,
+}
+
+/// Per-file detail backing one [`ModVerifyReport`] entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModFileVerifyEntry {
+    pub target_subpath: String,
+    pub resolved_path: PathBuf,
+    pub status: ModFileVerifyStatus,
+    /// Set when `resolved_path` exists but the ownership index now attributes it to a different
+    /// mod than the one being verified — i.e. another install has since overwritten it.
+    #[serde(default)]
+    pub overwritten_by: Option<String>,
+}
+
+/// [`crate::verify::verify_installed_mods`]'s report for one enabled mod.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModVerifyReport {
+    pub mod_name: String,
+    /// `None` when the mod has no install receipt at all — enabled in config but never actually
+    /// installed (or installed before receipts existed).
+    pub entries: Option<Vec<ModFileVerifyEntry>>,
+}
+
+impl ModVerifyReport {
+    /// Whether every tracked file is present and still owned by this mod.
+    pub fn is_healthy(&self) -> bool {
+        matches!(&self.entries, Some(entries) if entries.iter().all(|e| e.status == ModFileVerifyStatus::Ok && e.overwritten_by.is_none()))
+    }
+}
+
+/// One marker [`crate::verify::verify_game_target`] checked for under the configured game target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTargetMarker {
+    pub description: String,
+    pub path: String,
+    pub present: bool,
+}
+
+/// [`crate::verify::verify_game_target`]'s report: whether the configured `target_path` actually
+/// looks like an FM26 install rather than just an existing directory, which is all
+/// [`crate::mod_manager::install_mod`] itself checks before writing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTargetVerifyReport {
+    pub target_path: String,
+    pub markers: Vec<GameTargetMarker>,
+    pub valid: bool,
+}
+
+/// One file found by [`crate::logging::list_game_logs`] in Football Manager's own log/crash-dump
+/// folder, as opposed to FMMLoader's own logs which [`crate::logging::get_logs_dir`] covers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameLogEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub size_bytes: u64,
+    pub modified: String,
+}
@@ -0,0 +1,151 @@
+//! Update detection for installed mods and graphics packs.
+//!
+//! Neither a `ModManifest` nor a `GraphicsPackMetadata` record knows whether a newer version
+//! exists upstream or whether its files are still actually there, so this module computes
+//! that state on demand: an [`UpdateState`] per item, compared against the stored `version`
+//! (mods) or recorded `file_count` (graphics packs), and batched up for the UI to badge.
+
+use crate::config::{get_mods_dir, load_config, load_graphics_packs};
+use crate::mod_manager::read_manifest;
+use crate::repo_client::{self, SearchFacets};
+use crate::utils;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::{Emitter, Manager};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum UpdateState {
+    UpToDate,
+    UpdateAvailable { latest: String },
+    NotInstalled,
+    Broken,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModUpdateStatus {
+    pub name: String,
+    pub installed_version: String,
+    pub state: UpdateState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphicsPackUpdateStatus {
+    pub id: String,
+    pub name: String,
+    pub state: UpdateState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCheckBatch {
+    pub mods: Vec<ModUpdateStatus>,
+    pub graphics_packs: Vec<GraphicsPackUpdateStatus>,
+}
+
+/// Looks up the newest version the repository index has for `mod_name`, if any. Best-effort:
+/// a search failure or an unparsable version just means "no update info available", not an
+/// error for the whole batch.
+fn latest_remote_version(index_url: &str, mod_name: &str) -> Option<Version> {
+    let facets = SearchFacets::default();
+    let results = repo_client::search_mods(index_url, mod_name, &facets, 0).ok()?;
+
+    results
+        .hits
+        .into_iter()
+        .filter(|hit| hit.manifest.name == mod_name)
+        .filter_map(|hit| Version::parse(&hit.manifest.version).ok())
+        .max()
+}
+
+fn mod_update_state(name: &str, installed_version: &str, index_url: Option<&str>) -> UpdateState {
+    let mod_dir = get_mods_dir().join(name);
+
+    if !mod_dir.exists() {
+        return UpdateState::NotInstalled;
+    }
+    if read_manifest(&mod_dir).is_err() {
+        return UpdateState::Broken;
+    }
+
+    let Some(index_url) = index_url else {
+        return UpdateState::UpToDate;
+    };
+    let Ok(installed) = Version::parse(installed_version) else {
+        return UpdateState::UpToDate;
+    };
+
+    match latest_remote_version(index_url, name) {
+        Some(latest) if latest > installed => UpdateState::UpdateAvailable {
+            latest: latest.to_string(),
+        },
+        _ => UpdateState::UpToDate,
+    }
+}
+
+/// Graphics packs don't carry a version (they're installed straight from a zip, not a
+/// versioned manifest), so the only states reachable here are `NotInstalled`/`Broken`/
+/// `UpToDate` — a mismatch between the recorded `file_count` and what's actually on disk
+/// means tampering or a partial uninstall.
+fn graphics_pack_state(installed_to: &str, expected_file_count: usize) -> UpdateState {
+    let path = PathBuf::from(installed_to);
+
+    if !path.exists() {
+        return UpdateState::NotInstalled;
+    }
+
+    match utils::count_files_in_dir(&path) {
+        Ok(actual) if actual == expected_file_count => UpdateState::UpToDate,
+        _ => UpdateState::Broken,
+    }
+}
+
+/// Computes update state for every enabled mod and registered graphics pack, then emits the
+/// result as a single `update-check-results` batch so the UI can render badges without one
+/// event per item. `index_url` is optional — when unset, mods are only checked for
+/// `Broken`/`NotInstalled`, never `UpdateAvailable`, since there's no repository to compare
+/// against.
+#[tauri::command]
+pub async fn refresh_update_status(
+    app: tauri::AppHandle,
+    index_url: Option<String>,
+) -> Result<UpdateCheckBatch, String> {
+    let config = load_config()?;
+    let registry = load_graphics_packs()?;
+
+    let mods = config
+        .enabled_mods
+        .iter()
+        .map(|name| {
+            let installed_version = read_manifest(&get_mods_dir().join(name))
+                .map(|m| m.version)
+                .unwrap_or_default();
+
+            ModUpdateStatus {
+                name: name.clone(),
+                state: mod_update_state(name, &installed_version, index_url.as_deref()),
+                installed_version,
+            }
+        })
+        .collect();
+
+    let graphics_packs = registry
+        .graphics_packs
+        .iter()
+        .map(|pack| GraphicsPackUpdateStatus {
+            id: pack.id.clone(),
+            name: pack.name.clone(),
+            state: graphics_pack_state(&pack.installed_to, pack.file_count),
+        })
+        .collect();
+
+    let batch = UpdateCheckBatch {
+        mods,
+        graphics_packs,
+    };
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.emit("update-check-results", &batch);
+    }
+
+    Ok(batch)
+}
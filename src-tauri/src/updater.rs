@@ -1,20 +1,179 @@
+use crate::restore::{create_restore_point, rollback_to_restore_point};
+use ed25519_dalek::{Signature, VerifyingKey};
+use semver::Version;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const GITHUB_REPO: &str = "justinlevinedotme/FMMLoader-26";
 
+/// Public half of the Ed25519 keypair CI signs each release asset with, as 64 lowercase hex
+/// chars. Injected at build time via the `FMMLOADER_UPDATE_SIGNING_PUBLIC_KEY` environment
+/// variable (set by the release signing pipeline, which is the only place the matching private
+/// key lives) rather than hardcoded — a build made without that variable set has no key at all
+/// and [`embedded_signing_key`] refuses outright instead of silently verifying against a
+/// placeholder nobody controls the private half of.
+const UPDATE_SIGNING_PUBLIC_KEY_HEX: Option<&str> =
+    option_env!("FMMLOADER_UPDATE_SIGNING_PUBLIC_KEY");
+
+/// Resolves [`UPDATE_SIGNING_PUBLIC_KEY_HEX`] into a usable key, or a clear error if this build
+/// wasn't compiled with one embedded.
+fn embedded_signing_key() -> Result<VerifyingKey, String> {
+    let hex_key = UPDATE_SIGNING_PUBLIC_KEY_HEX.ok_or_else(|| {
+        "This build has no update-signing public key embedded; updates cannot be verified \
+         and will be refused"
+            .to_string()
+    })?;
+
+    let bytes = decode_hex_32(hex_key)?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| format!("Invalid embedded signing key: {}", e))
+}
+
+/// Decodes a 64-char hex string into 32 raw bytes. Hand-rolled rather than pulling in a hex
+/// crate for one call site.
+fn decode_hex_32(hex_str: &str) -> Result<[u8; 32], String> {
+    if hex_str.len() != 64 {
+        return Err(format!(
+            "Embedded signing key must be 64 hex characters, got {}",
+            hex_str.len()
+        ));
+    }
+
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex_str[i * 2..i * 2 + 2], 16)
+            .map_err(|_| "Embedded signing key is not valid hex".to_string())?;
+    }
+    Ok(bytes)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateInfo {
     pub has_update: bool,
     pub current_version: String,
     pub latest_version: String,
     pub download_url: String,
+    /// Direct download URL of the release asset for this platform, e.g.
+    /// `fmmloader26-windows.exe`. `None` if the release has no asset for this platform.
+    #[serde(default)]
+    pub asset_download_url: Option<String>,
+    /// Direct download URL of that asset's detached Ed25519 signature (`<asset>.sig`).
+    #[serde(default)]
+    pub signature_download_url: Option<String>,
+}
+
+/// Result of [`download_and_apply_update`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateApplyResult {
+    pub applied: bool,
+    /// Path to the restore point captured before swapping the executable, so a user (or a
+    /// future run) can roll back via the existing restore-point machinery.
+    pub restore_point: Option<String>,
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
 }
 
 #[derive(Deserialize)]
 struct GitHubRelease {
     tag_name: String,
     html_url: String,
+    #[serde(default)]
+    assets: Vec<GitHubAsset>,
+}
+
+/// Asset filename this platform expects a release to publish, e.g. `fmmloader26-windows.exe`.
+fn platform_asset_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "fmmloader26-windows.exe"
+    } else if cfg!(target_os = "macos") {
+        "fmmloader26-macos"
+    } else {
+        "fmmloader26-linux"
+    }
+}
+
+/// One entry of a release's `waves.json` asset: installs whose seed is at or below
+/// `seed_threshold` become eligible for the update once `start_time` has passed. Entries are
+/// expected in ascending `seed_threshold` order, expanding the rollout over time.
+#[derive(Debug, Clone, Deserialize)]
+struct WaveEntry {
+    seed_threshold: u32,
+    /// RFC 3339 timestamp.
+    start_time: String,
+}
+
+/// Downloads and parses `release`'s `waves.json` asset, if it published one. Any failure
+/// (missing asset, network error, bad JSON) is treated as "no staged rollout" rather than an
+/// error, so a broken `waves.json` can't block update checks entirely.
+fn fetch_release_waves(client: &reqwest::blocking::Client, release: &GitHubRelease) -> Option<Vec<WaveEntry>> {
+    let waves_url = release
+        .assets
+        .iter()
+        .find(|a| a.name == "waves.json")
+        .map(|a| a.browser_download_url.clone())?;
+
+    let body = client
+        .get(&waves_url)
+        .header("User-Agent", "FMMLoader26")
+        .timeout(std::time::Duration::from_secs(5))
+        .send()
+        .ok()?
+        .text()
+        .ok()?;
+
+    serde_json::from_str(&body).ok()
+}
+
+/// Returns `true` if `seed`'s wave hasn't started yet, and the newer release should be
+/// reported as `has_update = false` despite actually being newer. The relevant wave is the
+/// one with the smallest `seed_threshold` that's still `>= seed`; an install not covered by
+/// any published wave is treated as not yet rolled out.
+fn wave_gates_update(waves: &[WaveEntry], seed: u32) -> bool {
+    let relevant = waves
+        .iter()
+        .filter(|w| w.seed_threshold >= seed)
+        .min_by_key(|w| w.seed_threshold);
+
+    let Some(wave) = relevant else {
+        return true;
+    };
+
+    match chrono::DateTime::parse_from_rfc3339(&wave.start_time) {
+        Ok(start) => chrono::Utc::now() < start,
+        Err(_) => true,
+    }
+}
+
+/// This install's stable per-install seed in 0–1023, used to deterministically place it in
+/// the same update wave across restarts. Derived from a random install id generated on first
+/// use and persisted alongside the app's other local state.
+fn install_wave_seed() -> u32 {
+    let seed_path = crate::config::get_app_data_dir().join("update_wave_seed.txt");
+
+    if let Ok(existing) = std::fs::read_to_string(&seed_path) {
+        if let Ok(seed) = existing.trim().parse::<u32>() {
+            return seed;
+        }
+    }
+
+    let install_id = uuid::Uuid::new_v4().to_string();
+    let seed = seed_from_install_id(&install_id);
+
+    let _ = std::fs::create_dir_all(crate::config::get_app_data_dir());
+    let _ = std::fs::write(&seed_path, seed.to_string());
+
+    seed
+}
+
+fn seed_from_install_id(install_id: &str) -> u32 {
+    let hash = blake3::hash(install_id.as_bytes());
+    let bytes = hash.as_bytes();
+    u32::from(u16::from_be_bytes([bytes[0], bytes[1]])) % 1024
 }
 
 pub fn check_for_updates() -> Result<UpdateInfo, String> {
@@ -35,6 +194,8 @@ pub fn check_for_updates() -> Result<UpdateInfo, String> {
             current_version: CURRENT_VERSION.to_string(),
             latest_version: CURRENT_VERSION.to_string(),
             download_url: String::new(),
+            asset_download_url: None,
+            signature_download_url: None,
         });
     }
 
@@ -43,37 +204,156 @@ pub fn check_for_updates() -> Result<UpdateInfo, String> {
         .map_err(|e| format!("Failed to parse release data: {}", e))?;
 
     let latest_version = release.tag_name.trim_start_matches('v');
-    let has_update = compare_versions(CURRENT_VERSION, latest_version);
+    let mut has_update = compare_versions(CURRENT_VERSION, latest_version);
+
+    if has_update {
+        if let Some(waves) = fetch_release_waves(&client, &release) {
+            if wave_gates_update(&waves, install_wave_seed()) {
+                has_update = false;
+            }
+        }
+    }
+
+    let asset_name = platform_asset_name();
+    let asset_download_url = release
+        .assets
+        .iter()
+        .find(|a| a.name == asset_name)
+        .map(|a| a.browser_download_url.clone());
+    let signature_download_url = release
+        .assets
+        .iter()
+        .find(|a| a.name == format!("{}.sig", asset_name))
+        .map(|a| a.browser_download_url.clone());
 
     Ok(UpdateInfo {
         has_update,
         current_version: CURRENT_VERSION.to_string(),
         latest_version: latest_version.to_string(),
         download_url: release.html_url,
+        asset_download_url,
+        signature_download_url,
     })
 }
 
-fn compare_versions(current: &str, latest: &str) -> bool {
-    let current_parts: Vec<&str> = current.split('.').collect();
-    let latest_parts: Vec<&str> = latest.split('.').collect();
+/// Downloads the release asset and its detached signature, verifies the signature against
+/// [`embedded_signing_key`], and only then swaps it in for the running executable.
+/// Verification is mandatory: an asset with a missing or invalid signature is refused before
+/// anything on disk is touched. A restore point covering the current executable and config is
+/// captured right before the swap, so a failed or unwanted update can be undone with
+/// [`crate::restore::rollback_to_restore_point`].
+pub fn download_and_apply_update(info: &UpdateInfo) -> Result<UpdateApplyResult, String> {
+    let (Some(asset_url), Some(sig_url)) =
+        (&info.asset_download_url, &info.signature_download_url)
+    else {
+        return Err("Release has no downloadable asset for this platform".to_string());
+    };
 
-    let max_len = current_parts.len().max(latest_parts.len());
+    let client = reqwest::blocking::Client::new();
 
-    for i in 0..max_len {
-        let current_part = current_parts.get(i).unwrap_or(&"0");
-        let latest_part = latest_parts.get(i).unwrap_or(&"0");
+    let asset_bytes = client
+        .get(asset_url)
+        .header("User-Agent", "FMMLoader26")
+        .timeout(std::time::Duration::from_secs(120))
+        .send()
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download update: {}", e))?
+        .bytes()
+        .map_err(|e| format!("Failed to read downloaded update: {}", e))?;
+
+    let sig_bytes = client
+        .get(sig_url)
+        .header("User-Agent", "FMMLoader26")
+        .timeout(std::time::Duration::from_secs(30))
+        .send()
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download update signature: {}", e))?
+        .bytes()
+        .map_err(|e| format!("Failed to read update signature: {}", e))?;
 
-        let current_num = current_part.parse::<u32>().unwrap_or(0);
-        let latest_num = latest_part.parse::<u32>().unwrap_or(0);
+    verify_update_signature(&asset_bytes, &sig_bytes)?;
 
-        if latest_num > current_num {
-            return true;
-        } else if latest_num < current_num {
-            return false;
-        }
+    let current_exe =
+        std::env::current_exe().map_err(|e| format!("Failed to locate running executable: {}", e))?;
+    let config_path = crate::config::get_config_path();
+    let config = crate::config::load_config()?;
+
+    let restore_point = create_restore_point(
+        "pre-update",
+        &[current_exe.clone(), config_path],
+        config.backup_compression,
+        config.backup_compression_level,
+    )?;
+
+    let staged_path = current_exe.with_extension("new");
+    std::fs::write(&staged_path, &asset_bytes)
+        .map_err(|e| format!("Failed to stage downloaded update: {}", e))?;
+
+    if let Err(e) = apply_staged_executable(&staged_path, &current_exe) {
+        let _ = rollback_to_restore_point(&restore_point);
+        return Err(format!(
+            "Failed to apply update, rolled back to restore point: {}",
+            e
+        ));
+    }
+
+    Ok(UpdateApplyResult {
+        applied: true,
+        restore_point: Some(restore_point.to_string_lossy().to_string()),
+        message: format!("Updated to {}", info.latest_version),
+    })
+}
+
+/// Verifies `asset_bytes` against `sig_bytes` as a detached Ed25519 signature over the
+/// embedded signing key. Any malformed input is treated as a failed verification, not an
+/// error, so the caller always gets a clean "refused" outcome rather than a panic.
+fn verify_update_signature(asset_bytes: &[u8], sig_bytes: &[u8]) -> Result<(), String> {
+    let verifying_key = embedded_signing_key()?;
+    let signature = Signature::from_slice(sig_bytes)
+        .map_err(|_| "Update signature is malformed".to_string())?;
+
+    verifying_key
+        .verify_strict(asset_bytes, &signature)
+        .map_err(|_| "Update signature verification failed".to_string())
+}
+
+/// Swaps the staged executable in for `current_exe`. The previous executable is kept
+/// alongside as `<exe>.bak` rather than deleted, in case the restore point needs a known-good
+/// sibling to compare against.
+fn apply_staged_executable(staged_path: &PathBuf, current_exe: &PathBuf) -> Result<(), String> {
+    let backup_path = current_exe.with_extension("bak");
+    if backup_path.exists() {
+        std::fs::remove_file(&backup_path)
+            .map_err(|e| format!("Failed to remove stale backup: {}", e))?;
+    }
+
+    std::fs::rename(current_exe, &backup_path)
+        .map_err(|e| format!("Failed to back up current executable: {}", e))?;
+
+    if let Err(e) = std::fs::rename(staged_path, current_exe) {
+        // Best-effort restore of the original executable so the app isn't left unable to start.
+        let _ = std::fs::rename(&backup_path, current_exe);
+        return Err(format!("Failed to install staged executable: {}", e));
     }
 
-    false
+    Ok(())
+}
+
+/// Uses full SemVer precedence (via the `semver` crate, same as `update_checker`) rather than
+/// comparing `major.minor.patch` numerically and ignoring everything else: a pre-release
+/// (`-rc1`, `-beta`) ranks below the same `major.minor.patch` without one, and build metadata
+/// (`+build1`) is ignored for ordering, per the spec. An unparsable version on either side is
+/// treated as "no update" rather than erroring the whole check.
+fn compare_versions(current: &str, latest: &str) -> bool {
+    let (Ok(mut current), Ok(mut latest)) = (Version::parse(current), Version::parse(latest)) else {
+        return false;
+    };
+
+    // `semver::Version`'s `Ord` also compares build metadata, so drop it before comparing.
+    current.build = semver::BuildMetadata::EMPTY;
+    latest.build = semver::BuildMetadata::EMPTY;
+
+    latest > current
 }
 
 #[cfg(test)]
@@ -89,4 +369,98 @@ mod tests {
         assert!(!compare_versions("1.0.1", "1.0.0"));
         assert!(!compare_versions("2.0.0", "1.9.9"));
     }
+
+    #[test]
+    fn test_compare_versions_prerelease_ranks_below_release() {
+        assert!(compare_versions("1.0.0-rc1", "1.0.0"));
+        assert!(!compare_versions("1.0.0", "1.0.0-rc1"));
+        assert!(!compare_versions("1.2.0-rc2", "1.2.0-rc2"));
+    }
+
+    #[test]
+    fn test_compare_versions_prerelease_not_treated_as_update_over_stable() {
+        // A stable 1.2.0 should not look like an "update" over a later 1.2.0-rc2 pre-release.
+        assert!(!compare_versions("1.2.0", "1.2.0-rc2"));
+        assert!(compare_versions("1.2.0-rc2", "1.2.0"));
+    }
+
+    #[test]
+    fn test_compare_versions_ignores_build_metadata() {
+        assert!(!compare_versions("1.0.0+build1", "1.0.0+build2"));
+    }
+
+    #[test]
+    fn test_compare_versions_unparsable_is_no_update() {
+        assert!(!compare_versions("not-a-version", "1.0.0"));
+        assert!(!compare_versions("1.0.0", "also-not-a-version"));
+    }
+
+    #[test]
+    fn test_seed_from_install_id_is_stable_and_in_range() {
+        let seed = seed_from_install_id("11111111-1111-1111-1111-111111111111");
+        assert_eq!(seed, seed_from_install_id("11111111-1111-1111-1111-111111111111"));
+        assert!(seed < 1024);
+    }
+
+    #[test]
+    fn test_wave_gates_update_before_start_time() {
+        let waves = vec![WaveEntry {
+            seed_threshold: 1023,
+            start_time: "2999-01-01T00:00:00Z".to_string(),
+        }];
+        assert!(wave_gates_update(&waves, 500));
+    }
+
+    #[test]
+    fn test_wave_gates_update_after_start_time() {
+        let waves = vec![WaveEntry {
+            seed_threshold: 1023,
+            start_time: "2000-01-01T00:00:00Z".to_string(),
+        }];
+        assert!(!wave_gates_update(&waves, 500));
+    }
+
+    #[test]
+    fn test_wave_gates_update_picks_earliest_covering_wave() {
+        let waves = vec![
+            WaveEntry {
+                seed_threshold: 1023,
+                start_time: "2999-01-01T00:00:00Z".to_string(),
+            },
+            WaveEntry {
+                seed_threshold: 500,
+                start_time: "2000-01-01T00:00:00Z".to_string(),
+            },
+        ];
+        // seed 300 is covered by both waves; the 500-threshold wave is the earliest one that
+        // covers it and has already started, so the update should not be gated.
+        assert!(!wave_gates_update(&waves, 300));
+    }
+
+    #[test]
+    fn test_wave_gates_update_seed_not_covered_by_any_wave() {
+        let waves = vec![WaveEntry {
+            seed_threshold: 100,
+            start_time: "2000-01-01T00:00:00Z".to_string(),
+        }];
+        assert!(wave_gates_update(&waves, 500));
+    }
+
+    #[test]
+    fn test_verify_update_signature_rejects_malformed_signature() {
+        let result = verify_update_signature(b"some asset bytes", b"not a signature");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify_update_signature_rejects_signature_from_wrong_key() {
+        use ed25519_dalek::SigningKey;
+
+        // A well-formed signature, just not produced by the embedded release signing key.
+        let unrelated_key = SigningKey::from_bytes(&[7u8; 32]);
+        let signature = ed25519_dalek::Signer::sign(&unrelated_key, b"some asset bytes");
+
+        let result = verify_update_signature(b"some asset bytes", &signature.to_bytes());
+        assert!(result.is_err());
+    }
 }
@@ -1,39 +1,496 @@
 //! Shared utility functions for file operations and directory management.
 
+use crate::graphics_analyzer;
+use crate::types::{CopyReport, SkippedCopyEntry};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
 use std::fs;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
+/// How many symlinks [`copy_dir_recursive`] will follow along a single traversal path before
+/// giving up. A real cycle is caught earlier (the resolved target reappears in
+/// `visited_symlinks`), but this also bounds long-but-non-cyclic chains so a pathological pack
+/// can't still tie up a migration indefinitely.
+const MAX_SYMLINK_JUMPS: usize = 20;
+
+/// Typed failure reason from [`copy_dir_recursive`], so callers like
+/// [`crate::graphics::migrate_graphics_pack`] can explain *why* a copy was aborted instead of
+/// just showing a formatted string. Mirrors [`crate::import::ExtractionError`]'s shape. Every
+/// other helper in this module still returns `Result<_, String>` — `Other` is the catch-all
+/// those convert into via `From<String>`.
+#[derive(Debug, thiserror::Error)]
+pub enum CopyError {
+    #[error("Symlink at {0} forms a cycle (followed {1} link(s) without reaching new ground)")]
+    SymlinkLoop(PathBuf, usize),
+    #[error("Symlink at {0} points to a target that does not exist")]
+    BrokenSymlink(PathBuf),
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<String> for CopyError {
+    fn from(message: String) -> Self {
+        CopyError::Other(message)
+    }
+}
+
+impl CopyError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            CopyError::SymlinkLoop(..) => "ERR_SYMLINK_LOOP",
+            CopyError::BrokenSymlink(_) => "ERR_BROKEN_SYMLINK",
+            CopyError::Other(_) => "ERR_OTHER",
+        }
+    }
+}
+
+impl serde::Serialize for CopyError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CopyError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+/// Include/exclude rules narrowing a [`copy_dir_recursive_filtered`]/[`count_files_in_dir_filtered`]
+/// walk, mirroring [`crate::graphics_analyzer::ScanFilters`] but for path- and filesystem-boundary
+/// exclusion rather than content-type inclusion.
+#[derive(Debug, Clone, Default)]
+pub struct CopyFilter {
+    /// Directories to prune entirely, matched by prefix against each candidate entry's path
+    /// (so `src.join("node_modules")` excludes that subtree and everything under it).
+    pub excluded_directories: Vec<PathBuf>,
+    /// Glob patterns (`*`, `**`, `?` — see [`crate::graphics_analyzer::glob_match`]) matched
+    /// against each entry's path relative to the copy root. A pattern using glob syntax this
+    /// engine doesn't support (`[...]`, `{...}`, `!`) is warned about via `tracing::warn!` and
+    /// never excludes anything, rather than silently copying through what looks like an
+    /// enforced rule.
+    pub excluded_globs: Vec<String>,
+    /// When set, entries living on a different filesystem than the copy root are skipped
+    /// instead of copied, so a mounted network share or bind mount nested inside the source
+    /// tree doesn't get silently pulled in.
+    pub exclude_other_filesystems: bool,
+}
+
+/// Filesystem identity used by [`CopyFilter::exclude_other_filesystems`] to tell whether an
+/// entry still lives on the same volume as the copy root. The real device id on Unix; on
+/// Windows (which has no `dev()` equivalent in `std`) an approximation from the path's
+/// drive/UNC prefix.
+#[cfg(unix)]
+type FsId = u64;
+#[cfg(not(unix))]
+type FsId = String;
+
+#[cfg(unix)]
+fn filesystem_id(path: &Path) -> Option<FsId> {
+    use std::os::unix::fs::MetadataExt;
+    fs::metadata(path).ok().map(|m| m.dev())
+}
+
+#[cfg(not(unix))]
+fn filesystem_id(path: &Path) -> Option<FsId> {
+    let canonical = fs::canonicalize(path).ok()?;
+    match canonical.components().next() {
+        Some(std::path::Component::Prefix(prefix)) => {
+            Some(prefix.as_os_str().to_string_lossy().to_lowercase())
+        }
+        _ => None,
+    }
+}
+
+/// Glob syntax [`crate::graphics_analyzer::glob_match`] doesn't understand — character classes,
+/// brace expansion, and negation all parse as literal characters there instead of doing what a
+/// shell glob would, so a pattern using them is reported rather than silently mismatched.
+const UNSUPPORTED_GLOB_CHARS: [char; 5] = ['[', ']', '{', '}', '!'];
+
+/// Whether `path` (or its relative form `relative_path`, whichever rule needs it) should be
+/// pruned from the walk under `filter`.
+fn is_excluded(path: &Path, relative_path: &Path, filter: &CopyFilter, root_fs_id: Option<&FsId>) -> bool {
+    if filter
+        .excluded_directories
+        .iter()
+        .any(|excluded| path.starts_with(excluded))
+    {
+        return true;
+    }
+
+    let relative_str = relative_path.to_string_lossy();
+    for pattern in &filter.excluded_globs {
+        if pattern.contains(UNSUPPORTED_GLOB_CHARS) {
+            tracing::warn!(
+                "Exclude pattern '{}' uses glob syntax this engine can't evaluate ([ ] {{ }} ! \
+                 are unsupported) -- it will not exclude anything",
+                pattern
+            );
+            continue;
+        }
+        if graphics_analyzer::glob_match(pattern, &relative_str) {
+            return true;
+        }
+    }
+
+    if filter.exclude_other_filesystems {
+        if let Some(root_id) = root_fs_id {
+            if filesystem_id(path).as_ref() != Some(root_id) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
 /// Recursively copy a directory and all its contents.
 ///
 /// This is the single source of truth for directory copying across the application.
 /// Use this instead of implementing copy logic inline.
-pub fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
+///
+/// Entries are classified instead of assumed: regular files and directories are copied as
+/// normal, symlinks are followed (bounded by [`MAX_SYMLINK_JUMPS`] and checked against the set of
+/// resolved targets already open on the current path, so a self-referential link can't spin
+/// forever), and anything else — character/block devices, FIFOs, sockets — is skipped and
+/// recorded in the returned [`CopyReport`] instead of aborting the whole copy.
+pub fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<CopyReport, CopyError> {
+    copy_dir_recursive_with_mode(src, dst, false, false, &CopyFilter::default())
+}
+
+/// Same as [`copy_dir_recursive`], but pruning anything [`CopyFilter`] excludes along the way —
+/// useful when a source tree has subdirectories (caches, other mounted volumes) that should
+/// never end up in an install.
+#[allow(dead_code)]
+pub fn copy_dir_recursive_filtered(
+    src: &Path,
+    dst: &Path,
+    filter: &CopyFilter,
+) -> Result<CopyReport, CopyError> {
+    copy_dir_recursive_with_mode(src, dst, false, false, filter)
+}
+
+/// Same as [`copy_dir_recursive`], but skips any destination file whose size and modified time
+/// already match the source instead of unconditionally overwriting it — re-applying a large
+/// graphics pack over a previous install would otherwise rewrite tens of thousands of unchanged
+/// images. Set `exact_content` to always fall back to a streaming hash comparison rather than
+/// trusting size/mtime alone. The returned report's `files_copied` and `unchanged_skipped`
+/// together give a "N updated, M unchanged" summary.
+pub fn copy_dir_recursive_incremental(
+    src: &Path,
+    dst: &Path,
+    exact_content: bool,
+) -> Result<CopyReport, CopyError> {
+    copy_dir_recursive_with_mode(src, dst, true, exact_content, &CopyFilter::default())
+}
+
+fn copy_dir_recursive_with_mode(
+    src: &Path,
+    dst: &Path,
+    incremental: bool,
+    exact_content: bool,
+    filter: &CopyFilter,
+) -> Result<CopyReport, CopyError> {
     fs::create_dir_all(dst).map_err(|e| format!("Failed to create directory {:?}: {}", dst, e))?;
 
-    for entry in WalkDir::new(src) {
-        let entry = entry.map_err(|e| format!("Failed to walk directory: {}", e))?;
+    let root_fs_id = if filter.exclude_other_filesystems {
+        filesystem_id(src)
+    } else {
+        None
+    };
+
+    let mut report = CopyReport::default();
+    let mut visited_symlinks = HashSet::new();
+    copy_dir_recursive_inner(
+        src,
+        dst,
+        src,
+        &mut visited_symlinks,
+        0,
+        &mut report,
+        incremental,
+        exact_content,
+        filter,
+        root_fs_id.as_ref(),
+    )?;
+    Ok(report)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn copy_dir_recursive_inner(
+    src: &Path,
+    dst: &Path,
+    root: &Path,
+    visited_symlinks: &mut HashSet<PathBuf>,
+    jumps: usize,
+    report: &mut CopyReport,
+    incremental: bool,
+    exact_content: bool,
+    filter: &CopyFilter,
+    root_fs_id: Option<&FsId>,
+) -> Result<(), CopyError> {
+    let entries =
+        fs::read_dir(src).map_err(|e| format!("Failed to read directory {:?}: {}", src, e))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
         let path = entry.path();
+        let relative_path = path.strip_prefix(root).unwrap_or(&path);
+        if is_excluded(&path, relative_path, filter, root_fs_id) {
+            report.skipped.push(SkippedCopyEntry {
+                path,
+                reason: "excluded by copy filter".to_string(),
+            });
+            continue;
+        }
 
-        if let Ok(rel_path) = path.strip_prefix(src) {
-            let target_path = dst.join(rel_path);
+        let target_path = dst.join(entry.file_name());
+        let file_type = entry
+            .file_type()
+            .map_err(|e| format!("Failed to stat {:?}: {}", path, e))?;
 
-            if path.is_dir() {
-                fs::create_dir_all(&target_path)
-                    .map_err(|e| format!("Failed to create directory: {}", e))?;
-            } else {
-                if let Some(parent) = target_path.parent() {
-                    fs::create_dir_all(parent)
-                        .map_err(|e| format!("Failed to create parent directory: {}", e))?;
-                }
-                fs::copy(path, &target_path).map_err(|e| format!("Failed to copy file: {}", e))?;
+        if file_type.is_symlink() {
+            copy_symlink_entry(
+                &path,
+                &target_path,
+                root,
+                visited_symlinks,
+                jumps,
+                report,
+                incremental,
+                exact_content,
+                filter,
+                root_fs_id,
+            )?;
+        } else if file_type.is_dir() {
+            fs::create_dir_all(&target_path)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+            copy_dir_recursive_inner(
+                &path,
+                &target_path,
+                root,
+                visited_symlinks,
+                jumps,
+                report,
+                incremental,
+                exact_content,
+                filter,
+                root_fs_id,
+            )?;
+        } else if file_type.is_file() {
+            copy_file_with_mode(&path, &target_path, incremental, exact_content, report)
+                .map_err(CopyError::Other)?;
+        } else {
+            report.skipped.push(SkippedCopyEntry {
+                path,
+                reason: special_entry_reason(&file_type),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies `src` to `dst`, honoring `incremental`/`exact_content` (see
+/// [`copy_dir_recursive_incremental`]) and clearing a pre-existing read-only bit on `dst` before
+/// overwriting it so a previous install's protected files don't make the copy fail, restoring
+/// that bit on the fresh copy once it lands.
+fn copy_file_with_mode(
+    src: &Path,
+    dst: &Path,
+    incremental: bool,
+    exact_content: bool,
+    report: &mut CopyReport,
+) -> Result<(), String> {
+    if incremental {
+        if let Ok(dst_meta) = fs::symlink_metadata(dst) {
+            if dst_meta.is_file() && files_match(src, dst, exact_content)? {
+                report.unchanged_skipped += 1;
+                return Ok(());
             }
         }
     }
 
+    let restore_readonly = clear_readonly_if_set(dst)?;
+    copy_file_replacing(src, dst)?;
+    if restore_readonly {
+        set_readonly(dst)?;
+    }
+    report.files_copied += 1;
     Ok(())
 }
 
+/// Copies `src` to `dst` by writing to a sibling temp file and renaming it over `dst`, rather
+/// than truncating `dst` in place the way plain `fs::copy` would. Graphics deduplication can
+/// leave unrelated files hard-linked to the same inode as `dst` (see
+/// [`crate::graphics::deduplicate_graphics`]); truncating in place would corrupt every other
+/// pack still linked to that inode, while a rename only ever replaces `dst`'s directory entry
+/// and leaves the old inode (and anything else linked to it) untouched. Returns the number of
+/// bytes copied, same as `fs::copy`.
+pub(crate) fn copy_file_replacing(src: &Path, dst: &Path) -> Result<u64, String> {
+    let tmp = dst.with_extension("fmmloader-copy-tmp");
+    let bytes = fs::copy(src, &tmp).map_err(|e| format!("Failed to copy file: {}", e))?;
+    fs::rename(&tmp, dst).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        format!("Failed to finalize copied file: {}", e)
+    })?;
+    Ok(bytes)
+}
+
+/// Cheap-first comparison of two files' content: if their sizes differ they can't match, and if
+/// both report a `modified()` time and those agree too, they're treated as the same file without
+/// reading either one. Only when that's ambiguous — `modified()` unsupported on this platform, or
+/// disagreeing despite equal length — or `exact_content` is set, falls back to comparing a
+/// streaming SHA-256 of both files' actual bytes.
+fn files_match(a: &Path, b: &Path, exact_content: bool) -> Result<bool, String> {
+    let meta_a = fs::metadata(a).map_err(|e| format!("Failed to stat {:?}: {}", a, e))?;
+    let meta_b = fs::metadata(b).map_err(|e| format!("Failed to stat {:?}: {}", b, e))?;
+
+    if meta_a.len() != meta_b.len() {
+        return Ok(false);
+    }
+
+    let mtimes_agree = matches!((meta_a.modified(), meta_b.modified()), (Ok(ta), Ok(tb)) if ta == tb);
+    if mtimes_agree && !exact_content {
+        return Ok(true);
+    }
+
+    Ok(hash_file_contents(a)? == hash_file_contents(b)?)
+}
+
+/// Streams `path` through a SHA-256 hasher and returns the raw digest bytes, without holding the
+/// whole file in memory — mirrors [`crate::import::hash_file`], which returns the hex string
+/// form for manifests instead.
+fn hash_file_contents(path: &Path) -> Result<[u8; 32], String> {
+    let mut file =
+        fs::File::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let read = file
+            .read(&mut buf)
+            .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+/// If `path` exists and is marked read-only, clears that bit so it can be overwritten, returning
+/// whether it was cleared (so the caller can restore it with [`set_readonly`] afterward).
+fn clear_readonly_if_set(path: &Path) -> Result<bool, String> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Ok(false);
+    };
+    let mut permissions = metadata.permissions();
+    if !permissions.readonly() {
+        return Ok(false);
+    }
+
+    permissions.set_readonly(false);
+    fs::set_permissions(path, permissions)
+        .map_err(|e| format!("Failed to clear read-only bit on {:?}: {}", path, e))?;
+    Ok(true)
+}
+
+/// Re-applies the read-only bit to `path`, undoing [`clear_readonly_if_set`] once the overwrite
+/// it was guarding has completed.
+fn set_readonly(path: &Path) -> Result<(), String> {
+    let mut permissions = fs::metadata(path)
+        .map_err(|e| format!("Failed to stat {:?}: {}", path, e))?
+        .permissions();
+    permissions.set_readonly(true);
+    fs::set_permissions(path, permissions)
+        .map_err(|e| format!("Failed to restore read-only bit on {:?}: {}", path, e))
+}
+
+/// Resolves the symlink at `link_path` and either copies its target file or recurses into its
+/// target directory, tracking `link_path`'s resolved destination in `visited_symlinks` for the
+/// duration so a link further down the same path that resolves back to it is caught as a loop
+/// rather than re-entered.
+#[allow(clippy::too_many_arguments)]
+fn copy_symlink_entry(
+    link_path: &Path,
+    target_path: &Path,
+    root: &Path,
+    visited_symlinks: &mut HashSet<PathBuf>,
+    jumps: usize,
+    report: &mut CopyReport,
+    incremental: bool,
+    exact_content: bool,
+    filter: &CopyFilter,
+    root_fs_id: Option<&FsId>,
+) -> Result<(), CopyError> {
+    if jumps >= MAX_SYMLINK_JUMPS {
+        return Err(CopyError::SymlinkLoop(link_path.to_path_buf(), jumps));
+    }
+
+    let resolved = fs::canonicalize(link_path)
+        .map_err(|_| CopyError::BrokenSymlink(link_path.to_path_buf()))?;
+
+    if !visited_symlinks.insert(resolved.clone()) {
+        return Err(CopyError::SymlinkLoop(link_path.to_path_buf(), jumps + 1));
+    }
+
+    let metadata =
+        fs::metadata(&resolved).map_err(|_| CopyError::BrokenSymlink(link_path.to_path_buf()))?;
+
+    let result: Result<(), CopyError> = if metadata.is_dir() {
+        fs::create_dir_all(target_path)
+            .map_err(|e| CopyError::Other(format!("Failed to create directory: {}", e)))
+            .and_then(|()| {
+                copy_dir_recursive_inner(
+                    &resolved,
+                    target_path,
+                    root,
+                    visited_symlinks,
+                    jumps + 1,
+                    report,
+                    incremental,
+                    exact_content,
+                    filter,
+                    root_fs_id,
+                )
+            })
+    } else {
+        copy_file_with_mode(&resolved, target_path, incremental, exact_content, report)
+            .map_err(CopyError::Other)
+    };
+
+    visited_symlinks.remove(&resolved);
+    result
+}
+
+/// Names the reason a non-regular, non-symlink directory entry can't be copied, for
+/// [`CopyReport::skipped`].
+#[cfg(unix)]
+pub(crate) fn special_entry_reason(file_type: &fs::FileType) -> String {
+    use std::os::unix::fs::FileTypeExt;
+    if file_type.is_char_device() {
+        "character device".to_string()
+    } else if file_type.is_block_device() {
+        "block device".to_string()
+    } else if file_type.is_fifo() {
+        "named pipe (FIFO)".to_string()
+    } else if file_type.is_socket() {
+        "unix domain socket".to_string()
+    } else {
+        "unsupported file type".to_string()
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn special_entry_reason(_file_type: &fs::FileType) -> String {
+    "unsupported file type".to_string()
+}
+
 /// Count the number of files in a directory recursively.
 pub fn count_files_in_dir(dir: &Path) -> Result<usize, String> {
     Ok(WalkDir::new(dir)
@@ -43,6 +500,28 @@ pub fn count_files_in_dir(dir: &Path) -> Result<usize, String> {
         .count())
 }
 
+/// Same as [`count_files_in_dir`], but pruning anything `filter` excludes — so a dry-run file
+/// count matches what a subsequent [`copy_dir_recursive_filtered`] would actually copy, instead
+/// of counting entries that never make it into the destination.
+#[allow(dead_code)]
+pub fn count_files_in_dir_filtered(dir: &Path, filter: &CopyFilter) -> Result<usize, String> {
+    let root_fs_id = if filter.exclude_other_filesystems {
+        filesystem_id(dir)
+    } else {
+        None
+    };
+
+    Ok(WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|entry| {
+            let relative_path = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+            !is_excluded(entry.path(), relative_path, filter, root_fs_id.as_ref())
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .count())
+}
+
 /// Find the actual graphics content root in an extracted directory.
 ///
 /// Skips wrapper folders and finds where faces/, logos/, kits/ actually live.
@@ -0,0 +1,182 @@
+//! Game-file verification and repair — confirms the configured game target still looks like a
+//! real FM26 install, checks each enabled mod's tracked files are still where its install
+//! receipt says they should be, and offers a way back to a known-good state when they're not.
+//! Loosely mirrors FlightCore's `repair_and_verify`/"disable all but core" flows.
+
+use crate::config::{load_config, load_ownership_index, save_config};
+use crate::messages::{code_error, code_only, CODE_GAME_TARGET_INVALID, CODE_GAME_TARGET_NOT_SET};
+use crate::mod_manager::{install_mod, read_receipt};
+use crate::name_fix::get_db_dir;
+use crate::restore::{list_restore_points, rollback_to_restore_point};
+use crate::types::{
+    GameTargetMarker, GameTargetVerifyReport, ModFileVerifyEntry, ModFileVerifyStatus,
+    ModVerifyReport,
+};
+use std::path::PathBuf;
+
+/// Confirms the configured `target_path` actually contains an FM26 install, not just that the
+/// directory exists (all [`crate::mod_manager::install_mod`] itself checks). Reports each marker
+/// individually so the UI can tell the user exactly what's missing rather than a single
+/// pass/fail.
+#[tauri::command]
+pub fn verify_game_target() -> Result<GameTargetVerifyReport, String> {
+    let config = load_config()?;
+    let target_path = config
+        .target_path
+        .clone()
+        .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
+
+    let target = PathBuf::from(&target_path);
+    if !target.exists() {
+        return Err(code_error(
+            CODE_GAME_TARGET_INVALID,
+            "Game target path does not exist",
+        ));
+    }
+
+    let mut markers = vec![GameTargetMarker {
+        description: "StreamingAssets target directory exists".to_string(),
+        path: target_path.clone(),
+        present: true,
+    }];
+
+    let db_dir = get_db_dir(Some(&target_path));
+    markers.push(GameTargetMarker {
+        description: "FM26 database directory (shared/data/database/db)".to_string(),
+        path: db_dir
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        present: db_dir.is_ok(),
+    });
+
+    let valid = markers.iter().all(|m| m.present);
+
+    Ok(GameTargetVerifyReport {
+        target_path,
+        markers,
+        valid,
+    })
+}
+
+/// Walks every enabled mod's install receipt and reports, per tracked file, whether it's still
+/// present at `resolved_path` and still attributed to that mod in the ownership index — or
+/// missing, or silently overwritten by a later install of a different mod.
+#[tauri::command]
+pub fn verify_installed_mods() -> Result<Vec<ModVerifyReport>, String> {
+    let config = load_config()?;
+    let ownership_index = load_ownership_index().unwrap_or_default();
+
+    let mut reports = Vec::with_capacity(config.enabled_mods.len());
+
+    for mod_name in &config.enabled_mods {
+        let entries = read_receipt(mod_name)?.map(|receipt| {
+            receipt
+                .entries
+                .into_iter()
+                .map(|entry| {
+                    let exists = entry.resolved_path.exists();
+                    let owner = ownership_index
+                        .owners
+                        .get(&entry.resolved_path.to_string_lossy().to_string())
+                        .cloned();
+                    let overwritten_by = match owner {
+                        Some(owner) if &owner != mod_name => Some(owner),
+                        _ => None,
+                    };
+
+                    ModFileVerifyEntry {
+                        target_subpath: entry.target_subpath,
+                        resolved_path: entry.resolved_path,
+                        status: if exists {
+                            ModFileVerifyStatus::Ok
+                        } else {
+                            ModFileVerifyStatus::Missing
+                        },
+                        overwritten_by,
+                    }
+                })
+                .collect()
+        });
+
+        reports.push(ModVerifyReport {
+            mod_name: mod_name.clone(),
+            entries,
+        });
+    }
+
+    Ok(reports)
+}
+
+/// Reinstalls every enabled mod whose [`verify_installed_mods`] report isn't fully healthy, by
+/// replaying [`crate::mod_manager::install_mod`] — the same path `apply_mods` uses, so a repair
+/// is just a targeted re-apply rather than a separate code path.
+#[tauri::command]
+pub fn repair_mods() -> Result<String, String> {
+    let config = load_config()?;
+    let target_path = config
+        .target_path
+        .as_ref()
+        .ok_or_else(|| code_only(CODE_GAME_TARGET_NOT_SET))?;
+
+    let target = PathBuf::from(target_path);
+    if !target.exists() {
+        return Err(code_error(
+            CODE_GAME_TARGET_INVALID,
+            "Game target path does not exist",
+        ));
+    }
+
+    let reports = verify_installed_mods()?;
+    let mut results = Vec::new();
+
+    for report in reports {
+        if report.is_healthy() {
+            continue;
+        }
+
+        match install_mod(
+            &report.mod_name,
+            &target,
+            config.user_dir_path.as_deref(),
+            config.default_install_mode,
+            config.default_backup_mode,
+            config.backup_compression,
+            config.backup_compression_level,
+        ) {
+            Ok(msg) => results.push(format!("Repaired {}: {}", report.mod_name, msg)),
+            Err(e) => results.push(format!("Failed to repair {}: {}", report.mod_name, e)),
+        }
+    }
+
+    if results.is_empty() {
+        return Ok("All enabled mods verified OK; nothing to repair".to_string());
+    }
+
+    Ok(results.join("\n"))
+}
+
+/// Panic button: disables every currently-enabled mod and rolls back to the most recent restore
+/// point, mirroring FlightCore's "disable all but core" escape hatch for a game left in a broken
+/// state.
+#[tauri::command]
+pub fn disable_all_mods_and_restore() -> Result<String, String> {
+    let mut config = load_config()?;
+    let disabled = std::mem::take(&mut config.enabled_mods);
+    save_config(&config)?;
+
+    let points = list_restore_points()?;
+    let latest = points
+        .first()
+        .ok_or("No restore point available to roll back to")?;
+
+    let rollback_result = rollback_to_restore_point(&latest.path)?;
+
+    Ok(format!(
+        "Disabled {} mod(s) ({}) and rolled back to restore point '{}': {}",
+        disabled.len(),
+        disabled.join(", "),
+        latest.name,
+        rollback_result
+    ))
+}